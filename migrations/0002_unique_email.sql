@@ -0,0 +1,3 @@
+-- メールアドレスは正規化した値で一意にする
+-- 既存の値はアプリケーションと同じ規則で正規化できるよう、このファイルの前にRustで正規化する
+CREATE UNIQUE INDEX users_email ON users (email);
@@ -0,0 +1,12 @@
+CREATE TABLE revoked_tokens (
+    jti TEXT PRIMARY KEY,
+    expires_at TEXT NOT NULL
+);
+
+CREATE INDEX revoked_tokens_expires_at ON revoked_tokens (expires_at);
+
+-- revoked_before以前に発行されたユーザーのトークンはすべて無効
+CREATE TABLE user_revocations (
+    user_id INTEGER PRIMARY KEY REFERENCES users (id),
+    revoked_before TEXT NOT NULL
+);
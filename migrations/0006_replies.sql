@@ -0,0 +1,8 @@
+-- 返信は親の投稿と一緒に削除する
+ALTER TABLE posts ADD COLUMN parent_id TEXT REFERENCES posts (id) ON DELETE CASCADE;
+
+-- 既存の投稿は移行した時刻を最終アクティビティとする
+ALTER TABLE posts ADD COLUMN last_activity_at TEXT NOT NULL DEFAULT '1970-01-01 00:00:00+00:00';
+UPDATE posts SET last_activity_at = strftime('%Y-%m-%d %H:%M:%f+00:00', 'now');
+
+CREATE INDEX posts_parent_id ON posts (parent_id);
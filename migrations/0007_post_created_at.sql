@@ -0,0 +1,5 @@
+-- 既存の投稿は最終アクティビティの日時を作成日時とする
+ALTER TABLE posts ADD COLUMN created_at TEXT NOT NULL DEFAULT '1970-01-01 00:00:00+00:00';
+UPDATE posts SET created_at = last_activity_at;
+
+CREATE INDEX posts_created_at ON posts (created_at);
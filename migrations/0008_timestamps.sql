@@ -0,0 +1,9 @@
+-- 既存のユーザーは移行した時刻を登録日時とする
+ALTER TABLE users ADD COLUMN created_at TEXT NOT NULL DEFAULT '1970-01-01 00:00:00+00:00';
+UPDATE users SET created_at = strftime('%Y-%m-%d %H:%M:%f+00:00', 'now');
+ALTER TABLE users ADD COLUMN last_login_at TEXT;
+
+-- 既存の投稿は編集されていないものとして扱う
+ALTER TABLE posts ADD COLUMN updated_at TEXT NOT NULL DEFAULT '1970-01-01 00:00:00+00:00';
+UPDATE posts SET updated_at = created_at;
+ALTER TABLE posts ADD COLUMN edited_count INTEGER NOT NULL DEFAULT 0;
@@ -0,0 +1,12 @@
+-- 全文検索の転置索引。語への分割はアプリケーションで行う
+CREATE TABLE post_terms (
+    term TEXT NOT NULL,
+    post_id TEXT NOT NULL REFERENCES posts (id) ON DELETE CASCADE,
+    weight INTEGER NOT NULL,
+    PRIMARY KEY (term, post_id)
+) WITHOUT ROWID;
+
+CREATE INDEX post_terms_post_id ON post_terms (post_id);
+
+-- 索引を作った版。起動時に古い版の投稿を索引し直す
+ALTER TABLE posts ADD COLUMN search_version INTEGER NOT NULL DEFAULT 0;
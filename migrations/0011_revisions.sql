@@ -0,0 +1,14 @@
+-- 投稿の版。作成時の内容を版1とし、編集するたびに記録する
+CREATE TABLE post_revisions (
+    post_id TEXT NOT NULL REFERENCES posts (id) ON DELETE CASCADE,
+    number INTEGER NOT NULL,
+    title TEXT NOT NULL,
+    content TEXT NOT NULL,
+    edited_by INTEGER NOT NULL REFERENCES users (id),
+    created_at TEXT NOT NULL,
+    PRIMARY KEY (post_id, number)
+);
+
+-- 既存の投稿は以前の内容が残っていないため、現在の内容を最新の版とする
+INSERT INTO post_revisions (post_id, number, title, content, edited_by, created_at)
+SELECT id, edited_count + 1, title, content, user_id, updated_at FROM posts;
@@ -0,0 +1,2 @@
+-- 楽観的排他制御に使う版。既存の投稿は版1から始める
+ALTER TABLE posts ADD COLUMN version INTEGER NOT NULL DEFAULT 1;
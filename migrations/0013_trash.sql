@@ -0,0 +1,5 @@
+-- ゴミ箱に移された日時と移した利用者。ゴミ箱にない投稿ではNULL
+ALTER TABLE posts ADD COLUMN deleted_at TEXT;
+ALTER TABLE posts ADD COLUMN deleted_by INTEGER REFERENCES users (id);
+
+CREATE INDEX posts_deleted_at ON posts (deleted_at);
@@ -0,0 +1,5 @@
+-- 投稿の公開状態と公開予定の日時。既存の投稿は公開済みとする
+ALTER TABLE posts ADD COLUMN status TEXT NOT NULL DEFAULT 'published';
+ALTER TABLE posts ADD COLUMN publish_at TEXT;
+
+CREATE INDEX posts_publish_at ON posts (status, publish_at);
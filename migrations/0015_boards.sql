@@ -0,0 +1,24 @@
+CREATE TABLE boards (
+    id TEXT PRIMARY KEY,
+    slug TEXT NOT NULL UNIQUE,
+    name TEXT NOT NULL,
+    description TEXT,
+    posting_role TEXT NOT NULL,
+    allow_replies INTEGER NOT NULL,
+    max_post_length INTEGER NOT NULL,
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL
+);
+
+-- 既存の投稿は移行した時刻に作成したボードに属するものとする
+INSERT INTO boards
+    (id, slug, name, description, posting_role, allow_replies, max_post_length, created_at,
+     updated_at)
+VALUES
+    ('5d0f3a4e-2c1b-4e7a-9a43-6f1d2b8c0e17', 'general', 'General', NULL, 'member', 1, 10000,
+     strftime('%Y-%m-%d %H:%M:%f+00:00', 'now'), strftime('%Y-%m-%d %H:%M:%f+00:00', 'now'));
+
+ALTER TABLE posts ADD COLUMN board_id TEXT REFERENCES boards (id);
+UPDATE posts SET board_id = '5d0f3a4e-2c1b-4e7a-9a43-6f1d2b8c0e17';
+
+CREATE INDEX posts_board_id ON posts (board_id);
@@ -0,0 +1,402 @@
+use async_trait::async_trait;
+use axum::extract::*;
+use axum_extra::extract::{CookieJar, Multipart};
+use bytes::Bytes;
+use http::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{models, types::*};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum AdminBoardsPostResponse {
+    /// ボードを作成しました
+    Status201
+    (models::Board)
+    ,
+    /// 認証されていません
+    Status401
+    {
+        www_authenticate:
+        String
+    }
+    ,
+    /// 権限がありません
+    Status403
+    ,
+    /// スラッグは既に使われています
+    Status409
+}
+
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum AdminBoardsSlugDeleteResponse {
+    /// 削除しました
+    Status204
+    ,
+    /// 認証されていません
+    Status401
+    {
+        www_authenticate:
+        String
+    }
+    ,
+    /// 権限がありません
+    Status403
+    ,
+    /// ボードが見つかりません
+    Status404
+    ,
+    /// ボードに投稿があります
+    Status409
+}
+
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum AdminBoardsSlugPutResponse {
+    /// ボードを更新しました
+    Status200
+    (models::Board)
+    ,
+    /// 認証されていません
+    Status401
+    {
+        www_authenticate:
+        String
+    }
+    ,
+    /// 権限がありません
+    Status403
+    ,
+    /// ボードが見つかりません
+    Status404
+    ,
+    /// スラッグは既に使われています
+    Status409
+}
+
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum AdminUsersUserIdRoleDeleteResponse {
+    /// Role updated
+    Status200_RoleUpdated
+    (models::User)
+    ,
+    /// 認証されていません
+    Status401
+    {
+        www_authenticate:
+        String
+    }
+    ,
+    /// 権限がありません
+    Status403
+    ,
+    /// ユーザーが見つかりません
+    Status404
+}
+
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum AdminUsersUserIdRolePutResponse {
+    /// Role updated
+    Status200_RoleUpdated
+    (models::User)
+    ,
+    /// 認証されていません
+    Status401
+    {
+        www_authenticate:
+        String
+    }
+    ,
+    /// 権限がありません
+    Status403
+    ,
+    /// ユーザーが見つかりません
+    Status404
+}
+
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum AdminWebhookDeliveriesDeliveryIdRetryPostResponse {
+    /// 再試行を予定しました
+    Status200
+    (models::WebhookDelivery)
+    ,
+    /// 認証されていません
+    Status401
+    {
+        www_authenticate:
+        String
+    }
+    ,
+    /// 権限がありません
+    Status403
+    ,
+    /// 配信が見つかりません
+    Status404
+    ,
+    /// 配信はデッドレターではありません
+    Status409
+}
+
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum AdminWebhookDeliveriesGetResponse {
+    /// 配信の一覧
+    Status200
+    (Vec<models::WebhookDelivery>)
+    ,
+    /// 認証されていません
+    Status401
+    {
+        www_authenticate:
+        String
+    }
+    ,
+    /// 権限がありません
+    Status403
+}
+
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum AdminWebhooksGetResponse {
+    /// Webhookの一覧
+    Status200
+    (Vec<models::Webhook>)
+    ,
+    /// 認証されていません
+    Status401
+    {
+        www_authenticate:
+        String
+    }
+    ,
+    /// 権限がありません
+    Status403
+}
+
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum AdminWebhooksPostResponse {
+    /// Webhookを登録しました
+    Status201
+    (models::Webhook)
+    ,
+    /// 認証されていません
+    Status401
+    {
+        www_authenticate:
+        String
+    }
+    ,
+    /// 権限がありません
+    Status403
+}
+
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum AdminWebhooksWebhookIdDeleteResponse {
+    /// 削除しました
+    Status204
+    ,
+    /// 認証されていません
+    Status401
+    {
+        www_authenticate:
+        String
+    }
+    ,
+    /// 権限がありません
+    Status403
+    ,
+    /// Webhookが見つかりません
+    Status404
+}
+
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum AdminWebhooksWebhookIdDeliveriesGetResponse {
+    /// 配信の一覧
+    Status200
+    (Vec<models::WebhookDelivery>)
+    ,
+    /// 認証されていません
+    Status401
+    {
+        www_authenticate:
+        String
+    }
+    ,
+    /// 権限がありません
+    Status403
+    ,
+    /// Webhookが見つかりません
+    Status404
+}
+
+
+
+
+/// Admin
+#[async_trait]
+#[allow(clippy::ptr_arg)]
+pub trait Admin {
+    type Error: super::ApiError;
+
+    /// ボードを作成.
+    ///
+    /// AdminBoardsPost - POST /admin/boards
+    async fn admin_boards_post(
+    &self,
+    method: Method,
+    host: Host,
+    cookies: CookieJar,
+      header_params: models::AdminBoardsPostHeaderParams,
+            body: Option<models::AdminBoardsPostRequest>,
+    ) -> Result<AdminBoardsPostResponse, Self::Error>;
+
+    /// ボードを削除.
+    ///
+    /// AdminBoardsSlugDelete - DELETE /admin/boards/{slug}
+    async fn admin_boards_slug_delete(
+    &self,
+    method: Method,
+    host: Host,
+    cookies: CookieJar,
+      header_params: models::AdminBoardsSlugDeleteHeaderParams,
+      path_params: models::AdminBoardsSlugDeletePathParams,
+    ) -> Result<AdminBoardsSlugDeleteResponse, Self::Error>;
+
+    /// ボードを更新.
+    ///
+    /// AdminBoardsSlugPut - PUT /admin/boards/{slug}
+    async fn admin_boards_slug_put(
+    &self,
+    method: Method,
+    host: Host,
+    cookies: CookieJar,
+      header_params: models::AdminBoardsSlugPutHeaderParams,
+      path_params: models::AdminBoardsSlugPutPathParams,
+            body: Option<models::AdminBoardsPostRequest>,
+    ) -> Result<AdminBoardsSlugPutResponse, Self::Error>;
+
+    /// ロールを取り消し.
+    ///
+    /// AdminUsersUserIdRoleDelete - DELETE /admin/users/{userId}/role
+    async fn admin_users_user_id_role_delete(
+    &self,
+    method: Method,
+    host: Host,
+    cookies: CookieJar,
+      header_params: models::AdminUsersUserIdRoleDeleteHeaderParams,
+      path_params: models::AdminUsersUserIdRoleDeletePathParams,
+    ) -> Result<AdminUsersUserIdRoleDeleteResponse, Self::Error>;
+
+    /// ロールを付与.
+    ///
+    /// AdminUsersUserIdRolePut - PUT /admin/users/{userId}/role
+    async fn admin_users_user_id_role_put(
+    &self,
+    method: Method,
+    host: Host,
+    cookies: CookieJar,
+      header_params: models::AdminUsersUserIdRolePutHeaderParams,
+      path_params: models::AdminUsersUserIdRolePutPathParams,
+            body: Option<models::AdminUsersUserIdRolePutRequest>,
+    ) -> Result<AdminUsersUserIdRolePutResponse, Self::Error>;
+
+    /// デッドレターを再試行.
+    ///
+    /// AdminWebhookDeliveriesDeliveryIdRetryPost - POST /admin/webhook-deliveries/{deliveryId}/retry
+    async fn admin_webhook_deliveries_delivery_id_retry_post(
+    &self,
+    method: Method,
+    host: Host,
+    cookies: CookieJar,
+      header_params: models::AdminWebhookDeliveriesDeliveryIdRetryPostHeaderParams,
+      path_params: models::AdminWebhookDeliveriesDeliveryIdRetryPostPathParams,
+    ) -> Result<AdminWebhookDeliveriesDeliveryIdRetryPostResponse, Self::Error>;
+
+    /// すべてのWebhookの配信の記録.
+    ///
+    /// AdminWebhookDeliveriesGet - GET /admin/webhook-deliveries
+    async fn admin_webhook_deliveries_get(
+    &self,
+    method: Method,
+    host: Host,
+    cookies: CookieJar,
+      header_params: models::AdminWebhookDeliveriesGetHeaderParams,
+      query_params: models::AdminWebhookDeliveriesGetQueryParams,
+    ) -> Result<AdminWebhookDeliveriesGetResponse, Self::Error>;
+
+    /// Webhookの一覧.
+    ///
+    /// AdminWebhooksGet - GET /admin/webhooks
+    async fn admin_webhooks_get(
+    &self,
+    method: Method,
+    host: Host,
+    cookies: CookieJar,
+      header_params: models::AdminWebhooksGetHeaderParams,
+    ) -> Result<AdminWebhooksGetResponse, Self::Error>;
+
+    /// Webhookを登録.
+    ///
+    /// AdminWebhooksPost - POST /admin/webhooks
+    async fn admin_webhooks_post(
+    &self,
+    method: Method,
+    host: Host,
+    cookies: CookieJar,
+      header_params: models::AdminWebhooksPostHeaderParams,
+            body: Option<models::AdminWebhooksPostRequest>,
+    ) -> Result<AdminWebhooksPostResponse, Self::Error>;
+
+    /// Webhookを削除.
+    ///
+    /// AdminWebhooksWebhookIdDelete - DELETE /admin/webhooks/{webhookId}
+    async fn admin_webhooks_webhook_id_delete(
+    &self,
+    method: Method,
+    host: Host,
+    cookies: CookieJar,
+      header_params: models::AdminWebhooksWebhookIdDeleteHeaderParams,
+      path_params: models::AdminWebhooksWebhookIdDeletePathParams,
+    ) -> Result<AdminWebhooksWebhookIdDeleteResponse, Self::Error>;
+
+    /// Webhookの配信の記録.
+    ///
+    /// AdminWebhooksWebhookIdDeliveriesGet - GET /admin/webhooks/{webhookId}/deliveries
+    async fn admin_webhooks_webhook_id_deliveries_get(
+    &self,
+    method: Method,
+    host: Host,
+    cookies: CookieJar,
+      header_params: models::AdminWebhooksWebhookIdDeliveriesGetHeaderParams,
+      path_params: models::AdminWebhooksWebhookIdDeliveriesGetPathParams,
+      query_params: models::AdminWebhooksWebhookIdDeliveriesGetQueryParams,
+    ) -> Result<AdminWebhooksWebhookIdDeliveriesGetResponse, Self::Error>;
+}
@@ -7,6 +7,38 @@ use serde::{Deserialize, Serialize};
 
 use crate::{models, types::*};
 
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum AuthLogoutAllPostResponse {
+    /// すべてのセッションからログアウトしました
+    Status204
+    ,
+    /// 認証されていません
+    Status401
+    {
+        www_authenticate:
+        String
+    }
+}
+
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum AuthLogoutPostResponse {
+    /// ログアウトしました
+    Status204
+    ,
+    /// 認証されていません
+    Status401
+    {
+        www_authenticate:
+        String
+    }
+}
+
+
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
 #[must_use]
 #[allow(clippy::large_enum_variant)]
@@ -15,8 +47,35 @@ pub enum AuthPostResponse {
     Status200_AuthenticationSuccessful
     (models::Token)
     ,
-    /// リクエストが不正です
-    Status400
+    /// 失敗が続いたため一時的にロックされています
+    Status429
+    {
+        retry_after:
+        i64
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum AuthRefreshPostResponse {
+    /// Authentication successful, token returned
+    Status200_AuthenticationSuccessful
+    (models::Token)
+    ,
+    /// リフレッシュトークンが無効です
+    Status401
+}
+
+
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum GetJwksResponse {
+    /// 公開鍵の一覧
+    Status200
+    (models::JwkSet)
 }
 
 
@@ -24,6 +83,31 @@ pub enum AuthPostResponse {
 #[async_trait]
 #[allow(clippy::ptr_arg)]
 pub trait Auth {
+    type Error: super::ApiError;
+
+    /// すべてのセッションからログアウト.
+    ///
+    /// AuthLogoutAllPost - POST /auth/logout-all
+    async fn auth_logout_all_post(
+    &self,
+    method: Method,
+    host: Host,
+    cookies: CookieJar,
+      header_params: models::AuthLogoutAllPostHeaderParams,
+    ) -> Result<AuthLogoutAllPostResponse, Self::Error>;
+
+    /// ログアウト.
+    ///
+    /// AuthLogoutPost - POST /auth/logout
+    async fn auth_logout_post(
+    &self,
+    method: Method,
+    host: Host,
+    cookies: CookieJar,
+      header_params: models::AuthLogoutPostHeaderParams,
+            body: Option<models::AuthLogoutPostRequest>,
+    ) -> Result<AuthLogoutPostResponse, Self::Error>;
+
     /// ユーザー認証.
     ///
     /// AuthPost - POST /auth
@@ -32,6 +116,28 @@ pub trait Auth {
     method: Method,
     host: Host,
     cookies: CookieJar,
+      header_params: models::AuthPostHeaderParams,
             body: Option<models::Auth>,
-    ) -> Result<AuthPostResponse, String>;
+    ) -> Result<AuthPostResponse, Self::Error>;
+
+    /// アクセストークンの再発行.
+    ///
+    /// AuthRefreshPost - POST /auth/refresh
+    async fn auth_refresh_post(
+    &self,
+    method: Method,
+    host: Host,
+    cookies: CookieJar,
+            body: Option<models::AuthRefreshPostRequest>,
+    ) -> Result<AuthRefreshPostResponse, Self::Error>;
+
+    /// トークン検証用の公開鍵を取得.
+    ///
+    /// GetJwks - GET /.well-known/jwks.json
+    async fn get_jwks(
+    &self,
+    method: Method,
+    host: Host,
+    cookies: CookieJar,
+    ) -> Result<GetJwksResponse, Self::Error>;
 }
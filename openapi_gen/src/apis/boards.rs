@@ -0,0 +1,120 @@
+use async_trait::async_trait;
+use axum::extract::*;
+use axum_extra::extract::{CookieJar, Multipart};
+use bytes::Bytes;
+use http::Method;
+use serde::{Deserialize, Serialize};
+
+use crate::{models, types::*};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum BoardsGetResponse {
+    /// ボードの一覧
+    Status200
+    (Vec<models::Board>)
+    ,
+    /// 認証されていません
+    Status401
+    {
+        www_authenticate:
+        String
+    }
+}
+
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum BoardsSlugGetResponse {
+    /// ボード
+    Status200
+    (models::Board)
+    ,
+    /// 認証されていません
+    Status401
+    {
+        www_authenticate:
+        String
+    }
+    ,
+    /// ボードが見つかりません
+    Status404
+}
+
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum BoardsSlugPostsGetResponse {
+    /// List of posts
+    Status200_ListOfPosts
+    {
+        body: Vec<models::Post>,
+        link:
+        Option<
+        String
+        >,
+        x_next_cursor:
+        Option<
+        String
+        >
+    }
+    ,
+    /// 認証されていません
+    Status401
+    {
+        www_authenticate:
+        String
+    }
+    ,
+    /// ボードが見つかりません
+    Status404
+}
+
+
+
+
+/// Boards
+#[async_trait]
+#[allow(clippy::ptr_arg)]
+pub trait Boards {
+    type Error: super::ApiError;
+
+    /// ボードの一覧.
+    ///
+    /// BoardsGet - GET /boards
+    async fn boards_get(
+    &self,
+    method: Method,
+    host: Host,
+    cookies: CookieJar,
+      header_params: models::BoardsGetHeaderParams,
+    ) -> Result<BoardsGetResponse, Self::Error>;
+
+    /// ボードを取得.
+    ///
+    /// BoardsSlugGet - GET /boards/{slug}
+    async fn boards_slug_get(
+    &self,
+    method: Method,
+    host: Host,
+    cookies: CookieJar,
+      header_params: models::BoardsSlugGetHeaderParams,
+      path_params: models::BoardsSlugGetPathParams,
+    ) -> Result<BoardsSlugGetResponse, Self::Error>;
+
+    /// ボードの投稿を取得.
+    ///
+    /// BoardsSlugPostsGet - GET /boards/{slug}/posts
+    async fn boards_slug_posts_get(
+    &self,
+    method: Method,
+    host: Host,
+    cookies: CookieJar,
+      header_params: models::BoardsSlugPostsGetHeaderParams,
+      path_params: models::BoardsSlugPostsGetPathParams,
+      query_params: models::BoardsSlugPostsGetQueryParams,
+    ) -> Result<BoardsSlugPostsGetResponse, Self::Error>;
+}
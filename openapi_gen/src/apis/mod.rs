@@ -1,4 +1,11 @@
+pub mod admin;
 pub mod auth;
+pub mod boards;
 pub mod posts;
 pub mod users;
 
+
+/// APIの実装が返すエラー。サーバーは`application/problem+json` (RFC 7807) で応答する
+pub trait ApiError: std::fmt::Display + Send {
+    fn problem(&self) -> crate::models::Problem;
+}
@@ -10,16 +10,61 @@ use crate::{models, types::*};
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
 #[must_use]
 #[allow(clippy::large_enum_variant)]
-pub enum PostsGetResponse {
+pub enum MeDraftsGetResponse {
     /// List of posts
     Status200_ListOfPosts
     (Vec<models::Post>)
     ,
-    /// リクエストが不正です
-    Status400
+    /// 認証されていません
+    Status401
+    {
+        www_authenticate:
+        String
+    }
+}
+
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum MeTrashGetResponse {
+    /// List of posts
+    Status200_ListOfPosts
+    (Vec<models::Post>)
+    ,
+    /// 認証されていません
+    Status401
+    {
+        www_authenticate:
+        String
+    }
+}
+
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum PostsGetResponse {
+    /// List of posts
+    Status200_ListOfPosts
+    {
+        body: Vec<models::Post>,
+        link:
+        Option<
+        String
+        >,
+        x_next_cursor:
+        Option<
+        String
+        >
+    }
     ,
     /// 認証されていません
     Status401
+    {
+        www_authenticate:
+        String
+    }
 }
 
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
@@ -30,11 +75,15 @@ pub enum PostsPostResponse {
     Status201_PostCreated
     (models::Post)
     ,
-    /// リクエストが不正です
-    Status400
-    ,
     /// 認証されていません
     Status401
+    {
+        www_authenticate:
+        String
+    }
+    ,
+    /// 権限がありません
+    Status403
 }
 
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
@@ -44,58 +93,392 @@ pub enum PostsPostIdDeleteResponse {
     /// No Content
     Status204_NoContent
     ,
-    /// Bad Request
-    Status400_BadRequest
-    ,
     /// Unauthorized
     Status401_Unauthorized
+    {
+        www_authenticate:
+        String
+    }
+    ,
+    /// Forbidden
+    Status403_Forbidden
     ,
     /// Not Found
     Status404_NotFound
+    ,
+    /// Precondition Failed
+    Status412_PreconditionFailed
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum PostsPostIdDiffGetResponse {
+    /// 差分
+    Status200
+    (models::RevisionDiff)
+    ,
+    /// 認証されていません
+    Status401
+    {
+        www_authenticate:
+        String
+    }
+    ,
+    /// 投稿または版が見つかりません
+    Status404
 }
 
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
 #[must_use]
 #[allow(clippy::large_enum_variant)]
 pub enum PostsPostIdGetResponse {
+    /// Post created/updated successfully, tagged with its version
+    Status200_PostCreated
+    {
+        body: models::Post,
+        e_tag:
+        String
+    }
+    ,
+    /// 変更されていません
+    Status304
+    {
+        e_tag:
+        String
+    }
+    ,
+    /// 認証されていません
+    Status401
+    {
+        www_authenticate:
+        String
+    }
+    ,
+    /// 投稿が見つかりません
+    Status404
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum PostsPostIdModerationPutResponse {
     /// Post created/updated successfully
     Status200_PostCreated
     (models::Post)
     ,
-    /// リクエストが不正です
-    Status400
+    /// 認証されていません
+    Status401
+    {
+        www_authenticate:
+        String
+    }
+    ,
+    /// 権限がありません
+    Status403
+    ,
+    /// 投稿が見つかりません
+    Status404
+    ,
+    /// 投稿が他の更新で変更されています
+    Status412
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum PostsPostIdPatchResponse {
+    /// Post created/updated successfully, tagged with its version
+    Status200_PostCreated
+    {
+        body: models::Post,
+        e_tag:
+        String
+    }
+    ,
+    /// 認証されていません
+    Status401
+    {
+        www_authenticate:
+        String
+    }
+    ,
+    /// 権限がありません
+    Status403
+    ,
+    /// 投稿が見つかりません
+    Status404
+    ,
+    /// 投稿が他の更新で変更されています
+    Status412
+}
+
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum PostsPostIdPublishPostResponse {
+    /// Post created/updated successfully, tagged with its version
+    Status200_PostCreated
+    {
+        body: models::Post,
+        e_tag:
+        String
+    }
+    ,
+    /// 認証されていません
+    Status401
+    {
+        www_authenticate:
+        String
+    }
+    ,
+    /// 権限がありません
+    Status403
+    ,
+    /// 投稿が見つかりません
+    Status404
+    ,
+    /// 投稿は公開済みです
+    Status409
+    ,
+    /// 投稿が他の更新で変更されています
+    Status412
+}
+
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum PostsPostIdPurgePostResponse {
+    /// 削除しました
+    Status204
     ,
     /// 認証されていません
     Status401
+    {
+        www_authenticate:
+        String
+    }
+    ,
+    /// 権限がありません
+    Status403
     ,
     /// 投稿が見つかりません
     Status404
 }
 
+
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
 #[must_use]
 #[allow(clippy::large_enum_variant)]
 pub enum PostsPostIdPutResponse {
-    /// Post created/updated successfully
+    /// Post created/updated successfully, tagged with its version
     Status200_PostCreated
+    {
+        body: models::Post,
+        e_tag:
+        String
+    }
+    ,
+    /// 認証されていません
+    Status401
+    {
+        www_authenticate:
+        String
+    }
+    ,
+    /// 権限がありません
+    Status403
+    ,
+    /// 投稿が見つかりません
+    Status404
+    ,
+    /// 投稿が他の更新で変更されています
+    Status412
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum PostsPostIdRepliesGetResponse {
+    /// List of posts
+    Status200_ListOfPosts
+    (Vec<models::Post>)
+    ,
+    /// 認証されていません
+    Status401
+    {
+        www_authenticate:
+        String
+    }
+    ,
+    /// 投稿が見つかりません
+    Status404
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum PostsPostIdRepliesPostResponse {
+    /// Post created/updated successfully
+    Status201_PostCreated
     (models::Post)
     ,
-    /// リクエストが不正です
-    Status400
+    /// 認証されていません
+    Status401
+    {
+        www_authenticate:
+        String
+    }
+    ,
+    /// 権限がありません
+    Status403
+    ,
+    /// 投稿が見つかりません
+    Status404
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum PostsPostIdRestorePostResponse {
+    /// Post created/updated successfully, tagged with its version
+    Status200_PostCreated
+    {
+        body: models::Post,
+        e_tag:
+        String
+    }
+    ,
+    /// 認証されていません
+    Status401
+    {
+        www_authenticate:
+        String
+    }
+    ,
+    /// 権限がありません
+    Status403
+    ,
+    /// ゴミ箱に投稿が見つかりません
+    Status404
+}
+
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum PostsPostIdRevisionsGetResponse {
+    /// 版の一覧
+    Status200
+    (Vec<models::PostRevision>)
     ,
     /// 認証されていません
     Status401
+    {
+        www_authenticate:
+        String
+    }
     ,
     /// 投稿が見つかりません
     Status404
 }
 
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum PostsPostIdRevisionsNumberGetResponse {
+    /// 版
+    Status200
+    (models::PostRevision)
+    ,
+    /// 認証されていません
+    Status401
+    {
+        www_authenticate:
+        String
+    }
+    ,
+    /// 投稿または版が見つかりません
+    Status404
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum PostsPostIdRevisionsNumberRevertPostResponse {
+    /// Post created/updated successfully
+    Status200_PostCreated
+    (models::Post)
+    ,
+    /// 認証されていません
+    Status401
+    {
+        www_authenticate:
+        String
+    }
+    ,
+    /// 権限がありません
+    Status403
+    ,
+    /// 投稿または版が見つかりません
+    Status404
+    ,
+    /// 投稿が他の更新で変更されています
+    Status412
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[must_use]
+#[allow(clippy::large_enum_variant)]
+pub enum PostsSearchGetResponse {
+    /// 検索結果
+    Status200
+    (Vec<models::PostSearchResult>)
+    ,
+    /// 認証されていません
+    Status401
+    {
+        www_authenticate:
+        String
+    }
+}
+
+
+
 
 /// Posts
 #[async_trait]
 #[allow(clippy::ptr_arg)]
 pub trait Posts {
-    /// すべての投稿を取得.
+    type Error: super::ApiError;
+
+    /// 公開前の投稿を取得.
+    ///
+    /// MeDraftsGet - GET /me/drafts
+    async fn me_drafts_get(
+    &self,
+    method: Method,
+    host: Host,
+    cookies: CookieJar,
+      header_params: models::MeDraftsGetHeaderParams,
+      query_params: models::MeDraftsGetQueryParams,
+    ) -> Result<MeDraftsGetResponse, Self::Error>;
+
+    /// ゴミ箱の投稿を取得.
+    ///
+    /// MeTrashGet - GET /me/trash
+    async fn me_trash_get(
+    &self,
+    method: Method,
+    host: Host,
+    cookies: CookieJar,
+      header_params: models::MeTrashGetHeaderParams,
+      query_params: models::MeTrashGetQueryParams,
+    ) -> Result<MeTrashGetResponse, Self::Error>;
+
+    /// 投稿を取得.
     ///
     /// PostsGet - GET /posts
     async fn posts_get(
@@ -104,7 +487,8 @@ pub trait Posts {
     host: Host,
     cookies: CookieJar,
       header_params: models::PostsGetHeaderParams,
-    ) -> Result<PostsGetResponse, String>;
+      query_params: models::PostsGetQueryParams,
+    ) -> Result<PostsGetResponse, Self::Error>;
 
     /// 新規投稿作成.
     ///
@@ -116,7 +500,7 @@ pub trait Posts {
     cookies: CookieJar,
       header_params: models::PostsPostHeaderParams,
             body: Option<models::PostsPostRequest>,
-    ) -> Result<PostsPostResponse, String>;
+    ) -> Result<PostsPostResponse, Self::Error>;
 
     /// 投稿を削除.
     ///
@@ -128,7 +512,20 @@ pub trait Posts {
     cookies: CookieJar,
       header_params: models::PostsPostIdDeleteHeaderParams,
       path_params: models::PostsPostIdDeletePathParams,
-    ) -> Result<PostsPostIdDeleteResponse, String>;
+    ) -> Result<PostsPostIdDeleteResponse, Self::Error>;
+
+    /// 版の差分を取得.
+    ///
+    /// PostsPostIdDiffGet - GET /posts/{postId}/diff
+    async fn posts_post_id_diff_get(
+    &self,
+    method: Method,
+    host: Host,
+    cookies: CookieJar,
+      header_params: models::PostsPostIdDiffGetHeaderParams,
+      path_params: models::PostsPostIdDiffGetPathParams,
+      query_params: models::PostsPostIdDiffGetQueryParams,
+    ) -> Result<PostsPostIdDiffGetResponse, Self::Error>;
 
     /// IDで投稿を取得.
     ///
@@ -140,7 +537,58 @@ pub trait Posts {
     cookies: CookieJar,
       header_params: models::PostsPostIdGetHeaderParams,
       path_params: models::PostsPostIdGetPathParams,
-    ) -> Result<PostsPostIdGetResponse, String>;
+    ) -> Result<PostsPostIdGetResponse, Self::Error>;
+
+    /// 投稿のロック・ピン留め.
+    ///
+    /// PostsPostIdModerationPut - PUT /posts/{postId}/moderation
+    async fn posts_post_id_moderation_put(
+    &self,
+    method: Method,
+    host: Host,
+    cookies: CookieJar,
+      header_params: models::PostsPostIdModerationPutHeaderParams,
+      path_params: models::PostsPostIdModerationPutPathParams,
+            body: Option<models::PostsPostIdModerationPutRequest>,
+    ) -> Result<PostsPostIdModerationPutResponse, Self::Error>;
+
+    /// 投稿を部分的に更新.
+    ///
+    /// PostsPostIdPatch - PATCH /posts/{postId}
+    async fn posts_post_id_patch(
+    &self,
+    method: Method,
+    host: Host,
+    cookies: CookieJar,
+      header_params: models::PostsPostIdPatchHeaderParams,
+      path_params: models::PostsPostIdPatchPathParams,
+            body: Option<models::PostPatch>,
+    ) -> Result<PostsPostIdPatchResponse, Self::Error>;
+
+    /// 投稿を公開.
+    ///
+    /// PostsPostIdPublishPost - POST /posts/{postId}/publish
+    async fn posts_post_id_publish_post(
+    &self,
+    method: Method,
+    host: Host,
+    cookies: CookieJar,
+      header_params: models::PostsPostIdPublishPostHeaderParams,
+      path_params: models::PostsPostIdPublishPostPathParams,
+            body: Option<models::PostsPostIdPublishPostRequest>,
+    ) -> Result<PostsPostIdPublishPostResponse, Self::Error>;
+
+    /// 投稿を完全に削除.
+    ///
+    /// PostsPostIdPurgePost - POST /posts/{postId}/purge
+    async fn posts_post_id_purge_post(
+    &self,
+    method: Method,
+    host: Host,
+    cookies: CookieJar,
+      header_params: models::PostsPostIdPurgePostHeaderParams,
+      path_params: models::PostsPostIdPurgePostPathParams,
+    ) -> Result<PostsPostIdPurgePostResponse, Self::Error>;
 
     /// 投稿を更新.
     ///
@@ -153,5 +601,92 @@ pub trait Posts {
       header_params: models::PostsPostIdPutHeaderParams,
       path_params: models::PostsPostIdPutPathParams,
             body: Option<models::Post>,
-    ) -> Result<PostsPostIdPutResponse, String>;
+    ) -> Result<PostsPostIdPutResponse, Self::Error>;
+
+    /// 返信を取得.
+    ///
+    /// PostsPostIdRepliesGet - GET /posts/{postId}/replies
+    async fn posts_post_id_replies_get(
+    &self,
+    method: Method,
+    host: Host,
+    cookies: CookieJar,
+      header_params: models::PostsPostIdRepliesGetHeaderParams,
+      path_params: models::PostsPostIdRepliesGetPathParams,
+      query_params: models::PostsPostIdRepliesGetQueryParams,
+    ) -> Result<PostsPostIdRepliesGetResponse, Self::Error>;
+
+    /// 返信を投稿.
+    ///
+    /// PostsPostIdRepliesPost - POST /posts/{postId}/replies
+    async fn posts_post_id_replies_post(
+    &self,
+    method: Method,
+    host: Host,
+    cookies: CookieJar,
+      header_params: models::PostsPostIdRepliesPostHeaderParams,
+      path_params: models::PostsPostIdRepliesPostPathParams,
+            body: Option<models::PostsPostRequest>,
+    ) -> Result<PostsPostIdRepliesPostResponse, Self::Error>;
+
+    /// ゴミ箱から投稿を戻す.
+    ///
+    /// PostsPostIdRestorePost - POST /posts/{postId}/restore
+    async fn posts_post_id_restore_post(
+    &self,
+    method: Method,
+    host: Host,
+    cookies: CookieJar,
+      header_params: models::PostsPostIdRestorePostHeaderParams,
+      path_params: models::PostsPostIdRestorePostPathParams,
+    ) -> Result<PostsPostIdRestorePostResponse, Self::Error>;
+
+    /// 版の一覧を取得.
+    ///
+    /// PostsPostIdRevisionsGet - GET /posts/{postId}/revisions
+    async fn posts_post_id_revisions_get(
+    &self,
+    method: Method,
+    host: Host,
+    cookies: CookieJar,
+      header_params: models::PostsPostIdRevisionsGetHeaderParams,
+      path_params: models::PostsPostIdRevisionsGetPathParams,
+      query_params: models::PostsPostIdRevisionsGetQueryParams,
+    ) -> Result<PostsPostIdRevisionsGetResponse, Self::Error>;
+
+    /// 版を取得.
+    ///
+    /// PostsPostIdRevisionsNumberGet - GET /posts/{postId}/revisions/{number}
+    async fn posts_post_id_revisions_number_get(
+    &self,
+    method: Method,
+    host: Host,
+    cookies: CookieJar,
+      header_params: models::PostsPostIdRevisionsNumberGetHeaderParams,
+      path_params: models::PostsPostIdRevisionsNumberGetPathParams,
+    ) -> Result<PostsPostIdRevisionsNumberGetResponse, Self::Error>;
+
+    /// 版に戻す.
+    ///
+    /// PostsPostIdRevisionsNumberRevertPost - POST /posts/{postId}/revisions/{number}/revert
+    async fn posts_post_id_revisions_number_revert_post(
+    &self,
+    method: Method,
+    host: Host,
+    cookies: CookieJar,
+      header_params: models::PostsPostIdRevisionsNumberRevertPostHeaderParams,
+      path_params: models::PostsPostIdRevisionsNumberRevertPostPathParams,
+    ) -> Result<PostsPostIdRevisionsNumberRevertPostResponse, Self::Error>;
+
+    /// 投稿を検索.
+    ///
+    /// PostsSearchGet - GET /posts/search
+    async fn posts_search_get(
+    &self,
+    method: Method,
+    host: Host,
+    cookies: CookieJar,
+      header_params: models::PostsSearchGetHeaderParams,
+      query_params: models::PostsSearchGetQueryParams,
+    ) -> Result<PostsSearchGetResponse, Self::Error>;
 }
@@ -15,8 +15,8 @@ pub enum UsersPostResponse {
     Status201_UserCreatedSuccessfully
     (models::User)
     ,
-    /// リクエストが不正です
-    Status400
+    /// メールアドレスは既に登録されています
+    Status409
 }
 
 
@@ -24,6 +24,8 @@ pub enum UsersPostResponse {
 #[async_trait]
 #[allow(clippy::ptr_arg)]
 pub trait Users {
+    type Error: super::ApiError;
+
     /// 新規ユーザー登録.
     ///
     /// UsersPost - POST /users
@@ -33,5 +35,5 @@ pub trait Users {
     host: Host,
     cookies: CookieJar,
             body: Option<models::UsersPostRequest>,
-    ) -> Result<UsersPostResponse, String>;
+    ) -> Result<UsersPostResponse, Self::Error>;
 }
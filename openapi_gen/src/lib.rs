@@ -23,6 +23,7 @@ pub mod server;
 pub mod models;
 pub mod types;
 pub mod apis;
+pub mod problem;
 
 #[cfg(feature = "server")]
 pub(crate) mod header;
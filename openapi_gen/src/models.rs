@@ -9,18 +9,368 @@ use crate::{models, types::*};
 
       
       
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+    #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+    pub struct AdminBoardsPostHeaderParams {
+        pub authorization: Option<String>,
+    }
+
+            
+      
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+    #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+    pub struct AdminBoardsSlugDeleteHeaderParams {
+        pub authorization: Option<String>,
+    }
+
+            
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+    #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))] 
+    pub struct AdminBoardsSlugDeletePathParams {
+                pub slug: String,
+    }
+
+
+      
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+    #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+    pub struct AdminBoardsSlugPutHeaderParams {
+        pub authorization: Option<String>,
+    }
+
+            
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+    #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))] 
+    pub struct AdminBoardsSlugPutPathParams {
+                pub slug: String,
+    }
+
+
+      
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+    #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+    pub struct AdminUsersUserIdRoleDeleteHeaderParams {
+        pub authorization: Option<String>,
+    }
+
+            
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+    #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))] 
+    pub struct AdminUsersUserIdRoleDeletePathParams {
+                pub user_id: i64,
+    }
+
+
+
+      
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+    #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+    pub struct AdminUsersUserIdRolePutHeaderParams {
+        pub authorization: Option<String>,
+    }
+
+            
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+    #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))] 
+    pub struct AdminUsersUserIdRolePutPathParams {
+                pub user_id: i64,
+    }
+
+
+
+      
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+    #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+    pub struct AdminWebhookDeliveriesDeliveryIdRetryPostHeaderParams {
+        pub authorization: Option<String>,
+    }
+
+            
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+    #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))] 
+    pub struct AdminWebhookDeliveriesDeliveryIdRetryPostPathParams {
+                pub delivery_id: uuid::Uuid,
+    }
+
+
+
+      
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+    #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+    pub struct AdminWebhookDeliveriesGetHeaderParams {
+        pub authorization: Option<String>,
+    }
+
+            
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+    #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+    pub struct AdminWebhookDeliveriesGetQueryParams {
+    /// この状態の配信に絞り込みます
+                #[serde(rename = "status")]
+                #[serde(skip_serializing_if="Option::is_none")]
+                pub status: Option<models::WebhookDeliveryStatus>,
+    /// 取得する件数
+                #[serde(rename = "limit")]
+                #[validate(range(min = 1, max = 100))]
+                #[serde(skip_serializing_if="Option::is_none")]
+                pub limit: Option<i32>,
+    }
+
+
+
+      
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+    #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+    pub struct AdminWebhooksGetHeaderParams {
+        pub authorization: Option<String>,
+    }
+
+            
+
+      
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+    #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+    pub struct AdminWebhooksPostHeaderParams {
+        pub authorization: Option<String>,
+    }
+
+            
+
+      
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+    #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+    pub struct AdminWebhooksWebhookIdDeleteHeaderParams {
+        pub authorization: Option<String>,
+    }
+
+            
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+    #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))] 
+    pub struct AdminWebhooksWebhookIdDeletePathParams {
+                pub webhook_id: uuid::Uuid,
+    }
+
+
+
+      
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+    #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+    pub struct AdminWebhooksWebhookIdDeliveriesGetHeaderParams {
+        pub authorization: Option<String>,
+    }
+
+            
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+    #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))] 
+    pub struct AdminWebhooksWebhookIdDeliveriesGetPathParams {
+                pub webhook_id: uuid::Uuid,
+    }
+
+
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+    #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+    pub struct AdminWebhooksWebhookIdDeliveriesGetQueryParams {
+    /// この状態の配信に絞り込みます
+                #[serde(rename = "status")]
+                #[serde(skip_serializing_if="Option::is_none")]
+                pub status: Option<models::WebhookDeliveryStatus>,
+    /// 取得する件数
+                #[serde(rename = "limit")]
+                #[validate(range(min = 1, max = 100))]
+                #[serde(skip_serializing_if="Option::is_none")]
+                pub limit: Option<i32>,
+    }
+
+
+
+      
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+    #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+    pub struct AuthLogoutAllPostHeaderParams {
+        pub authorization: Option<String>,
+    }
+
+            
+
+      
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+    #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+    pub struct AuthLogoutPostHeaderParams {
+        pub authorization: Option<String>,
+    }
+
+            
+      
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+    #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+    pub struct AuthPostHeaderParams {
+        pub x_forwarded_for: Option<String>,
+    }
+
+            
+      
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+    #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+    pub struct BoardsGetHeaderParams {
+        pub authorization: Option<String>,
+    }
+
+            
+      
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+    #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+    pub struct BoardsSlugGetHeaderParams {
+        pub authorization: Option<String>,
+    }
+
+            
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+    #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))] 
+    pub struct BoardsSlugGetPathParams {
+                pub slug: String,
+    }
+
+
+      
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+    #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+    pub struct BoardsSlugPostsGetHeaderParams {
+        pub authorization: Option<String>,
+    }
+
+            
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+    #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))] 
+    pub struct BoardsSlugPostsGetPathParams {
+                pub slug: String,
+    }
+
+
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+    #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+    pub struct BoardsSlugPostsGetQueryParams {
+    /// 取得する件数
+                #[serde(rename = "limit")]
+                #[validate(range(min = 1, max = 100))]
+                #[serde(skip_serializing_if="Option::is_none")]
+                pub limit: Option<i32>,
+    /// 前のページで返されたカーソル。同じ並び順でのみ使用できます
+                #[serde(rename = "cursor")]
+                #[serde(skip_serializing_if="Option::is_none")]
+                pub cursor: Option<String>,
+    /// 並び順
+                #[serde(rename = "sort")]
+                #[serde(skip_serializing_if="Option::is_none")]
+                pub sort: Option<models::PostSort>,
+    /// 投稿を作成したユーザーのID
+                #[serde(rename = "userId")]
+                #[serde(skip_serializing_if="Option::is_none")]
+                pub user_id: Option<i64>,
+    /// この日時以降に作成された投稿に絞り込みます
+                #[serde(rename = "since")]
+                #[serde(skip_serializing_if="Option::is_none")]
+                pub since: Option<chrono::DateTime::<chrono::Utc>>,
+    /// この日時より前に作成された投稿に絞り込みます
+                #[serde(rename = "until")]
+                #[serde(skip_serializing_if="Option::is_none")]
+                pub until: Option<chrono::DateTime::<chrono::Utc>>,
+    }
+
+
+      
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+    #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+    pub struct MeDraftsGetHeaderParams {
+        pub authorization: Option<String>,
+    }
+
+            
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+    #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+    pub struct MeDraftsGetQueryParams {
+    /// 取得する件数
+                #[serde(rename = "limit")]
+                #[validate(range(min = 1, max = 100))]
+                #[serde(skip_serializing_if="Option::is_none")]
+                pub limit: Option<i32>,
+    /// 読み飛ばす件数
+                #[serde(rename = "offset")]
+                #[validate(range(min = 0))]
+                #[serde(skip_serializing_if="Option::is_none")]
+                pub offset: Option<i32>,
+    }
+
+
+      
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+    #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+    pub struct MeTrashGetHeaderParams {
+        pub authorization: Option<String>,
+    }
+
+            
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+    #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+    pub struct MeTrashGetQueryParams {
+    /// 取得する件数
+                #[serde(rename = "limit")]
+                #[validate(range(min = 1, max = 100))]
+                #[serde(skip_serializing_if="Option::is_none")]
+                pub limit: Option<i32>,
+    /// 読み飛ばす件数
+                #[serde(rename = "offset")]
+                #[validate(range(min = 0))]
+                #[serde(skip_serializing_if="Option::is_none")]
+                pub offset: Option<i32>,
+    }
+
+
+            
+      
     #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
     #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
     pub struct PostsGetHeaderParams {
-        pub authorization: String,
+        pub authorization: Option<String>,
+    }
+
+
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+    #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+    pub struct PostsGetQueryParams {
+    /// 取得する件数
+                #[serde(rename = "limit")]
+                #[validate(range(min = 1, max = 100))]
+                #[serde(skip_serializing_if="Option::is_none")]
+                pub limit: Option<i32>,
+    /// 前のページで返されたカーソル。同じ並び順でのみ使用できます
+                #[serde(rename = "cursor")]
+                #[serde(skip_serializing_if="Option::is_none")]
+                pub cursor: Option<String>,
+    /// 並び順
+                #[serde(rename = "sort")]
+                #[serde(skip_serializing_if="Option::is_none")]
+                pub sort: Option<models::PostSort>,
+    /// 投稿を作成したユーザーのID
+                #[serde(rename = "userId")]
+                #[serde(skip_serializing_if="Option::is_none")]
+                pub user_id: Option<i64>,
+    /// この日時以降に作成された投稿に絞り込みます
+                #[serde(rename = "since")]
+                #[serde(skip_serializing_if="Option::is_none")]
+                pub since: Option<chrono::DateTime::<chrono::Utc>>,
+    /// この日時より前に作成された投稿に絞り込みます
+                #[serde(rename = "until")]
+                #[serde(skip_serializing_if="Option::is_none")]
+                pub until: Option<chrono::DateTime::<chrono::Utc>>,
     }
 
+
             
       
     #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
     #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
     pub struct PostsPostHeaderParams {
-        pub authorization: String,
+        pub authorization: Option<String>,
     }
 
             
@@ -28,7 +378,8 @@ use crate::{models, types::*};
     #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
     #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
     pub struct PostsPostIdDeleteHeaderParams {
-        pub authorization: String,
+        pub authorization: Option<String>,
+        pub if_match: Option<String>,
     }
 
             
@@ -39,11 +390,43 @@ use crate::{models, types::*};
     }
 
 
+
+      
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+    #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+    pub struct PostsPostIdDiffGetHeaderParams {
+        pub authorization: Option<String>,
+    }
+
+            
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+    #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))] 
+    pub struct PostsPostIdDiffGetPathParams {
+                pub post_id: uuid::Uuid,
+    }
+
+
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+    #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+    pub struct PostsPostIdDiffGetQueryParams {
+    /// 比較元の版の番号
+                #[serde(rename = "from")]
+                #[validate(range(min = 1))]
+                pub from: i32,
+    /// 比較先の版の番号。省略すると最新の版と比較します
+                #[serde(rename = "to")]
+                #[validate(range(min = 1))]
+                #[serde(skip_serializing_if="Option::is_none")]
+                pub to: Option<i32>,
+    }
+
+
       
     #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
     #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
     pub struct PostsPostIdGetHeaderParams {
-        pub authorization: String,
+        pub authorization: Option<String>,
+        pub if_none_match: Option<String>,
     }
 
             
@@ -57,69 +440,4435 @@ use crate::{models, types::*};
       
     #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
     #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-    pub struct PostsPostIdPutHeaderParams {
-        pub authorization: String,
+    pub struct PostsPostIdModerationPutHeaderParams {
+        pub authorization: Option<String>,
+        pub if_match: Option<String>,
     }
 
             
     #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
     #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))] 
-    pub struct PostsPostIdPutPathParams {
+    pub struct PostsPostIdModerationPutPathParams {
+                pub post_id: uuid::Uuid,
+    }
+
+
+
+      
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+    #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+    pub struct PostsPostIdPatchHeaderParams {
+        pub authorization: Option<String>,
+        pub if_match: Option<String>,
+    }
+
+            
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+    #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))] 
+    pub struct PostsPostIdPatchPathParams {
+                pub post_id: uuid::Uuid,
+    }
+
+
+
+      
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+    #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+    pub struct PostsPostIdPublishPostHeaderParams {
+        pub authorization: Option<String>,
+        pub if_match: Option<String>,
+    }
+
+            
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+    #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))] 
+    pub struct PostsPostIdPublishPostPathParams {
                 pub post_id: uuid::Uuid,
     }
 
 
       
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+    #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+    pub struct PostsPostIdPurgePostHeaderParams {
+        pub authorization: Option<String>,
+    }
 
+            
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+    #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))] 
+    pub struct PostsPostIdPurgePostPathParams {
+                pub post_id: uuid::Uuid,
+    }
 
 
 
+      
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+    #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+    pub struct PostsPostIdPutHeaderParams {
+        pub authorization: Option<String>,
+        pub if_match: Option<String>,
+    }
+
+            
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+    #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))] 
+    pub struct PostsPostIdPutPathParams {
+                pub post_id: uuid::Uuid,
+    }
+
+
+      
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+    #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+    pub struct PostsPostIdRepliesGetHeaderParams {
+        pub authorization: Option<String>,
+    }
+
+            
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+    #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))] 
+    pub struct PostsPostIdRepliesGetPathParams {
+                pub post_id: uuid::Uuid,
+    }
+
+
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+    #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+    pub struct PostsPostIdRepliesGetQueryParams {
+    /// 取得する件数
+                #[serde(rename = "limit")]
+                #[validate(range(min = 1, max = 100))]
+                #[serde(skip_serializing_if="Option::is_none")]
+                pub limit: Option<i32>,
+    /// 読み飛ばす件数
+                #[serde(rename = "offset")]
+                #[validate(range(min = 0))]
+                #[serde(skip_serializing_if="Option::is_none")]
+                pub offset: Option<i32>,
+    }
+
+
+
+      
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+    #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+    pub struct PostsPostIdRepliesPostHeaderParams {
+        pub authorization: Option<String>,
+    }
+
+            
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+    #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))] 
+    pub struct PostsPostIdRepliesPostPathParams {
+                pub post_id: uuid::Uuid,
+    }
+
+
+
+      
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+    #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+    pub struct PostsPostIdRestorePostHeaderParams {
+        pub authorization: Option<String>,
+    }
+
+            
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+    #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))] 
+    pub struct PostsPostIdRestorePostPathParams {
+                pub post_id: uuid::Uuid,
+    }
+
+
+
+      
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+    #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+    pub struct PostsPostIdRevisionsGetHeaderParams {
+        pub authorization: Option<String>,
+    }
+
+            
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+    #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))] 
+    pub struct PostsPostIdRevisionsGetPathParams {
+                pub post_id: uuid::Uuid,
+    }
+
+
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+    #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+    pub struct PostsPostIdRevisionsGetQueryParams {
+    /// 取得する件数
+                #[serde(rename = "limit")]
+                #[validate(range(min = 1, max = 100))]
+                #[serde(skip_serializing_if="Option::is_none")]
+                pub limit: Option<i32>,
+    /// 読み飛ばす件数
+                #[serde(rename = "offset")]
+                #[validate(range(min = 0))]
+                #[serde(skip_serializing_if="Option::is_none")]
+                pub offset: Option<i32>,
+    }
+
+
+
+      
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+    #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+    pub struct PostsPostIdRevisionsNumberGetHeaderParams {
+        pub authorization: Option<String>,
+    }
+
+            
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+    #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))] 
+    pub struct PostsPostIdRevisionsNumberGetPathParams {
+                pub post_id: uuid::Uuid,
+                #[validate(range(min = 1))]
+                pub number: i32,
+    }
+
+
+
+      
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+    #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+    pub struct PostsPostIdRevisionsNumberRevertPostHeaderParams {
+        pub authorization: Option<String>,
+        pub if_match: Option<String>,
+    }
+
+            
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+    #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))] 
+    pub struct PostsPostIdRevisionsNumberRevertPostPathParams {
+                pub post_id: uuid::Uuid,
+                #[validate(range(min = 1))]
+                pub number: i32,
+    }
+
+
+
+      
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+    #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+    pub struct PostsSearchGetHeaderParams {
+        pub authorization: Option<String>,
+    }
+
+            
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+    #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+    pub struct PostsSearchGetQueryParams {
+    /// 検索する語
+                #[serde(rename = "q")]
+                #[validate(length(min = 1, max = 200))]
+                pub q: String,
+    /// 取得する件数
+                #[serde(rename = "limit")]
+                #[validate(range(min = 1, max = 100))]
+                #[serde(skip_serializing_if="Option::is_none")]
+                pub limit: Option<i32>,
+    /// 読み飛ばす件数
+                #[serde(rename = "offset")]
+                #[validate(range(min = 0))]
+                #[serde(skip_serializing_if="Option::is_none")]
+                pub offset: Option<i32>,
+    }
+
+
+      
+
+
+
+
+
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct AdminBoardsPostRequest {
+/// URLでボードを指定する名前。英小文字、数字とハイフンからなる32文字以下の文字列です
+    #[serde(rename = "slug")]
+    pub slug: String,
+
+/// ボードの表示名
+    #[serde(rename = "name")]
+    pub name: String,
+
+/// ボードの説明
+    #[serde(rename = "description")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub description: Option<String>,
+
+    #[serde(rename = "settings")]
+    #[validate(nested)]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub settings: Option<models::BoardSettings>,
+
+}
+
+
+impl AdminBoardsPostRequest {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(slug: String, name: String, ) -> AdminBoardsPostRequest {
+        AdminBoardsPostRequest {
+            slug,
+            name,
+            description: None,
+            settings: None,
+        }
+    }
+}
+
+/// Converts the AdminBoardsPostRequest value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for AdminBoardsPostRequest {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+
+            Some("slug".to_string()),
+            Some(self.slug.to_string()),
+
+
+            Some("name".to_string()),
+            Some(self.name.to_string()),
+
+
+            self.description.as_ref().map(|description| {
+                [
+                    "description".to_string(),
+                    description.to_string(),
+                ].join(",")
+            }),
+
+            // Skipping settings in query parameter serialization
+
+        ];
+
+        write!(f, "{}", params.into_iter().flatten().collect::<Vec<_>>().join(","))
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a AdminBoardsPostRequest value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for AdminBoardsPostRequest {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub slug: Vec<String>,
+            pub name: Vec<String>,
+            pub description: Vec<String>,
+            pub settings: Vec<models::BoardSettings>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => return std::result::Result::Err("Missing value while parsing AdminBoardsPostRequest".to_string())
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "slug" => intermediate_rep.slug.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "name" => intermediate_rep.name.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "description" => intermediate_rep.description.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "settings" => intermediate_rep.settings.push(<models::BoardSettings as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    _ => return std::result::Result::Err("Unexpected key while parsing AdminBoardsPostRequest".to_string())
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(AdminBoardsPostRequest {
+            slug: intermediate_rep.slug.into_iter().next().ok_or_else(|| "slug missing in AdminBoardsPostRequest".to_string())?,
+            name: intermediate_rep.name.into_iter().next().ok_or_else(|| "name missing in AdminBoardsPostRequest".to_string())?,
+            description: intermediate_rep.description.into_iter().next(),
+            settings: intermediate_rep.settings.into_iter().next(),
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<AdminBoardsPostRequest> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<AdminBoardsPostRequest>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(hdr_value: header::IntoHeaderValue<AdminBoardsPostRequest>) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for AdminBoardsPostRequest - value: {} is invalid {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<AdminBoardsPostRequest> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <AdminBoardsPostRequest as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into AdminBoardsPostRequest - {}",
+                                value, err))
+                    }
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+
+
+
+
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct AdminUsersUserIdRolePutRequest {
+    #[serde(rename = "role")]
+    pub role: models::Role,
+
+}
+
+
+impl AdminUsersUserIdRolePutRequest {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(role: models::Role, ) -> AdminUsersUserIdRolePutRequest {
+        AdminUsersUserIdRolePutRequest {
+            role,
+        }
+    }
+}
+
+/// Converts the AdminUsersUserIdRolePutRequest value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for AdminUsersUserIdRolePutRequest {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            // Skipping role in query parameter serialization
+
+        ];
+
+        write!(f, "{}", params.into_iter().flatten().collect::<Vec<_>>().join(","))
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a AdminUsersUserIdRolePutRequest value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for AdminUsersUserIdRolePutRequest {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub role: Vec<models::Role>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => return std::result::Result::Err("Missing value while parsing AdminUsersUserIdRolePutRequest".to_string())
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "role" => intermediate_rep.role.push(<models::Role as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    _ => return std::result::Result::Err("Unexpected key while parsing AdminUsersUserIdRolePutRequest".to_string())
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(AdminUsersUserIdRolePutRequest {
+            role: intermediate_rep.role.into_iter().next().ok_or_else(|| "role missing in AdminUsersUserIdRolePutRequest".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<AdminUsersUserIdRolePutRequest> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<AdminUsersUserIdRolePutRequest>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(hdr_value: header::IntoHeaderValue<AdminUsersUserIdRolePutRequest>) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for AdminUsersUserIdRolePutRequest - value: {} is invalid {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<AdminUsersUserIdRolePutRequest> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <AdminUsersUserIdRolePutRequest as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into AdminUsersUserIdRolePutRequest - {}",
+                                value, err))
+                    }
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+
+
+
+
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct AdminWebhooksPostRequest {
+/// 配信をPOSTするhttpまたはhttpsのURL
+    #[serde(rename = "url")]
+    pub url: String,
+
+/// 通知するイベント。省略するとすべてのイベントを通知します
+    #[serde(rename = "events")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub events: Option<Vec<models::WebhookEventType>>,
+
+}
+
+
+impl AdminWebhooksPostRequest {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(url: String, ) -> AdminWebhooksPostRequest {
+        AdminWebhooksPostRequest {
+            url,
+            events: None,
+        }
+    }
+}
+
+/// Converts the AdminWebhooksPostRequest value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for AdminWebhooksPostRequest {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+
+            Some("url".to_string()),
+            Some(self.url.to_string()),
+
+            // Skipping events in query parameter serialization
+
+        ];
+
+        write!(f, "{}", params.into_iter().flatten().collect::<Vec<_>>().join(","))
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a AdminWebhooksPostRequest value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for AdminWebhooksPostRequest {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub url: Vec<String>,
+            pub events: Vec<Vec<models::WebhookEventType>>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => return std::result::Result::Err("Missing value while parsing AdminWebhooksPostRequest".to_string())
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "url" => intermediate_rep.url.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    "events" => return std::result::Result::Err("Parsing a container in this style is not supported in AdminWebhooksPostRequest".to_string()),
+                    _ => return std::result::Result::Err("Unexpected key while parsing AdminWebhooksPostRequest".to_string())
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(AdminWebhooksPostRequest {
+            url: intermediate_rep.url.into_iter().next().ok_or_else(|| "url missing in AdminWebhooksPostRequest".to_string())?,
+            events: intermediate_rep.events.into_iter().next(),
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<AdminWebhooksPostRequest> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<AdminWebhooksPostRequest>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(hdr_value: header::IntoHeaderValue<AdminWebhooksPostRequest>) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for AdminWebhooksPostRequest - value: {} is invalid {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<AdminWebhooksPostRequest> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <AdminWebhooksPostRequest as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into AdminWebhooksPostRequest - {}",
+                                value, err))
+                    }
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+
+
+
+
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct Auth {
+/// ユーザーのメールアドレス
+    #[serde(rename = "email")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub email: Option<String>,
+
+/// ユーザーのパスワード
+    #[serde(rename = "password")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub password: Option<String>,
+
+}
+
+
+impl Auth {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new() -> Auth {
+        Auth {
+            email: None,
+            password: None,
+        }
+    }
+}
+
+/// Converts the Auth value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for Auth {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+
+            self.email.as_ref().map(|email| {
+                [
+                    "email".to_string(),
+                    email.to_string(),
+                ].join(",")
+            }),
+
+
+            self.password.as_ref().map(|password| {
+                [
+                    "password".to_string(),
+                    password.to_string(),
+                ].join(",")
+            }),
+
+        ];
+
+        write!(f, "{}", params.into_iter().flatten().collect::<Vec<_>>().join(","))
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a Auth value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for Auth {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub email: Vec<String>,
+            pub password: Vec<String>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => return std::result::Result::Err("Missing value while parsing Auth".to_string())
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "email" => intermediate_rep.email.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "password" => intermediate_rep.password.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    _ => return std::result::Result::Err("Unexpected key while parsing Auth".to_string())
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(Auth {
+            email: intermediate_rep.email.into_iter().next(),
+            password: intermediate_rep.password.into_iter().next(),
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<Auth> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<Auth>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(hdr_value: header::IntoHeaderValue<Auth>) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for Auth - value: {} is invalid {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<Auth> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <Auth as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into Auth - {}",
+                                value, err))
+                    }
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+
+
+
+
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct AuthLogoutPostRequest {
+/// 併せて失効させるリフレッシュトークン
+    #[serde(rename = "refreshToken")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub refresh_token: Option<String>,
+
+}
+
+
+impl AuthLogoutPostRequest {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new() -> AuthLogoutPostRequest {
+        AuthLogoutPostRequest {
+            refresh_token: None,
+        }
+    }
+}
+
+/// Converts the AuthLogoutPostRequest value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for AuthLogoutPostRequest {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+
+            self.refresh_token.as_ref().map(|refresh_token| {
+                [
+                    "refreshToken".to_string(),
+                    refresh_token.to_string(),
+                ].join(",")
+            }),
+
+        ];
+
+        write!(f, "{}", params.into_iter().flatten().collect::<Vec<_>>().join(","))
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a AuthLogoutPostRequest value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for AuthLogoutPostRequest {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub refresh_token: Vec<String>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => return std::result::Result::Err("Missing value while parsing AuthLogoutPostRequest".to_string())
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "refreshToken" => intermediate_rep.refresh_token.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    _ => return std::result::Result::Err("Unexpected key while parsing AuthLogoutPostRequest".to_string())
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(AuthLogoutPostRequest {
+            refresh_token: intermediate_rep.refresh_token.into_iter().next(),
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<AuthLogoutPostRequest> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<AuthLogoutPostRequest>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(hdr_value: header::IntoHeaderValue<AuthLogoutPostRequest>) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for AuthLogoutPostRequest - value: {} is invalid {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<AuthLogoutPostRequest> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <AuthLogoutPostRequest as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into AuthLogoutPostRequest - {}",
+                                value, err))
+                    }
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+
+
+
+
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct AuthRefreshPostRequest {
+/// リフレッシュトークン
+    #[serde(rename = "refreshToken")]
+    pub refresh_token: String,
+
+}
+
+
+impl AuthRefreshPostRequest {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(refresh_token: String, ) -> AuthRefreshPostRequest {
+        AuthRefreshPostRequest {
+            refresh_token,
+        }
+    }
+}
+
+/// Converts the AuthRefreshPostRequest value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for AuthRefreshPostRequest {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+
+            Some("refreshToken".to_string()),
+            Some(self.refresh_token.to_string()),
+
+        ];
+
+        write!(f, "{}", params.into_iter().flatten().collect::<Vec<_>>().join(","))
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a AuthRefreshPostRequest value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for AuthRefreshPostRequest {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub refresh_token: Vec<String>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => return std::result::Result::Err("Missing value while parsing AuthRefreshPostRequest".to_string())
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "refreshToken" => intermediate_rep.refresh_token.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    _ => return std::result::Result::Err("Unexpected key while parsing AuthRefreshPostRequest".to_string())
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(AuthRefreshPostRequest {
+            refresh_token: intermediate_rep.refresh_token.into_iter().next().ok_or_else(|| "refreshToken missing in AuthRefreshPostRequest".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<AuthRefreshPostRequest> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<AuthRefreshPostRequest>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(hdr_value: header::IntoHeaderValue<AuthRefreshPostRequest>) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for AuthRefreshPostRequest - value: {} is invalid {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<AuthRefreshPostRequest> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <AuthRefreshPostRequest as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into AuthRefreshPostRequest - {}",
+                                value, err))
+                    }
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+
+
+
+
+
+/// 投稿をまとめるボード
+
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct Board {
+    #[serde(rename = "id")]
+    pub id: uuid::Uuid,
+
+/// URLでボードを指定する名前。英小文字、数字とハイフンからなります
+    #[serde(rename = "slug")]
+    pub slug: String,
+
+/// ボードの表示名
+    #[serde(rename = "name")]
+    pub name: String,
+
+/// ボードの説明
+    #[serde(rename = "description")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub description: Option<String>,
+
+    #[serde(rename = "settings")]
+    #[validate(nested)]
+    pub settings: models::BoardSettings,
+
+    #[serde(rename = "createdAt")]
+    pub created_at: chrono::DateTime::<chrono::Utc>,
+
+    #[serde(rename = "updatedAt")]
+    pub updated_at: chrono::DateTime::<chrono::Utc>,
+
+}
+
+
+impl Board {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(id: uuid::Uuid, slug: String, name: String, settings: models::BoardSettings, created_at: chrono::DateTime::<chrono::Utc>, updated_at: chrono::DateTime::<chrono::Utc>, ) -> Board {
+        Board {
+            id,
+            slug,
+            name,
+            description: None,
+            settings,
+            created_at,
+            updated_at,
+        }
+    }
+}
+
+/// Converts the Board value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for Board {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            // Skipping id in query parameter serialization
+
+
+            Some("slug".to_string()),
+            Some(self.slug.to_string()),
+
+
+            Some("name".to_string()),
+            Some(self.name.to_string()),
+
+
+            self.description.as_ref().map(|description| {
+                [
+                    "description".to_string(),
+                    description.to_string(),
+                ].join(",")
+            }),
+
+            // Skipping settings in query parameter serialization
+
+            // Skipping createdAt in query parameter serialization
+
+            // Skipping updatedAt in query parameter serialization
+
+        ];
+
+        write!(f, "{}", params.into_iter().flatten().collect::<Vec<_>>().join(","))
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a Board value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for Board {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub id: Vec<uuid::Uuid>,
+            pub slug: Vec<String>,
+            pub name: Vec<String>,
+            pub description: Vec<String>,
+            pub settings: Vec<models::BoardSettings>,
+            pub created_at: Vec<chrono::DateTime::<chrono::Utc>>,
+            pub updated_at: Vec<chrono::DateTime::<chrono::Utc>>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => return std::result::Result::Err("Missing value while parsing Board".to_string())
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "id" => intermediate_rep.id.push(<uuid::Uuid as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "slug" => intermediate_rep.slug.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "name" => intermediate_rep.name.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "description" => intermediate_rep.description.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "settings" => intermediate_rep.settings.push(<models::BoardSettings as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "createdAt" => intermediate_rep.created_at.push(<chrono::DateTime::<chrono::Utc> as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "updatedAt" => intermediate_rep.updated_at.push(<chrono::DateTime::<chrono::Utc> as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    _ => return std::result::Result::Err("Unexpected key while parsing Board".to_string())
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(Board {
+            id: intermediate_rep.id.into_iter().next().ok_or_else(|| "id missing in Board".to_string())?,
+            slug: intermediate_rep.slug.into_iter().next().ok_or_else(|| "slug missing in Board".to_string())?,
+            name: intermediate_rep.name.into_iter().next().ok_or_else(|| "name missing in Board".to_string())?,
+            description: intermediate_rep.description.into_iter().next(),
+            settings: intermediate_rep.settings.into_iter().next().ok_or_else(|| "settings missing in Board".to_string())?,
+            created_at: intermediate_rep.created_at.into_iter().next().ok_or_else(|| "createdAt missing in Board".to_string())?,
+            updated_at: intermediate_rep.updated_at.into_iter().next().ok_or_else(|| "updatedAt missing in Board".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<Board> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<Board>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(hdr_value: header::IntoHeaderValue<Board>) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for Board - value: {} is invalid {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<Board> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <Board as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into Board - {}",
+                                value, err))
+                    }
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+
+
+
+
+
+
+/// 掲示板で発生したイベント
+
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct BoardEvent {
+/// イベントのID。`Last-Event-ID` に指定すると続きから受信できる
+    #[serde(rename = "id")]
+    pub id: i64,
+
+    #[serde(rename = "type")]
+    pub r#type: models::BoardEventType,
+
+/// 投稿が属するスレッドの先頭の投稿のID
+    #[serde(rename = "threadId")]
+    pub thread_id: uuid::Uuid,
+
+    #[serde(rename = "post")]
+    #[validate(nested)]
+    pub post: models::Post,
+
+/// イベントが発生した日時
+    #[serde(rename = "occurredAt")]
+    pub occurred_at: chrono::DateTime::<chrono::Utc>,
+
+}
+
+
+impl BoardEvent {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(id: i64, r#type: models::BoardEventType, thread_id: uuid::Uuid, post: models::Post, occurred_at: chrono::DateTime::<chrono::Utc>, ) -> BoardEvent {
+        BoardEvent {
+            id,
+            r#type,
+            thread_id,
+            post,
+            occurred_at,
+        }
+    }
+}
+
+/// Converts the BoardEvent value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for BoardEvent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+
+            Some("id".to_string()),
+            Some(self.id.to_string()),
+
+            // Skipping type in query parameter serialization
+
+            // Skipping threadId in query parameter serialization
+
+            // Skipping post in query parameter serialization
+
+            // Skipping occurredAt in query parameter serialization
+
+        ];
+
+        write!(f, "{}", params.into_iter().flatten().collect::<Vec<_>>().join(","))
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a BoardEvent value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for BoardEvent {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub id: Vec<i64>,
+            pub r#type: Vec<models::BoardEventType>,
+            pub thread_id: Vec<uuid::Uuid>,
+            pub post: Vec<models::Post>,
+            pub occurred_at: Vec<chrono::DateTime::<chrono::Utc>>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => return std::result::Result::Err("Missing value while parsing BoardEvent".to_string())
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "id" => intermediate_rep.id.push(<i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "type" => intermediate_rep.r#type.push(<models::BoardEventType as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "threadId" => intermediate_rep.thread_id.push(<uuid::Uuid as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "post" => intermediate_rep.post.push(<models::Post as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "occurredAt" => intermediate_rep.occurred_at.push(<chrono::DateTime::<chrono::Utc> as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    _ => return std::result::Result::Err("Unexpected key while parsing BoardEvent".to_string())
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(BoardEvent {
+            id: intermediate_rep.id.into_iter().next().ok_or_else(|| "id missing in BoardEvent".to_string())?,
+            r#type: intermediate_rep.r#type.into_iter().next().ok_or_else(|| "type missing in BoardEvent".to_string())?,
+            thread_id: intermediate_rep.thread_id.into_iter().next().ok_or_else(|| "threadId missing in BoardEvent".to_string())?,
+            post: intermediate_rep.post.into_iter().next().ok_or_else(|| "post missing in BoardEvent".to_string())?,
+            occurred_at: intermediate_rep.occurred_at.into_iter().next().ok_or_else(|| "occurredAt missing in BoardEvent".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<BoardEvent> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<BoardEvent>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(hdr_value: header::IntoHeaderValue<BoardEvent>) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for BoardEvent - value: {} is invalid {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<BoardEvent> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <BoardEvent as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into BoardEvent - {}",
+                                value, err))
+                    }
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+/// イベントの種類
+/// Enumeration of values.
+/// Since this enum's variants do not hold data, we can easily define them as `#[repr(C)]`
+/// which helps with FFI.
+#[allow(non_camel_case_types)]
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "conversion", derive(frunk_enum_derive::LabelledGenericEnum))]
+pub enum BoardEventType {
+    #[serde(rename = "post_created")]
+    PostCreated,
+    #[serde(rename = "post_updated")]
+    PostUpdated,
+    #[serde(rename = "post_deleted")]
+    PostDeleted,
+    #[serde(rename = "reply_created")]
+    ReplyCreated,
+}
+
+impl std::fmt::Display for BoardEventType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            BoardEventType::PostCreated => write!(f, "post_created"),
+            BoardEventType::PostUpdated => write!(f, "post_updated"),
+            BoardEventType::PostDeleted => write!(f, "post_deleted"),
+            BoardEventType::ReplyCreated => write!(f, "reply_created"),
+        }
+    }
+}
+
+impl std::str::FromStr for BoardEventType {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "post_created" => std::result::Result::Ok(BoardEventType::PostCreated),
+            "post_updated" => std::result::Result::Ok(BoardEventType::PostUpdated),
+            "post_deleted" => std::result::Result::Ok(BoardEventType::PostDeleted),
+            "reply_created" => std::result::Result::Ok(BoardEventType::ReplyCreated),
+            _ => std::result::Result::Err(format!("Value not valid: {}", s)),
+        }
+    }
+}
+
+
+
+
+
+
+/// ボードごとの投稿の設定
+
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct BoardSettings {
+    #[serde(rename = "postingRole")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub posting_role: Option<models::Role>,
+
+/// 返信を受け付けるか。受け付けない場合はモデレーターと管理者も返信できません。既定値は `true` です
+    #[serde(rename = "allowReplies")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub allow_replies: Option<bool>,
+
+/// 投稿の内容の最大の文字数。既定値は10000です
+    #[serde(rename = "maxPostLength")]
+    #[validate(range(min = 1, max = 10000))]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub max_post_length: Option<i32>,
+
+}
+
+
+impl BoardSettings {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new() -> BoardSettings {
+        BoardSettings {
+            posting_role: None,
+            allow_replies: None,
+            max_post_length: None,
+        }
+    }
+}
+
+/// Converts the BoardSettings value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for BoardSettings {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            // Skipping postingRole in query parameter serialization
+
+
+            self.allow_replies.as_ref().map(|allow_replies| {
+                [
+                    "allowReplies".to_string(),
+                    allow_replies.to_string(),
+                ].join(",")
+            }),
+
+
+            self.max_post_length.as_ref().map(|max_post_length| {
+                [
+                    "maxPostLength".to_string(),
+                    max_post_length.to_string(),
+                ].join(",")
+            }),
+
+        ];
+
+        write!(f, "{}", params.into_iter().flatten().collect::<Vec<_>>().join(","))
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a BoardSettings value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for BoardSettings {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub posting_role: Vec<models::Role>,
+            pub allow_replies: Vec<bool>,
+            pub max_post_length: Vec<i32>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => return std::result::Result::Err("Missing value while parsing BoardSettings".to_string())
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "postingRole" => intermediate_rep.posting_role.push(<models::Role as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "allowReplies" => intermediate_rep.allow_replies.push(<bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "maxPostLength" => intermediate_rep.max_post_length.push(<i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    _ => return std::result::Result::Err("Unexpected key while parsing BoardSettings".to_string())
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(BoardSettings {
+            posting_role: intermediate_rep.posting_role.into_iter().next(),
+            allow_replies: intermediate_rep.allow_replies.into_iter().next(),
+            max_post_length: intermediate_rep.max_post_length.into_iter().next(),
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<BoardSettings> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<BoardSettings>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(hdr_value: header::IntoHeaderValue<BoardSettings>) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for BoardSettings - value: {} is invalid {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<BoardSettings> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <BoardSettings as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into BoardSettings - {}",
+                                value, err))
+                    }
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+
+
+
+
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct DiffLine {
+    #[serde(rename = "op")]
+    pub op: models::DiffOp,
+
+/// 改行を含まない行の内容
+    #[serde(rename = "text")]
+    pub text: String,
+
+}
+
+
+impl DiffLine {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(op: models::DiffOp, text: String, ) -> DiffLine {
+        DiffLine {
+            op,
+            text,
+        }
+    }
+}
+
+/// Converts the DiffLine value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for DiffLine {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            // Skipping op in query parameter serialization
+
+
+            Some("text".to_string()),
+            Some(self.text.to_string()),
+
+        ];
+
+        write!(f, "{}", params.into_iter().flatten().collect::<Vec<_>>().join(","))
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a DiffLine value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for DiffLine {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub op: Vec<models::DiffOp>,
+            pub text: Vec<String>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => return std::result::Result::Err("Missing value while parsing DiffLine".to_string())
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "op" => intermediate_rep.op.push(<models::DiffOp as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "text" => intermediate_rep.text.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    _ => return std::result::Result::Err("Unexpected key while parsing DiffLine".to_string())
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(DiffLine {
+            op: intermediate_rep.op.into_iter().next().ok_or_else(|| "op missing in DiffLine".to_string())?,
+            text: intermediate_rep.text.into_iter().next().ok_or_else(|| "text missing in DiffLine".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<DiffLine> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<DiffLine>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(hdr_value: header::IntoHeaderValue<DiffLine>) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for DiffLine - value: {} is invalid {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<DiffLine> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <DiffLine as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into DiffLine - {}",
+                                value, err))
+                    }
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+
+
+
+
+
+/// 差分の行の種類
+/// Enumeration of values.
+/// Since this enum's variants do not hold data, we can easily define them as `#[repr(C)]`
+/// which helps with FFI.
+#[allow(non_camel_case_types)]
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "conversion", derive(frunk_enum_derive::LabelledGenericEnum))]
+pub enum DiffOp {
+    #[serde(rename = "equal")]
+    Equal,
+    #[serde(rename = "insert")]
+    Insert,
+    #[serde(rename = "delete")]
+    Delete,
+}
+
+impl std::fmt::Display for DiffOp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            DiffOp::Equal => write!(f, "equal"),
+            DiffOp::Insert => write!(f, "insert"),
+            DiffOp::Delete => write!(f, "delete"),
+        }
+    }
+}
+
+impl std::str::FromStr for DiffOp {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "equal" => std::result::Result::Ok(DiffOp::Equal),
+            "insert" => std::result::Result::Ok(DiffOp::Insert),
+            "delete" => std::result::Result::Ok(DiffOp::Delete),
+            _ => std::result::Result::Err(format!("Value not valid: {}", s)),
+        }
+    }
+}
+
+
+
+
+
+/// 安定したエラーコード
+/// Enumeration of values.
+/// Since this enum's variants do not hold data, we can easily define them as `#[repr(C)]`
+/// which helps with FFI.
+#[allow(non_camel_case_types)]
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "conversion", derive(frunk_enum_derive::LabelledGenericEnum))]
+pub enum ErrorCode {
+    #[serde(rename = "validation_failed")]
+    ValidationFailed,
+    #[serde(rename = "missing_body")]
+    MissingBody,
+    #[serde(rename = "internal_error")]
+    InternalError,
+    #[serde(rename = "conflict")]
+    Conflict,
+    #[serde(rename = "invalid_credentials")]
+    InvalidCredentials,
+}
+
+impl std::fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            ErrorCode::ValidationFailed => write!(f, "validation_failed"),
+            ErrorCode::MissingBody => write!(f, "missing_body"),
+            ErrorCode::InternalError => write!(f, "internal_error"),
+            ErrorCode::Conflict => write!(f, "conflict"),
+            ErrorCode::InvalidCredentials => write!(f, "invalid_credentials"),
+        }
+    }
+}
+
+impl std::str::FromStr for ErrorCode {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "validation_failed" => std::result::Result::Ok(ErrorCode::ValidationFailed),
+            "missing_body" => std::result::Result::Ok(ErrorCode::MissingBody),
+            "internal_error" => std::result::Result::Ok(ErrorCode::InternalError),
+            "conflict" => std::result::Result::Ok(ErrorCode::Conflict),
+            "invalid_credentials" => std::result::Result::Ok(ErrorCode::InvalidCredentials),
+            _ => std::result::Result::Err(format!("Value not valid: {}", s)),
+        }
+    }
+}
+
+
+
+
+
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct Jwk {
+/// 鍵の種類 (RSA, EC, OKP)
+    #[serde(rename = "kty")]
+    pub kty: String,
+
+/// トークンヘッダーのkidに対応する鍵ID
+    #[serde(rename = "kid")]
+    pub kid: String,
+
+/// 鍵の用途。常にsig
+    #[serde(rename = "use")]
+    pub r#use: String,
+
+/// 署名アルゴリズム (RS256, ES256, EdDSA)
+    #[serde(rename = "alg")]
+    pub alg: String,
+
+/// 曲線名 (EC, OKP)
+    #[serde(rename = "crv")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub crv: Option<String>,
+
+/// RSAの法 (base64url)
+    #[serde(rename = "n")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub n: Option<String>,
+
+/// RSAの公開指数 (base64url)
+    #[serde(rename = "e")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub e: Option<String>,
+
+/// 公開鍵のx座標 (EC) または公開鍵 (OKP) (base64url)
+    #[serde(rename = "x")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub x: Option<String>,
+
+/// 公開鍵のy座標 (base64url)
+    #[serde(rename = "y")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub y: Option<String>,
+
+}
+
+
+impl Jwk {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(kty: String, kid: String, r#use: String, alg: String, ) -> Jwk {
+        Jwk {
+            kty,
+            kid,
+            r#use,
+            alg,
+            crv: None,
+            n: None,
+            e: None,
+            x: None,
+            y: None,
+        }
+    }
+}
+
+/// Converts the Jwk value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for Jwk {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+
+            Some("kty".to_string()),
+            Some(self.kty.to_string()),
+
+
+            Some("kid".to_string()),
+            Some(self.kid.to_string()),
+
+
+            Some("use".to_string()),
+            Some(self.r#use.to_string()),
+
+
+            Some("alg".to_string()),
+            Some(self.alg.to_string()),
+
+
+            self.crv.as_ref().map(|crv| {
+                [
+                    "crv".to_string(),
+                    crv.to_string(),
+                ].join(",")
+            }),
+
+
+            self.n.as_ref().map(|n| {
+                [
+                    "n".to_string(),
+                    n.to_string(),
+                ].join(",")
+            }),
+
+
+            self.e.as_ref().map(|e| {
+                [
+                    "e".to_string(),
+                    e.to_string(),
+                ].join(",")
+            }),
+
+
+            self.x.as_ref().map(|x| {
+                [
+                    "x".to_string(),
+                    x.to_string(),
+                ].join(",")
+            }),
+
+
+            self.y.as_ref().map(|y| {
+                [
+                    "y".to_string(),
+                    y.to_string(),
+                ].join(",")
+            }),
+
+        ];
+
+        write!(f, "{}", params.into_iter().flatten().collect::<Vec<_>>().join(","))
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a Jwk value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for Jwk {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub kty: Vec<String>,
+            pub kid: Vec<String>,
+            pub r#use: Vec<String>,
+            pub alg: Vec<String>,
+            pub crv: Vec<String>,
+            pub n: Vec<String>,
+            pub e: Vec<String>,
+            pub x: Vec<String>,
+            pub y: Vec<String>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => return std::result::Result::Err("Missing value while parsing Jwk".to_string())
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "kty" => intermediate_rep.kty.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "kid" => intermediate_rep.kid.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "use" => intermediate_rep.r#use.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "alg" => intermediate_rep.alg.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "crv" => intermediate_rep.crv.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "n" => intermediate_rep.n.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "e" => intermediate_rep.e.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "x" => intermediate_rep.x.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "y" => intermediate_rep.y.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    _ => return std::result::Result::Err("Unexpected key while parsing Jwk".to_string())
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(Jwk {
+            kty: intermediate_rep.kty.into_iter().next().ok_or_else(|| "kty missing in Jwk".to_string())?,
+            kid: intermediate_rep.kid.into_iter().next().ok_or_else(|| "kid missing in Jwk".to_string())?,
+            r#use: intermediate_rep.r#use.into_iter().next().ok_or_else(|| "use missing in Jwk".to_string())?,
+            alg: intermediate_rep.alg.into_iter().next().ok_or_else(|| "alg missing in Jwk".to_string())?,
+            crv: intermediate_rep.crv.into_iter().next(),
+            n: intermediate_rep.n.into_iter().next(),
+            e: intermediate_rep.e.into_iter().next(),
+            x: intermediate_rep.x.into_iter().next(),
+            y: intermediate_rep.y.into_iter().next(),
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<Jwk> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<Jwk>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(hdr_value: header::IntoHeaderValue<Jwk>) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for Jwk - value: {} is invalid {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<Jwk> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <Jwk as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into Jwk - {}",
+                                value, err))
+                    }
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+
+
+
+
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct JwkSet {
+    #[serde(rename = "keys")]
+    #[validate(nested)]
+    pub keys: Vec<models::Jwk>,
+
+}
+
+
+impl JwkSet {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(keys: Vec<models::Jwk>, ) -> JwkSet {
+        JwkSet {
+            keys,
+        }
+    }
+}
+
+/// Converts the JwkSet value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for JwkSet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            // Skipping keys in query parameter serialization
+
+        ];
+
+        write!(f, "{}", params.into_iter().flatten().collect::<Vec<_>>().join(","))
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a JwkSet value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for JwkSet {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub keys: Vec<Vec<models::Jwk>>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => return std::result::Result::Err("Missing value while parsing JwkSet".to_string())
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    "keys" => return std::result::Result::Err("Parsing a container in this style is not supported in JwkSet".to_string()),
+                    _ => return std::result::Result::Err("Unexpected key while parsing JwkSet".to_string())
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(JwkSet {
+            keys: intermediate_rep.keys.into_iter().next().ok_or_else(|| "keys missing in JwkSet".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<JwkSet> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<JwkSet>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(hdr_value: header::IntoHeaderValue<JwkSet>) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for JwkSet - value: {} is invalid {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<JwkSet> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <JwkSet as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into JwkSet - {}",
+                                value, err))
+                    }
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
+        }
+    }
+}
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct Post {
+/// 投稿の一意の識別子
+    #[serde(rename = "id")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub id: Option<uuid::Uuid>,
+
+/// 投稿のタイトル
+    #[serde(rename = "title")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub title: Option<String>,
+
+/// 投稿の内容
+    #[serde(rename = "content")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub content: Option<String>,
+
+/// 投稿を作成したユーザーのID
+    #[serde(rename = "userId")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub user_id: Option<i64>,
+
+/// 投稿を作成したユーザーの名前
+    #[serde(rename = "authorName")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub author_name: Option<String>,
+
+/// ロックされている場合はモデレーター以外編集・削除できない
+    #[serde(rename = "locked")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub locked: Option<bool>,
+
+/// 一覧の先頭に表示される
+    #[serde(rename = "pinned")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub pinned: Option<bool>,
+
+/// 返信先の投稿のID。スレッドの先頭の投稿では省略される
+    #[serde(rename = "parentId")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub parent_id: Option<uuid::Uuid>,
+
+/// 直接の返信の数
+    #[serde(rename = "replyCount")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub reply_count: Option<i64>,
+
+/// 投稿が作成された日時
+    #[serde(rename = "createdAt")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub created_at: Option<chrono::DateTime::<chrono::Utc>>,
+
+/// 内容が最後に編集された日時。編集されていない場合は作成日時
+    #[serde(rename = "updatedAt")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub updated_at: Option<chrono::DateTime::<chrono::Utc>>,
+
+/// 内容が編集された回数
+    #[serde(rename = "editedCount")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub edited_count: Option<i64>,
+
+/// 投稿または配下の返信が最後に作成・更新された日時
+    #[serde(rename = "lastActivityAt")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub last_activity_at: Option<chrono::DateTime::<chrono::Utc>>,
+
+/// 投稿が保存されるたびに1つ進むバージョン。`ETag` ヘッダーと同じ値です
+    #[serde(rename = "version")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub version: Option<i64>,
+
+/// ゴミ箱に移された日時。ゴミ箱にない投稿では省略される
+    #[serde(rename = "deletedAt")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub deleted_at: Option<chrono::DateTime::<chrono::Utc>>,
+
+    #[serde(rename = "status")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub status: Option<models::PostStatus>,
+
+/// 公開する日時。公開予定の投稿でのみ返される
+    #[serde(rename = "publishAt")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub publish_at: Option<chrono::DateTime::<chrono::Utc>>,
+
+/// 投稿が属するボードのID
+    #[serde(rename = "boardId")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub board_id: Option<uuid::Uuid>,
+
+}
+
+
+impl Post {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new() -> Post {
+        Post {
+            id: None,
+            title: None,
+            content: None,
+            user_id: None,
+            author_name: None,
+            locked: None,
+            pinned: None,
+            parent_id: None,
+            reply_count: None,
+            created_at: None,
+            updated_at: None,
+            edited_count: None,
+            last_activity_at: None,
+            version: None,
+            deleted_at: None,
+            status: None,
+            publish_at: None,
+            board_id: None,
+        }
+    }
+}
+
+/// Converts the Post value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for Post {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            // Skipping id in query parameter serialization
+
+
+            self.title.as_ref().map(|title| {
+                [
+                    "title".to_string(),
+                    title.to_string(),
+                ].join(",")
+            }),
+
+
+            self.content.as_ref().map(|content| {
+                [
+                    "content".to_string(),
+                    content.to_string(),
+                ].join(",")
+            }),
+
+
+            self.user_id.as_ref().map(|user_id| {
+                [
+                    "userId".to_string(),
+                    user_id.to_string(),
+                ].join(",")
+            }),
+
+
+            self.author_name.as_ref().map(|author_name| {
+                [
+                    "authorName".to_string(),
+                    author_name.to_string(),
+                ].join(",")
+            }),
+
+
+            self.locked.as_ref().map(|locked| {
+                [
+                    "locked".to_string(),
+                    locked.to_string(),
+                ].join(",")
+            }),
+
+
+            self.pinned.as_ref().map(|pinned| {
+                [
+                    "pinned".to_string(),
+                    pinned.to_string(),
+                ].join(",")
+            }),
+
+            // Skipping parentId in query parameter serialization
+
+
+            self.reply_count.as_ref().map(|reply_count| {
+                [
+                    "replyCount".to_string(),
+                    reply_count.to_string(),
+                ].join(",")
+            }),
+
+            // Skipping createdAt in query parameter serialization
+
+            // Skipping updatedAt in query parameter serialization
+
+
+            self.edited_count.as_ref().map(|edited_count| {
+                [
+                    "editedCount".to_string(),
+                    edited_count.to_string(),
+                ].join(",")
+            }),
+
+            // Skipping lastActivityAt in query parameter serialization
+
+
+            self.version.as_ref().map(|version| {
+                [
+                    "version".to_string(),
+                    version.to_string(),
+                ].join(",")
+            }),
+
+            // Skipping deletedAt in query parameter serialization
+
+            // Skipping status in query parameter serialization
+
+            // Skipping publishAt in query parameter serialization
+
+            // Skipping boardId in query parameter serialization
+
+        ];
+
+        write!(f, "{}", params.into_iter().flatten().collect::<Vec<_>>().join(","))
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a Post value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for Post {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub id: Vec<uuid::Uuid>,
+            pub title: Vec<String>,
+            pub content: Vec<String>,
+            pub user_id: Vec<i64>,
+            pub author_name: Vec<String>,
+            pub locked: Vec<bool>,
+            pub pinned: Vec<bool>,
+            pub parent_id: Vec<uuid::Uuid>,
+            pub reply_count: Vec<i64>,
+            pub created_at: Vec<chrono::DateTime::<chrono::Utc>>,
+            pub updated_at: Vec<chrono::DateTime::<chrono::Utc>>,
+            pub edited_count: Vec<i64>,
+            pub last_activity_at: Vec<chrono::DateTime::<chrono::Utc>>,
+            pub version: Vec<i64>,
+            pub deleted_at: Vec<chrono::DateTime::<chrono::Utc>>,
+            pub status: Vec<models::PostStatus>,
+            pub publish_at: Vec<chrono::DateTime::<chrono::Utc>>,
+            pub board_id: Vec<uuid::Uuid>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => return std::result::Result::Err("Missing value while parsing Post".to_string())
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "id" => intermediate_rep.id.push(<uuid::Uuid as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "title" => intermediate_rep.title.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "content" => intermediate_rep.content.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "userId" => intermediate_rep.user_id.push(<i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "authorName" => intermediate_rep.author_name.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "locked" => intermediate_rep.locked.push(<bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "pinned" => intermediate_rep.pinned.push(<bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "parentId" => intermediate_rep.parent_id.push(<uuid::Uuid as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "replyCount" => intermediate_rep.reply_count.push(<i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "createdAt" => intermediate_rep.created_at.push(<chrono::DateTime::<chrono::Utc> as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "updatedAt" => intermediate_rep.updated_at.push(<chrono::DateTime::<chrono::Utc> as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "editedCount" => intermediate_rep.edited_count.push(<i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "lastActivityAt" => intermediate_rep.last_activity_at.push(<chrono::DateTime::<chrono::Utc> as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "version" => intermediate_rep.version.push(<i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "deletedAt" => intermediate_rep.deleted_at.push(<chrono::DateTime::<chrono::Utc> as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "status" => intermediate_rep.status.push(<models::PostStatus as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "publishAt" => intermediate_rep.publish_at.push(<chrono::DateTime::<chrono::Utc> as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "boardId" => intermediate_rep.board_id.push(<uuid::Uuid as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    _ => return std::result::Result::Err("Unexpected key while parsing Post".to_string())
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(Post {
+            id: intermediate_rep.id.into_iter().next(),
+            title: intermediate_rep.title.into_iter().next(),
+            content: intermediate_rep.content.into_iter().next(),
+            user_id: intermediate_rep.user_id.into_iter().next(),
+            author_name: intermediate_rep.author_name.into_iter().next(),
+            locked: intermediate_rep.locked.into_iter().next(),
+            pinned: intermediate_rep.pinned.into_iter().next(),
+            parent_id: intermediate_rep.parent_id.into_iter().next(),
+            reply_count: intermediate_rep.reply_count.into_iter().next(),
+            created_at: intermediate_rep.created_at.into_iter().next(),
+            updated_at: intermediate_rep.updated_at.into_iter().next(),
+            edited_count: intermediate_rep.edited_count.into_iter().next(),
+            last_activity_at: intermediate_rep.last_activity_at.into_iter().next(),
+            version: intermediate_rep.version.into_iter().next(),
+            deleted_at: intermediate_rep.deleted_at.into_iter().next(),
+            status: intermediate_rep.status.into_iter().next(),
+            publish_at: intermediate_rep.publish_at.into_iter().next(),
+            board_id: intermediate_rep.board_id.into_iter().next(),
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<Post> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<Post>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(hdr_value: header::IntoHeaderValue<Post>) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for Post - value: {} is invalid {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<Post> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <Post as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into Post - {}",
+                                value, err))
+                    }
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+
+
+
+
+
+/// 投稿に適用する JSON Merge Patch。省略した項目は変更しません
+
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct PostPatch {
+/// 変更できません。指定する場合は投稿のIDと同じ値にします
+    #[serde(rename = "id")]
+    #[serde(deserialize_with = "deserialize_optional_nullable")]
+    #[serde(default = "default_optional_nullable")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub id: Option<Nullable<uuid::Uuid>>,
+
+/// 変更できません。指定する場合は投稿の作成者のIDと同じ値にします
+    #[serde(rename = "userId")]
+    #[serde(deserialize_with = "deserialize_optional_nullable")]
+    #[serde(default = "default_optional_nullable")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub user_id: Option<Nullable<i64>>,
+
+/// 新しいタイトル
+    #[serde(rename = "title")]
+    #[serde(deserialize_with = "deserialize_optional_nullable")]
+    #[serde(default = "default_optional_nullable")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub title: Option<Nullable<String>>,
+
+/// 新しい内容
+    #[serde(rename = "content")]
+    #[serde(deserialize_with = "deserialize_optional_nullable")]
+    #[serde(default = "default_optional_nullable")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub content: Option<Nullable<String>>,
+
+}
+
+
+impl PostPatch {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new() -> PostPatch {
+        PostPatch {
+            id: None,
+            user_id: None,
+            title: None,
+            content: None,
+        }
+    }
+}
+
+/// Converts the PostPatch value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for PostPatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            // Skipping id in query parameter serialization
+
+
+            self.user_id.as_ref().map(|user_id| {
+                [
+                    "userId".to_string(),
+                    user_id.as_ref().map_or("null".to_string(), |x| x.to_string()),
+                ].join(",")
+            }),
+
+
+            self.title.as_ref().map(|title| {
+                [
+                    "title".to_string(),
+                    title.as_ref().map_or("null".to_string(), |x| x.to_string()),
+                ].join(",")
+            }),
+
+
+            self.content.as_ref().map(|content| {
+                [
+                    "content".to_string(),
+                    content.as_ref().map_or("null".to_string(), |x| x.to_string()),
+                ].join(",")
+            }),
+
+        ];
+
+        write!(f, "{}", params.into_iter().flatten().collect::<Vec<_>>().join(","))
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a PostPatch value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for PostPatch {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub id: Vec<uuid::Uuid>,
+            pub user_id: Vec<i64>,
+            pub title: Vec<String>,
+            pub content: Vec<String>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => return std::result::Result::Err("Missing value while parsing PostPatch".to_string())
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    "id" => return std::result::Result::Err("Parsing a nullable type in this style is not supported in PostPatch".to_string()),
+                    "userId" => return std::result::Result::Err("Parsing a nullable type in this style is not supported in PostPatch".to_string()),
+                    "title" => return std::result::Result::Err("Parsing a nullable type in this style is not supported in PostPatch".to_string()),
+                    "content" => return std::result::Result::Err("Parsing a nullable type in this style is not supported in PostPatch".to_string()),
+                    _ => return std::result::Result::Err("Unexpected key while parsing PostPatch".to_string())
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(PostPatch {
+            id: std::result::Result::Err("Nullable types not supported in PostPatch".to_string())?,
+            user_id: std::result::Result::Err("Nullable types not supported in PostPatch".to_string())?,
+            title: std::result::Result::Err("Nullable types not supported in PostPatch".to_string())?,
+            content: std::result::Result::Err("Nullable types not supported in PostPatch".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<PostPatch> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<PostPatch>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(hdr_value: header::IntoHeaderValue<PostPatch>) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for PostPatch - value: {} is invalid {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<PostPatch> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <PostPatch as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into PostPatch - {}",
+                                value, err))
+                    }
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+
+
+/// 投稿一覧の並び順
+/// Enumeration of values.
+/// Since this enum's variants do not hold data, we can easily define them as `#[repr(C)]`
+/// which helps with FFI.
+#[allow(non_camel_case_types)]
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "conversion", derive(frunk_enum_derive::LabelledGenericEnum))]
+pub enum PostSort {
+    #[serde(rename = "newest")]
+    Newest,
+    #[serde(rename = "oldest")]
+    Oldest,
+    #[serde(rename = "most-replied")]
+    MostReplied,
+}
+
+impl std::fmt::Display for PostSort {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            PostSort::Newest => write!(f, "newest"),
+            PostSort::Oldest => write!(f, "oldest"),
+            PostSort::MostReplied => write!(f, "most-replied"),
+        }
+    }
+}
+
+impl std::str::FromStr for PostSort {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "newest" => std::result::Result::Ok(PostSort::Newest),
+            "oldest" => std::result::Result::Ok(PostSort::Oldest),
+            "most-replied" => std::result::Result::Ok(PostSort::MostReplied),
+            _ => std::result::Result::Err(format!("Value not valid: {}", s)),
+        }
+    }
+}
+
+
+
+
+
+
+/// 投稿の公開状態
+/// Enumeration of values.
+/// Since this enum's variants do not hold data, we can easily define them as `#[repr(C)]`
+/// which helps with FFI.
+#[allow(non_camel_case_types)]
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "conversion", derive(frunk_enum_derive::LabelledGenericEnum))]
+pub enum PostStatus {
+    #[serde(rename = "draft")]
+    Draft,
+    #[serde(rename = "scheduled")]
+    Scheduled,
+    #[serde(rename = "published")]
+    Published,
+}
+
+impl std::fmt::Display for PostStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            PostStatus::Draft => write!(f, "draft"),
+            PostStatus::Scheduled => write!(f, "scheduled"),
+            PostStatus::Published => write!(f, "published"),
+        }
+    }
+}
+
+impl std::str::FromStr for PostStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "draft" => std::result::Result::Ok(PostStatus::Draft),
+            "scheduled" => std::result::Result::Ok(PostStatus::Scheduled),
+            "published" => std::result::Result::Ok(PostStatus::Published),
+            _ => std::result::Result::Err(format!("Value not valid: {}", s)),
+        }
+    }
+}
+
+
+
+
+
+
+/// 投稿のある時点の内容
+
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct PostRevision {
+/// 版の番号。作成時の内容が1です
+    #[serde(rename = "number")]
+    pub number: i32,
+
+    #[serde(rename = "title")]
+    pub title: String,
+
+    #[serde(rename = "content")]
+    pub content: String,
+
+/// この版を作成したユーザーのID。版1では投稿の作成者です
+    #[serde(rename = "editedBy")]
+    pub edited_by: i64,
+
+/// この版を作成したユーザーの名前
+    #[serde(rename = "editorName")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub editor_name: Option<String>,
+
+    #[serde(rename = "createdAt")]
+    pub created_at: chrono::DateTime::<chrono::Utc>,
+
+}
+
+
+impl PostRevision {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(number: i32, title: String, content: String, edited_by: i64, created_at: chrono::DateTime::<chrono::Utc>, ) -> PostRevision {
+        PostRevision {
+            number,
+            title,
+            content,
+            edited_by,
+            editor_name: None,
+            created_at,
+        }
+    }
+}
+
+/// Converts the PostRevision value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for PostRevision {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+
+            Some("number".to_string()),
+            Some(self.number.to_string()),
+
+
+            Some("title".to_string()),
+            Some(self.title.to_string()),
+
+
+            Some("content".to_string()),
+            Some(self.content.to_string()),
+
+
+            Some("editedBy".to_string()),
+            Some(self.edited_by.to_string()),
+
+
+            self.editor_name.as_ref().map(|editor_name| {
+                [
+                    "editorName".to_string(),
+                    editor_name.to_string(),
+                ].join(",")
+            }),
+
+            // Skipping createdAt in query parameter serialization
+
+        ];
+
+        write!(f, "{}", params.into_iter().flatten().collect::<Vec<_>>().join(","))
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a PostRevision value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for PostRevision {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub number: Vec<i32>,
+            pub title: Vec<String>,
+            pub content: Vec<String>,
+            pub edited_by: Vec<i64>,
+            pub editor_name: Vec<String>,
+            pub created_at: Vec<chrono::DateTime::<chrono::Utc>>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => return std::result::Result::Err("Missing value while parsing PostRevision".to_string())
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "number" => intermediate_rep.number.push(<i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "title" => intermediate_rep.title.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "content" => intermediate_rep.content.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "editedBy" => intermediate_rep.edited_by.push(<i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "editorName" => intermediate_rep.editor_name.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "createdAt" => intermediate_rep.created_at.push(<chrono::DateTime::<chrono::Utc> as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    _ => return std::result::Result::Err("Unexpected key while parsing PostRevision".to_string())
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(PostRevision {
+            number: intermediate_rep.number.into_iter().next().ok_or_else(|| "number missing in PostRevision".to_string())?,
+            title: intermediate_rep.title.into_iter().next().ok_or_else(|| "title missing in PostRevision".to_string())?,
+            content: intermediate_rep.content.into_iter().next().ok_or_else(|| "content missing in PostRevision".to_string())?,
+            edited_by: intermediate_rep.edited_by.into_iter().next().ok_or_else(|| "editedBy missing in PostRevision".to_string())?,
+            editor_name: intermediate_rep.editor_name.into_iter().next(),
+            created_at: intermediate_rep.created_at.into_iter().next().ok_or_else(|| "createdAt missing in PostRevision".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<PostRevision> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<PostRevision>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(hdr_value: header::IntoHeaderValue<PostRevision>) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for PostRevision - value: {} is invalid {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<PostRevision> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <PostRevision as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into PostRevision - {}",
+                                value, err))
+                    }
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+
+
+
+
+
+
+/// 検索に一致した投稿
+
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct PostSearchResult {
+    #[serde(rename = "post")]
+    #[validate(nested)]
+    pub post: models::Post,
+
+/// 並び順に用いたスコア。同じ検索の結果の間でのみ比較できます
+    #[serde(rename = "score")]
+    pub score: f64,
+
+/// 一致した部分を強調したタイトル
+    #[serde(rename = "title")]
+    pub title: Vec<models::TextFragment>,
+
+/// 本文の一致した部分の前後の抜粋。省略した箇所には `…` を含みます
+    #[serde(rename = "snippet")]
+    pub snippet: Vec<models::TextFragment>,
+
+}
+
+
+impl PostSearchResult {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(post: models::Post, score: f64, title: Vec<models::TextFragment>, snippet: Vec<models::TextFragment>, ) -> PostSearchResult {
+        PostSearchResult {
+            post,
+            score,
+            title,
+            snippet,
+        }
+    }
+}
+
+/// Converts the PostSearchResult value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for PostSearchResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            // Skipping post in query parameter serialization
+
+
+            Some("score".to_string()),
+            Some(self.score.to_string()),
+
+            // Skipping title in query parameter serialization
+
+            // Skipping snippet in query parameter serialization
+
+        ];
+
+        write!(f, "{}", params.into_iter().flatten().collect::<Vec<_>>().join(","))
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a PostSearchResult value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for PostSearchResult {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub post: Vec<models::Post>,
+            pub score: Vec<f64>,
+            pub title: Vec<Vec<models::TextFragment>>,
+            pub snippet: Vec<Vec<models::TextFragment>>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => return std::result::Result::Err("Missing value while parsing PostSearchResult".to_string())
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "post" => intermediate_rep.post.push(<models::Post as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "score" => intermediate_rep.score.push(<f64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    "title" => return std::result::Result::Err("Parsing a container in this style is not supported in PostSearchResult".to_string()),
+                    "snippet" => return std::result::Result::Err("Parsing a container in this style is not supported in PostSearchResult".to_string()),
+                    _ => return std::result::Result::Err("Unexpected key while parsing PostSearchResult".to_string())
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(PostSearchResult {
+            post: intermediate_rep.post.into_iter().next().ok_or_else(|| "post missing in PostSearchResult".to_string())?,
+            score: intermediate_rep.score.into_iter().next().ok_or_else(|| "score missing in PostSearchResult".to_string())?,
+            title: intermediate_rep.title.into_iter().next().ok_or_else(|| "title missing in PostSearchResult".to_string())?,
+            snippet: intermediate_rep.snippet.into_iter().next().ok_or_else(|| "snippet missing in PostSearchResult".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<PostSearchResult> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<PostSearchResult>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(hdr_value: header::IntoHeaderValue<PostSearchResult>) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for PostSearchResult - value: {} is invalid {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<PostSearchResult> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <PostSearchResult as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into PostSearchResult - {}",
+                                value, err))
+                    }
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+
+
+
+
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct PostsPostIdModerationPutRequest {
+/// 投稿をロックする
+    #[serde(rename = "locked")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub locked: Option<bool>,
+
+/// 投稿をピン留めする
+    #[serde(rename = "pinned")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub pinned: Option<bool>,
+
+}
+
+
+impl PostsPostIdModerationPutRequest {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new() -> PostsPostIdModerationPutRequest {
+        PostsPostIdModerationPutRequest {
+            locked: None,
+            pinned: None,
+        }
+    }
+}
+
+/// Converts the PostsPostIdModerationPutRequest value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for PostsPostIdModerationPutRequest {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+
+            self.locked.as_ref().map(|locked| {
+                [
+                    "locked".to_string(),
+                    locked.to_string(),
+                ].join(",")
+            }),
+
+
+            self.pinned.as_ref().map(|pinned| {
+                [
+                    "pinned".to_string(),
+                    pinned.to_string(),
+                ].join(",")
+            }),
+
+        ];
+
+        write!(f, "{}", params.into_iter().flatten().collect::<Vec<_>>().join(","))
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a PostsPostIdModerationPutRequest value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for PostsPostIdModerationPutRequest {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub locked: Vec<bool>,
+            pub pinned: Vec<bool>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => return std::result::Result::Err("Missing value while parsing PostsPostIdModerationPutRequest".to_string())
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "locked" => intermediate_rep.locked.push(<bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "pinned" => intermediate_rep.pinned.push(<bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    _ => return std::result::Result::Err("Unexpected key while parsing PostsPostIdModerationPutRequest".to_string())
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(PostsPostIdModerationPutRequest {
+            locked: intermediate_rep.locked.into_iter().next(),
+            pinned: intermediate_rep.pinned.into_iter().next(),
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<PostsPostIdModerationPutRequest> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<PostsPostIdModerationPutRequest>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(hdr_value: header::IntoHeaderValue<PostsPostIdModerationPutRequest>) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for PostsPostIdModerationPutRequest - value: {} is invalid {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<PostsPostIdModerationPutRequest> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <PostsPostIdModerationPutRequest as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into PostsPostIdModerationPutRequest - {}",
+                                value, err))
+                    }
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+
+
+
+
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct PostsPostIdPublishPostRequest {
+/// 公開する日時。現在より後の日時を指定します。省略するとすぐに公開します
+    #[serde(rename = "publishAt")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub publish_at: Option<chrono::DateTime::<chrono::Utc>>,
+
+}
+
+
+impl PostsPostIdPublishPostRequest {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new() -> PostsPostIdPublishPostRequest {
+        PostsPostIdPublishPostRequest {
+            publish_at: None,
+        }
+    }
+}
+
+/// Converts the PostsPostIdPublishPostRequest value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for PostsPostIdPublishPostRequest {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            // Skipping publishAt in query parameter serialization
+
+        ];
+
+        write!(f, "{}", params.into_iter().flatten().collect::<Vec<_>>().join(","))
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a PostsPostIdPublishPostRequest value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for PostsPostIdPublishPostRequest {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub publish_at: Vec<chrono::DateTime::<chrono::Utc>>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => return std::result::Result::Err("Missing value while parsing PostsPostIdPublishPostRequest".to_string())
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "publishAt" => intermediate_rep.publish_at.push(<chrono::DateTime::<chrono::Utc> as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    _ => return std::result::Result::Err("Unexpected key while parsing PostsPostIdPublishPostRequest".to_string())
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(PostsPostIdPublishPostRequest {
+            publish_at: intermediate_rep.publish_at.into_iter().next(),
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<PostsPostIdPublishPostRequest> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<PostsPostIdPublishPostRequest>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(hdr_value: header::IntoHeaderValue<PostsPostIdPublishPostRequest>) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for PostsPostIdPublishPostRequest - value: {} is invalid {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<PostsPostIdPublishPostRequest> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <PostsPostIdPublishPostRequest as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into PostsPostIdPublishPostRequest - {}",
+                                value, err))
+                    }
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
+        }
+    }
+}
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct PostsPostRequest {
+/// 投稿するボードのID。スレッドを作成する場合は必須です。返信は返信先の投稿と同じボードに属するため指定できません
+    #[serde(rename = "boardId")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub board_id: Option<uuid::Uuid>,
+
+/// 投稿のタイトル
+    #[serde(rename = "title")]
+    pub title: String,
+
+/// 投稿の内容
+    #[serde(rename = "content")]
+    pub content: String,
+
+    #[serde(rename = "status")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub status: Option<models::PostStatus>,
+
+/// 公開する日時。`status` が `scheduled` の場合は必須で、現在より後の日時を指定します。`status` を省略して指定すると公開予定の投稿になります
+    #[serde(rename = "publishAt")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub publish_at: Option<chrono::DateTime::<chrono::Utc>>,
+
+}
+
+
+impl PostsPostRequest {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(title: String, content: String, ) -> PostsPostRequest {
+        PostsPostRequest {
+            board_id: None,
+            title,
+            content,
+            status: None,
+            publish_at: None,
+        }
+    }
+}
+
+/// Converts the PostsPostRequest value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for PostsPostRequest {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+            // Skipping boardId in query parameter serialization
+
+
+            Some("title".to_string()),
+            Some(self.title.to_string()),
+
+
+            Some("content".to_string()),
+            Some(self.content.to_string()),
+
+            // Skipping status in query parameter serialization
+
+            // Skipping publishAt in query parameter serialization
+
+        ];
+
+        write!(f, "{}", params.into_iter().flatten().collect::<Vec<_>>().join(","))
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a PostsPostRequest value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for PostsPostRequest {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub board_id: Vec<uuid::Uuid>,
+            pub title: Vec<String>,
+            pub content: Vec<String>,
+            pub status: Vec<models::PostStatus>,
+            pub publish_at: Vec<chrono::DateTime::<chrono::Utc>>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => return std::result::Result::Err("Missing value while parsing PostsPostRequest".to_string())
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "boardId" => intermediate_rep.board_id.push(<uuid::Uuid as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "title" => intermediate_rep.title.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "content" => intermediate_rep.content.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "status" => intermediate_rep.status.push(<models::PostStatus as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "publishAt" => intermediate_rep.publish_at.push(<chrono::DateTime::<chrono::Utc> as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    _ => return std::result::Result::Err("Unexpected key while parsing PostsPostRequest".to_string())
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(PostsPostRequest {
+            board_id: intermediate_rep.board_id.into_iter().next(),
+            title: intermediate_rep.title.into_iter().next().ok_or_else(|| "title missing in PostsPostRequest".to_string())?,
+            content: intermediate_rep.content.into_iter().next().ok_or_else(|| "content missing in PostsPostRequest".to_string())?,
+            status: intermediate_rep.status.into_iter().next(),
+            publish_at: intermediate_rep.publish_at.into_iter().next(),
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<PostsPostRequest> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<PostsPostRequest>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(hdr_value: header::IntoHeaderValue<PostsPostRequest>) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for PostsPostRequest - value: {} is invalid {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<PostsPostRequest> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <PostsPostRequest as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into PostsPostRequest - {}",
+                                value, err))
+                    }
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+
+
+
+
+
+
+
+/// RFC 7807 のエラー応答
+
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct Problem {
+/// エラーの種類を表すURI。`urn:board:problem:` にエラーコードを続けたもの
+    #[serde(rename = "type")]
+    pub r#type: String,
+
+/// エラーの種類の短い説明
+    #[serde(rename = "title")]
+    pub title: String,
+
+/// HTTPステータスコード
+    #[serde(rename = "status")]
+    pub status: i32,
+
+/// このエラーに固有の説明
+    #[serde(rename = "detail")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub detail: Option<String>,
+
+    #[serde(rename = "code")]
+    pub code: models::ErrorCode,
+
+/// 項目ごとのエラー
+    #[serde(rename = "errors")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub errors: Option<Vec<models::ProblemFieldError>>,
+
+}
+
+
+impl Problem {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(r#type: String, title: String, status: i32, code: models::ErrorCode, ) -> Problem {
+        Problem {
+            r#type,
+            title,
+            status,
+            detail: None,
+            code,
+            errors: None,
+        }
+    }
+}
+
+/// Converts the Problem value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for Problem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+
+            Some("type".to_string()),
+            Some(self.r#type.to_string()),
+
+
+            Some("title".to_string()),
+            Some(self.title.to_string()),
+
+
+            Some("status".to_string()),
+            Some(self.status.to_string()),
+
+
+            self.detail.as_ref().map(|detail| {
+                [
+                    "detail".to_string(),
+                    detail.to_string(),
+                ].join(",")
+            }),
+
+            // Skipping code in query parameter serialization
+
+            // Skipping errors in query parameter serialization
+
+        ];
+
+        write!(f, "{}", params.into_iter().flatten().collect::<Vec<_>>().join(","))
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a Problem value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for Problem {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub r#type: Vec<String>,
+            pub title: Vec<String>,
+            pub status: Vec<i32>,
+            pub detail: Vec<String>,
+            pub code: Vec<models::ErrorCode>,
+            pub errors: Vec<Vec<models::ProblemFieldError>>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => return std::result::Result::Err("Missing value while parsing Problem".to_string())
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "type" => intermediate_rep.r#type.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "title" => intermediate_rep.title.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "status" => intermediate_rep.status.push(<i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "detail" => intermediate_rep.detail.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "code" => intermediate_rep.code.push(<models::ErrorCode as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    "errors" => return std::result::Result::Err("Parsing a container in this style is not supported in Problem".to_string()),
+                    _ => return std::result::Result::Err("Unexpected key while parsing Problem".to_string())
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(Problem {
+            r#type: intermediate_rep.r#type.into_iter().next().ok_or_else(|| "type missing in Problem".to_string())?,
+            title: intermediate_rep.title.into_iter().next().ok_or_else(|| "title missing in Problem".to_string())?,
+            status: intermediate_rep.status.into_iter().next().ok_or_else(|| "status missing in Problem".to_string())?,
+            detail: intermediate_rep.detail.into_iter().next(),
+            code: intermediate_rep.code.into_iter().next().ok_or_else(|| "code missing in Problem".to_string())?,
+            errors: intermediate_rep.errors.into_iter().next(),
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<Problem> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<Problem>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(hdr_value: header::IntoHeaderValue<Problem>) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for Problem - value: {} is invalid {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<Problem> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <Problem as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into Problem - {}",
+                                value, err))
+                    }
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+
+
+
+
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct ProblemFieldError {
+/// エラーのある項目。入れ子の項目は `.` と `[添字]` で連結する
+    #[serde(rename = "field")]
+    pub field: String,
+
+/// 検証の種類 (required, length, email, characters, common, personal, range など)
+    #[serde(rename = "code")]
+    pub code: String,
+
+/// エラーの説明
+    #[serde(rename = "message")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub message: Option<String>,
+
+}
+
+
+impl ProblemFieldError {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(field: String, code: String, ) -> ProblemFieldError {
+        ProblemFieldError {
+            field,
+            code,
+            message: None,
+        }
+    }
+}
+
+/// Converts the ProblemFieldError value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for ProblemFieldError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+
+            Some("field".to_string()),
+            Some(self.field.to_string()),
+
+
+            Some("code".to_string()),
+            Some(self.code.to_string()),
+
+
+            self.message.as_ref().map(|message| {
+                [
+                    "message".to_string(),
+                    message.to_string(),
+                ].join(",")
+            }),
+
+        ];
+
+        write!(f, "{}", params.into_iter().flatten().collect::<Vec<_>>().join(","))
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a ProblemFieldError value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for ProblemFieldError {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub field: Vec<String>,
+            pub code: Vec<String>,
+            pub message: Vec<String>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => return std::result::Result::Err("Missing value while parsing ProblemFieldError".to_string())
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "field" => intermediate_rep.field.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "code" => intermediate_rep.code.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "message" => intermediate_rep.message.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    _ => return std::result::Result::Err("Unexpected key while parsing ProblemFieldError".to_string())
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(ProblemFieldError {
+            field: intermediate_rep.field.into_iter().next().ok_or_else(|| "field missing in ProblemFieldError".to_string())?,
+            code: intermediate_rep.code.into_iter().next().ok_or_else(|| "code missing in ProblemFieldError".to_string())?,
+            message: intermediate_rep.message.into_iter().next(),
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<ProblemFieldError> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<ProblemFieldError>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(hdr_value: header::IntoHeaderValue<ProblemFieldError>) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for ProblemFieldError - value: {} is invalid {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<ProblemFieldError> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <ProblemFieldError as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into ProblemFieldError - {}",
+                                value, err))
+                    }
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+
+
+
+
+
+/// 2つの版の行単位の差分
+
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct RevisionDiff {
+/// 比較元の版の番号
+    #[serde(rename = "from")]
+    pub from: i32,
+
+/// 比較先の版の番号
+    #[serde(rename = "to")]
+    pub to: i32,
+
+    #[serde(rename = "title")]
+    pub title: Vec<models::DiffLine>,
+
+    #[serde(rename = "content")]
+    pub content: Vec<models::DiffLine>,
+
+}
+
+
+impl RevisionDiff {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(from: i32, to: i32, title: Vec<models::DiffLine>, content: Vec<models::DiffLine>, ) -> RevisionDiff {
+        RevisionDiff {
+            from,
+            to,
+            title,
+            content,
+        }
+    }
+}
+
+/// Converts the RevisionDiff value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for RevisionDiff {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+
+            Some("from".to_string()),
+            Some(self.from.to_string()),
+
+
+            Some("to".to_string()),
+            Some(self.to.to_string()),
+
+            // Skipping title in query parameter serialization
+
+            // Skipping content in query parameter serialization
+
+        ];
+
+        write!(f, "{}", params.into_iter().flatten().collect::<Vec<_>>().join(","))
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a RevisionDiff value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for RevisionDiff {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub from: Vec<i32>,
+            pub to: Vec<i32>,
+            pub title: Vec<Vec<models::DiffLine>>,
+            pub content: Vec<Vec<models::DiffLine>>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => return std::result::Result::Err("Missing value while parsing RevisionDiff".to_string())
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "from" => intermediate_rep.from.push(<i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "to" => intermediate_rep.to.push(<i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    "title" => return std::result::Result::Err("Parsing a container in this style is not supported in RevisionDiff".to_string()),
+                    "content" => return std::result::Result::Err("Parsing a container in this style is not supported in RevisionDiff".to_string()),
+                    _ => return std::result::Result::Err("Unexpected key while parsing RevisionDiff".to_string())
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(RevisionDiff {
+            from: intermediate_rep.from.into_iter().next().ok_or_else(|| "from missing in RevisionDiff".to_string())?,
+            to: intermediate_rep.to.into_iter().next().ok_or_else(|| "to missing in RevisionDiff".to_string())?,
+            title: intermediate_rep.title.into_iter().next().ok_or_else(|| "title missing in RevisionDiff".to_string())?,
+            content: intermediate_rep.content.into_iter().next().ok_or_else(|| "content missing in RevisionDiff".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<RevisionDiff> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<RevisionDiff>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(hdr_value: header::IntoHeaderValue<RevisionDiff>) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for RevisionDiff - value: {} is invalid {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<RevisionDiff> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <RevisionDiff as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into RevisionDiff - {}",
+                                value, err))
+                    }
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+
+/// ユーザーのロール
+/// Enumeration of values.
+/// Since this enum's variants do not hold data, we can easily define them as `#[repr(C)]`
+/// which helps with FFI.
+#[allow(non_camel_case_types)]
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "conversion", derive(frunk_enum_derive::LabelledGenericEnum))]
+pub enum Role {
+    #[serde(rename = "member")]
+    Member,
+    #[serde(rename = "moderator")]
+    Moderator,
+    #[serde(rename = "admin")]
+    Admin,
+}
+
+impl std::fmt::Display for Role {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            Role::Member => write!(f, "member"),
+            Role::Moderator => write!(f, "moderator"),
+            Role::Admin => write!(f, "admin"),
+        }
+    }
+}
+
+impl std::str::FromStr for Role {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "member" => std::result::Result::Ok(Role::Member),
+            "moderator" => std::result::Result::Ok(Role::Moderator),
+            "admin" => std::result::Result::Ok(Role::Admin),
+            _ => std::result::Result::Err(format!("Value not valid: {}", s)),
+        }
+    }
+}
+
+
+
+
+
+
+/// 強調する部分で区切った文字列の断片
+
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct TextFragment {
+    #[serde(rename = "text")]
+    pub text: String,
+
+/// 検索した語に一致する部分か
+    #[serde(rename = "highlighted")]
+    pub highlighted: bool,
+
+}
+
+
+impl TextFragment {
+    #[allow(clippy::new_without_default, clippy::too_many_arguments)]
+    pub fn new(text: String, highlighted: bool, ) -> TextFragment {
+        TextFragment {
+            text,
+            highlighted,
+        }
+    }
+}
+
+/// Converts the TextFragment value to the Query Parameters representation (style=form, explode=false)
+/// specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde serializer
+impl std::fmt::Display for TextFragment {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params: Vec<Option<String>> = vec![
+
+            Some("text".to_string()),
+            Some(self.text.to_string()),
+
+
+            Some("highlighted".to_string()),
+            Some(self.highlighted.to_string()),
+
+        ];
+
+        write!(f, "{}", params.into_iter().flatten().collect::<Vec<_>>().join(","))
+    }
+}
+
+/// Converts Query Parameters representation (style=form, explode=false) to a TextFragment value
+/// as specified in https://swagger.io/docs/specification/serialization/
+/// Should be implemented in a serde deserializer
+impl std::str::FromStr for TextFragment {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        /// An intermediate representation of the struct to use for parsing.
+        #[derive(Default)]
+        #[allow(dead_code)]
+        struct IntermediateRep {
+            pub text: Vec<String>,
+            pub highlighted: Vec<bool>,
+        }
+
+        let mut intermediate_rep = IntermediateRep::default();
+
+        // Parse into intermediate representation
+        let mut string_iter = s.split(',');
+        let mut key_result = string_iter.next();
+
+        while key_result.is_some() {
+            let val = match string_iter.next() {
+                Some(x) => x,
+                None => return std::result::Result::Err("Missing value while parsing TextFragment".to_string())
+            };
+
+            if let Some(key) = key_result {
+                #[allow(clippy::match_single_binding)]
+                match key {
+                    #[allow(clippy::redundant_clone)]
+                    "text" => intermediate_rep.text.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "highlighted" => intermediate_rep.highlighted.push(<bool as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    _ => return std::result::Result::Err("Unexpected key while parsing TextFragment".to_string())
+                }
+            }
+
+            // Get the next key
+            key_result = string_iter.next();
+        }
+
+        // Use the intermediate representation to return the struct
+        std::result::Result::Ok(TextFragment {
+            text: intermediate_rep.text.into_iter().next().ok_or_else(|| "text missing in TextFragment".to_string())?,
+            highlighted: intermediate_rep.highlighted.into_iter().next().ok_or_else(|| "highlighted missing in TextFragment".to_string())?,
+        })
+    }
+}
+
+// Methods for converting between header::IntoHeaderValue<TextFragment> and HeaderValue
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<header::IntoHeaderValue<TextFragment>> for HeaderValue {
+    type Error = String;
+
+    fn try_from(hdr_value: header::IntoHeaderValue<TextFragment>) -> std::result::Result<Self, Self::Error> {
+        let hdr_value = hdr_value.to_string();
+        match HeaderValue::from_str(&hdr_value) {
+             std::result::Result::Ok(value) => std::result::Result::Ok(value),
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Invalid header value for TextFragment - value: {} is invalid {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<TextFragment> {
+    type Error = String;
+
+    fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
+        match hdr_value.to_str() {
+             std::result::Result::Ok(value) => {
+                    match <TextFragment as std::str::FromStr>::from_str(value) {
+                        std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
+                        std::result::Result::Err(err) => std::result::Result::Err(
+                            format!("Unable to convert header value '{}' into TextFragment - {}",
+                                value, err))
+                    }
+             },
+             std::result::Result::Err(e) => std::result::Result::Err(
+                 format!("Unable to convert header: {:?} to string: {}",
+                     hdr_value, e))
+        }
+    }
+}
+
+
+
+
+
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
+#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
+pub struct Token {
+/// JWTトークン
+    #[serde(rename = "token")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub token: Option<String>,
 
-#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
-#[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct Auth {
-/// ユーザーのメールアドレス
-    #[serde(rename = "email")]
+/// アクセストークンの再発行に使用するリフレッシュトークン
+    #[serde(rename = "refreshToken")]
     #[serde(skip_serializing_if="Option::is_none")]
-    pub email: Option<String>,
+    pub refresh_token: Option<String>,
 
-/// ユーザーのパスワード
-    #[serde(rename = "password")]
+/// アクセストークンの有効期間(秒)
+    #[serde(rename = "expiresIn")]
     #[serde(skip_serializing_if="Option::is_none")]
-    pub password: Option<String>,
+    pub expires_in: Option<i64>,
 
 }
 
 
-impl Auth {
+impl Token {
     #[allow(clippy::new_without_default, clippy::too_many_arguments)]
-    pub fn new() -> Auth {
-        Auth {
-            email: None,
-            password: None,
+    pub fn new() -> Token {
+        Token {
+            token: None,
+            refresh_token: None,
+            expires_in: None,
         }
     }
 }
 
-/// Converts the Auth value to the Query Parameters representation (style=form, explode=false)
+/// Converts the Token value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::fmt::Display for Auth {
+impl std::fmt::Display for Token {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let params: Vec<Option<String>> = vec![
 
-            self.email.as_ref().map(|email| {
+            self.token.as_ref().map(|token| {
                 [
-                    "email".to_string(),
-                    email.to_string(),
+                    "token".to_string(),
+                    token.to_string(),
                 ].join(",")
             }),
 
 
-            self.password.as_ref().map(|password| {
+            self.refresh_token.as_ref().map(|refresh_token| {
                 [
-                    "password".to_string(),
-                    password.to_string(),
+                    "refreshToken".to_string(),
+                    refresh_token.to_string(),
+                ].join(",")
+            }),
+
+
+            self.expires_in.as_ref().map(|expires_in| {
+                [
+                    "expiresIn".to_string(),
+                    expires_in.to_string(),
                 ].join(",")
             }),
 
@@ -129,10 +4878,10 @@ impl std::fmt::Display for Auth {
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a Auth value
+/// Converts Query Parameters representation (style=form, explode=false) to a Token value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for Auth {
+impl std::str::FromStr for Token {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -140,8 +4889,9 @@ impl std::str::FromStr for Auth {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub email: Vec<String>,
-            pub password: Vec<String>,
+            pub token: Vec<String>,
+            pub refresh_token: Vec<String>,
+            pub expires_in: Vec<i64>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -153,17 +4903,19 @@ impl std::str::FromStr for Auth {
         while key_result.is_some() {
             let val = match string_iter.next() {
                 Some(x) => x,
-                None => return std::result::Result::Err("Missing value while parsing Auth".to_string())
+                None => return std::result::Result::Err("Missing value while parsing Token".to_string())
             };
 
             if let Some(key) = key_result {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "email" => intermediate_rep.email.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    "token" => intermediate_rep.token.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
                     #[allow(clippy::redundant_clone)]
-                    "password" => intermediate_rep.password.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
-                    _ => return std::result::Result::Err("Unexpected key while parsing Auth".to_string())
+                    "refreshToken" => intermediate_rep.refresh_token.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "expiresIn" => intermediate_rep.expires_in.push(<i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    _ => return std::result::Result::Err("Unexpected key while parsing Token".to_string())
                 }
             }
 
@@ -172,41 +4924,42 @@ impl std::str::FromStr for Auth {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(Auth {
-            email: intermediate_rep.email.into_iter().next(),
-            password: intermediate_rep.password.into_iter().next(),
+        std::result::Result::Ok(Token {
+            token: intermediate_rep.token.into_iter().next(),
+            refresh_token: intermediate_rep.refresh_token.into_iter().next(),
+            expires_in: intermediate_rep.expires_in.into_iter().next(),
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<Auth> and HeaderValue
+// Methods for converting between header::IntoHeaderValue<Token> and HeaderValue
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<header::IntoHeaderValue<Auth>> for HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<Token>> for HeaderValue {
     type Error = String;
 
-    fn try_from(hdr_value: header::IntoHeaderValue<Auth>) -> std::result::Result<Self, Self::Error> {
+    fn try_from(hdr_value: header::IntoHeaderValue<Token>) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match HeaderValue::from_str(&hdr_value) {
              std::result::Result::Ok(value) => std::result::Result::Ok(value),
              std::result::Result::Err(e) => std::result::Result::Err(
-                 format!("Invalid header value for Auth - value: {} is invalid {}",
+                 format!("Invalid header value for Token - value: {} is invalid {}",
                      hdr_value, e))
         }
     }
 }
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<Auth> {
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<Token> {
     type Error = String;
 
     fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
              std::result::Result::Ok(value) => {
-                    match <Auth as std::str::FromStr>::from_str(value) {
+                    match <Token as std::str::FromStr>::from_str(value) {
                         std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
                         std::result::Result::Err(err) => std::result::Result::Err(
-                            format!("Unable to convert header value '{}' into Auth - {}",
+                            format!("Unable to convert header value '{}' into Token - {}",
                                 value, err))
                     }
              },
@@ -216,93 +4969,115 @@ impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<Auth> {
         }
     }
 }
-
-
-
-
-
-
-
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct Post {
-/// 投稿の一意の識別子
+pub struct User {
+/// ユーザーの一意の識別子
     #[serde(rename = "id")]
     #[serde(skip_serializing_if="Option::is_none")]
-    pub id: Option<uuid::Uuid>,
+    pub id: Option<i64>,
 
-/// 投稿のタイトル
-    #[serde(rename = "title")]
+/// ユーザーの名前
+    #[serde(rename = "name")]
     #[serde(skip_serializing_if="Option::is_none")]
-    pub title: Option<String>,
+    pub name: Option<String>,
 
-/// 投稿の内容
-    #[serde(rename = "content")]
+/// ユーザーのメールアドレス
+    #[serde(rename = "email")]
     #[serde(skip_serializing_if="Option::is_none")]
-    pub content: Option<String>,
+    pub email: Option<String>,
 
-/// 投稿を作成したユーザーのID
-    #[serde(rename = "userId")]
+/// ユーザーのパスワード
+    #[serde(rename = "password")]
     #[serde(skip_serializing_if="Option::is_none")]
-    pub user_id: Option<i64>,
+    pub password: Option<String>,
+
+    #[serde(rename = "role")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub role: Option<models::Role>,
+
+/// ユーザーが登録された日時
+    #[serde(rename = "createdAt")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub created_at: Option<chrono::DateTime::<chrono::Utc>>,
+
+/// 最後にログインした日時。一度もログインしていない場合は省略される
+    #[serde(rename = "lastLoginAt")]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub last_login_at: Option<chrono::DateTime::<chrono::Utc>>,
 
 }
 
 
-impl Post {
+impl User {
     #[allow(clippy::new_without_default, clippy::too_many_arguments)]
-    pub fn new() -> Post {
-        Post {
+    pub fn new() -> User {
+        User {
             id: None,
-            title: None,
-            content: None,
-            user_id: None,
+            name: None,
+            email: None,
+            password: None,
+            role: None,
+            created_at: None,
+            last_login_at: None,
         }
     }
 }
 
-/// Converts the Post value to the Query Parameters representation (style=form, explode=false)
+/// Converts the User value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::fmt::Display for Post {
+impl std::fmt::Display for User {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let params: Vec<Option<String>> = vec![
-            // Skipping id in query parameter serialization
+
+            self.id.as_ref().map(|id| {
+                [
+                    "id".to_string(),
+                    id.to_string(),
+                ].join(",")
+            }),
 
 
-            self.title.as_ref().map(|title| {
+            self.name.as_ref().map(|name| {
                 [
-                    "title".to_string(),
-                    title.to_string(),
+                    "name".to_string(),
+                    name.to_string(),
                 ].join(",")
             }),
 
 
-            self.content.as_ref().map(|content| {
+            self.email.as_ref().map(|email| {
                 [
-                    "content".to_string(),
-                    content.to_string(),
+                    "email".to_string(),
+                    email.to_string(),
                 ].join(",")
             }),
 
 
-            self.user_id.as_ref().map(|user_id| {
+            self.password.as_ref().map(|password| {
                 [
-                    "userId".to_string(),
-                    user_id.to_string(),
+                    "password".to_string(),
+                    password.to_string(),
                 ].join(",")
             }),
 
+            // Skipping role in query parameter serialization
+
+            // Skipping createdAt in query parameter serialization
+
+            // Skipping lastLoginAt in query parameter serialization
+
         ];
 
         write!(f, "{}", params.into_iter().flatten().collect::<Vec<_>>().join(","))
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a Post value
+/// Converts Query Parameters representation (style=form, explode=false) to a User value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for Post {
+impl std::str::FromStr for User {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -310,10 +5085,13 @@ impl std::str::FromStr for Post {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub id: Vec<uuid::Uuid>,
-            pub title: Vec<String>,
-            pub content: Vec<String>,
-            pub user_id: Vec<i64>,
+            pub id: Vec<i64>,
+            pub name: Vec<String>,
+            pub email: Vec<String>,
+            pub password: Vec<String>,
+            pub role: Vec<models::Role>,
+            pub created_at: Vec<chrono::DateTime::<chrono::Utc>>,
+            pub last_login_at: Vec<chrono::DateTime::<chrono::Utc>>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -325,21 +5103,27 @@ impl std::str::FromStr for Post {
         while key_result.is_some() {
             let val = match string_iter.next() {
                 Some(x) => x,
-                None => return std::result::Result::Err("Missing value while parsing Post".to_string())
+                None => return std::result::Result::Err("Missing value while parsing User".to_string())
             };
 
             if let Some(key) = key_result {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "id" => intermediate_rep.id.push(<uuid::Uuid as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    "id" => intermediate_rep.id.push(<i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
                     #[allow(clippy::redundant_clone)]
-                    "title" => intermediate_rep.title.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    "name" => intermediate_rep.name.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
                     #[allow(clippy::redundant_clone)]
-                    "content" => intermediate_rep.content.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    "email" => intermediate_rep.email.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
                     #[allow(clippy::redundant_clone)]
-                    "userId" => intermediate_rep.user_id.push(<i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
-                    _ => return std::result::Result::Err("Unexpected key while parsing Post".to_string())
+                    "password" => intermediate_rep.password.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "role" => intermediate_rep.role.push(<models::Role as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "createdAt" => intermediate_rep.created_at.push(<chrono::DateTime::<chrono::Utc> as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "lastLoginAt" => intermediate_rep.last_login_at.push(<chrono::DateTime::<chrono::Utc> as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    _ => return std::result::Result::Err("Unexpected key while parsing User".to_string())
                 }
             }
 
@@ -348,43 +5132,46 @@ impl std::str::FromStr for Post {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(Post {
+        std::result::Result::Ok(User {
             id: intermediate_rep.id.into_iter().next(),
-            title: intermediate_rep.title.into_iter().next(),
-            content: intermediate_rep.content.into_iter().next(),
-            user_id: intermediate_rep.user_id.into_iter().next(),
+            name: intermediate_rep.name.into_iter().next(),
+            email: intermediate_rep.email.into_iter().next(),
+            password: intermediate_rep.password.into_iter().next(),
+            role: intermediate_rep.role.into_iter().next(),
+            created_at: intermediate_rep.created_at.into_iter().next(),
+            last_login_at: intermediate_rep.last_login_at.into_iter().next(),
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<Post> and HeaderValue
+// Methods for converting between header::IntoHeaderValue<User> and HeaderValue
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<header::IntoHeaderValue<Post>> for HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<User>> for HeaderValue {
     type Error = String;
 
-    fn try_from(hdr_value: header::IntoHeaderValue<Post>) -> std::result::Result<Self, Self::Error> {
+    fn try_from(hdr_value: header::IntoHeaderValue<User>) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match HeaderValue::from_str(&hdr_value) {
              std::result::Result::Ok(value) => std::result::Result::Ok(value),
              std::result::Result::Err(e) => std::result::Result::Err(
-                 format!("Invalid header value for Post - value: {} is invalid {}",
+                 format!("Invalid header value for User - value: {} is invalid {}",
                      hdr_value, e))
         }
     }
 }
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<Post> {
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<User> {
     type Error = String;
 
     fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
              std::result::Result::Ok(value) => {
-                    match <Post as std::str::FromStr>::from_str(value) {
+                    match <User as std::str::FromStr>::from_str(value) {
                         std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
                         std::result::Result::Err(err) => std::result::Result::Err(
-                            format!("Unable to convert header value '{}' into Post - {}",
+                            format!("Unable to convert header value '{}' into User - {}",
                                 value, err))
                     }
              },
@@ -400,44 +5187,52 @@ impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<Post> {
 
 
 
-
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct PostsPostRequest {
-/// 投稿のタイトル
-    #[serde(rename = "title")]
-    pub title: String,
+pub struct UsersPostRequest {
+/// ユーザーの名前
+    #[serde(rename = "name")]
+    pub name: String,
 
-/// 投稿の内容
-    #[serde(rename = "content")]
-    pub content: String,
+/// ユーザーのメールアドレス
+    #[serde(rename = "email")]
+    pub email: String,
+
+/// ユーザーのパスワード
+    #[serde(rename = "password")]
+    pub password: String,
 
 }
 
 
-impl PostsPostRequest {
+impl UsersPostRequest {
     #[allow(clippy::new_without_default, clippy::too_many_arguments)]
-    pub fn new(title: String, content: String, ) -> PostsPostRequest {
-        PostsPostRequest {
-            title,
-            content,
+    pub fn new(name: String, email: String, password: String, ) -> UsersPostRequest {
+        UsersPostRequest {
+            name,
+            email,
+            password,
         }
     }
 }
 
-/// Converts the PostsPostRequest value to the Query Parameters representation (style=form, explode=false)
+/// Converts the UsersPostRequest value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::fmt::Display for PostsPostRequest {
+impl std::fmt::Display for UsersPostRequest {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let params: Vec<Option<String>> = vec![
 
-            Some("title".to_string()),
-            Some(self.title.to_string()),
+            Some("name".to_string()),
+            Some(self.name.to_string()),
 
 
-            Some("content".to_string()),
-            Some(self.content.to_string()),
+            Some("email".to_string()),
+            Some(self.email.to_string()),
+
+
+            Some("password".to_string()),
+            Some(self.password.to_string()),
 
         ];
 
@@ -445,19 +5240,20 @@ impl std::fmt::Display for PostsPostRequest {
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a PostsPostRequest value
+/// Converts Query Parameters representation (style=form, explode=false) to a UsersPostRequest value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for PostsPostRequest {
+impl std::str::FromStr for UsersPostRequest {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
         /// An intermediate representation of the struct to use for parsing.
         #[derive(Default)]
         #[allow(dead_code)]
-        struct IntermediateRep {
-            pub title: Vec<String>,
-            pub content: Vec<String>,
+        struct IntermediateRep {
+            pub name: Vec<String>,
+            pub email: Vec<String>,
+            pub password: Vec<String>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -469,17 +5265,19 @@ impl std::str::FromStr for PostsPostRequest {
         while key_result.is_some() {
             let val = match string_iter.next() {
                 Some(x) => x,
-                None => return std::result::Result::Err("Missing value while parsing PostsPostRequest".to_string())
+                None => return std::result::Result::Err("Missing value while parsing UsersPostRequest".to_string())
             };
 
             if let Some(key) = key_result {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "title" => intermediate_rep.title.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    "name" => intermediate_rep.name.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
                     #[allow(clippy::redundant_clone)]
-                    "content" => intermediate_rep.content.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
-                    _ => return std::result::Result::Err("Unexpected key while parsing PostsPostRequest".to_string())
+                    "email" => intermediate_rep.email.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "password" => intermediate_rep.password.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    _ => return std::result::Result::Err("Unexpected key while parsing UsersPostRequest".to_string())
                 }
             }
 
@@ -488,41 +5286,42 @@ impl std::str::FromStr for PostsPostRequest {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(PostsPostRequest {
-            title: intermediate_rep.title.into_iter().next().ok_or_else(|| "title missing in PostsPostRequest".to_string())?,
-            content: intermediate_rep.content.into_iter().next().ok_or_else(|| "content missing in PostsPostRequest".to_string())?,
+        std::result::Result::Ok(UsersPostRequest {
+            name: intermediate_rep.name.into_iter().next().ok_or_else(|| "name missing in UsersPostRequest".to_string())?,
+            email: intermediate_rep.email.into_iter().next().ok_or_else(|| "email missing in UsersPostRequest".to_string())?,
+            password: intermediate_rep.password.into_iter().next().ok_or_else(|| "password missing in UsersPostRequest".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<PostsPostRequest> and HeaderValue
+// Methods for converting between header::IntoHeaderValue<UsersPostRequest> and HeaderValue
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<header::IntoHeaderValue<PostsPostRequest>> for HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<UsersPostRequest>> for HeaderValue {
     type Error = String;
 
-    fn try_from(hdr_value: header::IntoHeaderValue<PostsPostRequest>) -> std::result::Result<Self, Self::Error> {
+    fn try_from(hdr_value: header::IntoHeaderValue<UsersPostRequest>) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match HeaderValue::from_str(&hdr_value) {
              std::result::Result::Ok(value) => std::result::Result::Ok(value),
              std::result::Result::Err(e) => std::result::Result::Err(
-                 format!("Invalid header value for PostsPostRequest - value: {} is invalid {}",
+                 format!("Invalid header value for UsersPostRequest - value: {} is invalid {}",
                      hdr_value, e))
         }
     }
 }
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<PostsPostRequest> {
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<UsersPostRequest> {
     type Error = String;
 
     fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
              std::result::Result::Ok(value) => {
-                    match <PostsPostRequest as std::str::FromStr>::from_str(value) {
+                    match <UsersPostRequest as std::str::FromStr>::from_str(value) {
                         std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
                         std::result::Result::Err(err) => std::result::Result::Err(
-                            format!("Unable to convert header value '{}' into PostsPostRequest - {}",
+                            format!("Unable to convert header value '{}' into UsersPostRequest - {}",
                                 value, err))
                     }
              },
@@ -538,51 +5337,90 @@ impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<PostsPostReq
 
 
 
+/// イベントを通知する送信先
+
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct Token {
-/// JWTトークン
-    #[serde(rename = "token")]
+pub struct Webhook {
+    #[serde(rename = "id")]
+    pub id: uuid::Uuid,
+
+/// 配信をPOSTするURL
+    #[serde(rename = "url")]
+    pub url: String,
+
+/// 通知するイベント。空の場合はすべてのイベントを通知します
+    #[serde(rename = "events")]
+    pub events: Vec<models::WebhookEventType>,
+
+/// 署名の鍵。登録時の応答にのみ含まれます
+    #[serde(rename = "secret")]
     #[serde(skip_serializing_if="Option::is_none")]
-    pub token: Option<String>,
+    pub secret: Option<String>,
+
+/// 登録した管理者のID
+    #[serde(rename = "createdBy")]
+    pub created_by: i64,
+
+    #[serde(rename = "createdAt")]
+    pub created_at: chrono::DateTime::<chrono::Utc>,
 
 }
 
 
-impl Token {
+impl Webhook {
     #[allow(clippy::new_without_default, clippy::too_many_arguments)]
-    pub fn new() -> Token {
-        Token {
-            token: None,
+    pub fn new(id: uuid::Uuid, url: String, events: Vec<models::WebhookEventType>, created_by: i64, created_at: chrono::DateTime::<chrono::Utc>, ) -> Webhook {
+        Webhook {
+            id,
+            url,
+            events,
+            secret: None,
+            created_by,
+            created_at,
         }
     }
 }
 
-/// Converts the Token value to the Query Parameters representation (style=form, explode=false)
+/// Converts the Webhook value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::fmt::Display for Token {
+impl std::fmt::Display for Webhook {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let params: Vec<Option<String>> = vec![
+            // Skipping id in query parameter serialization
 
-            self.token.as_ref().map(|token| {
+
+            Some("url".to_string()),
+            Some(self.url.to_string()),
+
+            // Skipping events in query parameter serialization
+
+
+            self.secret.as_ref().map(|secret| {
                 [
-                    "token".to_string(),
-                    token.to_string(),
+                    "secret".to_string(),
+                    secret.to_string(),
                 ].join(",")
             }),
 
+
+            Some("createdBy".to_string()),
+            Some(self.created_by.to_string()),
+
+            // Skipping createdAt in query parameter serialization
+
         ];
 
         write!(f, "{}", params.into_iter().flatten().collect::<Vec<_>>().join(","))
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a Token value
+/// Converts Query Parameters representation (style=form, explode=false) to a Webhook value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for Token {
+impl std::str::FromStr for Webhook {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -590,7 +5428,12 @@ impl std::str::FromStr for Token {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub token: Vec<String>,
+            pub id: Vec<uuid::Uuid>,
+            pub url: Vec<String>,
+            pub events: Vec<Vec<models::WebhookEventType>>,
+            pub secret: Vec<String>,
+            pub created_by: Vec<i64>,
+            pub created_at: Vec<chrono::DateTime::<chrono::Utc>>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -602,15 +5445,24 @@ impl std::str::FromStr for Token {
         while key_result.is_some() {
             let val = match string_iter.next() {
                 Some(x) => x,
-                None => return std::result::Result::Err("Missing value while parsing Token".to_string())
+                None => return std::result::Result::Err("Missing value while parsing Webhook".to_string())
             };
 
             if let Some(key) = key_result {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "token" => intermediate_rep.token.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
-                    _ => return std::result::Result::Err("Unexpected key while parsing Token".to_string())
+                    "id" => intermediate_rep.id.push(<uuid::Uuid as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "url" => intermediate_rep.url.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    "events" => return std::result::Result::Err("Parsing a container in this style is not supported in Webhook".to_string()),
+                    #[allow(clippy::redundant_clone)]
+                    "secret" => intermediate_rep.secret.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "createdBy" => intermediate_rep.created_by.push(<i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "createdAt" => intermediate_rep.created_at.push(<chrono::DateTime::<chrono::Utc> as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    _ => return std::result::Result::Err("Unexpected key while parsing Webhook".to_string())
                 }
             }
 
@@ -619,40 +5471,45 @@ impl std::str::FromStr for Token {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(Token {
-            token: intermediate_rep.token.into_iter().next(),
+        std::result::Result::Ok(Webhook {
+            id: intermediate_rep.id.into_iter().next().ok_or_else(|| "id missing in Webhook".to_string())?,
+            url: intermediate_rep.url.into_iter().next().ok_or_else(|| "url missing in Webhook".to_string())?,
+            events: intermediate_rep.events.into_iter().next().ok_or_else(|| "events missing in Webhook".to_string())?,
+            secret: intermediate_rep.secret.into_iter().next(),
+            created_by: intermediate_rep.created_by.into_iter().next().ok_or_else(|| "createdBy missing in Webhook".to_string())?,
+            created_at: intermediate_rep.created_at.into_iter().next().ok_or_else(|| "createdAt missing in Webhook".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<Token> and HeaderValue
+// Methods for converting between header::IntoHeaderValue<Webhook> and HeaderValue
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<header::IntoHeaderValue<Token>> for HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<Webhook>> for HeaderValue {
     type Error = String;
 
-    fn try_from(hdr_value: header::IntoHeaderValue<Token>) -> std::result::Result<Self, Self::Error> {
+    fn try_from(hdr_value: header::IntoHeaderValue<Webhook>) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match HeaderValue::from_str(&hdr_value) {
              std::result::Result::Ok(value) => std::result::Result::Ok(value),
              std::result::Result::Err(e) => std::result::Result::Err(
-                 format!("Invalid header value for Token - value: {} is invalid {}",
+                 format!("Invalid header value for Webhook - value: {} is invalid {}",
                      hdr_value, e))
         }
     }
 }
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<Token> {
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<Webhook> {
     type Error = String;
 
     fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
              std::result::Result::Ok(value) => {
-                    match <Token as std::str::FromStr>::from_str(value) {
+                    match <Webhook as std::str::FromStr>::from_str(value) {
                         std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
                         std::result::Result::Err(err) => std::result::Result::Err(
-                            format!("Unable to convert header value '{}' into Token - {}",
+                            format!("Unable to convert header value '{}' into Webhook - {}",
                                 value, err))
                     }
              },
@@ -669,92 +5526,122 @@ impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<Token> {
 
 
 
+/// Webhookへの1つのイベントの配信
+
+
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct User {
-/// ユーザーの一意の識別子
+pub struct WebhookDelivery {
+/// 配信のID。`X-Board-Delivery` で送ります
     #[serde(rename = "id")]
+    pub id: uuid::Uuid,
+
+    #[serde(rename = "webhookId")]
+    pub webhook_id: uuid::Uuid,
+
+    #[serde(rename = "event")]
+    pub event: models::WebhookEventType,
+
+    #[serde(rename = "status")]
+    pub status: models::WebhookDeliveryStatus,
+
+/// 試行した回数
+    #[serde(rename = "attempts")]
+    pub attempts: i32,
+
+/// 次に試行する日時。`pending` の場合のみ含まれます
+    #[serde(rename = "nextAttemptAt")]
     #[serde(skip_serializing_if="Option::is_none")]
-    pub id: Option<i64>,
+    pub next_attempt_at: Option<chrono::DateTime::<chrono::Utc>>,
 
-/// ユーザーの名前
-    #[serde(rename = "name")]
+    #[serde(rename = "lastAttemptAt")]
     #[serde(skip_serializing_if="Option::is_none")]
-    pub name: Option<String>,
+    pub last_attempt_at: Option<chrono::DateTime::<chrono::Utc>>,
 
-/// ユーザーのメールアドレス
-    #[serde(rename = "email")]
+/// 最後の試行で送信先が返したステータスコード
+    #[serde(rename = "lastStatusCode")]
     #[serde(skip_serializing_if="Option::is_none")]
-    pub email: Option<String>,
+    pub last_status_code: Option<i32>,
 
-/// ユーザーのパスワード
-    #[serde(rename = "password")]
+/// 最後の試行が失敗した理由
+    #[serde(rename = "lastError")]
     #[serde(skip_serializing_if="Option::is_none")]
-    pub password: Option<String>,
+    pub last_error: Option<String>,
+
+    #[serde(rename = "createdAt")]
+    pub created_at: chrono::DateTime::<chrono::Utc>,
 
 }
 
 
-impl User {
+impl WebhookDelivery {
     #[allow(clippy::new_without_default, clippy::too_many_arguments)]
-    pub fn new() -> User {
-        User {
-            id: None,
-            name: None,
-            email: None,
-            password: None,
+    pub fn new(id: uuid::Uuid, webhook_id: uuid::Uuid, event: models::WebhookEventType, status: models::WebhookDeliveryStatus, attempts: i32, created_at: chrono::DateTime::<chrono::Utc>, ) -> WebhookDelivery {
+        WebhookDelivery {
+            id,
+            webhook_id,
+            event,
+            status,
+            attempts,
+            next_attempt_at: None,
+            last_attempt_at: None,
+            last_status_code: None,
+            last_error: None,
+            created_at,
         }
     }
 }
 
-/// Converts the User value to the Query Parameters representation (style=form, explode=false)
+/// Converts the WebhookDelivery value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::fmt::Display for User {
+impl std::fmt::Display for WebhookDelivery {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let params: Vec<Option<String>> = vec![
+            // Skipping id in query parameter serialization
 
-            self.id.as_ref().map(|id| {
-                [
-                    "id".to_string(),
-                    id.to_string(),
-                ].join(",")
-            }),
+            // Skipping webhookId in query parameter serialization
 
+            // Skipping event in query parameter serialization
 
-            self.name.as_ref().map(|name| {
-                [
-                    "name".to_string(),
-                    name.to_string(),
-                ].join(",")
-            }),
+            // Skipping status in query parameter serialization
 
 
-            self.email.as_ref().map(|email| {
+            Some("attempts".to_string()),
+            Some(self.attempts.to_string()),
+
+            // Skipping nextAttemptAt in query parameter serialization
+
+            // Skipping lastAttemptAt in query parameter serialization
+
+
+            self.last_status_code.as_ref().map(|last_status_code| {
                 [
-                    "email".to_string(),
-                    email.to_string(),
+                    "lastStatusCode".to_string(),
+                    last_status_code.to_string(),
                 ].join(",")
             }),
 
 
-            self.password.as_ref().map(|password| {
+            self.last_error.as_ref().map(|last_error| {
                 [
-                    "password".to_string(),
-                    password.to_string(),
+                    "lastError".to_string(),
+                    last_error.to_string(),
                 ].join(",")
             }),
 
+            // Skipping createdAt in query parameter serialization
+
         ];
 
         write!(f, "{}", params.into_iter().flatten().collect::<Vec<_>>().join(","))
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a User value
+/// Converts Query Parameters representation (style=form, explode=false) to a WebhookDelivery value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for User {
+impl std::str::FromStr for WebhookDelivery {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -762,10 +5649,16 @@ impl std::str::FromStr for User {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub id: Vec<i64>,
-            pub name: Vec<String>,
-            pub email: Vec<String>,
-            pub password: Vec<String>,
+            pub id: Vec<uuid::Uuid>,
+            pub webhook_id: Vec<uuid::Uuid>,
+            pub event: Vec<models::WebhookEventType>,
+            pub status: Vec<models::WebhookDeliveryStatus>,
+            pub attempts: Vec<i32>,
+            pub next_attempt_at: Vec<chrono::DateTime::<chrono::Utc>>,
+            pub last_attempt_at: Vec<chrono::DateTime::<chrono::Utc>>,
+            pub last_status_code: Vec<i32>,
+            pub last_error: Vec<String>,
+            pub created_at: Vec<chrono::DateTime::<chrono::Utc>>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -777,21 +5670,33 @@ impl std::str::FromStr for User {
         while key_result.is_some() {
             let val = match string_iter.next() {
                 Some(x) => x,
-                None => return std::result::Result::Err("Missing value while parsing User".to_string())
+                None => return std::result::Result::Err("Missing value while parsing WebhookDelivery".to_string())
             };
 
             if let Some(key) = key_result {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "id" => intermediate_rep.id.push(<i64 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    "id" => intermediate_rep.id.push(<uuid::Uuid as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
                     #[allow(clippy::redundant_clone)]
-                    "name" => intermediate_rep.name.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    "webhookId" => intermediate_rep.webhook_id.push(<uuid::Uuid as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
                     #[allow(clippy::redundant_clone)]
-                    "email" => intermediate_rep.email.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    "event" => intermediate_rep.event.push(<models::WebhookEventType as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
                     #[allow(clippy::redundant_clone)]
-                    "password" => intermediate_rep.password.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
-                    _ => return std::result::Result::Err("Unexpected key while parsing User".to_string())
+                    "status" => intermediate_rep.status.push(<models::WebhookDeliveryStatus as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "attempts" => intermediate_rep.attempts.push(<i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "nextAttemptAt" => intermediate_rep.next_attempt_at.push(<chrono::DateTime::<chrono::Utc> as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "lastAttemptAt" => intermediate_rep.last_attempt_at.push(<chrono::DateTime::<chrono::Utc> as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "lastStatusCode" => intermediate_rep.last_status_code.push(<i32 as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "lastError" => intermediate_rep.last_error.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "createdAt" => intermediate_rep.created_at.push(<chrono::DateTime::<chrono::Utc> as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    _ => return std::result::Result::Err("Unexpected key while parsing WebhookDelivery".to_string())
                 }
             }
 
@@ -800,43 +5705,49 @@ impl std::str::FromStr for User {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(User {
-            id: intermediate_rep.id.into_iter().next(),
-            name: intermediate_rep.name.into_iter().next(),
-            email: intermediate_rep.email.into_iter().next(),
-            password: intermediate_rep.password.into_iter().next(),
+        std::result::Result::Ok(WebhookDelivery {
+            id: intermediate_rep.id.into_iter().next().ok_or_else(|| "id missing in WebhookDelivery".to_string())?,
+            webhook_id: intermediate_rep.webhook_id.into_iter().next().ok_or_else(|| "webhookId missing in WebhookDelivery".to_string())?,
+            event: intermediate_rep.event.into_iter().next().ok_or_else(|| "event missing in WebhookDelivery".to_string())?,
+            status: intermediate_rep.status.into_iter().next().ok_or_else(|| "status missing in WebhookDelivery".to_string())?,
+            attempts: intermediate_rep.attempts.into_iter().next().ok_or_else(|| "attempts missing in WebhookDelivery".to_string())?,
+            next_attempt_at: intermediate_rep.next_attempt_at.into_iter().next(),
+            last_attempt_at: intermediate_rep.last_attempt_at.into_iter().next(),
+            last_status_code: intermediate_rep.last_status_code.into_iter().next(),
+            last_error: intermediate_rep.last_error.into_iter().next(),
+            created_at: intermediate_rep.created_at.into_iter().next().ok_or_else(|| "createdAt missing in WebhookDelivery".to_string())?,
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<User> and HeaderValue
+// Methods for converting between header::IntoHeaderValue<WebhookDelivery> and HeaderValue
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<header::IntoHeaderValue<User>> for HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<WebhookDelivery>> for HeaderValue {
     type Error = String;
 
-    fn try_from(hdr_value: header::IntoHeaderValue<User>) -> std::result::Result<Self, Self::Error> {
+    fn try_from(hdr_value: header::IntoHeaderValue<WebhookDelivery>) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match HeaderValue::from_str(&hdr_value) {
              std::result::Result::Ok(value) => std::result::Result::Ok(value),
              std::result::Result::Err(e) => std::result::Result::Err(
-                 format!("Invalid header value for User - value: {} is invalid {}",
+                 format!("Invalid header value for WebhookDelivery - value: {} is invalid {}",
                      hdr_value, e))
         }
     }
 }
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<User> {
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<WebhookDelivery> {
     type Error = String;
 
     fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
              std::result::Result::Ok(value) => {
-                    match <User as std::str::FromStr>::from_str(value) {
+                    match <WebhookDelivery as std::str::FromStr>::from_str(value) {
                         std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
                         std::result::Result::Err(err) => std::result::Result::Err(
-                            format!("Unable to convert header value '{}' into User - {}",
+                            format!("Unable to convert header value '{}' into WebhookDelivery - {}",
                                 value, err))
                     }
              },
@@ -847,58 +5758,161 @@ impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<User> {
     }
 }
 
+/// 配信の状態
+/// Enumeration of values.
+/// Since this enum's variants do not hold data, we can easily define them as `#[repr(C)]`
+/// which helps with FFI.
+#[allow(non_camel_case_types)]
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "conversion", derive(frunk_enum_derive::LabelledGenericEnum))]
+pub enum WebhookDeliveryStatus {
+    #[serde(rename = "pending")]
+    Pending,
+    #[serde(rename = "succeeded")]
+    Succeeded,
+    #[serde(rename = "dead")]
+    Dead,
+}
+
+impl std::fmt::Display for WebhookDeliveryStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            WebhookDeliveryStatus::Pending => write!(f, "pending"),
+            WebhookDeliveryStatus::Succeeded => write!(f, "succeeded"),
+            WebhookDeliveryStatus::Dead => write!(f, "dead"),
+        }
+    }
+}
+
+impl std::str::FromStr for WebhookDeliveryStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "pending" => std::result::Result::Ok(WebhookDeliveryStatus::Pending),
+            "succeeded" => std::result::Result::Ok(WebhookDeliveryStatus::Succeeded),
+            "dead" => std::result::Result::Ok(WebhookDeliveryStatus::Dead),
+            _ => std::result::Result::Err(format!("Value not valid: {}", s)),
+        }
+    }
+}
+
+/// Webhookで通知するイベントの種類
+/// Enumeration of values.
+/// Since this enum's variants do not hold data, we can easily define them as `#[repr(C)]`
+/// which helps with FFI.
+#[allow(non_camel_case_types)]
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize)]
+#[cfg_attr(feature = "conversion", derive(frunk_enum_derive::LabelledGenericEnum))]
+pub enum WebhookEventType {
+    #[serde(rename = "post_created")]
+    PostCreated,
+    #[serde(rename = "post_updated")]
+    PostUpdated,
+    #[serde(rename = "post_deleted")]
+    PostDeleted,
+    #[serde(rename = "reply_created")]
+    ReplyCreated,
+    #[serde(rename = "user_created")]
+    UserCreated,
+    #[serde(rename = "user_role_changed")]
+    UserRoleChanged,
+}
+
+impl std::fmt::Display for WebhookEventType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            WebhookEventType::PostCreated => write!(f, "post_created"),
+            WebhookEventType::PostUpdated => write!(f, "post_updated"),
+            WebhookEventType::PostDeleted => write!(f, "post_deleted"),
+            WebhookEventType::ReplyCreated => write!(f, "reply_created"),
+            WebhookEventType::UserCreated => write!(f, "user_created"),
+            WebhookEventType::UserRoleChanged => write!(f, "user_role_changed"),
+        }
+    }
+}
+
+impl std::str::FromStr for WebhookEventType {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "post_created" => std::result::Result::Ok(WebhookEventType::PostCreated),
+            "post_updated" => std::result::Result::Ok(WebhookEventType::PostUpdated),
+            "post_deleted" => std::result::Result::Ok(WebhookEventType::PostDeleted),
+            "reply_created" => std::result::Result::Ok(WebhookEventType::ReplyCreated),
+            "user_created" => std::result::Result::Ok(WebhookEventType::UserCreated),
+            "user_role_changed" => std::result::Result::Ok(WebhookEventType::UserRoleChanged),
+            _ => std::result::Result::Err(format!("Value not valid: {}", s)),
+        }
+    }
+}
+
+
 
 
 
 
 
+/// Webhookで送る本文。`type` に応じて `post` か `user` のいずれかを含みます。`user` にメールアドレスは含まれません
+
 
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, validator::Validate)]
 #[cfg_attr(feature = "conversion", derive(frunk::LabelledGeneric))]
-pub struct UsersPostRequest {
-/// ユーザーの名前
-    #[serde(rename = "name")]
-    pub name: String,
+pub struct WebhookPayload {
+/// イベントのID。同じイベントを複数のWebhookに送る場合も同じです
+    #[serde(rename = "id")]
+    pub id: uuid::Uuid,
 
-/// ユーザーのメールアドレス
-    #[serde(rename = "email")]
-    pub email: String,
+    #[serde(rename = "type")]
+    pub r#type: models::WebhookEventType,
 
-/// ユーザーのパスワード
-    #[serde(rename = "password")]
-    pub password: String,
+    #[serde(rename = "occurredAt")]
+    pub occurred_at: chrono::DateTime::<chrono::Utc>,
+
+    #[serde(rename = "post")]
+    #[validate(nested)]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub post: Option<models::Post>,
+
+    #[serde(rename = "user")]
+    #[validate(nested)]
+    #[serde(skip_serializing_if="Option::is_none")]
+    pub user: Option<models::User>,
 
 }
 
 
-impl UsersPostRequest {
+impl WebhookPayload {
     #[allow(clippy::new_without_default, clippy::too_many_arguments)]
-    pub fn new(name: String, email: String, password: String, ) -> UsersPostRequest {
-        UsersPostRequest {
-            name,
-            email,
-            password,
+    pub fn new(id: uuid::Uuid, r#type: models::WebhookEventType, occurred_at: chrono::DateTime::<chrono::Utc>, ) -> WebhookPayload {
+        WebhookPayload {
+            id,
+            r#type,
+            occurred_at,
+            post: None,
+            user: None,
         }
     }
 }
 
-/// Converts the UsersPostRequest value to the Query Parameters representation (style=form, explode=false)
+/// Converts the WebhookPayload value to the Query Parameters representation (style=form, explode=false)
 /// specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde serializer
-impl std::fmt::Display for UsersPostRequest {
+impl std::fmt::Display for WebhookPayload {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let params: Vec<Option<String>> = vec![
+            // Skipping id in query parameter serialization
 
-            Some("name".to_string()),
-            Some(self.name.to_string()),
-
+            // Skipping type in query parameter serialization
 
-            Some("email".to_string()),
-            Some(self.email.to_string()),
+            // Skipping occurredAt in query parameter serialization
 
+            // Skipping post in query parameter serialization
 
-            Some("password".to_string()),
-            Some(self.password.to_string()),
+            // Skipping user in query parameter serialization
 
         ];
 
@@ -906,10 +5920,10 @@ impl std::fmt::Display for UsersPostRequest {
     }
 }
 
-/// Converts Query Parameters representation (style=form, explode=false) to a UsersPostRequest value
+/// Converts Query Parameters representation (style=form, explode=false) to a WebhookPayload value
 /// as specified in https://swagger.io/docs/specification/serialization/
 /// Should be implemented in a serde deserializer
-impl std::str::FromStr for UsersPostRequest {
+impl std::str::FromStr for WebhookPayload {
     type Err = String;
 
     fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
@@ -917,9 +5931,11 @@ impl std::str::FromStr for UsersPostRequest {
         #[derive(Default)]
         #[allow(dead_code)]
         struct IntermediateRep {
-            pub name: Vec<String>,
-            pub email: Vec<String>,
-            pub password: Vec<String>,
+            pub id: Vec<uuid::Uuid>,
+            pub r#type: Vec<models::WebhookEventType>,
+            pub occurred_at: Vec<chrono::DateTime::<chrono::Utc>>,
+            pub post: Vec<models::Post>,
+            pub user: Vec<models::User>,
         }
 
         let mut intermediate_rep = IntermediateRep::default();
@@ -931,19 +5947,23 @@ impl std::str::FromStr for UsersPostRequest {
         while key_result.is_some() {
             let val = match string_iter.next() {
                 Some(x) => x,
-                None => return std::result::Result::Err("Missing value while parsing UsersPostRequest".to_string())
+                None => return std::result::Result::Err("Missing value while parsing WebhookPayload".to_string())
             };
 
             if let Some(key) = key_result {
                 #[allow(clippy::match_single_binding)]
                 match key {
                     #[allow(clippy::redundant_clone)]
-                    "name" => intermediate_rep.name.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    "id" => intermediate_rep.id.push(<uuid::Uuid as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
                     #[allow(clippy::redundant_clone)]
-                    "email" => intermediate_rep.email.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    "type" => intermediate_rep.r#type.push(<models::WebhookEventType as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
                     #[allow(clippy::redundant_clone)]
-                    "password" => intermediate_rep.password.push(<String as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
-                    _ => return std::result::Result::Err("Unexpected key while parsing UsersPostRequest".to_string())
+                    "occurredAt" => intermediate_rep.occurred_at.push(<chrono::DateTime::<chrono::Utc> as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "post" => intermediate_rep.post.push(<models::Post as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    #[allow(clippy::redundant_clone)]
+                    "user" => intermediate_rep.user.push(<models::User as std::str::FromStr>::from_str(val).map_err(|x| x.to_string())?),
+                    _ => return std::result::Result::Err("Unexpected key while parsing WebhookPayload".to_string())
                 }
             }
 
@@ -952,42 +5972,44 @@ impl std::str::FromStr for UsersPostRequest {
         }
 
         // Use the intermediate representation to return the struct
-        std::result::Result::Ok(UsersPostRequest {
-            name: intermediate_rep.name.into_iter().next().ok_or_else(|| "name missing in UsersPostRequest".to_string())?,
-            email: intermediate_rep.email.into_iter().next().ok_or_else(|| "email missing in UsersPostRequest".to_string())?,
-            password: intermediate_rep.password.into_iter().next().ok_or_else(|| "password missing in UsersPostRequest".to_string())?,
+        std::result::Result::Ok(WebhookPayload {
+            id: intermediate_rep.id.into_iter().next().ok_or_else(|| "id missing in WebhookPayload".to_string())?,
+            r#type: intermediate_rep.r#type.into_iter().next().ok_or_else(|| "type missing in WebhookPayload".to_string())?,
+            occurred_at: intermediate_rep.occurred_at.into_iter().next().ok_or_else(|| "occurredAt missing in WebhookPayload".to_string())?,
+            post: intermediate_rep.post.into_iter().next(),
+            user: intermediate_rep.user.into_iter().next(),
         })
     }
 }
 
-// Methods for converting between header::IntoHeaderValue<UsersPostRequest> and HeaderValue
+// Methods for converting between header::IntoHeaderValue<WebhookPayload> and HeaderValue
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<header::IntoHeaderValue<UsersPostRequest>> for HeaderValue {
+impl std::convert::TryFrom<header::IntoHeaderValue<WebhookPayload>> for HeaderValue {
     type Error = String;
 
-    fn try_from(hdr_value: header::IntoHeaderValue<UsersPostRequest>) -> std::result::Result<Self, Self::Error> {
+    fn try_from(hdr_value: header::IntoHeaderValue<WebhookPayload>) -> std::result::Result<Self, Self::Error> {
         let hdr_value = hdr_value.to_string();
         match HeaderValue::from_str(&hdr_value) {
              std::result::Result::Ok(value) => std::result::Result::Ok(value),
              std::result::Result::Err(e) => std::result::Result::Err(
-                 format!("Invalid header value for UsersPostRequest - value: {} is invalid {}",
+                 format!("Invalid header value for WebhookPayload - value: {} is invalid {}",
                      hdr_value, e))
         }
     }
 }
 
 #[cfg(feature = "server")]
-impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<UsersPostRequest> {
+impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<WebhookPayload> {
     type Error = String;
 
     fn try_from(hdr_value: HeaderValue) -> std::result::Result<Self, Self::Error> {
         match hdr_value.to_str() {
              std::result::Result::Ok(value) => {
-                    match <UsersPostRequest as std::str::FromStr>::from_str(value) {
+                    match <WebhookPayload as std::str::FromStr>::from_str(value) {
                         std::result::Result::Ok(value) => std::result::Result::Ok(header::IntoHeaderValue(value)),
                         std::result::Result::Err(err) => std::result::Result::Err(
-                            format!("Unable to convert header value '{}' into UsersPostRequest - {}",
+                            format!("Unable to convert header value '{}' into WebhookPayload - {}",
                                 value, err))
                     }
              },
@@ -999,4 +6021,3 @@ impl std::convert::TryFrom<HeaderValue> for header::IntoHeaderValue<UsersPostReq
 }
 
 
-
@@ -0,0 +1,73 @@
+use validator::{ValidationErrors, ValidationErrorsKind};
+
+use crate::models;
+
+/// エラーコードに対応する`type`のURI
+pub fn problem_type(code: models::ErrorCode) -> String {
+    format!("urn:board:problem:{}", code)
+}
+
+/// エラーコードに対応するRFC 7807のエラー応答
+pub fn problem(code: models::ErrorCode) -> models::Problem {
+    let (title, status) = match code {
+        models::ErrorCode::ValidationFailed => ("Validation failed", 400),
+        models::ErrorCode::MissingBody => ("Request body is required", 400),
+        models::ErrorCode::InternalError => ("Internal server error", 500),
+        models::ErrorCode::Conflict => ("Resource was modified concurrently", 409),
+        models::ErrorCode::InvalidCredentials => ("Email or password is incorrect", 400),
+    };
+    models::Problem::new(problem_type(code), title.to_string(), status, code)
+}
+
+/// 検証エラーを項目ごとのエラーの一覧にした応答
+pub fn validation_problem(errors: &ValidationErrors) -> models::Problem {
+    let mut field_errors = Vec::new();
+    collect_field_errors("", errors, &mut field_errors);
+    // HashMapの順序に依存しないよう項目名で並べる
+    field_errors.sort_by(|a, b| a.field.cmp(&b.field));
+    models::Problem {
+        errors: Some(field_errors),
+        ..problem(models::ErrorCode::ValidationFailed)
+    }
+}
+
+/// 解釈できないリクエストヘッダーの応答
+pub fn header_problem(name: &str, detail: &str) -> models::Problem {
+    let error = models::ProblemFieldError {
+        message: Some(detail.to_string()),
+        ..models::ProblemFieldError::new(name.to_string(), "header".to_string())
+    };
+    models::Problem {
+        errors: Some(vec![error]),
+        ..problem(models::ErrorCode::ValidationFailed)
+    }
+}
+
+/// 入れ子の構造体とリストをたどり、`a.b[0].c`の形式の項目名でエラーを集める
+fn collect_field_errors(
+    prefix: &str,
+    errors: &ValidationErrors,
+    out: &mut Vec<models::ProblemFieldError>,
+) {
+    for (field, kind) in errors.errors() {
+        let path = if prefix.is_empty() {
+            field.to_string()
+        } else {
+            format!("{}.{}", prefix, field)
+        };
+        match kind {
+            ValidationErrorsKind::Field(errors) => {
+                out.extend(errors.iter().map(|error| models::ProblemFieldError {
+                    message: Some(error.to_string()),
+                    ..models::ProblemFieldError::new(path.clone(), error.code.to_string())
+                }));
+            }
+            ValidationErrorsKind::Struct(errors) => collect_field_errors(&path, errors, out),
+            ValidationErrorsKind::List(items) => {
+                for (index, errors) in items {
+                    collect_field_errors(&format!("{}[{}]", path, index), errors, out);
+                }
+            }
+        }
+    }
+}
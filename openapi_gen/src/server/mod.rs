@@ -7,93 +7,5176 @@ use http::{header::CONTENT_TYPE, HeaderMap, HeaderName, HeaderValue, Method, Sta
 use tracing::error;
 use validator::{Validate, ValidationErrors};
 
-use crate::{header, types::*};
+use crate::{header, problem, types::*};
 
 #[allow(unused_imports)]
 use crate::{apis, models};
+use crate::apis::ApiError;
 
 
 /// Setup API Server.
 pub fn new<I, A>(api_impl: I) -> Router
 where
     I: AsRef<A> + Clone + Send + Sync + 'static,
-    A: apis::auth::Auth + apis::posts::Posts + apis::users::Users + 'static,
+    A: apis::admin::Admin + apis::auth::Auth + apis::boards::Boards + apis::posts::Posts + apis::users::Users + 'static,
 {
     // build our application with a route
     Router::new()
+        .route("/.well-known/jwks.json",
+            get(get_jwks::<I, A>)
+        )
+        .route("/admin/boards",
+            post(admin_boards_post::<I, A>)
+        )
+        .route("/admin/boards/:slug",
+            delete(admin_boards_slug_delete::<I, A>).put(admin_boards_slug_put::<I, A>)
+        )
+        .route("/admin/users/:user_id/role",
+            delete(admin_users_user_id_role_delete::<I, A>).put(admin_users_user_id_role_put::<I, A>)
+        )
+        .route("/admin/webhook-deliveries",
+            get(admin_webhook_deliveries_get::<I, A>)
+        )
+        .route("/admin/webhook-deliveries/:delivery_id/retry",
+            post(admin_webhook_deliveries_delivery_id_retry_post::<I, A>)
+        )
+        .route("/admin/webhooks",
+            get(admin_webhooks_get::<I, A>).post(admin_webhooks_post::<I, A>)
+        )
+        .route("/admin/webhooks/:webhook_id",
+            delete(admin_webhooks_webhook_id_delete::<I, A>)
+        )
+        .route("/admin/webhooks/:webhook_id/deliveries",
+            get(admin_webhooks_webhook_id_deliveries_get::<I, A>)
+        )
         .route("/auth",
             post(auth_post::<I, A>)
         )
+        .route("/auth/logout",
+            post(auth_logout_post::<I, A>)
+        )
+        .route("/auth/logout-all",
+            post(auth_logout_all_post::<I, A>)
+        )
+        .route("/auth/refresh",
+            post(auth_refresh_post::<I, A>)
+        )
+        .route("/boards",
+            get(boards_get::<I, A>)
+        )
+        .route("/boards/:slug",
+            get(boards_slug_get::<I, A>)
+        )
+        .route("/boards/:slug/posts",
+            get(boards_slug_posts_get::<I, A>)
+        )
+        .route("/me/drafts",
+            get(me_drafts_get::<I, A>)
+        )
+        .route("/me/trash",
+            get(me_trash_get::<I, A>)
+        )
         .route("/posts",
             get(posts_get::<I, A>).post(posts_post::<I, A>)
         )
         .route("/posts/:post_id",
-            delete(posts_post_id_delete::<I, A>).get(posts_post_id_get::<I, A>).put(posts_post_id_put::<I, A>)
+            delete(posts_post_id_delete::<I, A>).get(posts_post_id_get::<I, A>).patch(posts_post_id_patch::<I, A>).put(posts_post_id_put::<I, A>)
+        )
+        .route("/posts/:post_id/diff",
+            get(posts_post_id_diff_get::<I, A>)
+        )
+        .route("/posts/:post_id/moderation",
+            put(posts_post_id_moderation_put::<I, A>)
+        )
+        .route("/posts/:post_id/publish",
+            post(posts_post_id_publish_post::<I, A>)
+        )
+        .route("/posts/:post_id/purge",
+            post(posts_post_id_purge_post::<I, A>)
+        )
+        .route("/posts/:post_id/replies",
+            get(posts_post_id_replies_get::<I, A>).post(posts_post_id_replies_post::<I, A>)
+        )
+        .route("/posts/:post_id/restore",
+            post(posts_post_id_restore_post::<I, A>)
+        )
+        .route("/posts/:post_id/revisions",
+            get(posts_post_id_revisions_get::<I, A>)
+        )
+        .route("/posts/:post_id/revisions/:number",
+            get(posts_post_id_revisions_number_get::<I, A>)
+        )
+        .route("/posts/:post_id/revisions/:number/revert",
+            post(posts_post_id_revisions_number_revert_post::<I, A>)
+        )
+        .route("/posts/search",
+            get(posts_search_get::<I, A>)
         )
         .route("/users",
             post(users_post::<I, A>)
         )
         .with_state(api_impl)
 }
+    #[derive(validator::Validate)]
+    #[allow(dead_code)]
+    struct AdminBoardsPostBodyValidator<'a> {
+            #[validate(nested)]
+          body: &'a models::AdminBoardsPostRequest,
+    }
+
+
+#[tracing::instrument(skip_all)]
+fn admin_boards_post_validation(
+  header_params: models::AdminBoardsPostHeaderParams,
+        body: Option<models::AdminBoardsPostRequest>,
+) -> std::result::Result<(
+  models::AdminBoardsPostHeaderParams,
+        Option<models::AdminBoardsPostRequest>,
+), ValidationErrors>
+{
+  header_params.validate()?;
+            if let Some(body) = &body {
+              let b = AdminBoardsPostBodyValidator { body };
+              b.validate()?;
+            }
+
+Ok((
+  header_params,
+    body,
+))
+}
+/// AdminBoardsPost - POST /admin/boards
+#[tracing::instrument(skip_all)]
+async fn admin_boards_post<I, A>(
+  method: Method,
+  host: Host,
+  cookies: CookieJar,
+  headers: HeaderMap,
+ State(api_impl): State<I>,
+          Json(body): Json<Option<models::AdminBoardsPostRequest>>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::admin::Admin,
+{
+    // Header parameters
+    let header_params = {
+                let header_authorization = headers.get(HeaderName::from_static("authorization"));
+
+                let header_authorization = match header_authorization {
+                    Some(v) => match header::IntoHeaderValue::<String>::try_from((*v).clone()) {
+                        Ok(result) =>
+                            Some(result.0),
+                        Err(err) => {
+                            return problem_response(Response::builder(), problem::header_problem("Authorization", &err))
+                                        .map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+
+                        },
+                    },
+                    None => {
+                        None
+                    }
+                };
+
+       models::AdminBoardsPostHeaderParams {
+          authorization: header_authorization,
+       }
+  };
+
+
+      #[allow(clippy::redundant_closure)]
+      let validation = tokio::task::spawn_blocking(move ||
+    admin_boards_post_validation(
+        header_params,
+          body,
+    )
+  ).await.unwrap();
+
+  let Ok((
+    header_params,
+      body,
+  )) = validation else {
+    return problem_response(Response::builder(), problem::validation_problem(&validation.unwrap_err()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+  };
+
+  let result = api_impl.as_ref().admin_boards_post(
+      method,
+      host,
+      cookies,
+        header_params,
+              body,
+  ).await;
+
+  let mut response = Response::builder();
+
+  let resp = match result {
+                                            Ok(rsp) => match rsp {
+                                                apis::admin::AdminBoardsPostResponse::Status201
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(201);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::admin::AdminBoardsPostResponse::Status401
+                                                    {
+                                                        www_authenticate
+                                                    }
+                                                => {
+                                                    let www_authenticate = match header::IntoHeaderValue(www_authenticate).try_into() {
+                                                        Ok(val) => val,
+                                                        Err(e) => {
+                                                            return Response::builder()
+                                                                    .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                    .body(Body::from(format!("An internal server error occurred handling www_authenticate header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                        }
+                                                    };
+
+                                                    {
+                                                      let mut response_headers = response.headers_mut().unwrap();
+                                                      response_headers.insert(
+                                                          HeaderName::from_static("www-authenticate"),
+                                                          www_authenticate,
+                                                      );
+                                                    }
+                                                  let mut response = response.status(401);
+                                                  response.body(Body::empty())
+                                                },
+                                                apis::admin::AdminBoardsPostResponse::Status403
+                                                => {
+                                                  let mut response = response.status(403);
+                                                  response.body(Body::empty())
+                                                },
+                                                apis::admin::AdminBoardsPostResponse::Status409
+                                                => {
+                                                  let mut response = response.status(409);
+                                                  response.body(Body::empty())
+                                                },
+                                            },
+                                            Err(e) => {
+                                                let problem = e.problem();
+                                                if problem.status >= 500 {
+                                                    error!(error = %e);
+                                                }
+                                                problem_response(response, problem)
+                                            },
+                                        };
+
+                                        resp.map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })
+}
+#[tracing::instrument(skip_all)]
+fn admin_boards_slug_delete_validation(
+  header_params: models::AdminBoardsSlugDeleteHeaderParams,
+  path_params: models::AdminBoardsSlugDeletePathParams,
+) -> std::result::Result<(
+  models::AdminBoardsSlugDeleteHeaderParams,
+  models::AdminBoardsSlugDeletePathParams,
+), ValidationErrors>
+{
+  header_params.validate()?;
+  path_params.validate()?;
+
+Ok((
+  header_params,
+  path_params,
+))
+}
+/// AdminBoardsSlugDelete - DELETE /admin/boards/{slug}
+#[tracing::instrument(skip_all)]
+async fn admin_boards_slug_delete<I, A>(
+  method: Method,
+  host: Host,
+  cookies: CookieJar,
+  headers: HeaderMap,
+  Path(path_params): Path<models::AdminBoardsSlugDeletePathParams>,
+ State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::admin::Admin,
+{
+    // Header parameters
+    let header_params = {
+                let header_authorization = headers.get(HeaderName::from_static("authorization"));
+
+                let header_authorization = match header_authorization {
+                    Some(v) => match header::IntoHeaderValue::<String>::try_from((*v).clone()) {
+                        Ok(result) =>
+                            Some(result.0),
+                        Err(err) => {
+                            return problem_response(Response::builder(), problem::header_problem("Authorization", &err))
+                                        .map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+
+                        },
+                    },
+                    None => {
+                        None
+                    }
+                };
+
+       models::AdminBoardsSlugDeleteHeaderParams {
+          authorization: header_authorization,
+       }
+  };
+
+
+      #[allow(clippy::redundant_closure)]
+      let validation = tokio::task::spawn_blocking(move ||
+    admin_boards_slug_delete_validation(
+        header_params,
+        path_params,
+    )
+  ).await.unwrap();
+
+  let Ok((
+    header_params,
+    path_params,
+  )) = validation else {
+    return problem_response(Response::builder(), problem::validation_problem(&validation.unwrap_err()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+  };
+
+  let result = api_impl.as_ref().admin_boards_slug_delete(
+      method,
+      host,
+      cookies,
+        header_params,
+        path_params,
+  ).await;
+
+  let mut response = Response::builder();
+
+  let resp = match result {
+                                            Ok(rsp) => match rsp {
+                                                apis::admin::AdminBoardsSlugDeleteResponse::Status204
+                                                => {
+                                                  let mut response = response.status(204);
+                                                  response.body(Body::empty())
+                                                },
+                                                apis::admin::AdminBoardsSlugDeleteResponse::Status401
+                                                    {
+                                                        www_authenticate
+                                                    }
+                                                => {
+                                                    let www_authenticate = match header::IntoHeaderValue(www_authenticate).try_into() {
+                                                        Ok(val) => val,
+                                                        Err(e) => {
+                                                            return Response::builder()
+                                                                    .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                    .body(Body::from(format!("An internal server error occurred handling www_authenticate header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                        }
+                                                    };
+
+                                                    {
+                                                      let mut response_headers = response.headers_mut().unwrap();
+                                                      response_headers.insert(
+                                                          HeaderName::from_static("www-authenticate"),
+                                                          www_authenticate,
+                                                      );
+                                                    }
+                                                  let mut response = response.status(401);
+                                                  response.body(Body::empty())
+                                                },
+                                                apis::admin::AdminBoardsSlugDeleteResponse::Status403
+                                                => {
+                                                  let mut response = response.status(403);
+                                                  response.body(Body::empty())
+                                                },
+                                                apis::admin::AdminBoardsSlugDeleteResponse::Status404
+                                                => {
+                                                  let mut response = response.status(404);
+                                                  response.body(Body::empty())
+                                                },
+                                                apis::admin::AdminBoardsSlugDeleteResponse::Status409
+                                                => {
+                                                  let mut response = response.status(409);
+                                                  response.body(Body::empty())
+                                                },
+                                            },
+                                            Err(e) => {
+                                                let problem = e.problem();
+                                                if problem.status >= 500 {
+                                                    error!(error = %e);
+                                                }
+                                                problem_response(response, problem)
+                                            },
+                                        };
+
+                                        resp.map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })
+}
+    #[derive(validator::Validate)]
+    #[allow(dead_code)]
+    struct AdminBoardsSlugPutBodyValidator<'a> {
+            #[validate(nested)]
+          body: &'a models::AdminBoardsPostRequest,
+    }
+
+
+#[tracing::instrument(skip_all)]
+fn admin_boards_slug_put_validation(
+  header_params: models::AdminBoardsSlugPutHeaderParams,
+  path_params: models::AdminBoardsSlugPutPathParams,
+        body: Option<models::AdminBoardsPostRequest>,
+) -> std::result::Result<(
+  models::AdminBoardsSlugPutHeaderParams,
+  models::AdminBoardsSlugPutPathParams,
+        Option<models::AdminBoardsPostRequest>,
+), ValidationErrors>
+{
+  header_params.validate()?;
+  path_params.validate()?;
+            if let Some(body) = &body {
+              let b = AdminBoardsSlugPutBodyValidator { body };
+              b.validate()?;
+            }
+
+Ok((
+  header_params,
+  path_params,
+    body,
+))
+}
+/// AdminBoardsSlugPut - PUT /admin/boards/{slug}
+#[tracing::instrument(skip_all)]
+async fn admin_boards_slug_put<I, A>(
+  method: Method,
+  host: Host,
+  cookies: CookieJar,
+  headers: HeaderMap,
+  Path(path_params): Path<models::AdminBoardsSlugPutPathParams>,
+ State(api_impl): State<I>,
+          Json(body): Json<Option<models::AdminBoardsPostRequest>>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::admin::Admin,
+{
+    // Header parameters
+    let header_params = {
+                let header_authorization = headers.get(HeaderName::from_static("authorization"));
+
+                let header_authorization = match header_authorization {
+                    Some(v) => match header::IntoHeaderValue::<String>::try_from((*v).clone()) {
+                        Ok(result) =>
+                            Some(result.0),
+                        Err(err) => {
+                            return problem_response(Response::builder(), problem::header_problem("Authorization", &err))
+                                        .map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+
+                        },
+                    },
+                    None => {
+                        None
+                    }
+                };
+
+       models::AdminBoardsSlugPutHeaderParams {
+          authorization: header_authorization,
+       }
+  };
+
+
+      #[allow(clippy::redundant_closure)]
+      let validation = tokio::task::spawn_blocking(move ||
+    admin_boards_slug_put_validation(
+        header_params,
+        path_params,
+          body,
+    )
+  ).await.unwrap();
+
+  let Ok((
+    header_params,
+    path_params,
+      body,
+  )) = validation else {
+    return problem_response(Response::builder(), problem::validation_problem(&validation.unwrap_err()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+  };
+
+  let result = api_impl.as_ref().admin_boards_slug_put(
+      method,
+      host,
+      cookies,
+        header_params,
+        path_params,
+              body,
+  ).await;
+
+  let mut response = Response::builder();
+
+  let resp = match result {
+                                            Ok(rsp) => match rsp {
+                                                apis::admin::AdminBoardsSlugPutResponse::Status200
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(200);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::admin::AdminBoardsSlugPutResponse::Status401
+                                                    {
+                                                        www_authenticate
+                                                    }
+                                                => {
+                                                    let www_authenticate = match header::IntoHeaderValue(www_authenticate).try_into() {
+                                                        Ok(val) => val,
+                                                        Err(e) => {
+                                                            return Response::builder()
+                                                                    .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                    .body(Body::from(format!("An internal server error occurred handling www_authenticate header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                        }
+                                                    };
+
+                                                    {
+                                                      let mut response_headers = response.headers_mut().unwrap();
+                                                      response_headers.insert(
+                                                          HeaderName::from_static("www-authenticate"),
+                                                          www_authenticate,
+                                                      );
+                                                    }
+                                                  let mut response = response.status(401);
+                                                  response.body(Body::empty())
+                                                },
+                                                apis::admin::AdminBoardsSlugPutResponse::Status403
+                                                => {
+                                                  let mut response = response.status(403);
+                                                  response.body(Body::empty())
+                                                },
+                                                apis::admin::AdminBoardsSlugPutResponse::Status404
+                                                => {
+                                                  let mut response = response.status(404);
+                                                  response.body(Body::empty())
+                                                },
+                                                apis::admin::AdminBoardsSlugPutResponse::Status409
+                                                => {
+                                                  let mut response = response.status(409);
+                                                  response.body(Body::empty())
+                                                },
+                                            },
+                                            Err(e) => {
+                                                let problem = e.problem();
+                                                if problem.status >= 500 {
+                                                    error!(error = %e);
+                                                }
+                                                problem_response(response, problem)
+                                            },
+                                        };
+
+                                        resp.map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })
+}
+
+#[tracing::instrument(skip_all)]
+fn admin_users_user_id_role_delete_validation(
+  header_params: models::AdminUsersUserIdRoleDeleteHeaderParams,
+  path_params: models::AdminUsersUserIdRoleDeletePathParams,
+) -> std::result::Result<(
+  models::AdminUsersUserIdRoleDeleteHeaderParams,
+  models::AdminUsersUserIdRoleDeletePathParams,
+), ValidationErrors>
+{
+  header_params.validate()?;
+  path_params.validate()?;
+
+Ok((
+  header_params,
+  path_params,
+))
+}
+/// AdminUsersUserIdRoleDelete - DELETE /admin/users/{userId}/role
+#[tracing::instrument(skip_all)]
+async fn admin_users_user_id_role_delete<I, A>(
+  method: Method,
+  host: Host,
+  cookies: CookieJar,
+  headers: HeaderMap,
+  Path(path_params): Path<models::AdminUsersUserIdRoleDeletePathParams>,
+ State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::admin::Admin,
+{
+    // Header parameters
+    let header_params = {
+                let header_authorization = headers.get(HeaderName::from_static("authorization"));
+
+                let header_authorization = match header_authorization {
+                    Some(v) => match header::IntoHeaderValue::<String>::try_from((*v).clone()) {
+                        Ok(result) =>
+                            Some(result.0),
+                        Err(err) => {
+                            return problem_response(Response::builder(), problem::header_problem("Authorization", &err))
+                                        .map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+
+                        },
+                    },
+                    None => {
+                        None
+                    }
+                };
+
+       models::AdminUsersUserIdRoleDeleteHeaderParams {
+          authorization: header_authorization,
+       }
+  };
+
+
+      #[allow(clippy::redundant_closure)]
+      let validation = tokio::task::spawn_blocking(move ||
+    admin_users_user_id_role_delete_validation(
+        header_params,
+        path_params,
+    )
+  ).await.unwrap();
+
+  let Ok((
+    header_params,
+    path_params,
+  )) = validation else {
+    return problem_response(Response::builder(), problem::validation_problem(&validation.unwrap_err()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+  };
+
+  let result = api_impl.as_ref().admin_users_user_id_role_delete(
+      method,
+      host,
+      cookies,
+        header_params,
+        path_params,
+  ).await;
+
+  let mut response = Response::builder();
+
+  let resp = match result {
+                                            Ok(rsp) => match rsp {
+                                                apis::admin::AdminUsersUserIdRoleDeleteResponse::Status200_RoleUpdated
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(200);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::admin::AdminUsersUserIdRoleDeleteResponse::Status401
+                                                    {
+                                                        www_authenticate
+                                                    }
+                                                => {
+                                                    let www_authenticate = match header::IntoHeaderValue(www_authenticate).try_into() {
+                                                        Ok(val) => val,
+                                                        Err(e) => {
+                                                            return Response::builder()
+                                                                    .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                    .body(Body::from(format!("An internal server error occurred handling www_authenticate header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                        }
+                                                    };
+
+                                                    {
+                                                      let mut response_headers = response.headers_mut().unwrap();
+                                                      response_headers.insert(
+                                                          HeaderName::from_static("www-authenticate"),
+                                                          www_authenticate,
+                                                      );
+                                                    }
+                                                  let mut response = response.status(401);
+                                                  response.body(Body::empty())
+                                                },
+                                                apis::admin::AdminUsersUserIdRoleDeleteResponse::Status403
+                                                => {
+                                                  let mut response = response.status(403);
+                                                  response.body(Body::empty())
+                                                },
+                                                apis::admin::AdminUsersUserIdRoleDeleteResponse::Status404
+                                                => {
+                                                  let mut response = response.status(404);
+                                                  response.body(Body::empty())
+                                                },
+                                            },
+                                            Err(e) => {
+                                                let problem = e.problem();
+                                                if problem.status >= 500 {
+                                                    error!(error = %e);
+                                                }
+                                                problem_response(response, problem)
+                                            },
+                                        };
+
+                                        resp.map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })
+}
+
+    #[derive(validator::Validate)]
+    #[allow(dead_code)]
+    struct AdminUsersUserIdRolePutBodyValidator<'a> {
+            #[validate(nested)]
+          body: &'a models::AdminUsersUserIdRolePutRequest,
+    }
+
+
+#[tracing::instrument(skip_all)]
+fn admin_users_user_id_role_put_validation(
+  header_params: models::AdminUsersUserIdRolePutHeaderParams,
+  path_params: models::AdminUsersUserIdRolePutPathParams,
+        body: Option<models::AdminUsersUserIdRolePutRequest>,
+) -> std::result::Result<(
+  models::AdminUsersUserIdRolePutHeaderParams,
+  models::AdminUsersUserIdRolePutPathParams,
+        Option<models::AdminUsersUserIdRolePutRequest>,
+), ValidationErrors>
+{
+  header_params.validate()?;
+  path_params.validate()?;
+            if let Some(body) = &body {
+              let b = AdminUsersUserIdRolePutBodyValidator { body };
+              b.validate()?;
+            }
+
+Ok((
+  header_params,
+  path_params,
+    body,
+))
+}
+/// AdminUsersUserIdRolePut - PUT /admin/users/{userId}/role
+#[tracing::instrument(skip_all)]
+async fn admin_users_user_id_role_put<I, A>(
+  method: Method,
+  host: Host,
+  cookies: CookieJar,
+  headers: HeaderMap,
+  Path(path_params): Path<models::AdminUsersUserIdRolePutPathParams>,
+ State(api_impl): State<I>,
+          Json(body): Json<Option<models::AdminUsersUserIdRolePutRequest>>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::admin::Admin,
+{
+    // Header parameters
+    let header_params = {
+                let header_authorization = headers.get(HeaderName::from_static("authorization"));
+
+                let header_authorization = match header_authorization {
+                    Some(v) => match header::IntoHeaderValue::<String>::try_from((*v).clone()) {
+                        Ok(result) =>
+                            Some(result.0),
+                        Err(err) => {
+                            return problem_response(Response::builder(), problem::header_problem("Authorization", &err))
+                                        .map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+
+                        },
+                    },
+                    None => {
+                        None
+                    }
+                };
+
+       models::AdminUsersUserIdRolePutHeaderParams {
+          authorization: header_authorization,
+       }
+  };
+
+
+      #[allow(clippy::redundant_closure)]
+      let validation = tokio::task::spawn_blocking(move ||
+    admin_users_user_id_role_put_validation(
+        header_params,
+        path_params,
+          body,
+    )
+  ).await.unwrap();
+
+  let Ok((
+    header_params,
+    path_params,
+      body,
+  )) = validation else {
+    return problem_response(Response::builder(), problem::validation_problem(&validation.unwrap_err()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+  };
+
+  let result = api_impl.as_ref().admin_users_user_id_role_put(
+      method,
+      host,
+      cookies,
+        header_params,
+        path_params,
+              body,
+  ).await;
+
+  let mut response = Response::builder();
+
+  let resp = match result {
+                                            Ok(rsp) => match rsp {
+                                                apis::admin::AdminUsersUserIdRolePutResponse::Status200_RoleUpdated
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(200);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::admin::AdminUsersUserIdRolePutResponse::Status401
+                                                    {
+                                                        www_authenticate
+                                                    }
+                                                => {
+                                                    let www_authenticate = match header::IntoHeaderValue(www_authenticate).try_into() {
+                                                        Ok(val) => val,
+                                                        Err(e) => {
+                                                            return Response::builder()
+                                                                    .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                    .body(Body::from(format!("An internal server error occurred handling www_authenticate header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                        }
+                                                    };
+
+                                                    {
+                                                      let mut response_headers = response.headers_mut().unwrap();
+                                                      response_headers.insert(
+                                                          HeaderName::from_static("www-authenticate"),
+                                                          www_authenticate,
+                                                      );
+                                                    }
+                                                  let mut response = response.status(401);
+                                                  response.body(Body::empty())
+                                                },
+                                                apis::admin::AdminUsersUserIdRolePutResponse::Status403
+                                                => {
+                                                  let mut response = response.status(403);
+                                                  response.body(Body::empty())
+                                                },
+                                                apis::admin::AdminUsersUserIdRolePutResponse::Status404
+                                                => {
+                                                  let mut response = response.status(404);
+                                                  response.body(Body::empty())
+                                                },
+                                            },
+                                            Err(e) => {
+                                                let problem = e.problem();
+                                                if problem.status >= 500 {
+                                                    error!(error = %e);
+                                                }
+                                                problem_response(response, problem)
+                                            },
+                                        };
+
+                                        resp.map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })
+}
+
+#[tracing::instrument(skip_all)]
+fn admin_webhook_deliveries_delivery_id_retry_post_validation(
+  header_params: models::AdminWebhookDeliveriesDeliveryIdRetryPostHeaderParams,
+  path_params: models::AdminWebhookDeliveriesDeliveryIdRetryPostPathParams,
+) -> std::result::Result<(
+  models::AdminWebhookDeliveriesDeliveryIdRetryPostHeaderParams,
+  models::AdminWebhookDeliveriesDeliveryIdRetryPostPathParams,
+), ValidationErrors>
+{
+  header_params.validate()?;
+  path_params.validate()?;
+
+Ok((
+  header_params,
+  path_params,
+))
+}
+/// AdminWebhookDeliveriesDeliveryIdRetryPost - POST /admin/webhook-deliveries/{deliveryId}/retry
+#[tracing::instrument(skip_all)]
+async fn admin_webhook_deliveries_delivery_id_retry_post<I, A>(
+  method: Method,
+  host: Host,
+  cookies: CookieJar,
+  headers: HeaderMap,
+  Path(path_params): Path<models::AdminWebhookDeliveriesDeliveryIdRetryPostPathParams>,
+ State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::admin::Admin,
+{
+    // Header parameters
+    let header_params = {
+                let header_authorization = headers.get(HeaderName::from_static("authorization"));
+
+                let header_authorization = match header_authorization {
+                    Some(v) => match header::IntoHeaderValue::<String>::try_from((*v).clone()) {
+                        Ok(result) =>
+                            Some(result.0),
+                        Err(err) => {
+                            return problem_response(Response::builder(), problem::header_problem("Authorization", &err))
+                                        .map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+
+                        },
+                    },
+                    None => {
+                        None
+                    }
+                };
+
+       models::AdminWebhookDeliveriesDeliveryIdRetryPostHeaderParams {
+          authorization: header_authorization,
+       }
+  };
+
+
+      #[allow(clippy::redundant_closure)]
+      let validation = tokio::task::spawn_blocking(move ||
+    admin_webhook_deliveries_delivery_id_retry_post_validation(
+        header_params,
+        path_params,
+    )
+  ).await.unwrap();
+
+  let Ok((
+    header_params,
+    path_params,
+  )) = validation else {
+    return problem_response(Response::builder(), problem::validation_problem(&validation.unwrap_err()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+  };
+
+  let result = api_impl.as_ref().admin_webhook_deliveries_delivery_id_retry_post(
+      method,
+      host,
+      cookies,
+        header_params,
+        path_params,
+  ).await;
+
+  let mut response = Response::builder();
+
+  let resp = match result {
+                                            Ok(rsp) => match rsp {
+                                                apis::admin::AdminWebhookDeliveriesDeliveryIdRetryPostResponse::Status200
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(200);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::admin::AdminWebhookDeliveriesDeliveryIdRetryPostResponse::Status401
+                                                    {
+                                                        www_authenticate
+                                                    }
+                                                => {
+                                                    let www_authenticate = match header::IntoHeaderValue(www_authenticate).try_into() {
+                                                        Ok(val) => val,
+                                                        Err(e) => {
+                                                            return Response::builder()
+                                                                    .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                    .body(Body::from(format!("An internal server error occurred handling www_authenticate header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                        }
+                                                    };
+
+                                                    {
+                                                      let mut response_headers = response.headers_mut().unwrap();
+                                                      response_headers.insert(
+                                                          HeaderName::from_static("www-authenticate"),
+                                                          www_authenticate,
+                                                      );
+                                                    }
+                                                  let mut response = response.status(401);
+                                                  response.body(Body::empty())
+                                                },
+                                                apis::admin::AdminWebhookDeliveriesDeliveryIdRetryPostResponse::Status403
+                                                => {
+                                                  let mut response = response.status(403);
+                                                  response.body(Body::empty())
+                                                },
+                                                apis::admin::AdminWebhookDeliveriesDeliveryIdRetryPostResponse::Status404
+                                                => {
+                                                  let mut response = response.status(404);
+                                                  response.body(Body::empty())
+                                                },
+                                                apis::admin::AdminWebhookDeliveriesDeliveryIdRetryPostResponse::Status409
+                                                => {
+                                                  let mut response = response.status(409);
+                                                  response.body(Body::empty())
+                                                },
+                                            },
+                                            Err(e) => {
+                                                let problem = e.problem();
+                                                if problem.status >= 500 {
+                                                    error!(error = %e);
+                                                }
+                                                problem_response(response, problem)
+                                            },
+                                        };
+
+                                        resp.map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })
+}
+
+
+#[tracing::instrument(skip_all)]
+fn admin_webhook_deliveries_get_validation(
+  header_params: models::AdminWebhookDeliveriesGetHeaderParams,
+  query_params: models::AdminWebhookDeliveriesGetQueryParams,
+) -> std::result::Result<(
+  models::AdminWebhookDeliveriesGetHeaderParams,
+  models::AdminWebhookDeliveriesGetQueryParams,
+), ValidationErrors>
+{
+  header_params.validate()?;
+  query_params.validate()?;
+
+Ok((
+  header_params,
+  query_params,
+))
+}
+/// AdminWebhookDeliveriesGet - GET /admin/webhook-deliveries
+#[tracing::instrument(skip_all)]
+async fn admin_webhook_deliveries_get<I, A>(
+  method: Method,
+  host: Host,
+  cookies: CookieJar,
+  headers: HeaderMap,
+  Query(query_params): Query<models::AdminWebhookDeliveriesGetQueryParams>,
+ State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::admin::Admin,
+{
+    // Header parameters
+    let header_params = {
+                let header_authorization = headers.get(HeaderName::from_static("authorization"));
+
+                let header_authorization = match header_authorization {
+                    Some(v) => match header::IntoHeaderValue::<String>::try_from((*v).clone()) {
+                        Ok(result) =>
+                            Some(result.0),
+                        Err(err) => {
+                            return problem_response(Response::builder(), problem::header_problem("Authorization", &err))
+                                        .map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+
+                        },
+                    },
+                    None => {
+                        None
+                    }
+                };
+
+       models::AdminWebhookDeliveriesGetHeaderParams {
+          authorization: header_authorization,
+       }
+  };
+
+
+      #[allow(clippy::redundant_closure)]
+      let validation = tokio::task::spawn_blocking(move ||
+    admin_webhook_deliveries_get_validation(
+        header_params,
+        query_params,
+    )
+  ).await.unwrap();
+
+  let Ok((
+    header_params,
+    query_params,
+  )) = validation else {
+    return problem_response(Response::builder(), problem::validation_problem(&validation.unwrap_err()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+  };
+
+  let result = api_impl.as_ref().admin_webhook_deliveries_get(
+      method,
+      host,
+      cookies,
+        header_params,
+        query_params,
+  ).await;
+
+  let mut response = Response::builder();
+
+  let resp = match result {
+                                            Ok(rsp) => match rsp {
+                                                apis::admin::AdminWebhookDeliveriesGetResponse::Status200
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(200);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::admin::AdminWebhookDeliveriesGetResponse::Status401
+                                                    {
+                                                        www_authenticate
+                                                    }
+                                                => {
+                                                    let www_authenticate = match header::IntoHeaderValue(www_authenticate).try_into() {
+                                                        Ok(val) => val,
+                                                        Err(e) => {
+                                                            return Response::builder()
+                                                                    .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                    .body(Body::from(format!("An internal server error occurred handling www_authenticate header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                        }
+                                                    };
+
+                                                    {
+                                                      let mut response_headers = response.headers_mut().unwrap();
+                                                      response_headers.insert(
+                                                          HeaderName::from_static("www-authenticate"),
+                                                          www_authenticate,
+                                                      );
+                                                    }
+                                                  let mut response = response.status(401);
+                                                  response.body(Body::empty())
+                                                },
+                                                apis::admin::AdminWebhookDeliveriesGetResponse::Status403
+                                                => {
+                                                  let mut response = response.status(403);
+                                                  response.body(Body::empty())
+                                                },
+                                            },
+                                            Err(e) => {
+                                                let problem = e.problem();
+                                                if problem.status >= 500 {
+                                                    error!(error = %e);
+                                                }
+                                                problem_response(response, problem)
+                                            },
+                                        };
+
+                                        resp.map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })
+}
+
+
+#[tracing::instrument(skip_all)]
+fn admin_webhooks_get_validation(
+  header_params: models::AdminWebhooksGetHeaderParams,
+) -> std::result::Result<(
+  models::AdminWebhooksGetHeaderParams,
+), ValidationErrors>
+{
+  header_params.validate()?;
+
+Ok((
+  header_params,
+))
+}
+/// AdminWebhooksGet - GET /admin/webhooks
+#[tracing::instrument(skip_all)]
+async fn admin_webhooks_get<I, A>(
+  method: Method,
+  host: Host,
+  cookies: CookieJar,
+  headers: HeaderMap,
+ State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::admin::Admin,
+{
+    // Header parameters
+    let header_params = {
+                let header_authorization = headers.get(HeaderName::from_static("authorization"));
+
+                let header_authorization = match header_authorization {
+                    Some(v) => match header::IntoHeaderValue::<String>::try_from((*v).clone()) {
+                        Ok(result) =>
+                            Some(result.0),
+                        Err(err) => {
+                            return problem_response(Response::builder(), problem::header_problem("Authorization", &err))
+                                        .map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+
+                        },
+                    },
+                    None => {
+                        None
+                    }
+                };
+
+       models::AdminWebhooksGetHeaderParams {
+          authorization: header_authorization,
+       }
+  };
+
+
+      #[allow(clippy::redundant_closure)]
+      let validation = tokio::task::spawn_blocking(move ||
+    admin_webhooks_get_validation(
+        header_params,
+    )
+  ).await.unwrap();
+
+  let Ok((
+    header_params,
+  )) = validation else {
+    return problem_response(Response::builder(), problem::validation_problem(&validation.unwrap_err()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+  };
+
+  let result = api_impl.as_ref().admin_webhooks_get(
+      method,
+      host,
+      cookies,
+        header_params,
+  ).await;
+
+  let mut response = Response::builder();
+
+  let resp = match result {
+                                            Ok(rsp) => match rsp {
+                                                apis::admin::AdminWebhooksGetResponse::Status200
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(200);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::admin::AdminWebhooksGetResponse::Status401
+                                                    {
+                                                        www_authenticate
+                                                    }
+                                                => {
+                                                    let www_authenticate = match header::IntoHeaderValue(www_authenticate).try_into() {
+                                                        Ok(val) => val,
+                                                        Err(e) => {
+                                                            return Response::builder()
+                                                                    .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                    .body(Body::from(format!("An internal server error occurred handling www_authenticate header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                        }
+                                                    };
+
+                                                    {
+                                                      let mut response_headers = response.headers_mut().unwrap();
+                                                      response_headers.insert(
+                                                          HeaderName::from_static("www-authenticate"),
+                                                          www_authenticate,
+                                                      );
+                                                    }
+                                                  let mut response = response.status(401);
+                                                  response.body(Body::empty())
+                                                },
+                                                apis::admin::AdminWebhooksGetResponse::Status403
+                                                => {
+                                                  let mut response = response.status(403);
+                                                  response.body(Body::empty())
+                                                },
+                                            },
+                                            Err(e) => {
+                                                let problem = e.problem();
+                                                if problem.status >= 500 {
+                                                    error!(error = %e);
+                                                }
+                                                problem_response(response, problem)
+                                            },
+                                        };
+
+                                        resp.map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })
+}
+
+    #[derive(validator::Validate)]
+    #[allow(dead_code)]
+    struct AdminWebhooksPostBodyValidator<'a> {
+            #[validate(nested)]
+          body: &'a models::AdminWebhooksPostRequest,
+    }
+
+
+#[tracing::instrument(skip_all)]
+fn admin_webhooks_post_validation(
+  header_params: models::AdminWebhooksPostHeaderParams,
+        body: Option<models::AdminWebhooksPostRequest>,
+) -> std::result::Result<(
+  models::AdminWebhooksPostHeaderParams,
+        Option<models::AdminWebhooksPostRequest>,
+), ValidationErrors>
+{
+  header_params.validate()?;
+            if let Some(body) = &body {
+              let b = AdminWebhooksPostBodyValidator { body };
+              b.validate()?;
+            }
+
+Ok((
+  header_params,
+    body,
+))
+}
+/// AdminWebhooksPost - POST /admin/webhooks
+#[tracing::instrument(skip_all)]
+async fn admin_webhooks_post<I, A>(
+  method: Method,
+  host: Host,
+  cookies: CookieJar,
+  headers: HeaderMap,
+ State(api_impl): State<I>,
+          Json(body): Json<Option<models::AdminWebhooksPostRequest>>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::admin::Admin,
+{
+    // Header parameters
+    let header_params = {
+                let header_authorization = headers.get(HeaderName::from_static("authorization"));
+
+                let header_authorization = match header_authorization {
+                    Some(v) => match header::IntoHeaderValue::<String>::try_from((*v).clone()) {
+                        Ok(result) =>
+                            Some(result.0),
+                        Err(err) => {
+                            return problem_response(Response::builder(), problem::header_problem("Authorization", &err))
+                                        .map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+
+                        },
+                    },
+                    None => {
+                        None
+                    }
+                };
+
+       models::AdminWebhooksPostHeaderParams {
+          authorization: header_authorization,
+       }
+  };
+
+
+      #[allow(clippy::redundant_closure)]
+      let validation = tokio::task::spawn_blocking(move ||
+    admin_webhooks_post_validation(
+        header_params,
+          body,
+    )
+  ).await.unwrap();
+
+  let Ok((
+    header_params,
+      body,
+  )) = validation else {
+    return problem_response(Response::builder(), problem::validation_problem(&validation.unwrap_err()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+  };
+
+  let result = api_impl.as_ref().admin_webhooks_post(
+      method,
+      host,
+      cookies,
+        header_params,
+              body,
+  ).await;
+
+  let mut response = Response::builder();
+
+  let resp = match result {
+                                            Ok(rsp) => match rsp {
+                                                apis::admin::AdminWebhooksPostResponse::Status201
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(201);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::admin::AdminWebhooksPostResponse::Status401
+                                                    {
+                                                        www_authenticate
+                                                    }
+                                                => {
+                                                    let www_authenticate = match header::IntoHeaderValue(www_authenticate).try_into() {
+                                                        Ok(val) => val,
+                                                        Err(e) => {
+                                                            return Response::builder()
+                                                                    .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                    .body(Body::from(format!("An internal server error occurred handling www_authenticate header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                        }
+                                                    };
+
+                                                    {
+                                                      let mut response_headers = response.headers_mut().unwrap();
+                                                      response_headers.insert(
+                                                          HeaderName::from_static("www-authenticate"),
+                                                          www_authenticate,
+                                                      );
+                                                    }
+                                                  let mut response = response.status(401);
+                                                  response.body(Body::empty())
+                                                },
+                                                apis::admin::AdminWebhooksPostResponse::Status403
+                                                => {
+                                                  let mut response = response.status(403);
+                                                  response.body(Body::empty())
+                                                },
+                                            },
+                                            Err(e) => {
+                                                let problem = e.problem();
+                                                if problem.status >= 500 {
+                                                    error!(error = %e);
+                                                }
+                                                problem_response(response, problem)
+                                            },
+                                        };
+
+                                        resp.map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })
+}
+
+
+#[tracing::instrument(skip_all)]
+fn admin_webhooks_webhook_id_delete_validation(
+  header_params: models::AdminWebhooksWebhookIdDeleteHeaderParams,
+  path_params: models::AdminWebhooksWebhookIdDeletePathParams,
+) -> std::result::Result<(
+  models::AdminWebhooksWebhookIdDeleteHeaderParams,
+  models::AdminWebhooksWebhookIdDeletePathParams,
+), ValidationErrors>
+{
+  header_params.validate()?;
+  path_params.validate()?;
+
+Ok((
+  header_params,
+  path_params,
+))
+}
+/// AdminWebhooksWebhookIdDelete - DELETE /admin/webhooks/{webhookId}
+#[tracing::instrument(skip_all)]
+async fn admin_webhooks_webhook_id_delete<I, A>(
+  method: Method,
+  host: Host,
+  cookies: CookieJar,
+  headers: HeaderMap,
+  Path(path_params): Path<models::AdminWebhooksWebhookIdDeletePathParams>,
+ State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::admin::Admin,
+{
+    // Header parameters
+    let header_params = {
+                let header_authorization = headers.get(HeaderName::from_static("authorization"));
+
+                let header_authorization = match header_authorization {
+                    Some(v) => match header::IntoHeaderValue::<String>::try_from((*v).clone()) {
+                        Ok(result) =>
+                            Some(result.0),
+                        Err(err) => {
+                            return problem_response(Response::builder(), problem::header_problem("Authorization", &err))
+                                        .map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+
+                        },
+                    },
+                    None => {
+                        None
+                    }
+                };
+
+       models::AdminWebhooksWebhookIdDeleteHeaderParams {
+          authorization: header_authorization,
+       }
+  };
+
+
+      #[allow(clippy::redundant_closure)]
+      let validation = tokio::task::spawn_blocking(move ||
+    admin_webhooks_webhook_id_delete_validation(
+        header_params,
+        path_params,
+    )
+  ).await.unwrap();
+
+  let Ok((
+    header_params,
+    path_params,
+  )) = validation else {
+    return problem_response(Response::builder(), problem::validation_problem(&validation.unwrap_err()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+  };
+
+  let result = api_impl.as_ref().admin_webhooks_webhook_id_delete(
+      method,
+      host,
+      cookies,
+        header_params,
+        path_params,
+  ).await;
+
+  let mut response = Response::builder();
+
+  let resp = match result {
+                                            Ok(rsp) => match rsp {
+                                                apis::admin::AdminWebhooksWebhookIdDeleteResponse::Status204
+                                                => {
+                                                  let mut response = response.status(204);
+                                                  response.body(Body::empty())
+                                                },
+                                                apis::admin::AdminWebhooksWebhookIdDeleteResponse::Status401
+                                                    {
+                                                        www_authenticate
+                                                    }
+                                                => {
+                                                    let www_authenticate = match header::IntoHeaderValue(www_authenticate).try_into() {
+                                                        Ok(val) => val,
+                                                        Err(e) => {
+                                                            return Response::builder()
+                                                                    .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                    .body(Body::from(format!("An internal server error occurred handling www_authenticate header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                        }
+                                                    };
+
+                                                    {
+                                                      let mut response_headers = response.headers_mut().unwrap();
+                                                      response_headers.insert(
+                                                          HeaderName::from_static("www-authenticate"),
+                                                          www_authenticate,
+                                                      );
+                                                    }
+                                                  let mut response = response.status(401);
+                                                  response.body(Body::empty())
+                                                },
+                                                apis::admin::AdminWebhooksWebhookIdDeleteResponse::Status403
+                                                => {
+                                                  let mut response = response.status(403);
+                                                  response.body(Body::empty())
+                                                },
+                                                apis::admin::AdminWebhooksWebhookIdDeleteResponse::Status404
+                                                => {
+                                                  let mut response = response.status(404);
+                                                  response.body(Body::empty())
+                                                },
+                                            },
+                                            Err(e) => {
+                                                let problem = e.problem();
+                                                if problem.status >= 500 {
+                                                    error!(error = %e);
+                                                }
+                                                problem_response(response, problem)
+                                            },
+                                        };
+
+                                        resp.map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })
+}
+
+
+#[tracing::instrument(skip_all)]
+fn admin_webhooks_webhook_id_deliveries_get_validation(
+  header_params: models::AdminWebhooksWebhookIdDeliveriesGetHeaderParams,
+  path_params: models::AdminWebhooksWebhookIdDeliveriesGetPathParams,
+  query_params: models::AdminWebhooksWebhookIdDeliveriesGetQueryParams,
+) -> std::result::Result<(
+  models::AdminWebhooksWebhookIdDeliveriesGetHeaderParams,
+  models::AdminWebhooksWebhookIdDeliveriesGetPathParams,
+  models::AdminWebhooksWebhookIdDeliveriesGetQueryParams,
+), ValidationErrors>
+{
+  header_params.validate()?;
+  path_params.validate()?;
+  query_params.validate()?;
+
+Ok((
+  header_params,
+  path_params,
+  query_params,
+))
+}
+/// AdminWebhooksWebhookIdDeliveriesGet - GET /admin/webhooks/{webhookId}/deliveries
+#[tracing::instrument(skip_all)]
+async fn admin_webhooks_webhook_id_deliveries_get<I, A>(
+  method: Method,
+  host: Host,
+  cookies: CookieJar,
+  headers: HeaderMap,
+  Path(path_params): Path<models::AdminWebhooksWebhookIdDeliveriesGetPathParams>,
+  Query(query_params): Query<models::AdminWebhooksWebhookIdDeliveriesGetQueryParams>,
+ State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::admin::Admin,
+{
+    // Header parameters
+    let header_params = {
+                let header_authorization = headers.get(HeaderName::from_static("authorization"));
+
+                let header_authorization = match header_authorization {
+                    Some(v) => match header::IntoHeaderValue::<String>::try_from((*v).clone()) {
+                        Ok(result) =>
+                            Some(result.0),
+                        Err(err) => {
+                            return problem_response(Response::builder(), problem::header_problem("Authorization", &err))
+                                        .map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+
+                        },
+                    },
+                    None => {
+                        None
+                    }
+                };
+
+       models::AdminWebhooksWebhookIdDeliveriesGetHeaderParams {
+          authorization: header_authorization,
+       }
+  };
+
+
+      #[allow(clippy::redundant_closure)]
+      let validation = tokio::task::spawn_blocking(move ||
+    admin_webhooks_webhook_id_deliveries_get_validation(
+        header_params,
+        path_params,
+        query_params,
+    )
+  ).await.unwrap();
+
+  let Ok((
+    header_params,
+    path_params,
+    query_params,
+  )) = validation else {
+    return problem_response(Response::builder(), problem::validation_problem(&validation.unwrap_err()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+  };
+
+  let result = api_impl.as_ref().admin_webhooks_webhook_id_deliveries_get(
+      method,
+      host,
+      cookies,
+        header_params,
+        path_params,
+        query_params,
+  ).await;
+
+  let mut response = Response::builder();
+
+  let resp = match result {
+                                            Ok(rsp) => match rsp {
+                                                apis::admin::AdminWebhooksWebhookIdDeliveriesGetResponse::Status200
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(200);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::admin::AdminWebhooksWebhookIdDeliveriesGetResponse::Status401
+                                                    {
+                                                        www_authenticate
+                                                    }
+                                                => {
+                                                    let www_authenticate = match header::IntoHeaderValue(www_authenticate).try_into() {
+                                                        Ok(val) => val,
+                                                        Err(e) => {
+                                                            return Response::builder()
+                                                                    .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                    .body(Body::from(format!("An internal server error occurred handling www_authenticate header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                        }
+                                                    };
+
+                                                    {
+                                                      let mut response_headers = response.headers_mut().unwrap();
+                                                      response_headers.insert(
+                                                          HeaderName::from_static("www-authenticate"),
+                                                          www_authenticate,
+                                                      );
+                                                    }
+                                                  let mut response = response.status(401);
+                                                  response.body(Body::empty())
+                                                },
+                                                apis::admin::AdminWebhooksWebhookIdDeliveriesGetResponse::Status403
+                                                => {
+                                                  let mut response = response.status(403);
+                                                  response.body(Body::empty())
+                                                },
+                                                apis::admin::AdminWebhooksWebhookIdDeliveriesGetResponse::Status404
+                                                => {
+                                                  let mut response = response.status(404);
+                                                  response.body(Body::empty())
+                                                },
+                                            },
+                                            Err(e) => {
+                                                let problem = e.problem();
+                                                if problem.status >= 500 {
+                                                    error!(error = %e);
+                                                }
+                                                problem_response(response, problem)
+                                            },
+                                        };
+
+                                        resp.map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })
+}
+
+#[tracing::instrument(skip_all)]
+fn auth_logout_all_post_validation(
+  header_params: models::AuthLogoutAllPostHeaderParams,
+) -> std::result::Result<(
+  models::AuthLogoutAllPostHeaderParams,
+), ValidationErrors>
+{
+  header_params.validate()?;
+
+Ok((
+  header_params,
+))
+}
+/// AuthLogoutAllPost - POST /auth/logout-all
+#[tracing::instrument(skip_all)]
+async fn auth_logout_all_post<I, A>(
+  method: Method,
+  host: Host,
+  cookies: CookieJar,
+  headers: HeaderMap,
+ State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::auth::Auth,
+{
+    // Header parameters
+    let header_params = {
+                let header_authorization = headers.get(HeaderName::from_static("authorization"));
+
+                let header_authorization = match header_authorization {
+                    Some(v) => match header::IntoHeaderValue::<String>::try_from((*v).clone()) {
+                        Ok(result) =>
+                            Some(result.0),
+                        Err(err) => {
+                            return problem_response(Response::builder(), problem::header_problem("Authorization", &err))
+                                        .map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+
+                        },
+                    },
+                    None => {
+                        None
+                    }
+                };
+
+       models::AuthLogoutAllPostHeaderParams {
+          authorization: header_authorization,
+       }
+  };
+
+
+      #[allow(clippy::redundant_closure)]
+      let validation = tokio::task::spawn_blocking(move ||
+    auth_logout_all_post_validation(
+        header_params,
+    )
+  ).await.unwrap();
+
+  let Ok((
+    header_params,
+  )) = validation else {
+    return problem_response(Response::builder(), problem::validation_problem(&validation.unwrap_err()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+  };
+
+  let result = api_impl.as_ref().auth_logout_all_post(
+      method,
+      host,
+      cookies,
+        header_params,
+  ).await;
+
+  let mut response = Response::builder();
+
+  let resp = match result {
+                                            Ok(rsp) => match rsp {
+                                                apis::auth::AuthLogoutAllPostResponse::Status204
+                                                => {
+                                                  let mut response = response.status(204);
+                                                  response.body(Body::empty())
+                                                },
+                                                apis::auth::AuthLogoutAllPostResponse::Status401
+                                                    {
+                                                        www_authenticate
+                                                    }
+                                                => {
+                                                    let www_authenticate = match header::IntoHeaderValue(www_authenticate).try_into() {
+                                                        Ok(val) => val,
+                                                        Err(e) => {
+                                                            return Response::builder()
+                                                                    .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                    .body(Body::from(format!("An internal server error occurred handling www_authenticate header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                        }
+                                                    };
+
+                                                    {
+                                                      let mut response_headers = response.headers_mut().unwrap();
+                                                      response_headers.insert(
+                                                          HeaderName::from_static("www-authenticate"),
+                                                          www_authenticate,
+                                                      );
+                                                    }
+                                                  let mut response = response.status(401);
+                                                  response.body(Body::empty())
+                                                },
+                                            },
+                                            Err(e) => {
+                                                let problem = e.problem();
+                                                if problem.status >= 500 {
+                                                    error!(error = %e);
+                                                }
+                                                problem_response(response, problem)
+                                            },
+                                        };
+
+                                        resp.map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })
+}
+
+    #[derive(validator::Validate)]
+    #[allow(dead_code)]
+    struct AuthLogoutPostBodyValidator<'a> {
+            #[validate(nested)]
+          body: &'a models::AuthLogoutPostRequest,
+    }
+
+
+#[tracing::instrument(skip_all)]
+fn auth_logout_post_validation(
+  header_params: models::AuthLogoutPostHeaderParams,
+        body: Option<models::AuthLogoutPostRequest>,
+) -> std::result::Result<(
+  models::AuthLogoutPostHeaderParams,
+        Option<models::AuthLogoutPostRequest>,
+), ValidationErrors>
+{
+  header_params.validate()?;
+            if let Some(body) = &body {
+              let b = AuthLogoutPostBodyValidator { body };
+              b.validate()?;
+            }
+
+Ok((
+  header_params,
+    body,
+))
+}
+/// AuthLogoutPost - POST /auth/logout
+#[tracing::instrument(skip_all)]
+async fn auth_logout_post<I, A>(
+  method: Method,
+  host: Host,
+  cookies: CookieJar,
+  headers: HeaderMap,
+ State(api_impl): State<I>,
+          Json(body): Json<Option<models::AuthLogoutPostRequest>>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::auth::Auth,
+{
+    // Header parameters
+    let header_params = {
+                let header_authorization = headers.get(HeaderName::from_static("authorization"));
+
+                let header_authorization = match header_authorization {
+                    Some(v) => match header::IntoHeaderValue::<String>::try_from((*v).clone()) {
+                        Ok(result) =>
+                            Some(result.0),
+                        Err(err) => {
+                            return problem_response(Response::builder(), problem::header_problem("Authorization", &err))
+                                        .map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+
+                        },
+                    },
+                    None => {
+                        None
+                    }
+                };
+
+       models::AuthLogoutPostHeaderParams {
+          authorization: header_authorization,
+       }
+  };
+
+
+      #[allow(clippy::redundant_closure)]
+      let validation = tokio::task::spawn_blocking(move ||
+    auth_logout_post_validation(
+        header_params,
+          body,
+    )
+  ).await.unwrap();
+
+  let Ok((
+    header_params,
+      body,
+  )) = validation else {
+    return problem_response(Response::builder(), problem::validation_problem(&validation.unwrap_err()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+  };
+
+  let result = api_impl.as_ref().auth_logout_post(
+      method,
+      host,
+      cookies,
+        header_params,
+              body,
+  ).await;
+
+  let mut response = Response::builder();
+
+  let resp = match result {
+                                            Ok(rsp) => match rsp {
+                                                apis::auth::AuthLogoutPostResponse::Status204
+                                                => {
+                                                  let mut response = response.status(204);
+                                                  response.body(Body::empty())
+                                                },
+                                                apis::auth::AuthLogoutPostResponse::Status401
+                                                    {
+                                                        www_authenticate
+                                                    }
+                                                => {
+                                                    let www_authenticate = match header::IntoHeaderValue(www_authenticate).try_into() {
+                                                        Ok(val) => val,
+                                                        Err(e) => {
+                                                            return Response::builder()
+                                                                    .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                    .body(Body::from(format!("An internal server error occurred handling www_authenticate header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                        }
+                                                    };
+
+                                                    {
+                                                      let mut response_headers = response.headers_mut().unwrap();
+                                                      response_headers.insert(
+                                                          HeaderName::from_static("www-authenticate"),
+                                                          www_authenticate,
+                                                      );
+                                                    }
+                                                  let mut response = response.status(401);
+                                                  response.body(Body::empty())
+                                                },
+                                            },
+                                            Err(e) => {
+                                                let problem = e.problem();
+                                                if problem.status >= 500 {
+                                                    error!(error = %e);
+                                                }
+                                                problem_response(response, problem)
+                                            },
+                                        };
+
+                                        resp.map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })
+}
+
+    #[derive(validator::Validate)]
+    #[allow(dead_code)]
+    struct AuthPostBodyValidator<'a> {
+            #[validate(nested)]
+          body: &'a models::Auth,
+    }
+
+
+#[tracing::instrument(skip_all)]
+fn auth_post_validation(
+  header_params: models::AuthPostHeaderParams,
+        body: Option<models::Auth>,
+) -> std::result::Result<(
+  models::AuthPostHeaderParams,
+        Option<models::Auth>,
+), ValidationErrors>
+{
+  header_params.validate()?;
+            if let Some(body) = &body {
+              let b = AuthPostBodyValidator { body };
+              b.validate()?;
+            }
+
+Ok((
+  header_params,
+    body,
+))
+}
+/// AuthPost - POST /auth
+#[tracing::instrument(skip_all)]
+async fn auth_post<I, A>(
+  method: Method,
+  host: Host,
+  cookies: CookieJar,
+  headers: HeaderMap,
+ State(api_impl): State<I>,
+          Json(body): Json<Option<models::Auth>>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::auth::Auth,
+{
+    // Header parameters
+    let header_params = {
+                let header_x_forwarded_for = headers.get(HeaderName::from_static("x-forwarded-for"));
+
+                let header_x_forwarded_for = match header_x_forwarded_for {
+                    Some(v) => match header::IntoHeaderValue::<String>::try_from((*v).clone()) {
+                        Ok(result) =>
+                            Some(result.0),
+                        Err(err) => {
+                            return problem_response(Response::builder(), problem::header_problem("X-Forwarded-For", &err))
+                                        .map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+
+                        },
+                    },
+                    None => {
+                        None
+                    }
+                };
+
+       models::AuthPostHeaderParams {
+          x_forwarded_for: header_x_forwarded_for,
+       }
+  };
+
+
+      #[allow(clippy::redundant_closure)]
+      let validation = tokio::task::spawn_blocking(move ||
+    auth_post_validation(
+        header_params,
+          body,
+    )
+  ).await.unwrap();
+
+  let Ok((
+    header_params,
+      body,
+  )) = validation else {
+    return problem_response(Response::builder(), problem::validation_problem(&validation.unwrap_err()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+  };
+
+  let result = api_impl.as_ref().auth_post(
+      method,
+      host,
+      cookies,
+        header_params,
+              body,
+  ).await;
+
+  let mut response = Response::builder();
+
+  let resp = match result {
+                                            Ok(rsp) => match rsp {
+                                                apis::auth::AuthPostResponse::Status200_AuthenticationSuccessful
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(200);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::auth::AuthPostResponse::Status429
+                                                    {
+                                                        retry_after
+                                                    }
+                                                => {
+                                                    let retry_after = match header::IntoHeaderValue(retry_after).try_into() {
+                                                        Ok(val) => val,
+                                                        Err(e) => {
+                                                            return Response::builder()
+                                                                    .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                    .body(Body::from(format!("An internal server error occurred handling retry_after header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                        }
+                                                    };
+
+                                                    {
+                                                      let mut response_headers = response.headers_mut().unwrap();
+                                                      response_headers.insert(
+                                                          HeaderName::from_static("retry-after"),
+                                                          retry_after,
+                                                      );
+                                                    }
+                                                  let mut response = response.status(429);
+                                                  response.body(Body::empty())
+                                                },
+                                            },
+                                            Err(e) => {
+                                                let problem = e.problem();
+                                                if problem.status >= 500 {
+                                                    error!(error = %e);
+                                                }
+                                                problem_response(response, problem)
+                                            },
+                                        };
+
+                                        resp.map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })
+}
+
+    #[derive(validator::Validate)]
+    #[allow(dead_code)]
+    struct AuthRefreshPostBodyValidator<'a> {
+            #[validate(nested)]
+          body: &'a models::AuthRefreshPostRequest,
+    }
+
+
+#[tracing::instrument(skip_all)]
+fn auth_refresh_post_validation(
+        body: Option<models::AuthRefreshPostRequest>,
+) -> std::result::Result<(
+        Option<models::AuthRefreshPostRequest>,
+), ValidationErrors>
+{
+            if let Some(body) = &body {
+              let b = AuthRefreshPostBodyValidator { body };
+              b.validate()?;
+            }
+
+Ok((
+    body,
+))
+}
+/// AuthRefreshPost - POST /auth/refresh
+#[tracing::instrument(skip_all)]
+async fn auth_refresh_post<I, A>(
+  method: Method,
+  host: Host,
+  cookies: CookieJar,
+ State(api_impl): State<I>,
+          Json(body): Json<Option<models::AuthRefreshPostRequest>>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::auth::Auth,
+{
+
+      #[allow(clippy::redundant_closure)]
+      let validation = tokio::task::spawn_blocking(move ||
+    auth_refresh_post_validation(
+          body,
+    )
+  ).await.unwrap();
+
+  let Ok((
+      body,
+  )) = validation else {
+    return problem_response(Response::builder(), problem::validation_problem(&validation.unwrap_err()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+  };
+
+  let result = api_impl.as_ref().auth_refresh_post(
+      method,
+      host,
+      cookies,
+              body,
+  ).await;
+
+  let mut response = Response::builder();
+
+  let resp = match result {
+                                            Ok(rsp) => match rsp {
+                                                apis::auth::AuthRefreshPostResponse::Status200_AuthenticationSuccessful
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(200);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::auth::AuthRefreshPostResponse::Status401
+                                                => {
+                                                  let mut response = response.status(401);
+                                                  response.body(Body::empty())
+                                                },
+                                            },
+                                            Err(e) => {
+                                                let problem = e.problem();
+                                                if problem.status >= 500 {
+                                                    error!(error = %e);
+                                                }
+                                                problem_response(response, problem)
+                                            },
+                                        };
+
+                                        resp.map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })
+}
+
+#[tracing::instrument(skip_all)]
+fn get_jwks_validation(
+) -> std::result::Result<(
+), ValidationErrors>
+{
+
+Ok((
+))
+}
+/// GetJwks - GET /.well-known/jwks.json
+#[tracing::instrument(skip_all)]
+async fn get_jwks<I, A>(
+  method: Method,
+  host: Host,
+  cookies: CookieJar,
+ State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::auth::Auth,
+{
+
+      #[allow(clippy::redundant_closure)]
+      let validation = tokio::task::spawn_blocking(move ||
+    get_jwks_validation(
+    )
+  ).await.unwrap();
+
+  let Ok((
+  )) = validation else {
+    return problem_response(Response::builder(), problem::validation_problem(&validation.unwrap_err()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+  };
+
+  let result = api_impl.as_ref().get_jwks(
+      method,
+      host,
+      cookies,
+  ).await;
+
+  let mut response = Response::builder();
+
+  let resp = match result {
+                                            Ok(rsp) => match rsp {
+                                                apis::auth::GetJwksResponse::Status200
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(200);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                            },
+                                            Err(e) => {
+                                                let problem = e.problem();
+                                                if problem.status >= 500 {
+                                                    error!(error = %e);
+                                                }
+                                                problem_response(response, problem)
+                                            },
+                                        };
+
+                                        resp.map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })
+}
+#[tracing::instrument(skip_all)]
+fn boards_get_validation(
+  header_params: models::BoardsGetHeaderParams,
+) -> std::result::Result<(
+  models::BoardsGetHeaderParams,
+), ValidationErrors>
+{
+  header_params.validate()?;
+
+Ok((
+  header_params,
+))
+}
+/// BoardsGet - GET /boards
+#[tracing::instrument(skip_all)]
+async fn boards_get<I, A>(
+  method: Method,
+  host: Host,
+  cookies: CookieJar,
+  headers: HeaderMap,
+ State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::boards::Boards,
+{
+    // Header parameters
+    let header_params = {
+                let header_authorization = headers.get(HeaderName::from_static("authorization"));
+
+                let header_authorization = match header_authorization {
+                    Some(v) => match header::IntoHeaderValue::<String>::try_from((*v).clone()) {
+                        Ok(result) =>
+                            Some(result.0),
+                        Err(err) => {
+                            return problem_response(Response::builder(), problem::header_problem("Authorization", &err))
+                                        .map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+
+                        },
+                    },
+                    None => {
+                        None
+                    }
+                };
+
+       models::BoardsGetHeaderParams {
+          authorization: header_authorization,
+       }
+  };
+
+
+      #[allow(clippy::redundant_closure)]
+      let validation = tokio::task::spawn_blocking(move ||
+    boards_get_validation(
+        header_params,
+    )
+  ).await.unwrap();
+
+  let Ok((
+    header_params,
+  )) = validation else {
+    return problem_response(Response::builder(), problem::validation_problem(&validation.unwrap_err()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+  };
+
+  let result = api_impl.as_ref().boards_get(
+      method,
+      host,
+      cookies,
+        header_params,
+  ).await;
+
+  let mut response = Response::builder();
+
+  let resp = match result {
+                                            Ok(rsp) => match rsp {
+                                                apis::boards::BoardsGetResponse::Status200
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(200);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::boards::BoardsGetResponse::Status401
+                                                    {
+                                                        www_authenticate
+                                                    }
+                                                => {
+                                                    let www_authenticate = match header::IntoHeaderValue(www_authenticate).try_into() {
+                                                        Ok(val) => val,
+                                                        Err(e) => {
+                                                            return Response::builder()
+                                                                    .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                    .body(Body::from(format!("An internal server error occurred handling www_authenticate header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                        }
+                                                    };
+
+                                                    {
+                                                      let mut response_headers = response.headers_mut().unwrap();
+                                                      response_headers.insert(
+                                                          HeaderName::from_static("www-authenticate"),
+                                                          www_authenticate,
+                                                      );
+                                                    }
+                                                  let mut response = response.status(401);
+                                                  response.body(Body::empty())
+                                                },
+                                            },
+                                            Err(e) => {
+                                                let problem = e.problem();
+                                                if problem.status >= 500 {
+                                                    error!(error = %e);
+                                                }
+                                                problem_response(response, problem)
+                                            },
+                                        };
+
+                                        resp.map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })
+}
+#[tracing::instrument(skip_all)]
+fn boards_slug_get_validation(
+  header_params: models::BoardsSlugGetHeaderParams,
+  path_params: models::BoardsSlugGetPathParams,
+) -> std::result::Result<(
+  models::BoardsSlugGetHeaderParams,
+  models::BoardsSlugGetPathParams,
+), ValidationErrors>
+{
+  header_params.validate()?;
+  path_params.validate()?;
+
+Ok((
+  header_params,
+  path_params,
+))
+}
+/// BoardsSlugGet - GET /boards/{slug}
+#[tracing::instrument(skip_all)]
+async fn boards_slug_get<I, A>(
+  method: Method,
+  host: Host,
+  cookies: CookieJar,
+  headers: HeaderMap,
+  Path(path_params): Path<models::BoardsSlugGetPathParams>,
+ State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::boards::Boards,
+{
+    // Header parameters
+    let header_params = {
+                let header_authorization = headers.get(HeaderName::from_static("authorization"));
+
+                let header_authorization = match header_authorization {
+                    Some(v) => match header::IntoHeaderValue::<String>::try_from((*v).clone()) {
+                        Ok(result) =>
+                            Some(result.0),
+                        Err(err) => {
+                            return problem_response(Response::builder(), problem::header_problem("Authorization", &err))
+                                        .map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+
+                        },
+                    },
+                    None => {
+                        None
+                    }
+                };
+
+       models::BoardsSlugGetHeaderParams {
+          authorization: header_authorization,
+       }
+  };
+
+
+      #[allow(clippy::redundant_closure)]
+      let validation = tokio::task::spawn_blocking(move ||
+    boards_slug_get_validation(
+        header_params,
+        path_params,
+    )
+  ).await.unwrap();
+
+  let Ok((
+    header_params,
+    path_params,
+  )) = validation else {
+    return problem_response(Response::builder(), problem::validation_problem(&validation.unwrap_err()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+  };
+
+  let result = api_impl.as_ref().boards_slug_get(
+      method,
+      host,
+      cookies,
+        header_params,
+        path_params,
+  ).await;
+
+  let mut response = Response::builder();
+
+  let resp = match result {
+                                            Ok(rsp) => match rsp {
+                                                apis::boards::BoardsSlugGetResponse::Status200
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(200);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::boards::BoardsSlugGetResponse::Status401
+                                                    {
+                                                        www_authenticate
+                                                    }
+                                                => {
+                                                    let www_authenticate = match header::IntoHeaderValue(www_authenticate).try_into() {
+                                                        Ok(val) => val,
+                                                        Err(e) => {
+                                                            return Response::builder()
+                                                                    .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                    .body(Body::from(format!("An internal server error occurred handling www_authenticate header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                        }
+                                                    };
+
+                                                    {
+                                                      let mut response_headers = response.headers_mut().unwrap();
+                                                      response_headers.insert(
+                                                          HeaderName::from_static("www-authenticate"),
+                                                          www_authenticate,
+                                                      );
+                                                    }
+                                                  let mut response = response.status(401);
+                                                  response.body(Body::empty())
+                                                },
+                                                apis::boards::BoardsSlugGetResponse::Status404
+                                                => {
+                                                  let mut response = response.status(404);
+                                                  response.body(Body::empty())
+                                                },
+                                            },
+                                            Err(e) => {
+                                                let problem = e.problem();
+                                                if problem.status >= 500 {
+                                                    error!(error = %e);
+                                                }
+                                                problem_response(response, problem)
+                                            },
+                                        };
+
+                                        resp.map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })
+}
+#[tracing::instrument(skip_all)]
+fn boards_slug_posts_get_validation(
+  header_params: models::BoardsSlugPostsGetHeaderParams,
+  path_params: models::BoardsSlugPostsGetPathParams,
+  query_params: models::BoardsSlugPostsGetQueryParams,
+) -> std::result::Result<(
+  models::BoardsSlugPostsGetHeaderParams,
+  models::BoardsSlugPostsGetPathParams,
+  models::BoardsSlugPostsGetQueryParams,
+), ValidationErrors>
+{
+  header_params.validate()?;
+  path_params.validate()?;
+  query_params.validate()?;
+
+Ok((
+  header_params,
+  path_params,
+  query_params,
+))
+}
+/// BoardsSlugPostsGet - GET /boards/{slug}/posts
+#[tracing::instrument(skip_all)]
+async fn boards_slug_posts_get<I, A>(
+  method: Method,
+  host: Host,
+  cookies: CookieJar,
+  headers: HeaderMap,
+  Path(path_params): Path<models::BoardsSlugPostsGetPathParams>,
+  Query(query_params): Query<models::BoardsSlugPostsGetQueryParams>,
+ State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::boards::Boards,
+{
+    // Header parameters
+    let header_params = {
+                let header_authorization = headers.get(HeaderName::from_static("authorization"));
+
+                let header_authorization = match header_authorization {
+                    Some(v) => match header::IntoHeaderValue::<String>::try_from((*v).clone()) {
+                        Ok(result) =>
+                            Some(result.0),
+                        Err(err) => {
+                            return problem_response(Response::builder(), problem::header_problem("Authorization", &err))
+                                        .map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+
+                        },
+                    },
+                    None => {
+                        None
+                    }
+                };
+
+       models::BoardsSlugPostsGetHeaderParams {
+          authorization: header_authorization,
+       }
+  };
+
+
+      #[allow(clippy::redundant_closure)]
+      let validation = tokio::task::spawn_blocking(move ||
+    boards_slug_posts_get_validation(
+        header_params,
+        path_params,
+        query_params,
+    )
+  ).await.unwrap();
+
+  let Ok((
+    header_params,
+    path_params,
+    query_params,
+  )) = validation else {
+    return problem_response(Response::builder(), problem::validation_problem(&validation.unwrap_err()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+  };
+
+  let result = api_impl.as_ref().boards_slug_posts_get(
+      method,
+      host,
+      cookies,
+        header_params,
+        path_params,
+        query_params,
+  ).await;
+
+  let mut response = Response::builder();
+
+  let resp = match result {
+                                            Ok(rsp) => match rsp {
+                                                apis::boards::BoardsSlugPostsGetResponse::Status200_ListOfPosts
+                                                    {
+                                                        body,
+                                                        link,
+                                                        x_next_cursor
+                                                    }
+                                                => {
+                                                    if let Some(link) = link {
+                                                    let link = match header::IntoHeaderValue(link).try_into() {
+                                                        Ok(val) => val,
+                                                        Err(e) => {
+                                                            return Response::builder()
+                                                                    .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                    .body(Body::from(format!("An internal server error occurred handling link header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                        }
+                                                    };
+
+                                                    {
+                                                      let mut response_headers = response.headers_mut().unwrap();
+                                                      response_headers.insert(
+                                                          HeaderName::from_static("link"),
+                                                          link,
+                                                      );
+                                                    }
+                                                    }
+                                                    if let Some(x_next_cursor) = x_next_cursor {
+                                                    let x_next_cursor = match header::IntoHeaderValue(x_next_cursor).try_into() {
+                                                        Ok(val) => val,
+                                                        Err(e) => {
+                                                            return Response::builder()
+                                                                    .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                    .body(Body::from(format!("An internal server error occurred handling x_next_cursor header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                        }
+                                                    };
+
+                                                    {
+                                                      let mut response_headers = response.headers_mut().unwrap();
+                                                      response_headers.insert(
+                                                          HeaderName::from_static("x-next-cursor"),
+                                                          x_next_cursor,
+                                                      );
+                                                    }
+                                                    }
+                                                  let mut response = response.status(200);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::boards::BoardsSlugPostsGetResponse::Status401
+                                                    {
+                                                        www_authenticate
+                                                    }
+                                                => {
+                                                    let www_authenticate = match header::IntoHeaderValue(www_authenticate).try_into() {
+                                                        Ok(val) => val,
+                                                        Err(e) => {
+                                                            return Response::builder()
+                                                                    .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                    .body(Body::from(format!("An internal server error occurred handling www_authenticate header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                        }
+                                                    };
+
+                                                    {
+                                                      let mut response_headers = response.headers_mut().unwrap();
+                                                      response_headers.insert(
+                                                          HeaderName::from_static("www-authenticate"),
+                                                          www_authenticate,
+                                                      );
+                                                    }
+                                                  let mut response = response.status(401);
+                                                  response.body(Body::empty())
+                                                },
+                                                apis::boards::BoardsSlugPostsGetResponse::Status404
+                                                => {
+                                                  let mut response = response.status(404);
+                                                  response.body(Body::empty())
+                                                },
+                                            },
+                                            Err(e) => {
+                                                let problem = e.problem();
+                                                if problem.status >= 500 {
+                                                    error!(error = %e);
+                                                }
+                                                problem_response(response, problem)
+                                            },
+                                        };
+
+                                        resp.map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })
+}
+
+#[tracing::instrument(skip_all)]
+fn me_drafts_get_validation(
+  header_params: models::MeDraftsGetHeaderParams,
+  query_params: models::MeDraftsGetQueryParams,
+) -> std::result::Result<(
+  models::MeDraftsGetHeaderParams,
+  models::MeDraftsGetQueryParams,
+), ValidationErrors>
+{
+  header_params.validate()?;
+  query_params.validate()?;
+
+Ok((
+  header_params,
+  query_params,
+))
+}
+/// MeDraftsGet - GET /me/drafts
+#[tracing::instrument(skip_all)]
+async fn me_drafts_get<I, A>(
+  method: Method,
+  host: Host,
+  cookies: CookieJar,
+  headers: HeaderMap,
+  Query(query_params): Query<models::MeDraftsGetQueryParams>,
+ State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::posts::Posts,
+{
+    // Header parameters
+    let header_params = {
+                let header_authorization = headers.get(HeaderName::from_static("authorization"));
+
+                let header_authorization = match header_authorization {
+                    Some(v) => match header::IntoHeaderValue::<String>::try_from((*v).clone()) {
+                        Ok(result) =>
+                            Some(result.0),
+                        Err(err) => {
+                            return problem_response(Response::builder(), problem::header_problem("Authorization", &err))
+                                        .map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+
+                        },
+                    },
+                    None => {
+                        None
+                    }
+                };
+
+       models::MeDraftsGetHeaderParams {
+          authorization: header_authorization,
+       }
+  };
+
+
+      #[allow(clippy::redundant_closure)]
+      let validation = tokio::task::spawn_blocking(move ||
+    me_drafts_get_validation(
+        header_params,
+        query_params,
+    )
+  ).await.unwrap();
+
+  let Ok((
+    header_params,
+    query_params,
+  )) = validation else {
+    return problem_response(Response::builder(), problem::validation_problem(&validation.unwrap_err()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+  };
+
+  let result = api_impl.as_ref().me_drafts_get(
+      method,
+      host,
+      cookies,
+        header_params,
+        query_params,
+  ).await;
+
+  let mut response = Response::builder();
+
+  let resp = match result {
+                                            Ok(rsp) => match rsp {
+                                                apis::posts::MeDraftsGetResponse::Status200_ListOfPosts
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(200);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::posts::MeDraftsGetResponse::Status401
+                                                    {
+                                                        www_authenticate
+                                                    }
+                                                => {
+                                                    let www_authenticate = match header::IntoHeaderValue(www_authenticate).try_into() {
+                                                        Ok(val) => val,
+                                                        Err(e) => {
+                                                            return Response::builder()
+                                                                    .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                    .body(Body::from(format!("An internal server error occurred handling www_authenticate header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                        }
+                                                    };
+
+                                                    {
+                                                      let mut response_headers = response.headers_mut().unwrap();
+                                                      response_headers.insert(
+                                                          HeaderName::from_static("www-authenticate"),
+                                                          www_authenticate,
+                                                      );
+                                                    }
+                                                  let mut response = response.status(401);
+                                                  response.body(Body::empty())
+                                                },
+                                            },
+                                            Err(e) => {
+                                                let problem = e.problem();
+                                                if problem.status >= 500 {
+                                                    error!(error = %e);
+                                                }
+                                                problem_response(response, problem)
+                                            },
+                                        };
+
+                                        resp.map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })
+}
+
+#[tracing::instrument(skip_all)]
+fn me_trash_get_validation(
+  header_params: models::MeTrashGetHeaderParams,
+  query_params: models::MeTrashGetQueryParams,
+) -> std::result::Result<(
+  models::MeTrashGetHeaderParams,
+  models::MeTrashGetQueryParams,
+), ValidationErrors>
+{
+  header_params.validate()?;
+  query_params.validate()?;
+
+Ok((
+  header_params,
+  query_params,
+))
+}
+/// MeTrashGet - GET /me/trash
+#[tracing::instrument(skip_all)]
+async fn me_trash_get<I, A>(
+  method: Method,
+  host: Host,
+  cookies: CookieJar,
+  headers: HeaderMap,
+  Query(query_params): Query<models::MeTrashGetQueryParams>,
+ State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::posts::Posts,
+{
+    // Header parameters
+    let header_params = {
+                let header_authorization = headers.get(HeaderName::from_static("authorization"));
+
+                let header_authorization = match header_authorization {
+                    Some(v) => match header::IntoHeaderValue::<String>::try_from((*v).clone()) {
+                        Ok(result) =>
+                            Some(result.0),
+                        Err(err) => {
+                            return problem_response(Response::builder(), problem::header_problem("Authorization", &err))
+                                        .map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+
+                        },
+                    },
+                    None => {
+                        None
+                    }
+                };
+
+       models::MeTrashGetHeaderParams {
+          authorization: header_authorization,
+       }
+  };
+
+
+      #[allow(clippy::redundant_closure)]
+      let validation = tokio::task::spawn_blocking(move ||
+    me_trash_get_validation(
+        header_params,
+        query_params,
+    )
+  ).await.unwrap();
+
+  let Ok((
+    header_params,
+    query_params,
+  )) = validation else {
+    return problem_response(Response::builder(), problem::validation_problem(&validation.unwrap_err()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+  };
+
+  let result = api_impl.as_ref().me_trash_get(
+      method,
+      host,
+      cookies,
+        header_params,
+        query_params,
+  ).await;
+
+  let mut response = Response::builder();
+
+  let resp = match result {
+                                            Ok(rsp) => match rsp {
+                                                apis::posts::MeTrashGetResponse::Status200_ListOfPosts
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(200);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::posts::MeTrashGetResponse::Status401
+                                                    {
+                                                        www_authenticate
+                                                    }
+                                                => {
+                                                    let www_authenticate = match header::IntoHeaderValue(www_authenticate).try_into() {
+                                                        Ok(val) => val,
+                                                        Err(e) => {
+                                                            return Response::builder()
+                                                                    .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                    .body(Body::from(format!("An internal server error occurred handling www_authenticate header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                        }
+                                                    };
+
+                                                    {
+                                                      let mut response_headers = response.headers_mut().unwrap();
+                                                      response_headers.insert(
+                                                          HeaderName::from_static("www-authenticate"),
+                                                          www_authenticate,
+                                                      );
+                                                    }
+                                                  let mut response = response.status(401);
+                                                  response.body(Body::empty())
+                                                },
+                                            },
+                                            Err(e) => {
+                                                let problem = e.problem();
+                                                if problem.status >= 500 {
+                                                    error!(error = %e);
+                                                }
+                                                problem_response(response, problem)
+                                            },
+                                        };
+
+                                        resp.map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })
+}
+
+#[tracing::instrument(skip_all)]
+fn posts_get_validation(
+  header_params: models::PostsGetHeaderParams,
+  query_params: models::PostsGetQueryParams,
+) -> std::result::Result<(
+  models::PostsGetHeaderParams,
+  models::PostsGetQueryParams,
+), ValidationErrors>
+{
+  header_params.validate()?;
+  query_params.validate()?;
+
+Ok((
+  header_params,
+  query_params,
+))
+}
+/// PostsGet - GET /posts
+#[tracing::instrument(skip_all)]
+async fn posts_get<I, A>(
+  method: Method,
+  host: Host,
+  cookies: CookieJar,
+  headers: HeaderMap,
+  Query(query_params): Query<models::PostsGetQueryParams>,
+ State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::posts::Posts,
+{
+    // Header parameters
+    let header_params = {
+                let header_authorization = headers.get(HeaderName::from_static("authorization"));
+
+                let header_authorization = match header_authorization {
+                    Some(v) => match header::IntoHeaderValue::<String>::try_from((*v).clone()) {
+                        Ok(result) =>
+                            Some(result.0),
+                        Err(err) => {
+                            return problem_response(Response::builder(), problem::header_problem("Authorization", &err))
+                                        .map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+
+                        },
+                    },
+                    None => {
+                        None
+                    }
+                };
+
+       models::PostsGetHeaderParams {
+          authorization: header_authorization,
+       }
+  };
+
+
+      #[allow(clippy::redundant_closure)]
+      let validation = tokio::task::spawn_blocking(move ||
+    posts_get_validation(
+        header_params,
+        query_params,
+    )
+  ).await.unwrap();
+
+  let Ok((
+    header_params,
+    query_params,
+  )) = validation else {
+    return problem_response(Response::builder(), problem::validation_problem(&validation.unwrap_err()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+  };
+
+  let result = api_impl.as_ref().posts_get(
+      method,
+      host,
+      cookies,
+        header_params,
+        query_params,
+  ).await;
+
+  let mut response = Response::builder();
+
+  let resp = match result {
+                                            Ok(rsp) => match rsp {
+                                                apis::posts::PostsGetResponse::Status200_ListOfPosts
+                                                    {
+                                                        body,
+                                                        link,
+                                                        x_next_cursor
+                                                    }
+                                                => {
+                                                    if let Some(link) = link {
+                                                    let link = match header::IntoHeaderValue(link).try_into() {
+                                                        Ok(val) => val,
+                                                        Err(e) => {
+                                                            return Response::builder()
+                                                                    .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                    .body(Body::from(format!("An internal server error occurred handling link header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                        }
+                                                    };
+
+                                                    {
+                                                      let mut response_headers = response.headers_mut().unwrap();
+                                                      response_headers.insert(
+                                                          HeaderName::from_static("link"),
+                                                          link,
+                                                      );
+                                                    }
+                                                    }
+                                                    if let Some(x_next_cursor) = x_next_cursor {
+                                                    let x_next_cursor = match header::IntoHeaderValue(x_next_cursor).try_into() {
+                                                        Ok(val) => val,
+                                                        Err(e) => {
+                                                            return Response::builder()
+                                                                    .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                    .body(Body::from(format!("An internal server error occurred handling x_next_cursor header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                        }
+                                                    };
+
+                                                    {
+                                                      let mut response_headers = response.headers_mut().unwrap();
+                                                      response_headers.insert(
+                                                          HeaderName::from_static("x-next-cursor"),
+                                                          x_next_cursor,
+                                                      );
+                                                    }
+                                                    }
+                                                  let mut response = response.status(200);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::posts::PostsGetResponse::Status401
+                                                    {
+                                                        www_authenticate
+                                                    }
+                                                => {
+                                                    let www_authenticate = match header::IntoHeaderValue(www_authenticate).try_into() {
+                                                        Ok(val) => val,
+                                                        Err(e) => {
+                                                            return Response::builder()
+                                                                    .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                    .body(Body::from(format!("An internal server error occurred handling www_authenticate header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                        }
+                                                    };
+
+                                                    {
+                                                      let mut response_headers = response.headers_mut().unwrap();
+                                                      response_headers.insert(
+                                                          HeaderName::from_static("www-authenticate"),
+                                                          www_authenticate,
+                                                      );
+                                                    }
+                                                  let mut response = response.status(401);
+                                                  response.body(Body::empty())
+                                                },
+                                            },
+                                            Err(e) => {
+                                                let problem = e.problem();
+                                                if problem.status >= 500 {
+                                                    error!(error = %e);
+                                                }
+                                                problem_response(response, problem)
+                                            },
+                                        };
+
+                                        resp.map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })
+}
+
+    #[derive(validator::Validate)]
+    #[allow(dead_code)]
+    struct PostsPostBodyValidator<'a> {
+            #[validate(nested)]
+          body: &'a models::PostsPostRequest,
+    }
+
+
+#[tracing::instrument(skip_all)]
+fn posts_post_validation(
+  header_params: models::PostsPostHeaderParams,
+        body: Option<models::PostsPostRequest>,
+) -> std::result::Result<(
+  models::PostsPostHeaderParams,
+        Option<models::PostsPostRequest>,
+), ValidationErrors>
+{
+  header_params.validate()?;
+            if let Some(body) = &body {
+              let b = PostsPostBodyValidator { body };
+              b.validate()?;
+            }
+
+Ok((
+  header_params,
+    body,
+))
+}
+/// PostsPost - POST /posts
+#[tracing::instrument(skip_all)]
+async fn posts_post<I, A>(
+  method: Method,
+  host: Host,
+  cookies: CookieJar,
+  headers: HeaderMap,
+ State(api_impl): State<I>,
+          Json(body): Json<Option<models::PostsPostRequest>>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::posts::Posts,
+{
+    // Header parameters
+    let header_params = {
+                let header_authorization = headers.get(HeaderName::from_static("authorization"));
+
+                let header_authorization = match header_authorization {
+                    Some(v) => match header::IntoHeaderValue::<String>::try_from((*v).clone()) {
+                        Ok(result) =>
+                            Some(result.0),
+                        Err(err) => {
+                            return problem_response(Response::builder(), problem::header_problem("Authorization", &err))
+                                        .map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+
+                        },
+                    },
+                    None => {
+                        None
+                    }
+                };
+
+       models::PostsPostHeaderParams {
+          authorization: header_authorization,
+       }
+  };
+
+
+      #[allow(clippy::redundant_closure)]
+      let validation = tokio::task::spawn_blocking(move ||
+    posts_post_validation(
+        header_params,
+          body,
+    )
+  ).await.unwrap();
+
+  let Ok((
+    header_params,
+      body,
+  )) = validation else {
+    return problem_response(Response::builder(), problem::validation_problem(&validation.unwrap_err()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+  };
+
+  let result = api_impl.as_ref().posts_post(
+      method,
+      host,
+      cookies,
+        header_params,
+              body,
+  ).await;
+
+  let mut response = Response::builder();
+
+  let resp = match result {
+                                            Ok(rsp) => match rsp {
+                                                apis::posts::PostsPostResponse::Status201_PostCreated
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(201);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::posts::PostsPostResponse::Status401
+                                                    {
+                                                        www_authenticate
+                                                    }
+                                                => {
+                                                    let www_authenticate = match header::IntoHeaderValue(www_authenticate).try_into() {
+                                                        Ok(val) => val,
+                                                        Err(e) => {
+                                                            return Response::builder()
+                                                                    .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                    .body(Body::from(format!("An internal server error occurred handling www_authenticate header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                        }
+                                                    };
+
+                                                    {
+                                                      let mut response_headers = response.headers_mut().unwrap();
+                                                      response_headers.insert(
+                                                          HeaderName::from_static("www-authenticate"),
+                                                          www_authenticate,
+                                                      );
+                                                    }
+                                                  let mut response = response.status(401);
+                                                  response.body(Body::empty())
+                                                },
+                                                apis::posts::PostsPostResponse::Status403
+                                                => {
+                                                  let mut response = response.status(403);
+                                                  response.body(Body::empty())
+                                                },
+                                            },
+                                            Err(e) => {
+                                                let problem = e.problem();
+                                                if problem.status >= 500 {
+                                                    error!(error = %e);
+                                                }
+                                                problem_response(response, problem)
+                                            },
+                                        };
+
+                                        resp.map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })
+}
+
+
+#[tracing::instrument(skip_all)]
+fn posts_post_id_delete_validation(
+  header_params: models::PostsPostIdDeleteHeaderParams,
+  path_params: models::PostsPostIdDeletePathParams,
+) -> std::result::Result<(
+  models::PostsPostIdDeleteHeaderParams,
+  models::PostsPostIdDeletePathParams,
+), ValidationErrors>
+{
+  header_params.validate()?;
+  path_params.validate()?;
+
+Ok((
+  header_params,
+  path_params,
+))
+}
+/// PostsPostIdDelete - DELETE /posts/{postId}
+#[tracing::instrument(skip_all)]
+async fn posts_post_id_delete<I, A>(
+  method: Method,
+  host: Host,
+  cookies: CookieJar,
+  headers: HeaderMap,
+  Path(path_params): Path<models::PostsPostIdDeletePathParams>,
+ State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::posts::Posts,
+{
+    // Header parameters
+    let header_params = {
+                let header_authorization = headers.get(HeaderName::from_static("authorization"));
+
+                let header_authorization = match header_authorization {
+                    Some(v) => match header::IntoHeaderValue::<String>::try_from((*v).clone()) {
+                        Ok(result) =>
+                            Some(result.0),
+                        Err(err) => {
+                            return problem_response(Response::builder(), problem::header_problem("Authorization", &err))
+                                        .map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+
+                        },
+                    },
+                    None => {
+                        None
+                    }
+                };
+
+                let header_if_match = headers.get(HeaderName::from_static("if-match"));
+
+                let header_if_match = match header_if_match {
+                    Some(v) => match header::IntoHeaderValue::<String>::try_from((*v).clone()) {
+                        Ok(result) =>
+                            Some(result.0),
+                        Err(err) => {
+                            return problem_response(Response::builder(), problem::header_problem("If-Match", &err))
+                                        .map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+
+                        },
+                    },
+                    None => {
+                        None
+                    }
+                };
+
+       models::PostsPostIdDeleteHeaderParams {
+          authorization: header_authorization,
+          if_match: header_if_match,
+       }
+  };
+
+
+      #[allow(clippy::redundant_closure)]
+      let validation = tokio::task::spawn_blocking(move ||
+    posts_post_id_delete_validation(
+        header_params,
+        path_params,
+    )
+  ).await.unwrap();
+
+  let Ok((
+    header_params,
+    path_params,
+  )) = validation else {
+    return problem_response(Response::builder(), problem::validation_problem(&validation.unwrap_err()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+  };
+
+  let result = api_impl.as_ref().posts_post_id_delete(
+      method,
+      host,
+      cookies,
+        header_params,
+        path_params,
+  ).await;
+
+  let mut response = Response::builder();
+
+  let resp = match result {
+                                            Ok(rsp) => match rsp {
+                                                apis::posts::PostsPostIdDeleteResponse::Status204_NoContent
+                                                => {
+                                                  let mut response = response.status(204);
+                                                  response.body(Body::empty())
+                                                },
+                                                apis::posts::PostsPostIdDeleteResponse::Status401_Unauthorized
+                                                    {
+                                                        www_authenticate
+                                                    }
+                                                => {
+                                                    let www_authenticate = match header::IntoHeaderValue(www_authenticate).try_into() {
+                                                        Ok(val) => val,
+                                                        Err(e) => {
+                                                            return Response::builder()
+                                                                    .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                    .body(Body::from(format!("An internal server error occurred handling www_authenticate header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                        }
+                                                    };
+
+                                                    {
+                                                      let mut response_headers = response.headers_mut().unwrap();
+                                                      response_headers.insert(
+                                                          HeaderName::from_static("www-authenticate"),
+                                                          www_authenticate,
+                                                      );
+                                                    }
+                                                  let mut response = response.status(401);
+                                                  response.body(Body::empty())
+                                                },
+                                                apis::posts::PostsPostIdDeleteResponse::Status403_Forbidden
+                                                => {
+                                                  let mut response = response.status(403);
+                                                  response.body(Body::empty())
+                                                },
+                                                apis::posts::PostsPostIdDeleteResponse::Status404_NotFound
+                                                => {
+                                                  let mut response = response.status(404);
+                                                  response.body(Body::empty())
+                                                },
+                                                apis::posts::PostsPostIdDeleteResponse::Status412_PreconditionFailed
+                                                => {
+                                                  let mut response = response.status(412);
+                                                  response.body(Body::empty())
+                                                },
+                                            },
+                                            Err(e) => {
+                                                let problem = e.problem();
+                                                if problem.status >= 500 {
+                                                    error!(error = %e);
+                                                }
+                                                problem_response(response, problem)
+                                            },
+                                        };
+
+                                        resp.map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })
+}
+
+#[tracing::instrument(skip_all)]
+fn posts_post_id_diff_get_validation(
+  header_params: models::PostsPostIdDiffGetHeaderParams,
+  path_params: models::PostsPostIdDiffGetPathParams,
+  query_params: models::PostsPostIdDiffGetQueryParams,
+) -> std::result::Result<(
+  models::PostsPostIdDiffGetHeaderParams,
+  models::PostsPostIdDiffGetPathParams,
+  models::PostsPostIdDiffGetQueryParams,
+), ValidationErrors>
+{
+  header_params.validate()?;
+  path_params.validate()?;
+  query_params.validate()?;
+
+Ok((
+  header_params,
+  path_params,
+  query_params,
+))
+}
+/// PostsPostIdDiffGet - GET /posts/{postId}/diff
+#[tracing::instrument(skip_all)]
+async fn posts_post_id_diff_get<I, A>(
+  method: Method,
+  host: Host,
+  cookies: CookieJar,
+  headers: HeaderMap,
+  Path(path_params): Path<models::PostsPostIdDiffGetPathParams>,
+  Query(query_params): Query<models::PostsPostIdDiffGetQueryParams>,
+ State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::posts::Posts,
+{
+    // Header parameters
+    let header_params = {
+                let header_authorization = headers.get(HeaderName::from_static("authorization"));
+
+                let header_authorization = match header_authorization {
+                    Some(v) => match header::IntoHeaderValue::<String>::try_from((*v).clone()) {
+                        Ok(result) =>
+                            Some(result.0),
+                        Err(err) => {
+                            return problem_response(Response::builder(), problem::header_problem("Authorization", &err))
+                                        .map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+
+                        },
+                    },
+                    None => {
+                        None
+                    }
+                };
+
+       models::PostsPostIdDiffGetHeaderParams {
+          authorization: header_authorization,
+       }
+  };
+
+
+      #[allow(clippy::redundant_closure)]
+      let validation = tokio::task::spawn_blocking(move ||
+    posts_post_id_diff_get_validation(
+        header_params,
+        path_params,
+        query_params,
+    )
+  ).await.unwrap();
+
+  let Ok((
+    header_params,
+    path_params,
+    query_params,
+  )) = validation else {
+    return problem_response(Response::builder(), problem::validation_problem(&validation.unwrap_err()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+  };
+
+  let result = api_impl.as_ref().posts_post_id_diff_get(
+      method,
+      host,
+      cookies,
+        header_params,
+        path_params,
+        query_params,
+  ).await;
+
+  let mut response = Response::builder();
+
+  let resp = match result {
+                                            Ok(rsp) => match rsp {
+                                                apis::posts::PostsPostIdDiffGetResponse::Status200
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(200);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::posts::PostsPostIdDiffGetResponse::Status401
+                                                    {
+                                                        www_authenticate
+                                                    }
+                                                => {
+                                                    let www_authenticate = match header::IntoHeaderValue(www_authenticate).try_into() {
+                                                        Ok(val) => val,
+                                                        Err(e) => {
+                                                            return Response::builder()
+                                                                    .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                    .body(Body::from(format!("An internal server error occurred handling www_authenticate header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                        }
+                                                    };
+
+                                                    {
+                                                      let mut response_headers = response.headers_mut().unwrap();
+                                                      response_headers.insert(
+                                                          HeaderName::from_static("www-authenticate"),
+                                                          www_authenticate,
+                                                      );
+                                                    }
+                                                  let mut response = response.status(401);
+                                                  response.body(Body::empty())
+                                                },
+                                                apis::posts::PostsPostIdDiffGetResponse::Status404
+                                                => {
+                                                  let mut response = response.status(404);
+                                                  response.body(Body::empty())
+                                                },
+                                            },
+                                            Err(e) => {
+                                                let problem = e.problem();
+                                                if problem.status >= 500 {
+                                                    error!(error = %e);
+                                                }
+                                                problem_response(response, problem)
+                                            },
+                                        };
+
+                                        resp.map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })
+}
+
+#[tracing::instrument(skip_all)]
+fn posts_post_id_get_validation(
+  header_params: models::PostsPostIdGetHeaderParams,
+  path_params: models::PostsPostIdGetPathParams,
+) -> std::result::Result<(
+  models::PostsPostIdGetHeaderParams,
+  models::PostsPostIdGetPathParams,
+), ValidationErrors>
+{
+  header_params.validate()?;
+  path_params.validate()?;
+
+Ok((
+  header_params,
+  path_params,
+))
+}
+/// PostsPostIdGet - GET /posts/{postId}
+#[tracing::instrument(skip_all)]
+async fn posts_post_id_get<I, A>(
+  method: Method,
+  host: Host,
+  cookies: CookieJar,
+  headers: HeaderMap,
+  Path(path_params): Path<models::PostsPostIdGetPathParams>,
+ State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::posts::Posts,
+{
+    // Header parameters
+    let header_params = {
+                let header_authorization = headers.get(HeaderName::from_static("authorization"));
+
+                let header_authorization = match header_authorization {
+                    Some(v) => match header::IntoHeaderValue::<String>::try_from((*v).clone()) {
+                        Ok(result) =>
+                            Some(result.0),
+                        Err(err) => {
+                            return problem_response(Response::builder(), problem::header_problem("Authorization", &err))
+                                        .map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+
+                        },
+                    },
+                    None => {
+                        None
+                    }
+                };
+
+                let header_if_none_match = headers.get(HeaderName::from_static("if-none-match"));
+
+                let header_if_none_match = match header_if_none_match {
+                    Some(v) => match header::IntoHeaderValue::<String>::try_from((*v).clone()) {
+                        Ok(result) =>
+                            Some(result.0),
+                        Err(err) => {
+                            return problem_response(Response::builder(), problem::header_problem("If-None-Match", &err))
+                                        .map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+
+                        },
+                    },
+                    None => {
+                        None
+                    }
+                };
+
+       models::PostsPostIdGetHeaderParams {
+          authorization: header_authorization,
+          if_none_match: header_if_none_match,
+       }
+  };
+
+
+      #[allow(clippy::redundant_closure)]
+      let validation = tokio::task::spawn_blocking(move ||
+    posts_post_id_get_validation(
+        header_params,
+        path_params,
+    )
+  ).await.unwrap();
+
+  let Ok((
+    header_params,
+    path_params,
+  )) = validation else {
+    return problem_response(Response::builder(), problem::validation_problem(&validation.unwrap_err()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+  };
+
+  let result = api_impl.as_ref().posts_post_id_get(
+      method,
+      host,
+      cookies,
+        header_params,
+        path_params,
+  ).await;
+
+  let mut response = Response::builder();
+
+  let resp = match result {
+                                            Ok(rsp) => match rsp {
+                                                apis::posts::PostsPostIdGetResponse::Status200_PostCreated
+                                                    {
+                                                        body,
+                                                        e_tag
+                                                    }
+                                                => {
+                                                    let e_tag = match header::IntoHeaderValue(e_tag).try_into() {
+                                                        Ok(val) => val,
+                                                        Err(e) => {
+                                                            return Response::builder()
+                                                                    .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                    .body(Body::from(format!("An internal server error occurred handling e_tag header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                        }
+                                                    };
+
+                                                    {
+                                                      let mut response_headers = response.headers_mut().unwrap();
+                                                      response_headers.insert(
+                                                          HeaderName::from_static("etag"),
+                                                          e_tag,
+                                                      );
+                                                    }
+                                                  let mut response = response.status(200);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::posts::PostsPostIdGetResponse::Status304
+                                                    {
+                                                        e_tag
+                                                    }
+                                                => {
+                                                    let e_tag = match header::IntoHeaderValue(e_tag).try_into() {
+                                                        Ok(val) => val,
+                                                        Err(e) => {
+                                                            return Response::builder()
+                                                                    .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                    .body(Body::from(format!("An internal server error occurred handling e_tag header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                        }
+                                                    };
+
+                                                    {
+                                                      let mut response_headers = response.headers_mut().unwrap();
+                                                      response_headers.insert(
+                                                          HeaderName::from_static("etag"),
+                                                          e_tag,
+                                                      );
+                                                    }
+                                                  let mut response = response.status(304);
+                                                  response.body(Body::empty())
+                                                },
+                                                apis::posts::PostsPostIdGetResponse::Status401
+                                                    {
+                                                        www_authenticate
+                                                    }
+                                                => {
+                                                    let www_authenticate = match header::IntoHeaderValue(www_authenticate).try_into() {
+                                                        Ok(val) => val,
+                                                        Err(e) => {
+                                                            return Response::builder()
+                                                                    .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                    .body(Body::from(format!("An internal server error occurred handling www_authenticate header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                        }
+                                                    };
+
+                                                    {
+                                                      let mut response_headers = response.headers_mut().unwrap();
+                                                      response_headers.insert(
+                                                          HeaderName::from_static("www-authenticate"),
+                                                          www_authenticate,
+                                                      );
+                                                    }
+                                                  let mut response = response.status(401);
+                                                  response.body(Body::empty())
+                                                },
+                                                apis::posts::PostsPostIdGetResponse::Status404
+                                                => {
+                                                  let mut response = response.status(404);
+                                                  response.body(Body::empty())
+                                                },
+                                            },
+                                            Err(e) => {
+                                                let problem = e.problem();
+                                                if problem.status >= 500 {
+                                                    error!(error = %e);
+                                                }
+                                                problem_response(response, problem)
+                                            },
+                                        };
+
+                                        resp.map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })
+}
+
+
+    #[derive(validator::Validate)]
+    #[allow(dead_code)]
+    struct PostsPostIdModerationPutBodyValidator<'a> {
+            #[validate(nested)]
+          body: &'a models::PostsPostIdModerationPutRequest,
+    }
+
+
+#[tracing::instrument(skip_all)]
+fn posts_post_id_moderation_put_validation(
+  header_params: models::PostsPostIdModerationPutHeaderParams,
+  path_params: models::PostsPostIdModerationPutPathParams,
+        body: Option<models::PostsPostIdModerationPutRequest>,
+) -> std::result::Result<(
+  models::PostsPostIdModerationPutHeaderParams,
+  models::PostsPostIdModerationPutPathParams,
+        Option<models::PostsPostIdModerationPutRequest>,
+), ValidationErrors>
+{
+  header_params.validate()?;
+  path_params.validate()?;
+            if let Some(body) = &body {
+              let b = PostsPostIdModerationPutBodyValidator { body };
+              b.validate()?;
+            }
+
+Ok((
+  header_params,
+  path_params,
+    body,
+))
+}
+/// PostsPostIdModerationPut - PUT /posts/{postId}/moderation
+#[tracing::instrument(skip_all)]
+async fn posts_post_id_moderation_put<I, A>(
+  method: Method,
+  host: Host,
+  cookies: CookieJar,
+  headers: HeaderMap,
+  Path(path_params): Path<models::PostsPostIdModerationPutPathParams>,
+ State(api_impl): State<I>,
+          Json(body): Json<Option<models::PostsPostIdModerationPutRequest>>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::posts::Posts,
+{
+    // Header parameters
+    let header_params = {
+                let header_authorization = headers.get(HeaderName::from_static("authorization"));
+
+                let header_authorization = match header_authorization {
+                    Some(v) => match header::IntoHeaderValue::<String>::try_from((*v).clone()) {
+                        Ok(result) =>
+                            Some(result.0),
+                        Err(err) => {
+                            return problem_response(Response::builder(), problem::header_problem("Authorization", &err))
+                                        .map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+
+                        },
+                    },
+                    None => {
+                        None
+                    }
+                };
+
+                let header_if_match = headers.get(HeaderName::from_static("if-match"));
+
+                let header_if_match = match header_if_match {
+                    Some(v) => match header::IntoHeaderValue::<String>::try_from((*v).clone()) {
+                        Ok(result) =>
+                            Some(result.0),
+                        Err(err) => {
+                            return problem_response(Response::builder(), problem::header_problem("If-Match", &err))
+                                        .map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+
+                        },
+                    },
+                    None => {
+                        None
+                    }
+                };
+
+       models::PostsPostIdModerationPutHeaderParams {
+          authorization: header_authorization,
+          if_match: header_if_match,
+       }
+  };
+
+
+      #[allow(clippy::redundant_closure)]
+      let validation = tokio::task::spawn_blocking(move ||
+    posts_post_id_moderation_put_validation(
+        header_params,
+        path_params,
+          body,
+    )
+  ).await.unwrap();
+
+  let Ok((
+    header_params,
+    path_params,
+      body,
+  )) = validation else {
+    return problem_response(Response::builder(), problem::validation_problem(&validation.unwrap_err()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+  };
+
+  let result = api_impl.as_ref().posts_post_id_moderation_put(
+      method,
+      host,
+      cookies,
+        header_params,
+        path_params,
+              body,
+  ).await;
+
+  let mut response = Response::builder();
+
+  let resp = match result {
+                                            Ok(rsp) => match rsp {
+                                                apis::posts::PostsPostIdModerationPutResponse::Status200_PostCreated
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(200);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::posts::PostsPostIdModerationPutResponse::Status401
+                                                    {
+                                                        www_authenticate
+                                                    }
+                                                => {
+                                                    let www_authenticate = match header::IntoHeaderValue(www_authenticate).try_into() {
+                                                        Ok(val) => val,
+                                                        Err(e) => {
+                                                            return Response::builder()
+                                                                    .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                    .body(Body::from(format!("An internal server error occurred handling www_authenticate header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                        }
+                                                    };
+
+                                                    {
+                                                      let mut response_headers = response.headers_mut().unwrap();
+                                                      response_headers.insert(
+                                                          HeaderName::from_static("www-authenticate"),
+                                                          www_authenticate,
+                                                      );
+                                                    }
+                                                  let mut response = response.status(401);
+                                                  response.body(Body::empty())
+                                                },
+                                                apis::posts::PostsPostIdModerationPutResponse::Status403
+                                                => {
+                                                  let mut response = response.status(403);
+                                                  response.body(Body::empty())
+                                                },
+                                                apis::posts::PostsPostIdModerationPutResponse::Status404
+                                                => {
+                                                  let mut response = response.status(404);
+                                                  response.body(Body::empty())
+                                                },
+                                                apis::posts::PostsPostIdModerationPutResponse::Status412
+                                                => {
+                                                  let mut response = response.status(412);
+                                                  response.body(Body::empty())
+                                                },
+                                            },
+                                            Err(e) => {
+                                                let problem = e.problem();
+                                                if problem.status >= 500 {
+                                                    error!(error = %e);
+                                                }
+                                                problem_response(response, problem)
+                                            },
+                                        };
+
+                                        resp.map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })
+}
+
+
+    #[derive(validator::Validate)]
+    #[allow(dead_code)]
+    struct PostsPostIdPatchBodyValidator<'a> {
+            #[validate(nested)]
+          body: &'a models::PostPatch,
+    }
+
+
+#[tracing::instrument(skip_all)]
+fn posts_post_id_patch_validation(
+  header_params: models::PostsPostIdPatchHeaderParams,
+  path_params: models::PostsPostIdPatchPathParams,
+        body: Option<models::PostPatch>,
+) -> std::result::Result<(
+  models::PostsPostIdPatchHeaderParams,
+  models::PostsPostIdPatchPathParams,
+        Option<models::PostPatch>,
+), ValidationErrors>
+{
+  header_params.validate()?;
+  path_params.validate()?;
+            if let Some(body) = &body {
+              let b = PostsPostIdPatchBodyValidator { body };
+              b.validate()?;
+            }
+
+Ok((
+  header_params,
+  path_params,
+    body,
+))
+}
+/// PostsPostIdPatch - PATCH /posts/{postId}
+#[tracing::instrument(skip_all)]
+async fn posts_post_id_patch<I, A>(
+  method: Method,
+  host: Host,
+  cookies: CookieJar,
+  headers: HeaderMap,
+  Path(path_params): Path<models::PostsPostIdPatchPathParams>,
+ State(api_impl): State<I>,
+          Json(body): Json<Option<models::PostPatch>>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::posts::Posts,
+{
+    // Header parameters
+    let header_params = {
+                let header_authorization = headers.get(HeaderName::from_static("authorization"));
+
+                let header_authorization = match header_authorization {
+                    Some(v) => match header::IntoHeaderValue::<String>::try_from((*v).clone()) {
+                        Ok(result) =>
+                            Some(result.0),
+                        Err(err) => {
+                            return problem_response(Response::builder(), problem::header_problem("Authorization", &err))
+                                        .map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+
+                        },
+                    },
+                    None => {
+                        None
+                    }
+                };
+
+                let header_if_match = headers.get(HeaderName::from_static("if-match"));
+
+                let header_if_match = match header_if_match {
+                    Some(v) => match header::IntoHeaderValue::<String>::try_from((*v).clone()) {
+                        Ok(result) =>
+                            Some(result.0),
+                        Err(err) => {
+                            return problem_response(Response::builder(), problem::header_problem("If-Match", &err))
+                                        .map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+
+                        },
+                    },
+                    None => {
+                        None
+                    }
+                };
+
+       models::PostsPostIdPatchHeaderParams {
+          authorization: header_authorization,
+          if_match: header_if_match,
+       }
+  };
+
+
+      #[allow(clippy::redundant_closure)]
+      let validation = tokio::task::spawn_blocking(move ||
+    posts_post_id_patch_validation(
+        header_params,
+        path_params,
+          body,
+    )
+  ).await.unwrap();
+
+  let Ok((
+    header_params,
+    path_params,
+      body,
+  )) = validation else {
+    return problem_response(Response::builder(), problem::validation_problem(&validation.unwrap_err()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+  };
+
+  let result = api_impl.as_ref().posts_post_id_patch(
+      method,
+      host,
+      cookies,
+        header_params,
+        path_params,
+              body,
+  ).await;
+
+  let mut response = Response::builder();
+
+  let resp = match result {
+                                            Ok(rsp) => match rsp {
+                                                apis::posts::PostsPostIdPatchResponse::Status200_PostCreated
+                                                    {
+                                                        body,
+                                                        e_tag
+                                                    }
+                                                => {
+                                                    let e_tag = match header::IntoHeaderValue(e_tag).try_into() {
+                                                        Ok(val) => val,
+                                                        Err(e) => {
+                                                            return Response::builder()
+                                                                    .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                    .body(Body::from(format!("An internal server error occurred handling e_tag header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                        }
+                                                    };
+
+                                                    {
+                                                      let mut response_headers = response.headers_mut().unwrap();
+                                                      response_headers.insert(
+                                                          HeaderName::from_static("etag"),
+                                                          e_tag,
+                                                      );
+                                                    }
+                                                  let mut response = response.status(200);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::posts::PostsPostIdPatchResponse::Status401
+                                                    {
+                                                        www_authenticate
+                                                    }
+                                                => {
+                                                    let www_authenticate = match header::IntoHeaderValue(www_authenticate).try_into() {
+                                                        Ok(val) => val,
+                                                        Err(e) => {
+                                                            return Response::builder()
+                                                                    .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                    .body(Body::from(format!("An internal server error occurred handling www_authenticate header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                        }
+                                                    };
+
+                                                    {
+                                                      let mut response_headers = response.headers_mut().unwrap();
+                                                      response_headers.insert(
+                                                          HeaderName::from_static("www-authenticate"),
+                                                          www_authenticate,
+                                                      );
+                                                    }
+                                                  let mut response = response.status(401);
+                                                  response.body(Body::empty())
+                                                },
+                                                apis::posts::PostsPostIdPatchResponse::Status403
+                                                => {
+                                                  let mut response = response.status(403);
+                                                  response.body(Body::empty())
+                                                },
+                                                apis::posts::PostsPostIdPatchResponse::Status404
+                                                => {
+                                                  let mut response = response.status(404);
+                                                  response.body(Body::empty())
+                                                },
+                                                apis::posts::PostsPostIdPatchResponse::Status412
+                                                => {
+                                                  let mut response = response.status(412);
+                                                  response.body(Body::empty())
+                                                },
+                                            },
+                                            Err(e) => {
+                                                let problem = e.problem();
+                                                if problem.status >= 500 {
+                                                    error!(error = %e);
+                                                }
+                                                problem_response(response, problem)
+                                            },
+                                        };
+
+                                        resp.map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })
+}
+    #[derive(validator::Validate)]
+    #[allow(dead_code)]
+    struct PostsPostIdPublishPostBodyValidator<'a> {
+            #[validate(nested)]
+          body: &'a models::PostsPostIdPublishPostRequest,
+    }
+
+
+#[tracing::instrument(skip_all)]
+fn posts_post_id_publish_post_validation(
+  header_params: models::PostsPostIdPublishPostHeaderParams,
+  path_params: models::PostsPostIdPublishPostPathParams,
+        body: Option<models::PostsPostIdPublishPostRequest>,
+) -> std::result::Result<(
+  models::PostsPostIdPublishPostHeaderParams,
+  models::PostsPostIdPublishPostPathParams,
+        Option<models::PostsPostIdPublishPostRequest>,
+), ValidationErrors>
+{
+  header_params.validate()?;
+  path_params.validate()?;
+            if let Some(body) = &body {
+              let b = PostsPostIdPublishPostBodyValidator { body };
+              b.validate()?;
+            }
+
+Ok((
+  header_params,
+  path_params,
+    body,
+))
+}
+/// PostsPostIdPublishPost - POST /posts/{postId}/publish
+#[tracing::instrument(skip_all)]
+async fn posts_post_id_publish_post<I, A>(
+  method: Method,
+  host: Host,
+  cookies: CookieJar,
+  headers: HeaderMap,
+  Path(path_params): Path<models::PostsPostIdPublishPostPathParams>,
+ State(api_impl): State<I>,
+          Json(body): Json<Option<models::PostsPostIdPublishPostRequest>>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::posts::Posts,
+{
+    // Header parameters
+    let header_params = {
+                let header_authorization = headers.get(HeaderName::from_static("authorization"));
+
+                let header_authorization = match header_authorization {
+                    Some(v) => match header::IntoHeaderValue::<String>::try_from((*v).clone()) {
+                        Ok(result) =>
+                            Some(result.0),
+                        Err(err) => {
+                            return problem_response(Response::builder(), problem::header_problem("Authorization", &err))
+                                        .map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+
+                        },
+                    },
+                    None => {
+                        None
+                    }
+                };
+
+                let header_if_match = headers.get(HeaderName::from_static("if-match"));
+
+                let header_if_match = match header_if_match {
+                    Some(v) => match header::IntoHeaderValue::<String>::try_from((*v).clone()) {
+                        Ok(result) =>
+                            Some(result.0),
+                        Err(err) => {
+                            return problem_response(Response::builder(), problem::header_problem("If-Match", &err))
+                                        .map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+
+                        },
+                    },
+                    None => {
+                        None
+                    }
+                };
+
+       models::PostsPostIdPublishPostHeaderParams {
+          authorization: header_authorization,
+          if_match: header_if_match,
+       }
+  };
+
+
+      #[allow(clippy::redundant_closure)]
+      let validation = tokio::task::spawn_blocking(move ||
+    posts_post_id_publish_post_validation(
+        header_params,
+        path_params,
+          body,
+    )
+  ).await.unwrap();
+
+  let Ok((
+    header_params,
+    path_params,
+      body,
+  )) = validation else {
+    return problem_response(Response::builder(), problem::validation_problem(&validation.unwrap_err()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+  };
+
+  let result = api_impl.as_ref().posts_post_id_publish_post(
+      method,
+      host,
+      cookies,
+        header_params,
+        path_params,
+              body,
+  ).await;
+
+  let mut response = Response::builder();
+
+  let resp = match result {
+                                            Ok(rsp) => match rsp {
+                                                apis::posts::PostsPostIdPublishPostResponse::Status200_PostCreated
+                                                    {
+                                                        body,
+                                                        e_tag
+                                                    }
+                                                => {
+                                                    let e_tag = match header::IntoHeaderValue(e_tag).try_into() {
+                                                        Ok(val) => val,
+                                                        Err(e) => {
+                                                            return Response::builder()
+                                                                    .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                    .body(Body::from(format!("An internal server error occurred handling e_tag header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                        }
+                                                    };
+
+                                                    {
+                                                      let mut response_headers = response.headers_mut().unwrap();
+                                                      response_headers.insert(
+                                                          HeaderName::from_static("etag"),
+                                                          e_tag,
+                                                      );
+                                                    }
+                                                  let mut response = response.status(200);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::posts::PostsPostIdPublishPostResponse::Status401
+                                                    {
+                                                        www_authenticate
+                                                    }
+                                                => {
+                                                    let www_authenticate = match header::IntoHeaderValue(www_authenticate).try_into() {
+                                                        Ok(val) => val,
+                                                        Err(e) => {
+                                                            return Response::builder()
+                                                                    .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                    .body(Body::from(format!("An internal server error occurred handling www_authenticate header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                        }
+                                                    };
+
+                                                    {
+                                                      let mut response_headers = response.headers_mut().unwrap();
+                                                      response_headers.insert(
+                                                          HeaderName::from_static("www-authenticate"),
+                                                          www_authenticate,
+                                                      );
+                                                    }
+                                                  let mut response = response.status(401);
+                                                  response.body(Body::empty())
+                                                },
+                                                apis::posts::PostsPostIdPublishPostResponse::Status403
+                                                => {
+                                                  let mut response = response.status(403);
+                                                  response.body(Body::empty())
+                                                },
+                                                apis::posts::PostsPostIdPublishPostResponse::Status404
+                                                => {
+                                                  let mut response = response.status(404);
+                                                  response.body(Body::empty())
+                                                },
+                                                apis::posts::PostsPostIdPublishPostResponse::Status409
+                                                => {
+                                                  let mut response = response.status(409);
+                                                  response.body(Body::empty())
+                                                },
+                                                apis::posts::PostsPostIdPublishPostResponse::Status412
+                                                => {
+                                                  let mut response = response.status(412);
+                                                  response.body(Body::empty())
+                                                },
+                                            },
+                                            Err(e) => {
+                                                let problem = e.problem();
+                                                if problem.status >= 500 {
+                                                    error!(error = %e);
+                                                }
+                                                problem_response(response, problem)
+                                            },
+                                        };
+
+                                        resp.map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })
+}
+
+#[tracing::instrument(skip_all)]
+fn posts_post_id_purge_post_validation(
+  header_params: models::PostsPostIdPurgePostHeaderParams,
+  path_params: models::PostsPostIdPurgePostPathParams,
+) -> std::result::Result<(
+  models::PostsPostIdPurgePostHeaderParams,
+  models::PostsPostIdPurgePostPathParams,
+), ValidationErrors>
+{
+  header_params.validate()?;
+  path_params.validate()?;
+
+Ok((
+  header_params,
+  path_params,
+))
+}
+/// PostsPostIdPurgePost - POST /posts/{postId}/purge
+#[tracing::instrument(skip_all)]
+async fn posts_post_id_purge_post<I, A>(
+  method: Method,
+  host: Host,
+  cookies: CookieJar,
+  headers: HeaderMap,
+  Path(path_params): Path<models::PostsPostIdPurgePostPathParams>,
+ State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::posts::Posts,
+{
+    // Header parameters
+    let header_params = {
+                let header_authorization = headers.get(HeaderName::from_static("authorization"));
+
+                let header_authorization = match header_authorization {
+                    Some(v) => match header::IntoHeaderValue::<String>::try_from((*v).clone()) {
+                        Ok(result) =>
+                            Some(result.0),
+                        Err(err) => {
+                            return problem_response(Response::builder(), problem::header_problem("Authorization", &err))
+                                        .map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+
+                        },
+                    },
+                    None => {
+                        None
+                    }
+                };
+
+       models::PostsPostIdPurgePostHeaderParams {
+          authorization: header_authorization,
+       }
+  };
+
+
+      #[allow(clippy::redundant_closure)]
+      let validation = tokio::task::spawn_blocking(move ||
+    posts_post_id_purge_post_validation(
+        header_params,
+        path_params,
+    )
+  ).await.unwrap();
+
+  let Ok((
+    header_params,
+    path_params,
+  )) = validation else {
+    return problem_response(Response::builder(), problem::validation_problem(&validation.unwrap_err()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+  };
+
+  let result = api_impl.as_ref().posts_post_id_purge_post(
+      method,
+      host,
+      cookies,
+        header_params,
+        path_params,
+  ).await;
+
+  let mut response = Response::builder();
+
+  let resp = match result {
+                                            Ok(rsp) => match rsp {
+                                                apis::posts::PostsPostIdPurgePostResponse::Status204
+                                                => {
+                                                  let mut response = response.status(204);
+                                                  response.body(Body::empty())
+                                                },
+                                                apis::posts::PostsPostIdPurgePostResponse::Status401
+                                                    {
+                                                        www_authenticate
+                                                    }
+                                                => {
+                                                    let www_authenticate = match header::IntoHeaderValue(www_authenticate).try_into() {
+                                                        Ok(val) => val,
+                                                        Err(e) => {
+                                                            return Response::builder()
+                                                                    .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                    .body(Body::from(format!("An internal server error occurred handling www_authenticate header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                        }
+                                                    };
+
+                                                    {
+                                                      let mut response_headers = response.headers_mut().unwrap();
+                                                      response_headers.insert(
+                                                          HeaderName::from_static("www-authenticate"),
+                                                          www_authenticate,
+                                                      );
+                                                    }
+                                                  let mut response = response.status(401);
+                                                  response.body(Body::empty())
+                                                },
+                                                apis::posts::PostsPostIdPurgePostResponse::Status403
+                                                => {
+                                                  let mut response = response.status(403);
+                                                  response.body(Body::empty())
+                                                },
+                                                apis::posts::PostsPostIdPurgePostResponse::Status404
+                                                => {
+                                                  let mut response = response.status(404);
+                                                  response.body(Body::empty())
+                                                },
+                                            },
+                                            Err(e) => {
+                                                let problem = e.problem();
+                                                if problem.status >= 500 {
+                                                    error!(error = %e);
+                                                }
+                                                problem_response(response, problem)
+                                            },
+                                        };
+
+                                        resp.map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })
+}
+
+    #[derive(validator::Validate)]
+    #[allow(dead_code)]
+    struct PostsPostIdPutBodyValidator<'a> {
+            #[validate(nested)]
+          body: &'a models::Post,
+    }
+
+
+#[tracing::instrument(skip_all)]
+fn posts_post_id_put_validation(
+  header_params: models::PostsPostIdPutHeaderParams,
+  path_params: models::PostsPostIdPutPathParams,
+        body: Option<models::Post>,
+) -> std::result::Result<(
+  models::PostsPostIdPutHeaderParams,
+  models::PostsPostIdPutPathParams,
+        Option<models::Post>,
+), ValidationErrors>
+{
+  header_params.validate()?;
+  path_params.validate()?;
+            if let Some(body) = &body {
+              let b = PostsPostIdPutBodyValidator { body };
+              b.validate()?;
+            }
+
+Ok((
+  header_params,
+  path_params,
+    body,
+))
+}
+/// PostsPostIdPut - PUT /posts/{postId}
+#[tracing::instrument(skip_all)]
+async fn posts_post_id_put<I, A>(
+  method: Method,
+  host: Host,
+  cookies: CookieJar,
+  headers: HeaderMap,
+  Path(path_params): Path<models::PostsPostIdPutPathParams>,
+ State(api_impl): State<I>,
+          Json(body): Json<Option<models::Post>>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::posts::Posts,
+{
+    // Header parameters
+    let header_params = {
+                let header_authorization = headers.get(HeaderName::from_static("authorization"));
+
+                let header_authorization = match header_authorization {
+                    Some(v) => match header::IntoHeaderValue::<String>::try_from((*v).clone()) {
+                        Ok(result) =>
+                            Some(result.0),
+                        Err(err) => {
+                            return problem_response(Response::builder(), problem::header_problem("Authorization", &err))
+                                        .map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+
+                        },
+                    },
+                    None => {
+                        None
+                    }
+                };
+
+                let header_if_match = headers.get(HeaderName::from_static("if-match"));
+
+                let header_if_match = match header_if_match {
+                    Some(v) => match header::IntoHeaderValue::<String>::try_from((*v).clone()) {
+                        Ok(result) =>
+                            Some(result.0),
+                        Err(err) => {
+                            return problem_response(Response::builder(), problem::header_problem("If-Match", &err))
+                                        .map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+
+                        },
+                    },
+                    None => {
+                        None
+                    }
+                };
+
+       models::PostsPostIdPutHeaderParams {
+          authorization: header_authorization,
+          if_match: header_if_match,
+       }
+  };
+
+
+      #[allow(clippy::redundant_closure)]
+      let validation = tokio::task::spawn_blocking(move ||
+    posts_post_id_put_validation(
+        header_params,
+        path_params,
+          body,
+    )
+  ).await.unwrap();
+
+  let Ok((
+    header_params,
+    path_params,
+      body,
+  )) = validation else {
+    return problem_response(Response::builder(), problem::validation_problem(&validation.unwrap_err()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+  };
+
+  let result = api_impl.as_ref().posts_post_id_put(
+      method,
+      host,
+      cookies,
+        header_params,
+        path_params,
+              body,
+  ).await;
+
+  let mut response = Response::builder();
+
+  let resp = match result {
+                                            Ok(rsp) => match rsp {
+                                                apis::posts::PostsPostIdPutResponse::Status200_PostCreated
+                                                    {
+                                                        body,
+                                                        e_tag
+                                                    }
+                                                => {
+                                                    let e_tag = match header::IntoHeaderValue(e_tag).try_into() {
+                                                        Ok(val) => val,
+                                                        Err(e) => {
+                                                            return Response::builder()
+                                                                    .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                    .body(Body::from(format!("An internal server error occurred handling e_tag header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                        }
+                                                    };
+
+                                                    {
+                                                      let mut response_headers = response.headers_mut().unwrap();
+                                                      response_headers.insert(
+                                                          HeaderName::from_static("etag"),
+                                                          e_tag,
+                                                      );
+                                                    }
+                                                  let mut response = response.status(200);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::posts::PostsPostIdPutResponse::Status401
+                                                    {
+                                                        www_authenticate
+                                                    }
+                                                => {
+                                                    let www_authenticate = match header::IntoHeaderValue(www_authenticate).try_into() {
+                                                        Ok(val) => val,
+                                                        Err(e) => {
+                                                            return Response::builder()
+                                                                    .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                    .body(Body::from(format!("An internal server error occurred handling www_authenticate header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                        }
+                                                    };
+
+                                                    {
+                                                      let mut response_headers = response.headers_mut().unwrap();
+                                                      response_headers.insert(
+                                                          HeaderName::from_static("www-authenticate"),
+                                                          www_authenticate,
+                                                      );
+                                                    }
+                                                  let mut response = response.status(401);
+                                                  response.body(Body::empty())
+                                                },
+                                                apis::posts::PostsPostIdPutResponse::Status403
+                                                => {
+                                                  let mut response = response.status(403);
+                                                  response.body(Body::empty())
+                                                },
+                                                apis::posts::PostsPostIdPutResponse::Status404
+                                                => {
+                                                  let mut response = response.status(404);
+                                                  response.body(Body::empty())
+                                                },
+                                                apis::posts::PostsPostIdPutResponse::Status412
+                                                => {
+                                                  let mut response = response.status(412);
+                                                  response.body(Body::empty())
+                                                },
+                                            },
+                                            Err(e) => {
+                                                let problem = e.problem();
+                                                if problem.status >= 500 {
+                                                    error!(error = %e);
+                                                }
+                                                problem_response(response, problem)
+                                            },
+                                        };
+
+                                        resp.map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })
+}
+
+
+
+#[tracing::instrument(skip_all)]
+fn posts_post_id_replies_get_validation(
+  header_params: models::PostsPostIdRepliesGetHeaderParams,
+  path_params: models::PostsPostIdRepliesGetPathParams,
+  query_params: models::PostsPostIdRepliesGetQueryParams,
+) -> std::result::Result<(
+  models::PostsPostIdRepliesGetHeaderParams,
+  models::PostsPostIdRepliesGetPathParams,
+  models::PostsPostIdRepliesGetQueryParams,
+), ValidationErrors>
+{
+  header_params.validate()?;
+  path_params.validate()?;
+  query_params.validate()?;
+
+Ok((
+  header_params,
+  path_params,
+  query_params,
+))
+}
+/// PostsPostIdRepliesGet - GET /posts/{postId}/replies
+#[tracing::instrument(skip_all)]
+async fn posts_post_id_replies_get<I, A>(
+  method: Method,
+  host: Host,
+  cookies: CookieJar,
+  headers: HeaderMap,
+  Path(path_params): Path<models::PostsPostIdRepliesGetPathParams>,
+  Query(query_params): Query<models::PostsPostIdRepliesGetQueryParams>,
+ State(api_impl): State<I>,
+) -> Result<Response, StatusCode>
+where
+    I: AsRef<A> + Send + Sync,
+    A: apis::posts::Posts,
+{
+    // Header parameters
+    let header_params = {
+                let header_authorization = headers.get(HeaderName::from_static("authorization"));
+
+                let header_authorization = match header_authorization {
+                    Some(v) => match header::IntoHeaderValue::<String>::try_from((*v).clone()) {
+                        Ok(result) =>
+                            Some(result.0),
+                        Err(err) => {
+                            return problem_response(Response::builder(), problem::header_problem("Authorization", &err))
+                                        .map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+
+                        },
+                    },
+                    None => {
+                        None
+                    }
+                };
+
+       models::PostsPostIdRepliesGetHeaderParams {
+          authorization: header_authorization,
+       }
+  };
+
+
+      #[allow(clippy::redundant_closure)]
+      let validation = tokio::task::spawn_blocking(move ||
+    posts_post_id_replies_get_validation(
+        header_params,
+        path_params,
+        query_params,
+    )
+  ).await.unwrap();
+
+  let Ok((
+    header_params,
+    path_params,
+    query_params,
+  )) = validation else {
+    return problem_response(Response::builder(), problem::validation_problem(&validation.unwrap_err()))
+            .map_err(|_| StatusCode::BAD_REQUEST);
+  };
+
+  let result = api_impl.as_ref().posts_post_id_replies_get(
+      method,
+      host,
+      cookies,
+        header_params,
+        path_params,
+        query_params,
+  ).await;
+
+  let mut response = Response::builder();
+
+  let resp = match result {
+                                            Ok(rsp) => match rsp {
+                                                apis::posts::PostsPostIdRepliesGetResponse::Status200_ListOfPosts
+                                                    (body)
+                                                => {
+                                                  let mut response = response.status(200);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
+                                                },
+                                                apis::posts::PostsPostIdRepliesGetResponse::Status401
+                                                    {
+                                                        www_authenticate
+                                                    }
+                                                => {
+                                                    let www_authenticate = match header::IntoHeaderValue(www_authenticate).try_into() {
+                                                        Ok(val) => val,
+                                                        Err(e) => {
+                                                            return Response::builder()
+                                                                    .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                    .body(Body::from(format!("An internal server error occurred handling www_authenticate header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                        }
+                                                    };
+
+                                                    {
+                                                      let mut response_headers = response.headers_mut().unwrap();
+                                                      response_headers.insert(
+                                                          HeaderName::from_static("www-authenticate"),
+                                                          www_authenticate,
+                                                      );
+                                                    }
+                                                  let mut response = response.status(401);
+                                                  response.body(Body::empty())
+                                                },
+                                                apis::posts::PostsPostIdRepliesGetResponse::Status404
+                                                => {
+                                                  let mut response = response.status(404);
+                                                  response.body(Body::empty())
+                                                },
+                                            },
+                                            Err(e) => {
+                                                let problem = e.problem();
+                                                if problem.status >= 500 {
+                                                    error!(error = %e);
+                                                }
+                                                problem_response(response, problem)
+                                            },
+                                        };
+
+                                        resp.map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })
+}
 
     #[derive(validator::Validate)]
     #[allow(dead_code)]
-    struct AuthPostBodyValidator<'a> {
+    struct PostsPostIdRepliesPostBodyValidator<'a> {
             #[validate(nested)]
-          body: &'a models::Auth,
+          body: &'a models::PostsPostRequest,
     }
 
 
 #[tracing::instrument(skip_all)]
-fn auth_post_validation(
-        body: Option<models::Auth>,
+fn posts_post_id_replies_post_validation(
+  header_params: models::PostsPostIdRepliesPostHeaderParams,
+  path_params: models::PostsPostIdRepliesPostPathParams,
+        body: Option<models::PostsPostRequest>,
 ) -> std::result::Result<(
-        Option<models::Auth>,
+  models::PostsPostIdRepliesPostHeaderParams,
+  models::PostsPostIdRepliesPostPathParams,
+        Option<models::PostsPostRequest>,
 ), ValidationErrors>
 {
+  header_params.validate()?;
+  path_params.validate()?;
             if let Some(body) = &body {
-              let b = AuthPostBodyValidator { body };
+              let b = PostsPostIdRepliesPostBodyValidator { body };
               b.validate()?;
             }
 
 Ok((
+  header_params,
+  path_params,
     body,
 ))
 }
-/// AuthPost - POST /auth
+/// PostsPostIdRepliesPost - POST /posts/{postId}/replies
 #[tracing::instrument(skip_all)]
-async fn auth_post<I, A>(
+async fn posts_post_id_replies_post<I, A>(
   method: Method,
   host: Host,
   cookies: CookieJar,
+  headers: HeaderMap,
+  Path(path_params): Path<models::PostsPostIdRepliesPostPathParams>,
  State(api_impl): State<I>,
-          Json(body): Json<Option<models::Auth>>,
+          Json(body): Json<Option<models::PostsPostRequest>>,
 ) -> Result<Response, StatusCode>
 where
     I: AsRef<A> + Send + Sync,
-    A: apis::auth::Auth,
+    A: apis::posts::Posts,
 {
+    // Header parameters
+    let header_params = {
+                let header_authorization = headers.get(HeaderName::from_static("authorization"));
+
+                let header_authorization = match header_authorization {
+                    Some(v) => match header::IntoHeaderValue::<String>::try_from((*v).clone()) {
+                        Ok(result) =>
+                            Some(result.0),
+                        Err(err) => {
+                            return problem_response(Response::builder(), problem::header_problem("Authorization", &err))
+                                        .map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+
+                        },
+                    },
+                    None => {
+                        None
+                    }
+                };
+
+       models::PostsPostIdRepliesPostHeaderParams {
+          authorization: header_authorization,
+       }
+  };
+
 
       #[allow(clippy::redundant_closure)]
       let validation = tokio::task::spawn_blocking(move ||
-    auth_post_validation(
+    posts_post_id_replies_post_validation(
+        header_params,
+        path_params,
           body,
     )
   ).await.unwrap();
 
   let Ok((
+    header_params,
+    path_params,
       body,
   )) = validation else {
-    return Response::builder()
-            .status(StatusCode::BAD_REQUEST)
-            .body(Body::from(validation.unwrap_err().to_string()))
+    return problem_response(Response::builder(), problem::validation_problem(&validation.unwrap_err()))
             .map_err(|_| StatusCode::BAD_REQUEST);
   };
 
-  let result = api_impl.as_ref().auth_post(
+  let result = api_impl.as_ref().posts_post_id_replies_post(
       method,
       host,
       cookies,
+        header_params,
+        path_params,
               body,
   ).await;
 
@@ -101,10 +5184,10 @@ where
 
   let resp = match result {
                                             Ok(rsp) => match rsp {
-                                                apis::auth::AuthPostResponse::Status200_AuthenticationSuccessful
+                                                apis::posts::PostsPostIdRepliesPostResponse::Status201_PostCreated
                                                     (body)
                                                 => {
-                                                  let mut response = response.status(200);
+                                                  let mut response = response.status(201);
                                                   {
                                                     let mut response_headers = response.headers_mut().unwrap();
                                                     response_headers.insert(
@@ -119,43 +5202,77 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::auth::AuthPostResponse::Status400
+                                                apis::posts::PostsPostIdRepliesPostResponse::Status401
+                                                    {
+                                                        www_authenticate
+                                                    }
+                                                => {
+                                                    let www_authenticate = match header::IntoHeaderValue(www_authenticate).try_into() {
+                                                        Ok(val) => val,
+                                                        Err(e) => {
+                                                            return Response::builder()
+                                                                    .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                    .body(Body::from(format!("An internal server error occurred handling www_authenticate header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                        }
+                                                    };
+
+                                                    {
+                                                      let mut response_headers = response.headers_mut().unwrap();
+                                                      response_headers.insert(
+                                                          HeaderName::from_static("www-authenticate"),
+                                                          www_authenticate,
+                                                      );
+                                                    }
+                                                  let mut response = response.status(401);
+                                                  response.body(Body::empty())
+                                                },
+                                                apis::posts::PostsPostIdRepliesPostResponse::Status403
+                                                => {
+                                                  let mut response = response.status(403);
+                                                  response.body(Body::empty())
+                                                },
+                                                apis::posts::PostsPostIdRepliesPostResponse::Status404
                                                 => {
-                                                  let mut response = response.status(400);
+                                                  let mut response = response.status(404);
                                                   response.body(Body::empty())
                                                 },
                                             },
-                                            Err(_) => {
-                                                // Application code returned an error. This should not happen, as the implementation should
-                                                // return a valid response.
-                                                response.status(500).body(Body::empty())
+                                            Err(e) => {
+                                                let problem = e.problem();
+                                                if problem.status >= 500 {
+                                                    error!(error = %e);
+                                                }
+                                                problem_response(response, problem)
                                             },
                                         };
 
                                         resp.map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })
 }
-
-
 #[tracing::instrument(skip_all)]
-fn posts_get_validation(
-  header_params: models::PostsGetHeaderParams,
+fn posts_post_id_restore_post_validation(
+  header_params: models::PostsPostIdRestorePostHeaderParams,
+  path_params: models::PostsPostIdRestorePostPathParams,
 ) -> std::result::Result<(
-  models::PostsGetHeaderParams,
+  models::PostsPostIdRestorePostHeaderParams,
+  models::PostsPostIdRestorePostPathParams,
 ), ValidationErrors>
 {
   header_params.validate()?;
+  path_params.validate()?;
 
 Ok((
   header_params,
+  path_params,
 ))
 }
-/// PostsGet - GET /posts
+/// PostsPostIdRestorePost - POST /posts/{postId}/restore
 #[tracing::instrument(skip_all)]
-async fn posts_get<I, A>(
+async fn posts_post_id_restore_post<I, A>(
   method: Method,
   host: Host,
   cookies: CookieJar,
   headers: HeaderMap,
+  Path(path_params): Path<models::PostsPostIdRestorePostPathParams>,
  State(api_impl): State<I>,
 ) -> Result<Response, StatusCode>
 where
@@ -169,22 +5286,19 @@ where
                 let header_authorization = match header_authorization {
                     Some(v) => match header::IntoHeaderValue::<String>::try_from((*v).clone()) {
                         Ok(result) =>
-                            result.0,
+                            Some(result.0),
                         Err(err) => {
-                            return Response::builder()
-                                        .status(StatusCode::BAD_REQUEST)
-                                        .body(Body::from(format!("Invalid header Authorization - {}", err))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                            return problem_response(Response::builder(), problem::header_problem("Authorization", &err))
+                                        .map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
 
                         },
                     },
                     None => {
-                        return Response::builder()
-                                        .status(StatusCode::BAD_REQUEST)
-                                        .body(Body::from("Missing required header Authorization")).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                        None
                     }
                 };
 
-       models::PostsGetHeaderParams {
+       models::PostsPostIdRestorePostHeaderParams {
           authorization: header_authorization,
        }
   };
@@ -192,34 +5306,54 @@ where
 
       #[allow(clippy::redundant_closure)]
       let validation = tokio::task::spawn_blocking(move ||
-    posts_get_validation(
+    posts_post_id_restore_post_validation(
         header_params,
+        path_params,
     )
   ).await.unwrap();
 
   let Ok((
     header_params,
+    path_params,
   )) = validation else {
-    return Response::builder()
-            .status(StatusCode::BAD_REQUEST)
-            .body(Body::from(validation.unwrap_err().to_string()))
+    return problem_response(Response::builder(), problem::validation_problem(&validation.unwrap_err()))
             .map_err(|_| StatusCode::BAD_REQUEST);
   };
 
-  let result = api_impl.as_ref().posts_get(
+  let result = api_impl.as_ref().posts_post_id_restore_post(
       method,
       host,
       cookies,
         header_params,
+        path_params,
   ).await;
 
   let mut response = Response::builder();
 
   let resp = match result {
                                             Ok(rsp) => match rsp {
-                                                apis::posts::PostsGetResponse::Status200_ListOfPosts
-                                                    (body)
+                                                apis::posts::PostsPostIdRestorePostResponse::Status200_PostCreated
+                                                    {
+                                                        body,
+                                                        e_tag
+                                                    }
                                                 => {
+                                                    let e_tag = match header::IntoHeaderValue(e_tag).try_into() {
+                                                        Ok(val) => val,
+                                                        Err(e) => {
+                                                            return Response::builder()
+                                                                    .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                    .body(Body::from(format!("An internal server error occurred handling e_tag header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                        }
+                                                    };
+
+                                                    {
+                                                      let mut response_headers = response.headers_mut().unwrap();
+                                                      response_headers.insert(
+                                                          HeaderName::from_static("etag"),
+                                                          e_tag,
+                                                      );
+                                                    }
                                                   let mut response = response.status(200);
                                                   {
                                                     let mut response_headers = response.headers_mut().unwrap();
@@ -235,64 +5369,84 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::posts::PostsGetResponse::Status400
+                                                apis::posts::PostsPostIdRestorePostResponse::Status401
+                                                    {
+                                                        www_authenticate
+                                                    }
+                                                => {
+                                                    let www_authenticate = match header::IntoHeaderValue(www_authenticate).try_into() {
+                                                        Ok(val) => val,
+                                                        Err(e) => {
+                                                            return Response::builder()
+                                                                    .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                    .body(Body::from(format!("An internal server error occurred handling www_authenticate header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                        }
+                                                    };
+
+                                                    {
+                                                      let mut response_headers = response.headers_mut().unwrap();
+                                                      response_headers.insert(
+                                                          HeaderName::from_static("www-authenticate"),
+                                                          www_authenticate,
+                                                      );
+                                                    }
+                                                  let mut response = response.status(401);
+                                                  response.body(Body::empty())
+                                                },
+                                                apis::posts::PostsPostIdRestorePostResponse::Status403
                                                 => {
-                                                  let mut response = response.status(400);
+                                                  let mut response = response.status(403);
                                                   response.body(Body::empty())
                                                 },
-                                                apis::posts::PostsGetResponse::Status401
+                                                apis::posts::PostsPostIdRestorePostResponse::Status404
                                                 => {
-                                                  let mut response = response.status(401);
+                                                  let mut response = response.status(404);
                                                   response.body(Body::empty())
                                                 },
                                             },
-                                            Err(_) => {
-                                                // Application code returned an error. This should not happen, as the implementation should
-                                                // return a valid response.
-                                                response.status(500).body(Body::empty())
+                                            Err(e) => {
+                                                let problem = e.problem();
+                                                if problem.status >= 500 {
+                                                    error!(error = %e);
+                                                }
+                                                problem_response(response, problem)
                                             },
                                         };
 
                                         resp.map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })
 }
 
-    #[derive(validator::Validate)]
-    #[allow(dead_code)]
-    struct PostsPostBodyValidator<'a> {
-            #[validate(nested)]
-          body: &'a models::PostsPostRequest,
-    }
-
-
 #[tracing::instrument(skip_all)]
-fn posts_post_validation(
-  header_params: models::PostsPostHeaderParams,
-        body: Option<models::PostsPostRequest>,
+fn posts_post_id_revisions_get_validation(
+  header_params: models::PostsPostIdRevisionsGetHeaderParams,
+  path_params: models::PostsPostIdRevisionsGetPathParams,
+  query_params: models::PostsPostIdRevisionsGetQueryParams,
 ) -> std::result::Result<(
-  models::PostsPostHeaderParams,
-        Option<models::PostsPostRequest>,
+  models::PostsPostIdRevisionsGetHeaderParams,
+  models::PostsPostIdRevisionsGetPathParams,
+  models::PostsPostIdRevisionsGetQueryParams,
 ), ValidationErrors>
 {
   header_params.validate()?;
-            if let Some(body) = &body {
-              let b = PostsPostBodyValidator { body };
-              b.validate()?;
-            }
+  path_params.validate()?;
+  query_params.validate()?;
 
 Ok((
   header_params,
-    body,
+  path_params,
+  query_params,
 ))
 }
-/// PostsPost - POST /posts
+/// PostsPostIdRevisionsGet - GET /posts/{postId}/revisions
 #[tracing::instrument(skip_all)]
-async fn posts_post<I, A>(
+async fn posts_post_id_revisions_get<I, A>(
   method: Method,
   host: Host,
   cookies: CookieJar,
   headers: HeaderMap,
+  Path(path_params): Path<models::PostsPostIdRevisionsGetPathParams>,
+  Query(query_params): Query<models::PostsPostIdRevisionsGetQueryParams>,
  State(api_impl): State<I>,
-          Json(body): Json<Option<models::PostsPostRequest>>,
 ) -> Result<Response, StatusCode>
 where
     I: AsRef<A> + Send + Sync,
@@ -305,22 +5459,19 @@ where
                 let header_authorization = match header_authorization {
                     Some(v) => match header::IntoHeaderValue::<String>::try_from((*v).clone()) {
                         Ok(result) =>
-                            result.0,
+                            Some(result.0),
                         Err(err) => {
-                            return Response::builder()
-                                        .status(StatusCode::BAD_REQUEST)
-                                        .body(Body::from(format!("Invalid header Authorization - {}", err))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                            return problem_response(Response::builder(), problem::header_problem("Authorization", &err))
+                                        .map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
 
                         },
                     },
                     None => {
-                        return Response::builder()
-                                        .status(StatusCode::BAD_REQUEST)
-                                        .body(Body::from("Missing required header Authorization")).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                        None
                     }
                 };
 
-       models::PostsPostHeaderParams {
+       models::PostsPostIdRevisionsGetHeaderParams {
           authorization: header_authorization,
        }
   };
@@ -328,38 +5479,39 @@ where
 
       #[allow(clippy::redundant_closure)]
       let validation = tokio::task::spawn_blocking(move ||
-    posts_post_validation(
+    posts_post_id_revisions_get_validation(
         header_params,
-          body,
+        path_params,
+        query_params,
     )
   ).await.unwrap();
 
   let Ok((
     header_params,
-      body,
+    path_params,
+    query_params,
   )) = validation else {
-    return Response::builder()
-            .status(StatusCode::BAD_REQUEST)
-            .body(Body::from(validation.unwrap_err().to_string()))
+    return problem_response(Response::builder(), problem::validation_problem(&validation.unwrap_err()))
             .map_err(|_| StatusCode::BAD_REQUEST);
   };
 
-  let result = api_impl.as_ref().posts_post(
+  let result = api_impl.as_ref().posts_post_id_revisions_get(
       method,
       host,
       cookies,
         header_params,
-              body,
+        path_params,
+        query_params,
   ).await;
 
   let mut response = Response::builder();
 
   let resp = match result {
                                             Ok(rsp) => match rsp {
-                                                apis::posts::PostsPostResponse::Status201_PostCreated
+                                                apis::posts::PostsPostIdRevisionsGetResponse::Status200
                                                     (body)
                                                 => {
-                                                  let mut response = response.status(201);
+                                                  let mut response = response.status(200);
                                                   {
                                                     let mut response_headers = response.headers_mut().unwrap();
                                                     response_headers.insert(
@@ -374,21 +5526,42 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::posts::PostsPostResponse::Status400
+                                                apis::posts::PostsPostIdRevisionsGetResponse::Status401
+                                                    {
+                                                        www_authenticate
+                                                    }
                                                 => {
-                                                  let mut response = response.status(400);
+                                                    let www_authenticate = match header::IntoHeaderValue(www_authenticate).try_into() {
+                                                        Ok(val) => val,
+                                                        Err(e) => {
+                                                            return Response::builder()
+                                                                    .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                    .body(Body::from(format!("An internal server error occurred handling www_authenticate header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                        }
+                                                    };
+
+                                                    {
+                                                      let mut response_headers = response.headers_mut().unwrap();
+                                                      response_headers.insert(
+                                                          HeaderName::from_static("www-authenticate"),
+                                                          www_authenticate,
+                                                      );
+                                                    }
+                                                  let mut response = response.status(401);
                                                   response.body(Body::empty())
                                                 },
-                                                apis::posts::PostsPostResponse::Status401
+                                                apis::posts::PostsPostIdRevisionsGetResponse::Status404
                                                 => {
-                                                  let mut response = response.status(401);
+                                                  let mut response = response.status(404);
                                                   response.body(Body::empty())
                                                 },
                                             },
-                                            Err(_) => {
-                                                // Application code returned an error. This should not happen, as the implementation should
-                                                // return a valid response.
-                                                response.status(500).body(Body::empty())
+                                            Err(e) => {
+                                                let problem = e.problem();
+                                                if problem.status >= 500 {
+                                                    error!(error = %e);
+                                                }
+                                                problem_response(response, problem)
                                             },
                                         };
 
@@ -397,12 +5570,12 @@ where
 
 
 #[tracing::instrument(skip_all)]
-fn posts_post_id_delete_validation(
-  header_params: models::PostsPostIdDeleteHeaderParams,
-  path_params: models::PostsPostIdDeletePathParams,
+fn posts_post_id_revisions_number_get_validation(
+  header_params: models::PostsPostIdRevisionsNumberGetHeaderParams,
+  path_params: models::PostsPostIdRevisionsNumberGetPathParams,
 ) -> std::result::Result<(
-  models::PostsPostIdDeleteHeaderParams,
-  models::PostsPostIdDeletePathParams,
+  models::PostsPostIdRevisionsNumberGetHeaderParams,
+  models::PostsPostIdRevisionsNumberGetPathParams,
 ), ValidationErrors>
 {
   header_params.validate()?;
@@ -413,14 +5586,14 @@ Ok((
   path_params,
 ))
 }
-/// PostsPostIdDelete - DELETE /posts/{postId}
+/// PostsPostIdRevisionsNumberGet - GET /posts/{postId}/revisions/{number}
 #[tracing::instrument(skip_all)]
-async fn posts_post_id_delete<I, A>(
+async fn posts_post_id_revisions_number_get<I, A>(
   method: Method,
   host: Host,
   cookies: CookieJar,
   headers: HeaderMap,
-  Path(path_params): Path<models::PostsPostIdDeletePathParams>,
+  Path(path_params): Path<models::PostsPostIdRevisionsNumberGetPathParams>,
  State(api_impl): State<I>,
 ) -> Result<Response, StatusCode>
 where
@@ -434,22 +5607,19 @@ where
                 let header_authorization = match header_authorization {
                     Some(v) => match header::IntoHeaderValue::<String>::try_from((*v).clone()) {
                         Ok(result) =>
-                            result.0,
+                            Some(result.0),
                         Err(err) => {
-                            return Response::builder()
-                                        .status(StatusCode::BAD_REQUEST)
-                                        .body(Body::from(format!("Invalid header Authorization - {}", err))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                            return problem_response(Response::builder(), problem::header_problem("Authorization", &err))
+                                        .map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
 
                         },
                     },
                     None => {
-                        return Response::builder()
-                                        .status(StatusCode::BAD_REQUEST)
-                                        .body(Body::from("Missing required header Authorization")).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                        None
                     }
                 };
 
-       models::PostsPostIdDeleteHeaderParams {
+       models::PostsPostIdRevisionsNumberGetHeaderParams {
           authorization: header_authorization,
        }
   };
@@ -457,7 +5627,7 @@ where
 
       #[allow(clippy::redundant_closure)]
       let validation = tokio::task::spawn_blocking(move ||
-    posts_post_id_delete_validation(
+    posts_post_id_revisions_number_get_validation(
         header_params,
         path_params,
     )
@@ -467,13 +5637,11 @@ where
     header_params,
     path_params,
   )) = validation else {
-    return Response::builder()
-            .status(StatusCode::BAD_REQUEST)
-            .body(Body::from(validation.unwrap_err().to_string()))
+    return problem_response(Response::builder(), problem::validation_problem(&validation.unwrap_err()))
             .map_err(|_| StatusCode::BAD_REQUEST);
   };
 
-  let result = api_impl.as_ref().posts_post_id_delete(
+  let result = api_impl.as_ref().posts_post_id_revisions_number_get(
       method,
       host,
       cookies,
@@ -485,31 +5653,60 @@ where
 
   let resp = match result {
                                             Ok(rsp) => match rsp {
-                                                apis::posts::PostsPostIdDeleteResponse::Status204_NoContent
-                                                => {
-                                                  let mut response = response.status(204);
-                                                  response.body(Body::empty())
-                                                },
-                                                apis::posts::PostsPostIdDeleteResponse::Status400_BadRequest
+                                                apis::posts::PostsPostIdRevisionsNumberGetResponse::Status200
+                                                    (body)
                                                 => {
-                                                  let mut response = response.status(400);
-                                                  response.body(Body::empty())
+                                                  let mut response = response.status(200);
+                                                  {
+                                                    let mut response_headers = response.headers_mut().unwrap();
+                                                    response_headers.insert(
+                                                        CONTENT_TYPE,
+                                                        HeaderValue::from_str("application/json").map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })?);
+                                                  }
+
+                                                  let body_content =  tokio::task::spawn_blocking(move ||
+                                                      serde_json::to_vec(&body).map_err(|e| {
+                                                        error!(error = ?e);
+                                                        StatusCode::INTERNAL_SERVER_ERROR
+                                                      })).await.unwrap()?;
+                                                  response.body(Body::from(body_content))
                                                 },
-                                                apis::posts::PostsPostIdDeleteResponse::Status401_Unauthorized
+                                                apis::posts::PostsPostIdRevisionsNumberGetResponse::Status401
+                                                    {
+                                                        www_authenticate
+                                                    }
                                                 => {
+                                                    let www_authenticate = match header::IntoHeaderValue(www_authenticate).try_into() {
+                                                        Ok(val) => val,
+                                                        Err(e) => {
+                                                            return Response::builder()
+                                                                    .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                    .body(Body::from(format!("An internal server error occurred handling www_authenticate header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                        }
+                                                    };
+
+                                                    {
+                                                      let mut response_headers = response.headers_mut().unwrap();
+                                                      response_headers.insert(
+                                                          HeaderName::from_static("www-authenticate"),
+                                                          www_authenticate,
+                                                      );
+                                                    }
                                                   let mut response = response.status(401);
                                                   response.body(Body::empty())
                                                 },
-                                                apis::posts::PostsPostIdDeleteResponse::Status404_NotFound
+                                                apis::posts::PostsPostIdRevisionsNumberGetResponse::Status404
                                                 => {
                                                   let mut response = response.status(404);
                                                   response.body(Body::empty())
                                                 },
                                             },
-                                            Err(_) => {
-                                                // Application code returned an error. This should not happen, as the implementation should
-                                                // return a valid response.
-                                                response.status(500).body(Body::empty())
+                                            Err(e) => {
+                                                let problem = e.problem();
+                                                if problem.status >= 500 {
+                                                    error!(error = %e);
+                                                }
+                                                problem_response(response, problem)
                                             },
                                         };
 
@@ -518,12 +5715,12 @@ where
 
 
 #[tracing::instrument(skip_all)]
-fn posts_post_id_get_validation(
-  header_params: models::PostsPostIdGetHeaderParams,
-  path_params: models::PostsPostIdGetPathParams,
+fn posts_post_id_revisions_number_revert_post_validation(
+  header_params: models::PostsPostIdRevisionsNumberRevertPostHeaderParams,
+  path_params: models::PostsPostIdRevisionsNumberRevertPostPathParams,
 ) -> std::result::Result<(
-  models::PostsPostIdGetHeaderParams,
-  models::PostsPostIdGetPathParams,
+  models::PostsPostIdRevisionsNumberRevertPostHeaderParams,
+  models::PostsPostIdRevisionsNumberRevertPostPathParams,
 ), ValidationErrors>
 {
   header_params.validate()?;
@@ -534,14 +5731,14 @@ Ok((
   path_params,
 ))
 }
-/// PostsPostIdGet - GET /posts/{postId}
+/// PostsPostIdRevisionsNumberRevertPost - POST /posts/{postId}/revisions/{number}/revert
 #[tracing::instrument(skip_all)]
-async fn posts_post_id_get<I, A>(
+async fn posts_post_id_revisions_number_revert_post<I, A>(
   method: Method,
   host: Host,
   cookies: CookieJar,
   headers: HeaderMap,
-  Path(path_params): Path<models::PostsPostIdGetPathParams>,
+  Path(path_params): Path<models::PostsPostIdRevisionsNumberRevertPostPathParams>,
  State(api_impl): State<I>,
 ) -> Result<Response, StatusCode>
 where
@@ -555,30 +5752,45 @@ where
                 let header_authorization = match header_authorization {
                     Some(v) => match header::IntoHeaderValue::<String>::try_from((*v).clone()) {
                         Ok(result) =>
-                            result.0,
+                            Some(result.0),
                         Err(err) => {
-                            return Response::builder()
-                                        .status(StatusCode::BAD_REQUEST)
-                                        .body(Body::from(format!("Invalid header Authorization - {}", err))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                            return problem_response(Response::builder(), problem::header_problem("Authorization", &err))
+                                        .map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
 
                         },
                     },
                     None => {
-                        return Response::builder()
-                                        .status(StatusCode::BAD_REQUEST)
-                                        .body(Body::from("Missing required header Authorization")).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                        None
                     }
                 };
 
-       models::PostsPostIdGetHeaderParams {
+                let header_if_match = headers.get(HeaderName::from_static("if-match"));
+
+                let header_if_match = match header_if_match {
+                    Some(v) => match header::IntoHeaderValue::<String>::try_from((*v).clone()) {
+                        Ok(result) =>
+                            Some(result.0),
+                        Err(err) => {
+                            return problem_response(Response::builder(), problem::header_problem("If-Match", &err))
+                                        .map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+
+                        },
+                    },
+                    None => {
+                        None
+                    }
+                };
+
+       models::PostsPostIdRevisionsNumberRevertPostHeaderParams {
           authorization: header_authorization,
+          if_match: header_if_match,
        }
   };
 
 
       #[allow(clippy::redundant_closure)]
       let validation = tokio::task::spawn_blocking(move ||
-    posts_post_id_get_validation(
+    posts_post_id_revisions_number_revert_post_validation(
         header_params,
         path_params,
     )
@@ -588,13 +5800,11 @@ where
     header_params,
     path_params,
   )) = validation else {
-    return Response::builder()
-            .status(StatusCode::BAD_REQUEST)
-            .body(Body::from(validation.unwrap_err().to_string()))
+    return problem_response(Response::builder(), problem::validation_problem(&validation.unwrap_err()))
             .map_err(|_| StatusCode::BAD_REQUEST);
   };
 
-  let result = api_impl.as_ref().posts_post_id_get(
+  let result = api_impl.as_ref().posts_post_id_revisions_number_revert_post(
       method,
       host,
       cookies,
@@ -606,7 +5816,7 @@ where
 
   let resp = match result {
                                             Ok(rsp) => match rsp {
-                                                apis::posts::PostsPostIdGetResponse::Status200_PostCreated
+                                                apis::posts::PostsPostIdRevisionsNumberRevertPostResponse::Status200_PostCreated
                                                     (body)
                                                 => {
                                                   let mut response = response.status(200);
@@ -624,74 +5834,84 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::posts::PostsPostIdGetResponse::Status400
+                                                apis::posts::PostsPostIdRevisionsNumberRevertPostResponse::Status401
+                                                    {
+                                                        www_authenticate
+                                                    }
                                                 => {
-                                                  let mut response = response.status(400);
+                                                    let www_authenticate = match header::IntoHeaderValue(www_authenticate).try_into() {
+                                                        Ok(val) => val,
+                                                        Err(e) => {
+                                                            return Response::builder()
+                                                                    .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                    .body(Body::from(format!("An internal server error occurred handling www_authenticate header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                        }
+                                                    };
+
+                                                    {
+                                                      let mut response_headers = response.headers_mut().unwrap();
+                                                      response_headers.insert(
+                                                          HeaderName::from_static("www-authenticate"),
+                                                          www_authenticate,
+                                                      );
+                                                    }
+                                                  let mut response = response.status(401);
                                                   response.body(Body::empty())
                                                 },
-                                                apis::posts::PostsPostIdGetResponse::Status401
+                                                apis::posts::PostsPostIdRevisionsNumberRevertPostResponse::Status403
                                                 => {
-                                                  let mut response = response.status(401);
+                                                  let mut response = response.status(403);
                                                   response.body(Body::empty())
                                                 },
-                                                apis::posts::PostsPostIdGetResponse::Status404
+                                                apis::posts::PostsPostIdRevisionsNumberRevertPostResponse::Status404
                                                 => {
                                                   let mut response = response.status(404);
                                                   response.body(Body::empty())
                                                 },
+                                                apis::posts::PostsPostIdRevisionsNumberRevertPostResponse::Status412
+                                                => {
+                                                  let mut response = response.status(412);
+                                                  response.body(Body::empty())
+                                                },
                                             },
-                                            Err(_) => {
-                                                // Application code returned an error. This should not happen, as the implementation should
-                                                // return a valid response.
-                                                response.status(500).body(Body::empty())
+                                            Err(e) => {
+                                                let problem = e.problem();
+                                                if problem.status >= 500 {
+                                                    error!(error = %e);
+                                                }
+                                                problem_response(response, problem)
                                             },
                                         };
 
                                         resp.map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })
 }
 
-    #[derive(validator::Validate)]
-    #[allow(dead_code)]
-    struct PostsPostIdPutBodyValidator<'a> {
-            #[validate(nested)]
-          body: &'a models::Post,
-    }
-
-
 #[tracing::instrument(skip_all)]
-fn posts_post_id_put_validation(
-  header_params: models::PostsPostIdPutHeaderParams,
-  path_params: models::PostsPostIdPutPathParams,
-        body: Option<models::Post>,
+fn posts_search_get_validation(
+  header_params: models::PostsSearchGetHeaderParams,
+  query_params: models::PostsSearchGetQueryParams,
 ) -> std::result::Result<(
-  models::PostsPostIdPutHeaderParams,
-  models::PostsPostIdPutPathParams,
-        Option<models::Post>,
+  models::PostsSearchGetHeaderParams,
+  models::PostsSearchGetQueryParams,
 ), ValidationErrors>
 {
   header_params.validate()?;
-  path_params.validate()?;
-            if let Some(body) = &body {
-              let b = PostsPostIdPutBodyValidator { body };
-              b.validate()?;
-            }
+  query_params.validate()?;
 
 Ok((
   header_params,
-  path_params,
-    body,
+  query_params,
 ))
 }
-/// PostsPostIdPut - PUT /posts/{postId}
+/// PostsSearchGet - GET /posts/search
 #[tracing::instrument(skip_all)]
-async fn posts_post_id_put<I, A>(
+async fn posts_search_get<I, A>(
   method: Method,
   host: Host,
   cookies: CookieJar,
   headers: HeaderMap,
-  Path(path_params): Path<models::PostsPostIdPutPathParams>,
+  Query(query_params): Query<models::PostsSearchGetQueryParams>,
  State(api_impl): State<I>,
-          Json(body): Json<Option<models::Post>>,
 ) -> Result<Response, StatusCode>
 where
     I: AsRef<A> + Send + Sync,
@@ -704,22 +5924,19 @@ where
                 let header_authorization = match header_authorization {
                     Some(v) => match header::IntoHeaderValue::<String>::try_from((*v).clone()) {
                         Ok(result) =>
-                            result.0,
+                            Some(result.0),
                         Err(err) => {
-                            return Response::builder()
-                                        .status(StatusCode::BAD_REQUEST)
-                                        .body(Body::from(format!("Invalid header Authorization - {}", err))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                            return problem_response(Response::builder(), problem::header_problem("Authorization", &err))
+                                        .map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
 
                         },
                     },
                     None => {
-                        return Response::builder()
-                                        .status(StatusCode::BAD_REQUEST)
-                                        .body(Body::from("Missing required header Authorization")).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                        None
                     }
                 };
 
-       models::PostsPostIdPutHeaderParams {
+       models::PostsSearchGetHeaderParams {
           authorization: header_authorization,
        }
   };
@@ -727,38 +5944,33 @@ where
 
       #[allow(clippy::redundant_closure)]
       let validation = tokio::task::spawn_blocking(move ||
-    posts_post_id_put_validation(
+    posts_search_get_validation(
         header_params,
-        path_params,
-          body,
+        query_params,
     )
   ).await.unwrap();
 
   let Ok((
     header_params,
-    path_params,
-      body,
+    query_params,
   )) = validation else {
-    return Response::builder()
-            .status(StatusCode::BAD_REQUEST)
-            .body(Body::from(validation.unwrap_err().to_string()))
+    return problem_response(Response::builder(), problem::validation_problem(&validation.unwrap_err()))
             .map_err(|_| StatusCode::BAD_REQUEST);
   };
 
-  let result = api_impl.as_ref().posts_post_id_put(
+  let result = api_impl.as_ref().posts_search_get(
       method,
       host,
       cookies,
         header_params,
-        path_params,
-              body,
+        query_params,
   ).await;
 
   let mut response = Response::builder();
 
   let resp = match result {
                                             Ok(rsp) => match rsp {
-                                                apis::posts::PostsPostIdPutResponse::Status200_PostCreated
+                                                apis::posts::PostsSearchGetResponse::Status200
                                                     (body)
                                                 => {
                                                   let mut response = response.status(200);
@@ -776,26 +5988,37 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::posts::PostsPostIdPutResponse::Status400
-                                                => {
-                                                  let mut response = response.status(400);
-                                                  response.body(Body::empty())
-                                                },
-                                                apis::posts::PostsPostIdPutResponse::Status401
+                                                apis::posts::PostsSearchGetResponse::Status401
+                                                    {
+                                                        www_authenticate
+                                                    }
                                                 => {
+                                                    let www_authenticate = match header::IntoHeaderValue(www_authenticate).try_into() {
+                                                        Ok(val) => val,
+                                                        Err(e) => {
+                                                            return Response::builder()
+                                                                    .status(StatusCode::INTERNAL_SERVER_ERROR)
+                                                                    .body(Body::from(format!("An internal server error occurred handling www_authenticate header - {}", e))).map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR });
+                                                        }
+                                                    };
+
+                                                    {
+                                                      let mut response_headers = response.headers_mut().unwrap();
+                                                      response_headers.insert(
+                                                          HeaderName::from_static("www-authenticate"),
+                                                          www_authenticate,
+                                                      );
+                                                    }
                                                   let mut response = response.status(401);
                                                   response.body(Body::empty())
                                                 },
-                                                apis::posts::PostsPostIdPutResponse::Status404
-                                                => {
-                                                  let mut response = response.status(404);
-                                                  response.body(Body::empty())
-                                                },
                                             },
-                                            Err(_) => {
-                                                // Application code returned an error. This should not happen, as the implementation should
-                                                // return a valid response.
-                                                response.status(500).body(Body::empty())
+                                            Err(e) => {
+                                                let problem = e.problem();
+                                                if problem.status >= 500 {
+                                                    error!(error = %e);
+                                                }
+                                                problem_response(response, problem)
                                             },
                                         };
 
@@ -850,9 +6073,7 @@ where
   let Ok((
       body,
   )) = validation else {
-    return Response::builder()
-            .status(StatusCode::BAD_REQUEST)
-            .body(Body::from(validation.unwrap_err().to_string()))
+    return problem_response(Response::builder(), problem::validation_problem(&validation.unwrap_err()))
             .map_err(|_| StatusCode::BAD_REQUEST);
   };
 
@@ -885,19 +6106,29 @@ where
                                                       })).await.unwrap()?;
                                                   response.body(Body::from(body_content))
                                                 },
-                                                apis::users::UsersPostResponse::Status400
+                                                apis::users::UsersPostResponse::Status409
                                                 => {
-                                                  let mut response = response.status(400);
+                                                  let mut response = response.status(409);
                                                   response.body(Body::empty())
                                                 },
                                             },
-                                            Err(_) => {
-                                                // Application code returned an error. This should not happen, as the implementation should
-                                                // return a valid response.
-                                                response.status(500).body(Body::empty())
+                                            Err(e) => {
+                                                let problem = e.problem();
+                                                if problem.status >= 500 {
+                                                    error!(error = %e);
+                                                }
+                                                problem_response(response, problem)
                                             },
                                         };
 
                                         resp.map_err(|e| { error!(error = ?e); StatusCode::INTERNAL_SERVER_ERROR })
 }
 
+/// RFC 7807 の`application/problem+json`応答を作る
+fn problem_response(response: http::response::Builder, problem: models::Problem) -> Result<Response, http::Error> {
+    let body = serde_json::to_vec(&problem).unwrap_or_default();
+    response
+        .status(problem.status as u16)
+        .header(CONTENT_TYPE, "application/problem+json")
+        .body(Body::from(body))
+}
@@ -0,0 +1,968 @@
+use crate::value_object::Password;
+use argon2::{Algorithm, Argon2, Params, Version};
+use chrono::{DateTime, Utc};
+use clap::{Parser, ValueEnum};
+use serde::Deserialize;
+use std::collections::HashSet;
+use std::net::SocketAddr;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use thiserror::Error;
+use uuid::Uuid;
+
+/// 本番モードで要求するJWTシークレットの最小バイト数
+pub const MIN_SECRET_LEN: usize = 32;
+
+/// アクセストークンの有効期間の上限(秒)。失効させるまで使えるため短く保つ
+pub const MAX_ACCESS_TOKEN_LIFETIME: u64 = 24 * 60 * 60;
+
+/// リフレッシュトークンの有効期間の上限(秒)。日時の計算があふれないようにする
+pub const MAX_REFRESH_TOKEN_LIFETIME: u64 = 365 * 24 * 60 * 60;
+
+/// ログインのロックと失敗を数える期間の上限(秒)。日時の計算があふれないようにする
+pub const MAX_LOGIN_PERIOD: u64 = 365 * 24 * 60 * 60;
+
+/// 設定ファイルを指定しなかった場合に探すパス
+const DEFAULT_CONFIG_PATH: &str = "board.toml";
+
+#[derive(Debug, Error)]
+pub enum ConfigError {
+    #[error("Failed to read config file {path}: {source}")]
+    Read {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    #[error("Failed to parse config file {path}: {source}")]
+    Parse {
+        path: PathBuf,
+        source: toml::de::Error,
+    },
+    #[error("Invalid value for environment variable {name}: {value}")]
+    Env { name: &'static str, value: String },
+    #[error("JWT secret is not configured; set jwt.secret, jwt.secret_file or BOARD_JWT_SECRET")]
+    MissingSecret,
+    #[error("JWT secret must be at least {MIN_SECRET_LEN} bytes long")]
+    SecretTooShort,
+    #[error("JWT key id {0:?} is configured more than once")]
+    DuplicateKid(String),
+    #[error("Invalid log level: {0}")]
+    LogLevel(String),
+    #[error("password.min_length must be between 1 and {}", Password::MAX_LENGTH)]
+    PasswordMinLength,
+    #[error("Invalid Argon2 parameters: {0}")]
+    Argon2(argon2::Error),
+    #[error("events.replay_size must be at least 1")]
+    EventsReplaySize,
+    #[error("websocket.ping_interval and websocket.send_timeout must be at least 1")]
+    WebSocketTimeout,
+    #[error("webhooks.timeout, webhooks.max_attempts, webhooks.backoff and webhooks.poll_interval must be at least 1")]
+    Webhooks,
+    #[error(
+        "login.max_lockout and login.failure_window must be at most {MAX_LOGIN_PERIOD} seconds"
+    )]
+    LoginPeriod,
+    #[error("jwt.access_token_lifetime must be between 1 and {MAX_ACCESS_TOKEN_LIFETIME} seconds")]
+    AccessTokenLifetime,
+    #[error(
+        "jwt.refresh_token_lifetime must be between 1 and {MAX_REFRESH_TOKEN_LIFETIME} seconds"
+    )]
+    RefreshTokenLifetime,
+    #[error(
+        "users.admin_password must be between 1 and {} characters",
+        Password::MAX_LENGTH
+    )]
+    AdminPassword,
+}
+
+/// コマンドライン引数。指定された値は設定ファイルと環境変数より優先される
+#[derive(Debug, Default, Parser)]
+#[command(version, about = "掲示板アプリケーションAPIサーバー", long_about = None)]
+pub struct Cli {
+    /// 設定ファイル(TOML)のパス
+    #[arg(short, long, env = "BOARD_CONFIG")]
+    pub config: Option<PathBuf>,
+    /// 待ち受けアドレス
+    #[arg(long)]
+    pub bind: Option<SocketAddr>,
+    /// ログレベル (trace, debug, info, warn, error)
+    #[arg(long)]
+    pub log_level: Option<String>,
+    /// ストレージバックエンド
+    #[arg(long, value_enum)]
+    pub storage: Option<StorageBackend>,
+    /// SQLiteデータベースファイルのパス
+    #[arg(long)]
+    pub database_path: Option<PathBuf>,
+    /// JWTシークレットを読み込むファイル
+    #[arg(long)]
+    pub jwt_secret_file: Option<PathBuf>,
+    /// アクセストークンの有効期間(秒)
+    #[arg(long)]
+    pub access_token_lifetime: Option<u64>,
+    /// リフレッシュトークンの有効期間(秒)
+    #[arg(long)]
+    pub refresh_token_lifetime: Option<u64>,
+    /// 開発モード(シークレット未設定を許可する)
+    #[arg(long)]
+    pub dev: bool,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// 開発モード。シークレットが未設定でも起動できる
+    pub dev: bool,
+    pub server: ServerConfig,
+    pub log: LogConfig,
+    pub jwt: JwtConfig,
+    pub storage: StorageConfig,
+    pub users: UsersConfig,
+    pub posts: PostsConfig,
+    pub password: PasswordConfig,
+    pub login: LoginConfig,
+    pub events: EventsConfig,
+    pub websocket: WebSocketConfig,
+    pub webhooks: WebhooksConfig,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ServerConfig {
+    pub bind: SocketAddr,
+    /// リバースプロキシが付けた`X-Forwarded-For`を信頼する。無効の場合は接続元のアドレスを使う
+    pub trust_proxy: bool,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct LogConfig {
+    pub level: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct JwtConfig {
+    pub secret: Option<String>,
+    /// シークレットを直接書く代わりにファイルから読み込む
+    pub secret_file: Option<PathBuf>,
+    /// アクセストークンの有効期間(秒)
+    pub access_token_lifetime: u64,
+    /// リフレッシュトークンの有効期間(秒)
+    pub refresh_token_lifetime: u64,
+    /// 非対称鍵による署名鍵。設定した場合はシークレットを使わない
+    pub keys: Vec<JwtKeyConfig>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub enum KeyAlgorithm {
+    RS256,
+    ES256,
+    EdDSA,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct JwtKeyConfig {
+    /// トークンヘッダーとJWKSに載せる鍵ID
+    pub kid: String,
+    pub algorithm: KeyAlgorithm,
+    /// PKCS#8形式(RSAはPKCS#1も可)のPEM秘密鍵
+    pub private_key_file: PathBuf,
+    /// 新規トークンの署名に使い始める時刻。省略時は即時
+    pub activate_at: Option<DateTime<Utc>>,
+    /// 検証にも使わなくなる時刻。省略時は設定から外すまで有効
+    pub retire_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum StorageBackend {
+    Memory,
+    Sqlite,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct StorageConfig {
+    pub backend: StorageBackend,
+    pub path: PathBuf,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct PostsConfig {
+    /// 返信をネストできる深さ。1の場合は返信への返信を許可しない
+    pub max_reply_depth: usize,
+    /// ゴミ箱の投稿を完全に削除するまでの期間(秒)
+    pub trash_retention: u64,
+}
+
+/// ログインの失敗が続いた場合のロック
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct LoginConfig {
+    /// アカウントをロックするまでに許す失敗の回数
+    pub max_failures_per_account: u32,
+    /// クライアントのアドレスをロックするまでに許す失敗の回数
+    pub max_failures_per_ip: u32,
+    /// 最初のロックの時間(秒)。以降は失敗するたびに倍になる
+    pub lockout: u64,
+    /// ロックの時間の上限(秒)
+    pub max_lockout: u64,
+    /// 最後の失敗またはロックの解除からこの時間(秒)が経つと失敗の回数を戻す
+    pub failure_window: u64,
+}
+
+/// `GET /events`で配信するイベント
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct EventsConfig {
+    /// `Last-Event-ID`による再送のために保持するイベントの数。配信が遅れた接続もこの範囲で追いつく
+    pub replay_size: usize,
+}
+
+/// `GET /ws`の接続
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct WebSocketConfig {
+    /// Pingを送る間隔(秒)。続けて2回応答がない接続は切断する
+    pub ping_interval: u64,
+    /// 1つのメッセージの送信を待つ時間(秒)。受信が追いつかない接続は切断する
+    pub send_timeout: u64,
+    /// 1つの接続で購読できるスレッドの数
+    pub max_threads: usize,
+}
+
+/// Webhookの配信
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct WebhooksConfig {
+    /// 1回の送信で応答を待つ時間(秒)
+    pub timeout: u64,
+    /// 試行する回数の上限。すべて失敗した配信はデッドレターとして残す
+    pub max_attempts: u32,
+    /// 最初の再試行までの時間(秒)。以降は試行するたびに倍にする
+    pub backoff: u64,
+    /// 再試行までの時間の上限(秒)
+    pub max_backoff: u64,
+    /// 再試行の予定を確認する間隔(秒)。新しいイベントはすぐに配信する
+    pub poll_interval: u64,
+    /// 配信に成功した記録を残す期間(秒)
+    pub retention: u64,
+}
+
+/// 登録時に課すパスワードの規則とハッシュのパラメータ
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct PasswordConfig {
+    /// 最小の文字数
+    pub min_length: usize,
+    pub require_lowercase: bool,
+    pub require_uppercase: bool,
+    pub require_digit: bool,
+    /// 英数字以外の文字を要求する
+    pub require_symbol: bool,
+    /// 使用を禁止するパスワード。大文字と小文字は区別しない
+    pub deny_list: HashSet<String>,
+    /// 禁止するパスワードを1行に1つ書いたファイル。`#`で始まる行は無視する
+    pub deny_list_file: Option<PathBuf>,
+    pub argon2: Argon2Config,
+}
+
+/// Argon2idのパラメータ。保存済みのハッシュがこれより弱い場合はログイン時に再ハッシュする
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Argon2Config {
+    /// メモリ使用量(KiB)
+    pub memory_cost: u32,
+    /// 反復回数
+    pub time_cost: u32,
+    /// 並列度
+    pub parallelism: u32,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct UsersConfig {
+    /// 起動時に管理者ロールを付与するメールアドレス
+    pub admin_emails: Vec<String>,
+    /// メモリ上のストレージで`admin_emails`の管理者を作成する際のパスワード
+    pub admin_password: Option<String>,
+}
+
+impl Default for ServerConfig {
+    fn default() -> Self {
+        Self {
+            bind: SocketAddr::from(([127, 0, 0, 1], 8080)),
+            trust_proxy: false,
+        }
+    }
+}
+
+impl Default for LogConfig {
+    fn default() -> Self {
+        Self {
+            level: "info".to_string(),
+        }
+    }
+}
+
+impl Default for JwtConfig {
+    fn default() -> Self {
+        Self {
+            secret: None,
+            secret_file: None,
+            access_token_lifetime: 15 * 60,
+            refresh_token_lifetime: 30 * 24 * 60 * 60,
+            keys: Vec::new(),
+        }
+    }
+}
+
+impl Default for PostsConfig {
+    fn default() -> Self {
+        Self {
+            max_reply_depth: 3,
+            trash_retention: 30 * 24 * 60 * 60,
+        }
+    }
+}
+
+impl Default for LoginConfig {
+    fn default() -> Self {
+        Self {
+            max_failures_per_account: 5,
+            max_failures_per_ip: 20,
+            lockout: 30,
+            max_lockout: 60 * 60,
+            failure_window: 15 * 60,
+        }
+    }
+}
+
+impl Default for EventsConfig {
+    fn default() -> Self {
+        Self { replay_size: 1000 }
+    }
+}
+
+impl Default for WebSocketConfig {
+    fn default() -> Self {
+        Self {
+            ping_interval: 30,
+            send_timeout: 10,
+            max_threads: 50,
+        }
+    }
+}
+
+impl Default for WebhooksConfig {
+    fn default() -> Self {
+        Self {
+            timeout: 10,
+            max_attempts: 8,
+            backoff: 30,
+            max_backoff: 60 * 60,
+            poll_interval: 5,
+            retention: 7 * 24 * 60 * 60,
+        }
+    }
+}
+
+impl Default for PasswordConfig {
+    fn default() -> Self {
+        Self {
+            min_length: 8,
+            require_lowercase: false,
+            require_uppercase: false,
+            require_digit: false,
+            require_symbol: false,
+            deny_list: HashSet::new(),
+            deny_list_file: None,
+            argon2: Argon2Config::default(),
+        }
+    }
+}
+
+impl Default for Argon2Config {
+    /// argon2クレートの既定値(OWASPの推奨値)
+    fn default() -> Self {
+        Self {
+            memory_cost: Params::DEFAULT_M_COST,
+            time_cost: Params::DEFAULT_T_COST,
+            parallelism: Params::DEFAULT_P_COST,
+        }
+    }
+}
+
+impl Default for StorageConfig {
+    fn default() -> Self {
+        Self {
+            backend: StorageBackend::Sqlite,
+            path: PathBuf::from("board.db"),
+        }
+    }
+}
+
+impl Config {
+    /// デフォルト値 < 設定ファイル < 環境変数 < コマンドライン引数 の順で設定を組み立て、検証する
+    pub fn load(cli: &Cli) -> Result<Self, ConfigError> {
+        let mut config = match &cli.config {
+            Some(path) => Self::from_file(path)?,
+            None if Path::new(DEFAULT_CONFIG_PATH).exists() => {
+                Self::from_file(Path::new(DEFAULT_CONFIG_PATH))?
+            }
+            None => Self::default(),
+        };
+        config.apply_env(|name| std::env::var(name).ok())?;
+        config.apply_cli(cli);
+        config.finalize()?;
+        Ok(config)
+    }
+
+    pub fn from_file(path: &Path) -> Result<Self, ConfigError> {
+        let text = std::fs::read_to_string(path).map_err(|source| ConfigError::Read {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        toml::from_str(&text).map_err(|source| ConfigError::Parse {
+            path: path.to_path_buf(),
+            source,
+        })
+    }
+
+    fn apply_env(&mut self, var: impl Fn(&str) -> Option<String>) -> Result<(), ConfigError> {
+        fn parse<T: std::str::FromStr>(
+            name: &'static str,
+            value: String,
+        ) -> Result<T, ConfigError> {
+            value.parse().map_err(|_e| ConfigError::Env { name, value })
+        }
+
+        if let Some(value) = var("BOARD_DEV") {
+            self.dev = parse("BOARD_DEV", value)?;
+        }
+        if let Some(value) = var("BOARD_BIND") {
+            self.server.bind = parse("BOARD_BIND", value)?;
+        }
+        if let Some(value) = var("BOARD_TRUST_PROXY") {
+            self.server.trust_proxy = parse("BOARD_TRUST_PROXY", value)?;
+        }
+        if let Some(value) = var("BOARD_LOG_LEVEL") {
+            self.log.level = value;
+        }
+        if let Some(value) = var("BOARD_JWT_SECRET") {
+            self.jwt.secret = Some(value);
+        }
+        if let Some(value) = var("BOARD_JWT_SECRET_FILE") {
+            self.jwt.secret_file = Some(PathBuf::from(value));
+        }
+        if let Some(value) = var("BOARD_ACCESS_TOKEN_LIFETIME") {
+            self.jwt.access_token_lifetime = parse("BOARD_ACCESS_TOKEN_LIFETIME", value)?;
+        }
+        if let Some(value) = var("BOARD_REFRESH_TOKEN_LIFETIME") {
+            self.jwt.refresh_token_lifetime = parse("BOARD_REFRESH_TOKEN_LIFETIME", value)?;
+        }
+        if let Some(value) = var("BOARD_STORAGE") {
+            self.storage.backend =
+                StorageBackend::from_str(&value, true).map_err(|_e| ConfigError::Env {
+                    name: "BOARD_STORAGE",
+                    value,
+                })?;
+        }
+        if let Some(value) = var("BOARD_DATABASE_PATH") {
+            self.storage.path = PathBuf::from(value);
+        }
+        if let Some(value) = var("BOARD_MAX_REPLY_DEPTH") {
+            self.posts.max_reply_depth = parse("BOARD_MAX_REPLY_DEPTH", value)?;
+        }
+        if let Some(value) = var("BOARD_TRASH_RETENTION") {
+            self.posts.trash_retention = parse("BOARD_TRASH_RETENTION", value)?;
+        }
+        if let Some(value) = var("BOARD_PASSWORD_MIN_LENGTH") {
+            self.password.min_length = parse("BOARD_PASSWORD_MIN_LENGTH", value)?;
+        }
+        if let Some(value) = var("BOARD_PASSWORD_DENY_LIST_FILE") {
+            self.password.deny_list_file = Some(PathBuf::from(value));
+        }
+        if let Some(value) = var("BOARD_ADMIN_EMAILS") {
+            self.users.admin_emails = value
+                .split(',')
+                .map(str::trim)
+                .filter(|email| !email.is_empty())
+                .map(str::to_string)
+                .collect();
+        }
+        if let Some(value) = var("BOARD_ADMIN_PASSWORD") {
+            self.users.admin_password = Some(value);
+        }
+        Ok(())
+    }
+
+    fn apply_cli(&mut self, cli: &Cli) {
+        self.dev |= cli.dev;
+        if let Some(bind) = cli.bind {
+            self.server.bind = bind;
+        }
+        if let Some(level) = &cli.log_level {
+            self.log.level = level.clone();
+        }
+        if let Some(backend) = cli.storage {
+            self.storage.backend = backend;
+        }
+        if let Some(path) = &cli.database_path {
+            self.storage.path = path.clone();
+        }
+        if let Some(path) = &cli.jwt_secret_file {
+            self.jwt.secret_file = Some(path.clone());
+        }
+        if let Some(lifetime) = cli.access_token_lifetime {
+            self.jwt.access_token_lifetime = lifetime;
+        }
+        if let Some(lifetime) = cli.refresh_token_lifetime {
+            self.jwt.refresh_token_lifetime = lifetime;
+        }
+    }
+
+    /// シークレットファイルを読み込み、本番モードでのシークレットの有無と長さを検証する
+    fn finalize(&mut self) -> Result<(), ConfigError> {
+        self.log_level()?;
+        self.finalize_password()?;
+        if !(1..=MAX_ACCESS_TOKEN_LIFETIME).contains(&self.jwt.access_token_lifetime) {
+            return Err(ConfigError::AccessTokenLifetime);
+        }
+        if !(1..=MAX_REFRESH_TOKEN_LIFETIME).contains(&self.jwt.refresh_token_lifetime) {
+            return Err(ConfigError::RefreshTokenLifetime);
+        }
+        if self.events.replay_size == 0 {
+            return Err(ConfigError::EventsReplaySize);
+        }
+        if self.websocket.ping_interval == 0 || self.websocket.send_timeout == 0 {
+            return Err(ConfigError::WebSocketTimeout);
+        }
+        if self.login.max_lockout > MAX_LOGIN_PERIOD || self.login.failure_window > MAX_LOGIN_PERIOD
+        {
+            return Err(ConfigError::LoginPeriod);
+        }
+        if let Some(password) = &self.users.admin_password {
+            Password::parse(password).map_err(|_e| ConfigError::AdminPassword)?;
+        }
+        let webhooks = &self.webhooks;
+        if webhooks.timeout == 0
+            || webhooks.max_attempts == 0
+            || webhooks.backoff == 0
+            || webhooks.poll_interval == 0
+        {
+            return Err(ConfigError::Webhooks);
+        }
+        if !self.jwt.keys.is_empty() {
+            return self.validate_keys();
+        }
+        if let Some(path) = &self.jwt.secret_file {
+            let secret = std::fs::read_to_string(path).map_err(|source| ConfigError::Read {
+                path: path.clone(),
+                source,
+            })?;
+            self.jwt.secret = Some(secret.trim_end().to_string());
+        }
+        match &self.jwt.secret {
+            Some(secret) if secret.len() >= MIN_SECRET_LEN => Ok(()),
+            _ if self.dev => {
+                // 開発モードではプロセスごとのランダムなシークレットで代用する
+                if self.jwt.secret.is_none() {
+                    self.jwt.secret = Some(Uuid::new_v4().to_string());
+                }
+                Ok(())
+            }
+            Some(_) => Err(ConfigError::SecretTooShort),
+            None => Err(ConfigError::MissingSecret),
+        }
+    }
+
+    /// 禁止するパスワードの一覧を読み込み、パスワードの規則とArgon2のパラメータを検証する
+    fn finalize_password(&mut self) -> Result<(), ConfigError> {
+        if !(1..=Password::MAX_LENGTH).contains(&self.password.min_length) {
+            return Err(ConfigError::PasswordMinLength);
+        }
+        self.argon2_params()?;
+        let mut deny_list: HashSet<String> = self
+            .password
+            .deny_list
+            .iter()
+            .map(|password| password.to_lowercase())
+            .collect();
+        if let Some(path) = &self.password.deny_list_file {
+            let text = std::fs::read_to_string(path).map_err(|source| ConfigError::Read {
+                path: path.clone(),
+                source,
+            })?;
+            deny_list.extend(
+                text.lines()
+                    .map(str::trim)
+                    .filter(|line| !line.is_empty() && !line.starts_with('#'))
+                    .map(str::to_lowercase),
+            );
+        }
+        self.password.deny_list = deny_list;
+        Ok(())
+    }
+
+    fn argon2_params(&self) -> Result<Params, ConfigError> {
+        let argon2 = &self.password.argon2;
+        Params::new(
+            argon2.memory_cost,
+            argon2.time_cost,
+            argon2.parallelism,
+            None,
+        )
+        .map_err(ConfigError::Argon2)
+    }
+
+    /// パスワードのハッシュ化に使うArgon2id
+    pub fn argon2(&self) -> Argon2<'static> {
+        let params = self
+            .argon2_params()
+            .expect("argon2 parameters are validated on load");
+        Argon2::new(Algorithm::Argon2id, Version::V0x13, params)
+    }
+
+    fn validate_keys(&self) -> Result<(), ConfigError> {
+        let mut kids = std::collections::HashSet::new();
+        for key in &self.jwt.keys {
+            if !kids.insert(key.kid.as_str()) {
+                return Err(ConfigError::DuplicateKid(key.kid.clone()));
+            }
+        }
+        Ok(())
+    }
+
+    pub fn log_level(&self) -> Result<tracing::Level, ConfigError> {
+        self.log
+            .level
+            .parse()
+            .map_err(|_e| ConfigError::LogLevel(self.log.level.clone()))
+    }
+
+    pub fn jwt_secret(&self) -> &[u8] {
+        self.jwt.secret.as_deref().unwrap_or_default().as_bytes()
+    }
+
+    pub fn access_token_lifetime(&self) -> Duration {
+        Duration::from_secs(self.jwt.access_token_lifetime)
+    }
+
+    pub fn refresh_token_lifetime(&self) -> Duration {
+        Duration::from_secs(self.jwt.refresh_token_lifetime)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    const SECRET: &str = "0123456789abcdef0123456789abcdef";
+
+    fn env(vars: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+        let vars: HashMap<String, String> = vars
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        move |name| vars.get(name).cloned()
+    }
+
+    mod from_file {
+        use super::*;
+
+        #[test]
+        fn test_parse_toml() {
+            let config: Config = toml::from_str(
+                r#"
+                dev = true
+
+                [server]
+                bind = "0.0.0.0:3000"
+
+                [jwt]
+                access_token_lifetime = 600
+
+                [storage]
+                backend = "memory"
+                "#,
+            )
+            .unwrap();
+
+            assert!(config.dev);
+            assert_eq!(config.server.bind.port(), 3000);
+            assert_eq!(config.access_token_lifetime(), Duration::from_secs(600));
+            assert_eq!(config.storage.backend, StorageBackend::Memory);
+            assert_eq!(config.log.level, "info");
+        }
+
+        #[test]
+        fn test_reject_unknown_field() {
+            let result = toml::from_str::<Config>("[server]\nport = 80\n");
+
+            assert!(result.is_err());
+        }
+    }
+
+    mod apply_env {
+        use super::*;
+
+        #[test]
+        fn test_env_overrides_file_and_cli_overrides_env() {
+            let mut config: Config = toml::from_str("[log]\nlevel = \"warn\"\n").unwrap();
+            config
+                .apply_env(env(&[
+                    ("BOARD_LOG_LEVEL", "debug"),
+                    ("BOARD_STORAGE", "memory"),
+                    ("BOARD_BIND", "127.0.0.1:9000"),
+                ]))
+                .unwrap();
+            config.apply_cli(&Cli {
+                bind: Some("127.0.0.1:9100".parse().unwrap()),
+                ..Cli::default()
+            });
+
+            assert_eq!(config.log.level, "debug");
+            assert_eq!(config.storage.backend, StorageBackend::Memory);
+            assert_eq!(config.server.bind.port(), 9100);
+        }
+
+        #[test]
+        fn test_admin_emails() {
+            let mut config = Config::default();
+            config
+                .apply_env(env(&[(
+                    "BOARD_ADMIN_EMAILS",
+                    "root@example.com, Ops@Example.com,",
+                )]))
+                .unwrap();
+
+            assert_eq!(
+                config.users.admin_emails,
+                vec![
+                    "root@example.com".to_string(),
+                    "Ops@Example.com".to_string()
+                ]
+            );
+        }
+
+        #[test]
+        fn test_invalid_env_value() {
+            let mut config = Config::default();
+
+            let result = config.apply_env(env(&[("BOARD_ACCESS_TOKEN_LIFETIME", "soon")]));
+
+            assert!(matches!(
+                result,
+                Err(ConfigError::Env {
+                    name: "BOARD_ACCESS_TOKEN_LIFETIME",
+                    ..
+                })
+            ));
+        }
+    }
+
+    mod finalize {
+        use super::*;
+
+        #[test]
+        fn test_missing_secret() {
+            let mut config = Config::default();
+
+            assert!(matches!(config.finalize(), Err(ConfigError::MissingSecret)));
+        }
+
+        #[test]
+        fn test_short_secret() {
+            let mut config = Config::default();
+            config.jwt.secret = Some("secret".to_string());
+
+            assert!(matches!(
+                config.finalize(),
+                Err(ConfigError::SecretTooShort)
+            ));
+        }
+
+        #[test]
+        fn test_dev_mode_generates_secret() {
+            let mut config = Config {
+                dev: true,
+                ..Config::default()
+            };
+
+            config.finalize().unwrap();
+
+            assert!(!config.jwt_secret().is_empty());
+        }
+
+        #[test]
+        fn test_secret_file() {
+            let path = std::env::temp_dir().join(format!("board-app-secret-{}", Uuid::new_v4()));
+            std::fs::write(&path, format!("{}\n", SECRET)).unwrap();
+            let mut config = Config::default();
+            config.jwt.secret_file = Some(path.clone());
+
+            config.finalize().unwrap();
+
+            assert_eq!(config.jwt_secret(), SECRET.as_bytes());
+            std::fs::remove_file(path).unwrap();
+        }
+
+        #[test]
+        fn test_keys_replace_secret() {
+            let mut config: Config = toml::from_str(
+                r#"
+                [[jwt.keys]]
+                kid = "2026-10"
+                algorithm = "ES256"
+                private_key_file = "keys/2026-10.pem"
+                activate_at = "2026-10-01T00:00:00Z"
+                "#,
+            )
+            .unwrap();
+
+            config.finalize().unwrap();
+
+            assert_eq!(config.jwt.keys[0].algorithm, KeyAlgorithm::ES256);
+            assert!(config.jwt.secret.is_none());
+        }
+
+        #[test]
+        fn test_duplicate_kid() {
+            let mut config: Config = toml::from_str(
+                r#"
+                [[jwt.keys]]
+                kid = "a"
+                algorithm = "RS256"
+                private_key_file = "a.pem"
+
+                [[jwt.keys]]
+                kid = "a"
+                algorithm = "EdDSA"
+                private_key_file = "b.pem"
+                "#,
+            )
+            .unwrap();
+
+            assert!(matches!(
+                config.finalize(),
+                Err(ConfigError::DuplicateKid(kid)) if kid == "a"
+            ));
+        }
+
+        #[test]
+        fn test_password_deny_list_file() {
+            let path = std::env::temp_dir().join(format!("board-app-deny-{}", Uuid::new_v4()));
+            std::fs::write(&path, "# common passwords\nQwerty123\n\n  letmein  \n").unwrap();
+            let mut config: Config = toml::from_str(
+                r#"
+                [password]
+                deny_list = ["Password1"]
+                "#,
+            )
+            .unwrap();
+            config.jwt.secret = Some(SECRET.to_string());
+            config.password.deny_list_file = Some(path.clone());
+
+            config.finalize().unwrap();
+
+            assert_eq!(
+                config.password.deny_list,
+                HashSet::from(["password1", "qwerty123", "letmein"].map(str::to_string))
+            );
+            std::fs::remove_file(path).unwrap();
+        }
+
+        #[test]
+        fn test_invalid_password_config() {
+            let mut config = Config::default();
+            config.jwt.secret = Some(SECRET.to_string());
+            config.password.min_length = 0;
+
+            assert!(matches!(
+                config.finalize(),
+                Err(ConfigError::PasswordMinLength)
+            ));
+
+            config.password.min_length = 8;
+            config.password.argon2.parallelism = 0;
+
+            assert!(matches!(config.finalize(), Err(ConfigError::Argon2(_))));
+        }
+
+        #[test]
+        fn test_login_period_is_limited() {
+            let mut config = Config::default();
+            config.jwt.secret = Some(SECRET.to_string());
+            config.login.failure_window = u64::MAX;
+
+            assert!(matches!(config.finalize(), Err(ConfigError::LoginPeriod)));
+
+            config.login.failure_window = MAX_LOGIN_PERIOD;
+            config.login.max_lockout = MAX_LOGIN_PERIOD + 1;
+
+            assert!(matches!(config.finalize(), Err(ConfigError::LoginPeriod)));
+        }
+
+        #[test]
+        fn test_access_token_lifetime_is_limited() {
+            let mut config = Config::default();
+            config.jwt.secret = Some(SECRET.to_string());
+
+            for lifetime in [0, MAX_ACCESS_TOKEN_LIFETIME + 1, u64::MAX] {
+                config.jwt.access_token_lifetime = lifetime;
+                assert!(matches!(
+                    config.finalize(),
+                    Err(ConfigError::AccessTokenLifetime)
+                ));
+            }
+
+            config.jwt.access_token_lifetime = MAX_ACCESS_TOKEN_LIFETIME;
+
+            assert!(config.finalize().is_ok());
+        }
+
+        #[test]
+        fn test_refresh_token_lifetime_is_limited() {
+            let mut config = Config::default();
+            config.jwt.secret = Some(SECRET.to_string());
+
+            for lifetime in [0, MAX_REFRESH_TOKEN_LIFETIME + 1, u64::MAX] {
+                config.jwt.refresh_token_lifetime = lifetime;
+                assert!(matches!(
+                    config.finalize(),
+                    Err(ConfigError::RefreshTokenLifetime)
+                ));
+            }
+
+            config.jwt.refresh_token_lifetime = MAX_REFRESH_TOKEN_LIFETIME;
+
+            assert!(config.finalize().is_ok());
+        }
+
+        #[test]
+        fn test_admin_password_is_validated() {
+            let mut config = Config::default();
+            config.jwt.secret = Some(SECRET.to_string());
+            config.users.admin_password = Some(String::new());
+
+            assert!(matches!(config.finalize(), Err(ConfigError::AdminPassword)));
+
+            config.users.admin_password = Some("password".to_string());
+
+            assert!(config.finalize().is_ok());
+        }
+
+        #[test]
+        fn test_invalid_log_level() {
+            let mut config = Config::default();
+            config.jwt.secret = Some(SECRET.to_string());
+            config.log.level = "loud".to_string();
+
+            assert!(matches!(config.finalize(), Err(ConfigError::LogLevel(_))));
+        }
+    }
+}
@@ -1,9 +1,15 @@
-use crate::value_object::{Content, Email, HashedPassword, Name, Password, PostId, Title, UserId};
+use crate::value_object::{
+    BoardDescription, BoardId, BoardName, BoardSlug, Content, DeliveryStatus, Email,
+    HashedPassword, Name, Password, PostId, PostStatus, Role, Title, UserId, ValueError,
+    WebhookEvent, WebhookUrl,
+};
 use argon2::{
     password_hash::{rand_core::OsRng, PasswordHasher, SaltString},
     Argon2,
 };
+use chrono::{DateTime, Utc};
 use thiserror::Error;
+use uuid::Uuid;
 
 #[derive(Debug, Clone)]
 pub struct User {
@@ -11,13 +17,141 @@ pub struct User {
     pub name: Name,
     pub email: Email,
     pub password: HashedPassword,
+    pub role: Role,
+    pub created_at: DateTime<Utc>,
+    /// 最後にパスワードでログインした日時。一度もログインしていない場合は`None`
+    pub last_login_at: Option<DateTime<Utc>>,
+}
+/// 永続化前のユーザー。idはリポジトリが採番する
+#[derive(Debug, Clone)]
+pub struct NewUser {
+    pub name: Name,
+    pub email: Email,
+    pub password: HashedPassword,
+    pub role: Role,
+    pub created_at: DateTime<Utc>,
+}
+
+/// マイグレーションで作成する既定のボードのID
+pub const GENERAL_BOARD_ID: BoardId = Uuid::from_u128(0x5d0f3a4e_2c1b_4e7a_9a43_6f1d2b8c0e17);
+
+/// 投稿をまとめる掲示板
+#[derive(Debug, Clone)]
+pub struct Board {
+    pub id: BoardId,
+    /// URLでボードを指定する一意の名前
+    pub slug: BoardSlug,
+    pub name: BoardName,
+    pub description: Option<BoardDescription>,
+    pub settings: BoardSettings,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
 }
+
+/// ボードごとの投稿の設定
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BoardSettings {
+    /// スレッドを作成できる最低のロール。返信はこのロールに関わらず行える
+    pub posting_role: Role,
+    /// 返信を受け付けるか。受け付けない場合はモデレーターと管理者も返信できない
+    pub allow_replies: bool,
+    /// 投稿の内容の最大の文字数。`Content::MAX_LENGTH`を超えない
+    pub max_post_length: usize,
+}
+
 #[derive(Debug, Clone)]
 pub struct Post {
     pub id: PostId,
     pub user_id: UserId,
+    /// 投稿が属するボード。返信は返信先の投稿と同じボードに属する
+    pub board_id: BoardId,
+    pub title: Title,
+    pub content: Content,
+    /// ロックされた投稿は作成者でも編集・削除できない
+    pub locked: bool,
+    /// ピン留めされた投稿は一覧の先頭に表示する
+    pub pinned: bool,
+    /// 返信先の投稿。スレッドの先頭の投稿では`None`
+    pub parent_id: Option<PostId>,
+    /// 直接の返信の数。読み出し時に集計するため保存時は無視される
+    pub reply_count: usize,
+    pub created_at: DateTime<Utc>,
+    /// 内容が最後に編集された日時。編集されていない場合は`created_at`と同じ
+    pub updated_at: DateTime<Utc>,
+    /// 内容が編集された回数
+    pub edited_count: u32,
+    /// 投稿または配下の返信が最後に作成・更新された日時
+    pub last_activity_at: DateTime<Utc>,
+    /// 保存するたびに1つ進めるバージョン。同時に行われた更新の競合を検出する
+    ///
+    /// 返信による`last_activity_at`の更新では進めない
+    pub version: u32,
+    /// ゴミ箱に移された日時。ゴミ箱にない投稿では`None`
+    pub deleted_at: Option<DateTime<Utc>>,
+    /// 投稿をゴミ箱に移した利用者。投稿と一緒に移された返信では親の投稿を移した利用者
+    pub deleted_by: Option<UserId>,
+    /// 公開前の投稿は一覧や検索に含めず、作成者以外には存在しないものとして扱う
+    pub status: PostStatus,
+    /// 公開する日時。公開予定の投稿でのみ設定される
+    pub publish_at: Option<DateTime<Utc>>,
+}
+
+/// 投稿のある時点のタイトルと内容。作成時の内容を版1とし、編集するたびに番号を1つ進めて記録する
+#[derive(Debug, Clone)]
+pub struct PostRevision {
+    pub post_id: PostId,
+    pub number: u32,
     pub title: Title,
     pub content: Content,
+    /// 版を作成した利用者。版1では投稿の作成者
+    pub edited_by: UserId,
+    pub created_at: DateTime<Utc>,
+}
+
+/// サーバー側で管理するリフレッシュトークン。トークン本体ではなくハッシュを保持する
+#[derive(Debug, Clone)]
+pub struct RefreshToken {
+    pub token_hash: String,
+    /// 同じログインから順に発行されたトークンの系列
+    pub family_id: Uuid,
+    pub user_id: UserId,
+    pub expires_at: DateTime<Utc>,
+    pub used: bool,
+    pub revoked: bool,
+}
+
+/// イベントを通知する送信先
+#[derive(Debug, Clone)]
+pub struct Webhook {
+    pub id: Uuid,
+    pub url: WebhookUrl,
+    /// 配信の署名に使う鍵。作成時にのみ利用者に返す
+    pub secret: String,
+    /// 通知するイベント。空の場合はすべてのイベントを通知する
+    pub events: Vec<WebhookEvent>,
+    pub created_by: UserId,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Webhookへの1つのイベントの配信と、その試行の記録
+#[derive(Debug, Clone)]
+pub struct WebhookDelivery {
+    pub id: Uuid,
+    pub webhook_id: Uuid,
+    pub event: WebhookEvent,
+    /// 送信するJSON。再試行でも同じ内容を送る
+    pub payload: String,
+    pub status: DeliveryStatus,
+    /// 試行した回数
+    pub attempts: u32,
+    /// 次に試行する日時。`Pending`以外では意味を持たない
+    pub next_attempt_at: DateTime<Utc>,
+    pub last_attempt_at: Option<DateTime<Utc>>,
+    /// 最後の試行で送信先が返したステータスコード
+    pub last_status_code: Option<u16>,
+    /// 最後の試行が失敗した理由
+    pub last_error: Option<String>,
+    pub created_at: DateTime<Utc>,
 }
 
 #[derive(Debug, Error)]
@@ -33,27 +167,281 @@ pub enum UserError {
 }
 
 impl User {
+    pub fn new(id: UserId, user: NewUser) -> Self {
+        Self {
+            id,
+            name: user.name,
+            email: user.email,
+            password: user.password,
+            role: user.role,
+            created_at: user.created_at,
+            last_login_at: None,
+        }
+    }
+}
+
+impl NewUser {
+    /// パスワードを`argon2`でハッシュ化する。各値は値オブジェクトとして検証済み
     pub fn new(
-        id: UserId,
         name: Name,
         email: Email,
         password: Password,
+        role: Role,
+        argon2: &Argon2<'_>,
     ) -> Result<Self, UserError> {
-        let password = hash_password(password).map_err(|_e| UserError::CreateUser)?;
+        let password = hash_password(argon2, &password).map_err(|_e| UserError::CreateUser)?;
         Ok(Self {
-            id,
             name,
             email,
             password,
+            role,
+            created_at: Utc::now(),
         })
     }
 }
 
-fn hash_password(password: Password) -> Result<HashedPassword, HashPasswordError> {
+impl Default for BoardSettings {
+    fn default() -> Self {
+        Self {
+            posting_role: Role::Member,
+            allow_replies: true,
+            max_post_length: Content::MAX_LENGTH,
+        }
+    }
+}
+
+impl BoardSettings {
+    /// 投稿の内容がボードの最大の文字数以下か確かめる
+    pub fn check_content(&self, content: &Content) -> Result<(), ValueError> {
+        if content.as_str().chars().count() > self.max_post_length {
+            return Err(ValueError::TooLong {
+                max: self.max_post_length,
+            });
+        }
+        Ok(())
+    }
+}
+
+impl Board {
+    pub fn new(
+        slug: BoardSlug,
+        name: BoardName,
+        description: Option<BoardDescription>,
+        settings: BoardSettings,
+    ) -> Self {
+        let now = Utc::now();
+        Self {
+            id: BoardId::new_v4(),
+            slug,
+            name,
+            description,
+            settings,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    /// SQLiteのマイグレーションで作成するものと同じ既定のボード
+    pub fn general() -> Self {
+        Self {
+            id: GENERAL_BOARD_ID,
+            ..Self::new(
+                BoardSlug::parse("general").expect("slug is valid"),
+                BoardName::parse("General").expect("name is valid"),
+                None,
+                BoardSettings::default(),
+            )
+        }
+    }
+
+    /// スラッグ、名前、説明と設定をすべて置き換える
+    pub fn update(
+        &mut self,
+        slug: BoardSlug,
+        name: BoardName,
+        description: Option<BoardDescription>,
+        settings: BoardSettings,
+    ) {
+        self.slug = slug;
+        self.name = name;
+        self.description = description;
+        self.settings = settings;
+        self.updated_at = Utc::now();
+    }
+}
+
+impl Post {
+    /// 新しい投稿。`parent_id`を指定すると返信になる
+    pub fn new(
+        user_id: UserId,
+        board_id: BoardId,
+        title: Title,
+        content: Content,
+        parent_id: Option<PostId>,
+    ) -> Self {
+        let now = Utc::now();
+        Self {
+            id: PostId::new_v4(),
+            user_id,
+            board_id,
+            title,
+            content,
+            locked: false,
+            pinned: false,
+            parent_id,
+            reply_count: 0,
+            created_at: now,
+            updated_at: now,
+            edited_count: 0,
+            last_activity_at: now,
+            version: 1,
+            deleted_at: None,
+            deleted_by: None,
+            status: PostStatus::Published,
+            publish_at: None,
+        }
+    }
+
+    /// 公開前の新しい投稿。`publish_at`を指定すると公開予定、指定しないと下書きになる
+    pub fn unpublished(
+        user_id: UserId,
+        board_id: BoardId,
+        title: Title,
+        content: Content,
+        publish_at: Option<DateTime<Utc>>,
+    ) -> Self {
+        Self {
+            status: match publish_at {
+                Some(_) => PostStatus::Scheduled,
+                None => PostStatus::Draft,
+            },
+            publish_at,
+            ..Self::new(user_id, board_id, title, content, None)
+        }
+    }
+
+    pub fn is_published(&self) -> bool {
+        self.status == PostStatus::Published
+    }
+
+    /// タイトルと内容を置き換え、編集の記録を更新する
+    pub fn edit(&mut self, title: Title, content: Content) {
+        let now = Utc::now();
+        self.title = title;
+        self.content = content;
+        self.updated_at = now;
+        self.edited_count += 1;
+        self.last_activity_at = now;
+        self.version += 1;
+    }
+
+    /// ロックとピン留めの状態を変更する。`None`の項目は変更しない
+    pub fn moderate(&mut self, locked: Option<bool>, pinned: Option<bool>) {
+        if let Some(locked) = locked {
+            self.locked = locked;
+        }
+        if let Some(pinned) = pinned {
+            self.pinned = pinned;
+        }
+        self.version += 1;
+    }
+
+    /// `deleted_by`が投稿をゴミ箱に移す
+    pub fn trash(&mut self, deleted_by: UserId) {
+        self.deleted_at = Some(Utc::now());
+        self.deleted_by = Some(deleted_by);
+        self.version += 1;
+    }
+
+    /// ゴミ箱から元に戻す
+    pub fn restore(&mut self) {
+        self.deleted_at = None;
+        self.deleted_by = None;
+        self.version += 1;
+    }
+
+    /// `publish_at`に公開する予定にする
+    pub fn schedule(&mut self, publish_at: DateTime<Utc>) {
+        self.status = PostStatus::Scheduled;
+        self.publish_at = Some(publish_at);
+        self.version += 1;
+    }
+
+    /// `at`に公開する。公開した日時を作成日時とし、一覧では新しい投稿として扱う
+    pub fn publish(&mut self, at: DateTime<Utc>) {
+        self.status = PostStatus::Published;
+        self.publish_at = None;
+        self.created_at = at;
+        self.updated_at = self.updated_at.max(at);
+        self.last_activity_at = self.last_activity_at.max(at);
+        self.version += 1;
+    }
+}
+
+impl PostRevision {
+    /// 投稿の現在の内容を版にする。番号は編集された回数から決まる
+    pub fn of(post: &Post, edited_by: UserId) -> Self {
+        Self {
+            post_id: post.id,
+            number: post.edited_count + 1,
+            title: post.title.clone(),
+            content: post.content.clone(),
+            edited_by,
+            created_at: post.updated_at,
+        }
+    }
+}
+
+impl Webhook {
+    pub fn new(
+        url: WebhookUrl,
+        events: Vec<WebhookEvent>,
+        secret: String,
+        created_by: UserId,
+    ) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            url,
+            secret,
+            events,
+            created_by,
+            created_at: Utc::now(),
+        }
+    }
+
+    /// イベントを通知する対象か
+    pub fn accepts(&self, event: WebhookEvent) -> bool {
+        self.events.is_empty() || self.events.contains(&event)
+    }
+}
+
+impl WebhookDelivery {
+    /// すぐに配信する新しい配信
+    pub fn new(webhook_id: Uuid, event: WebhookEvent, payload: String) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            webhook_id,
+            event,
+            payload,
+            status: DeliveryStatus::Pending,
+            attempts: 0,
+            next_attempt_at: now,
+            last_attempt_at: None,
+            last_status_code: None,
+            last_error: None,
+            created_at: now,
+        }
+    }
+}
+
+pub fn hash_password(
+    argon2: &Argon2<'_>,
+    password: &Password,
+) -> Result<HashedPassword, HashPasswordError> {
     let salt = SaltString::generate(&mut OsRng);
-    let argon2 = Argon2::default();
     let password_hash = argon2
-        .hash_password(password.as_bytes(), &salt)
+        .hash_password(password.as_str().as_bytes(), &salt)
         .map_err(|_e| HashPasswordError::HashPassword)?
         .to_string();
     Ok(HashedPassword::from(password_hash))
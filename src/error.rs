@@ -0,0 +1,160 @@
+use crate::entity::{HashPasswordError, UserError};
+use crate::repository::RepositoryError;
+use crate::service::jwt::KeyError;
+use crate::service::password::PasswordError;
+use crate::value_object::ValueError;
+use openapi::apis::ApiError;
+use openapi::models::{self, ErrorCode};
+use openapi::problem;
+use thiserror::Error;
+use validator::{ValidationError, ValidationErrors};
+
+/// ハンドラーが返すエラー。クライアントには`application/problem+json`で返す
+#[derive(Debug, Error)]
+pub enum AppError {
+    #[error("Request body is required")]
+    MissingBody,
+    #[error("Request validation failed: {0}")]
+    Validation(#[from] ValidationErrors),
+    #[error(transparent)]
+    Repository(#[from] RepositoryError),
+    #[error(transparent)]
+    Key(#[from] KeyError),
+    #[error(transparent)]
+    User(#[from] UserError),
+    #[error(transparent)]
+    HashPassword(#[from] HashPasswordError),
+    #[error("Stored password hash is invalid")]
+    PasswordHash,
+    #[error("Email or password is incorrect")]
+    InvalidCredentials,
+}
+
+impl AppError {
+    /// 必須の項目がない場合の検証エラー
+    pub fn required(field: &'static str) -> Self {
+        let mut errors = ValidationErrors::new();
+        errors.add(field, ValidationError::new("required"));
+        AppError::Validation(errors)
+    }
+
+    /// 変更できない項目を変更しようとした場合の検証エラー
+    pub fn read_only(fields: impl IntoIterator<Item = &'static str>) -> Self {
+        let mut errors = ValidationErrors::new();
+        for field in fields {
+            errors.add(field, ValidationError::new("read_only"));
+        }
+        AppError::Validation(errors)
+    }
+
+    /// 他の項目との組み合わせや現在の状態に対して値が不正な項目の検証エラー
+    pub fn rejected(field: &'static str, code: &'static str) -> Self {
+        let mut errors = ValidationErrors::new();
+        errors.add(field, ValidationError::new(code));
+        AppError::Validation(errors)
+    }
+
+    /// 値オブジェクトの生成に失敗した項目をまとめて検証エラーにする
+    pub fn invalid<'a>(
+        fields: impl IntoIterator<Item = (&'static str, Option<&'a ValueError>)>,
+    ) -> Self {
+        let mut errors = ValidationErrors::new();
+        for (field, error) in fields {
+            if let Some(error) = error {
+                errors.add(field, field_error(error.code(), error));
+            }
+        }
+        AppError::Validation(errors)
+    }
+
+    /// パスワードの規則に違反した理由をすべて`password`の検証エラーにする
+    pub fn weak_password(violations: &[PasswordError]) -> Self {
+        let mut errors = ValidationErrors::new();
+        for violation in violations {
+            errors.add("password", field_error(violation.code(), violation));
+        }
+        AppError::Validation(errors)
+    }
+}
+
+fn field_error(code: &'static str, error: &impl std::fmt::Display) -> ValidationError {
+    let mut validation = ValidationError::new(code);
+    validation.message = Some(error.to_string().into());
+    validation
+}
+
+impl ApiError for AppError {
+    fn problem(&self) -> models::Problem {
+        match self {
+            AppError::MissingBody => problem::problem(ErrorCode::MissingBody),
+            AppError::Validation(errors) => problem::validation_problem(errors),
+            AppError::Repository(RepositoryError::Stale) => problem::problem(ErrorCode::Conflict),
+            AppError::InvalidCredentials => problem::problem(ErrorCode::InvalidCredentials),
+            // 内部のエラーの詳細はログにのみ残す
+            _ => problem::problem(ErrorCode::InternalError),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod problem {
+        use super::*;
+
+        #[test]
+        fn test_validation_lists_fields() {
+            let problem = AppError::required("email").problem();
+
+            assert_eq!(problem.status, 400);
+            assert_eq!(problem.code, ErrorCode::ValidationFailed);
+            assert_eq!(problem.r#type, "urn:board:problem:validation_failed");
+            let errors = problem.errors.unwrap();
+            assert_eq!(errors.len(), 1);
+            assert_eq!(errors[0].field, "email");
+            assert_eq!(errors[0].code, "required");
+        }
+
+        #[test]
+        fn test_invalid_values() {
+            let problem = AppError::invalid([
+                ("title", Some(&ValueError::Empty)),
+                ("content", None),
+                ("email", Some(&ValueError::InvalidEmail)),
+            ])
+            .problem();
+
+            let errors = problem.errors.unwrap();
+            assert_eq!(
+                errors
+                    .iter()
+                    .map(|e| (e.field.as_str(), e.code.as_str()))
+                    .collect::<Vec<_>>(),
+                vec![("email", "email"), ("title", "required")]
+            );
+            assert_eq!(
+                errors[0].message.as_deref(),
+                Some("must be a valid email address")
+            );
+        }
+
+        #[test]
+        fn test_stale_record_is_conflict() {
+            let problem = AppError::Repository(RepositoryError::Stale).problem();
+
+            assert_eq!(problem.status, 409);
+            assert_eq!(problem.code, ErrorCode::Conflict);
+        }
+
+        #[test]
+        fn test_internal_error_hides_detail() {
+            let problem = AppError::Repository(RepositoryError::Poisoned).problem();
+
+            assert_eq!(problem.status, 500);
+            assert_eq!(problem.code, ErrorCode::InternalError);
+            assert_eq!(problem.detail, None);
+            assert_eq!(problem.errors, None);
+        }
+    }
+}
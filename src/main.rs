@@ -1,41 +1,141 @@
+mod config;
 mod entity;
+mod error;
+mod repository;
 mod service;
+mod sse;
 mod value_object;
+mod ws;
 
-use crate::entity::User;
-use crate::service::jwt;
-use crate::value_object::PostId;
+use crate::config::{Cli, Config, StorageBackend};
+use crate::entity::NewUser;
+use crate::error::AppError;
+use crate::repository::memory::{
+    InMemoryBoardRepository, InMemoryPostRepository, InMemoryRefreshTokenRepository,
+    InMemoryRevocationRepository, InMemoryUserRepository, InMemoryWebhookRepository,
+};
+use crate::repository::sqlite::{
+    Database, SqliteBoardRepository, SqlitePostRepository, SqliteRefreshTokenRepository,
+    SqliteRevocationRepository, SqliteUserRepository, SqliteWebhookRepository,
+};
+use crate::repository::{
+    BoardRepository, DeliveryQuery, PostCursor, PostQuery, PostRepository, PostSort,
+    RefreshTokenRepository, RepositoryError, RevocationRepository, UserRepository,
+    WebhookRepository,
+};
+use crate::service::auth::{self, AuthError, AuthenticatedUser};
+use crate::service::cursor;
+use crate::service::diff::{self, DiffLine, DiffOp};
+use crate::service::etag;
+use crate::service::events::{BoardEvent, EventBus, EventKind};
+use crate::service::jwt::KeyRing;
+use crate::service::login_throttle::{Attempt, LoginThrottle, ThrottleKey};
+use crate::service::password;
+use crate::service::policy::{self, PostAction};
+use crate::service::refresh_token::{self, RefreshError};
+use crate::service::search::{self, Fragment, SearchQuery};
+use crate::service::webhook::{self, WebhookDispatcher};
+use crate::value_object::{
+    normalize_email, BoardDescription, BoardId, BoardName, BoardSlug, Content, DeliveryStatus,
+    Email, HashedPassword, Name, Password, PostId, PostStatus, Role, Title, UserId, ValueError,
+    WebhookEvent, WebhookUrl,
+};
 use argon2::Argon2;
-use axum::{async_trait, extract::Host, http::Method};
+use axum::async_trait;
+use axum::extract::{ConnectInfo, Host, Request, State};
+use axum::http::header::{AUTHORIZATION, CONTENT_TYPE, WWW_AUTHENTICATE};
+use axum::http::{HeaderMap, HeaderName, HeaderValue, Method, StatusCode};
+use axum::middleware::{self, Next};
+use axum::response::{IntoResponse, Response};
 use axum_extra::extract::CookieJar;
+use chrono::{DateTime, SecondsFormat, Utc};
+use clap::Parser;
 use openapi::apis::posts::PostsPostIdDeleteResponse;
 use openapi::models::{
-    Post, PostsGetHeaderParams, PostsPostHeaderParams, PostsPostIdDeleteHeaderParams,
-    PostsPostIdDeletePathParams, PostsPostIdGetHeaderParams, PostsPostIdGetPathParams,
-    PostsPostIdPutHeaderParams, PostsPostIdPutPathParams,
+    AdminBoardsPostHeaderParams, AdminBoardsSlugDeleteHeaderParams,
+    AdminBoardsSlugDeletePathParams, AdminBoardsSlugPutHeaderParams, AdminBoardsSlugPutPathParams,
+    AdminUsersUserIdRoleDeleteHeaderParams, AdminUsersUserIdRoleDeletePathParams,
+    AdminUsersUserIdRolePutHeaderParams, AdminUsersUserIdRolePutPathParams,
+    AdminWebhookDeliveriesDeliveryIdRetryPostHeaderParams,
+    AdminWebhookDeliveriesDeliveryIdRetryPostPathParams, AdminWebhookDeliveriesGetHeaderParams,
+    AdminWebhookDeliveriesGetQueryParams, AdminWebhooksGetHeaderParams,
+    AdminWebhooksPostHeaderParams, AdminWebhooksWebhookIdDeleteHeaderParams,
+    AdminWebhooksWebhookIdDeletePathParams, AdminWebhooksWebhookIdDeliveriesGetHeaderParams,
+    AdminWebhooksWebhookIdDeliveriesGetPathParams, AdminWebhooksWebhookIdDeliveriesGetQueryParams,
+    AuthLogoutAllPostHeaderParams, AuthLogoutPostHeaderParams, AuthPostHeaderParams,
+    BoardsGetHeaderParams, BoardsSlugGetHeaderParams, BoardsSlugGetPathParams,
+    BoardsSlugPostsGetHeaderParams, BoardsSlugPostsGetPathParams, BoardsSlugPostsGetQueryParams,
+    MeDraftsGetHeaderParams, MeDraftsGetQueryParams, MeTrashGetHeaderParams, MeTrashGetQueryParams,
+    Post, PostsGetHeaderParams, PostsGetQueryParams, PostsPostHeaderParams,
+    PostsPostIdDeleteHeaderParams, PostsPostIdDeletePathParams, PostsPostIdDiffGetHeaderParams,
+    PostsPostIdDiffGetPathParams, PostsPostIdDiffGetQueryParams, PostsPostIdGetHeaderParams,
+    PostsPostIdGetPathParams, PostsPostIdModerationPutHeaderParams,
+    PostsPostIdModerationPutPathParams, PostsPostIdPatchHeaderParams, PostsPostIdPatchPathParams,
+    PostsPostIdPublishPostHeaderParams, PostsPostIdPublishPostPathParams,
+    PostsPostIdPurgePostHeaderParams, PostsPostIdPurgePostPathParams, PostsPostIdPutHeaderParams,
+    PostsPostIdPutPathParams, PostsPostIdRepliesGetHeaderParams, PostsPostIdRepliesGetPathParams,
+    PostsPostIdRepliesGetQueryParams, PostsPostIdRepliesPostHeaderParams,
+    PostsPostIdRepliesPostPathParams, PostsPostIdRestorePostHeaderParams,
+    PostsPostIdRestorePostPathParams, PostsPostIdRevisionsGetHeaderParams,
+    PostsPostIdRevisionsGetPathParams, PostsPostIdRevisionsGetQueryParams,
+    PostsPostIdRevisionsNumberGetHeaderParams, PostsPostIdRevisionsNumberGetPathParams,
+    PostsPostIdRevisionsNumberRevertPostHeaderParams,
+    PostsPostIdRevisionsNumberRevertPostPathParams, PostsSearchGetHeaderParams,
+    PostsSearchGetQueryParams,
 };
 use openapi::server::new;
+use openapi::types::Nullable;
 use openapi::{
     apis::{
-        auth::{Auth, AuthPostResponse},
+        admin::{
+            Admin, AdminBoardsPostResponse, AdminBoardsSlugDeleteResponse,
+            AdminBoardsSlugPutResponse, AdminUsersUserIdRoleDeleteResponse,
+            AdminUsersUserIdRolePutResponse, AdminWebhookDeliveriesDeliveryIdRetryPostResponse,
+            AdminWebhookDeliveriesGetResponse, AdminWebhooksGetResponse, AdminWebhooksPostResponse,
+            AdminWebhooksWebhookIdDeleteResponse, AdminWebhooksWebhookIdDeliveriesGetResponse,
+        },
+        auth::{
+            Auth, AuthLogoutAllPostResponse, AuthLogoutPostResponse, AuthPostResponse,
+            AuthRefreshPostResponse, GetJwksResponse,
+        },
+        boards::{Boards, BoardsGetResponse, BoardsSlugGetResponse, BoardsSlugPostsGetResponse},
         posts::{
-            Posts, PostsGetResponse, PostsPostIdGetResponse, PostsPostIdPutResponse,
-            PostsPostResponse,
+            MeDraftsGetResponse, MeTrashGetResponse, Posts, PostsGetResponse,
+            PostsPostIdDiffGetResponse, PostsPostIdGetResponse, PostsPostIdModerationPutResponse,
+            PostsPostIdPatchResponse, PostsPostIdPublishPostResponse, PostsPostIdPurgePostResponse,
+            PostsPostIdPutResponse, PostsPostIdRepliesGetResponse, PostsPostIdRepliesPostResponse,
+            PostsPostIdRestorePostResponse, PostsPostIdRevisionsGetResponse,
+            PostsPostIdRevisionsNumberGetResponse, PostsPostIdRevisionsNumberRevertPostResponse,
+            PostsPostResponse, PostsSearchGetResponse,
         },
         users::{Users, UsersPostResponse},
+        ApiError,
     },
     models,
 };
 use password_hash::{PasswordHash, PasswordVerifier};
-use std::sync::{Arc, Mutex};
+use std::collections::HashMap;
+use std::net::{IpAddr, SocketAddr};
+use std::sync::{Arc, OnceLock};
+use uuid::Uuid;
 use validator::Validate;
 
-const SECRET: &str = "secret";
+const X_FORWARDED_FOR: HeaderName = HeaderName::from_static("x-forwarded-for");
 
 #[derive(Clone)]
 struct ApiImpl {
-    users: Arc<Mutex<Vec<entity::User>>>,
-    posts: Arc<Mutex<Vec<entity::Post>>>,
+    users: Arc<dyn UserRepository>,
+    posts: Arc<dyn PostRepository>,
+    boards: Arc<dyn BoardRepository>,
+    refresh_tokens: Arc<dyn RefreshTokenRepository>,
+    revocations: Arc<dyn RevocationRepository>,
+    keys: Arc<KeyRing>,
+    config: Arc<Config>,
+    login_throttle: Arc<LoginThrottle>,
+    dummy_hash: Arc<OnceLock<HashedPassword>>,
+    events: Arc<EventBus>,
+    webhooks: Arc<dyn WebhookRepository>,
+    dispatcher: Arc<WebhookDispatcher>,
 }
 
 impl AsRef<ApiImpl> for ApiImpl {
@@ -44,67 +144,1070 @@ impl AsRef<ApiImpl> for ApiImpl {
     }
 }
 
+impl ApiImpl {
+    fn in_memory(config: Config, keys: KeyRing) -> Self {
+        let webhooks: Arc<dyn WebhookRepository> = Arc::new(InMemoryWebhookRepository::new());
+        Self {
+            users: Arc::new(InMemoryUserRepository::new()),
+            posts: Arc::new(InMemoryPostRepository::new()),
+            boards: Arc::new(InMemoryBoardRepository::new()),
+            refresh_tokens: Arc::new(InMemoryRefreshTokenRepository::new()),
+            revocations: Arc::new(InMemoryRevocationRepository::new()),
+            keys: Arc::new(keys),
+            login_throttle: Arc::new(LoginThrottle::new(config.login.clone())),
+            dummy_hash: Arc::new(OnceLock::new()),
+            events: Arc::new(EventBus::new(config.events.replay_size)),
+            dispatcher: Arc::new(WebhookDispatcher::new(
+                webhooks.clone(),
+                config.webhooks.clone(),
+            )),
+            webhooks,
+            config: Arc::new(config),
+        }
+    }
+
+    fn sqlite(db: Database, config: Config, keys: KeyRing) -> Self {
+        let webhooks: Arc<dyn WebhookRepository> =
+            Arc::new(SqliteWebhookRepository::new(db.clone()));
+        Self {
+            users: Arc::new(SqliteUserRepository::new(db.clone())),
+            posts: Arc::new(SqlitePostRepository::new(db.clone())),
+            boards: Arc::new(SqliteBoardRepository::new(db.clone())),
+            refresh_tokens: Arc::new(SqliteRefreshTokenRepository::new(db.clone())),
+            revocations: Arc::new(SqliteRevocationRepository::new(db)),
+            keys: Arc::new(keys),
+            login_throttle: Arc::new(LoginThrottle::new(config.login.clone())),
+            dummy_hash: Arc::new(OnceLock::new()),
+            events: Arc::new(EventBus::new(config.events.replay_size)),
+            dispatcher: Arc::new(WebhookDispatcher::new(
+                webhooks.clone(),
+                config.webhooks.clone(),
+            )),
+            webhooks,
+            config: Arc::new(config),
+        }
+    }
+
+    /// `Authorization`ヘッダーを検証する。認証に失敗した場合は`WWW-Authenticate`の値を返す
+    fn authenticate(
+        &self,
+        authorization: Option<&str>,
+    ) -> Result<Result<AuthenticatedUser, String>, AppError> {
+        match auth::authenticate(&self.keys, self.revocations.as_ref(), authorization) {
+            Ok(user) => Ok(Ok(user)),
+            Err(AuthError::Repository(e)) => Err(e.into()),
+            Err(e) => {
+                tracing::debug!(error = %e, "authentication failed");
+                Ok(Err(e.challenge()))
+            }
+        }
+    }
+
+    /// ハンドラーを生成しないエンドポイントで`Authorization`ヘッダーまたは`access_token`を検証する
+    ///
+    /// ブラウザーの`EventSource`と`WebSocket`はヘッダーを付けられないため、
+    /// RFC 6750 2.3節のクエリパラメーターでも受け付ける。両方を指定した場合は検証エラーにする
+    fn authenticate_request(
+        &self,
+        headers: &HeaderMap,
+        access_token: Option<&str>,
+    ) -> Result<Result<AuthenticatedUser, String>, AppError> {
+        let authorization = match (headers.get(AUTHORIZATION), access_token) {
+            (Some(_), Some(_)) => return Err(AppError::rejected("access_token", "duplicate")),
+            (Some(value), None) => value.to_str().ok().map(str::to_string),
+            (None, Some(token)) => Some(format!("Bearer {}", token)),
+            (None, None) => None,
+        };
+        self.authenticate(authorization.as_deref())
+    }
+
+    /// アクセストークンと、指定した系列のリフレッシュトークンを発行する
+    fn issue_tokens(
+        &self,
+        user_id: UserId,
+        role: Role,
+        family_id: Uuid,
+    ) -> Result<models::Token, AppError> {
+        let token = self.keys.create_token(
+            &user_id.to_string(),
+            role,
+            self.config.access_token_lifetime(),
+        )?;
+        let refresh_token = refresh_token::issue(
+            self.refresh_tokens.as_ref(),
+            user_id,
+            family_id,
+            self.config.refresh_token_lifetime(),
+        )?;
+        Ok(models::Token {
+            token: Some(token),
+            refresh_token: Some(refresh_token),
+            expires_in: Some(self.config.access_token_lifetime().as_secs() as i64),
+        })
+    }
+
+    /// 失敗した試行で新たにロックしたキーを監査ログに残す
+    fn log_login_failure(&self, attempt: Attempt) {
+        for lockout in attempt.lockouts {
+            tracing::warn!(
+                target: "audit",
+                key = %lockout.key,
+                failures = lockout.failures,
+                until = %lockout.until,
+                "login locked out after repeated failures"
+            );
+        }
+    }
+
+    /// 未登録のメールアドレスとの照合に使うハッシュ。初回に現在の設定で作る
+    fn dummy_hash(&self, argon2: &Argon2<'_>) -> Result<&str, AppError> {
+        if let Some(hash) = self.dummy_hash.get() {
+            return Ok(hash);
+        }
+        let password = Password::parse("dummy password").map_err(|_| AppError::PasswordHash)?;
+        let hash = entity::hash_password(argon2, &password)?;
+        Ok(self.dummy_hash.get_or_init(|| hash))
+    }
+
+    /// 現在の設定でパスワードをハッシュ化し直す。失敗してもログインは妨げない
+    fn rehash_password(&self, user_id: UserId, password: &Password, argon2: &Argon2<'_>) {
+        let result = entity::hash_password(argon2, password)
+            .map_err(|e| e.to_string())
+            .and_then(|hash| {
+                self.users
+                    .set_password(user_id, &hash)
+                    .map_err(|e| e.to_string())
+            });
+        match result {
+            Ok(()) => tracing::info!(user_id, "upgraded password hash"),
+            Err(e) => tracing::warn!(user_id, error = %e, "failed to upgrade password hash"),
+        }
+    }
+
+    /// 作成者の名前を添えて応答用の投稿に変換する
+    fn with_author(&self, post: entity::Post) -> Result<models::Post, AppError> {
+        let author = self.users.find(post.user_id)?;
+        Ok(post_model(post, author.map(|user| user.name.into_string())))
+    }
+
+    /// 複数の投稿を変換する。同じ作成者は一度だけ読み出す
+    fn with_authors(&self, posts: Vec<entity::Post>) -> Result<Vec<models::Post>, AppError> {
+        let mut names: HashMap<UserId, Option<String>> = HashMap::new();
+        posts
+            .into_iter()
+            .map(|post| {
+                let name = match names.get(&post.user_id) {
+                    Some(name) => name.clone(),
+                    None => {
+                        let name = self
+                            .users
+                            .find(post.user_id)?
+                            .map(|user| user.name.into_string());
+                        names.insert(post.user_id, name.clone());
+                        name
+                    }
+                };
+                Ok(post_model(post, name))
+            })
+            .collect()
+    }
+
+    /// 編集者の名前を添えて応答用の版に変換する。同じ編集者は一度だけ読み出す
+    fn with_editors(
+        &self,
+        revisions: Vec<entity::PostRevision>,
+    ) -> Result<Vec<models::PostRevision>, AppError> {
+        let mut names: HashMap<UserId, Option<String>> = HashMap::new();
+        revisions
+            .into_iter()
+            .map(|revision| {
+                let name = match names.get(&revision.edited_by) {
+                    Some(name) => name.clone(),
+                    None => {
+                        let name = self
+                            .users
+                            .find(revision.edited_by)?
+                            .map(|user| user.name.into_string());
+                        names.insert(revision.edited_by, name.clone());
+                        name
+                    }
+                };
+                Ok(revision_model(revision, name))
+            })
+            .collect()
+    }
+
+    /// 投稿のスレッド内での深さ。スレッドの先頭の投稿は0
+    fn depth(&self, post: &entity::Post) -> Result<usize, AppError> {
+        let mut depth = 0;
+        let mut parent_id = post.parent_id;
+        while let Some(id) = parent_id {
+            depth += 1;
+            parent_id = self.posts.find(id)?.and_then(|parent| parent.parent_id);
+        }
+        Ok(depth)
+    }
+
+    /// 投稿が属するスレッドの先頭の投稿のID
+    fn thread_id(&self, post: &entity::Post) -> Result<PostId, RepositoryError> {
+        let mut thread_id = post.id;
+        let mut parent_id = post.parent_id;
+        while let Some(id) = parent_id {
+            thread_id = id;
+            parent_id = self.posts.find(id)?.and_then(|parent| parent.parent_id);
+        }
+        Ok(thread_id)
+    }
+
+    /// 投稿が属するボード。投稿が属するボードは削除できないため、ない場合は壊れた記録として扱う
+    fn board_of(&self, post: &entity::Post) -> Result<entity::Board, RepositoryError> {
+        self.boards.find(post.board_id)?.ok_or_else(|| {
+            RepositoryError::Corrupted(format!(
+                "board {} of post {} is missing",
+                post.board_id, post.id
+            ))
+        })
+    }
+
+    /// 返信を除く公開済みの投稿の1ページを取得する
+    ///
+    /// 次のページがある場合は、同じ条件で`path`から続きを取得する`Link`の値とカーソルを付ける
+    fn list_page(
+        &self,
+        query: &PostsGetQueryParams,
+        board_id: Option<BoardId>,
+        path: &str,
+    ) -> Result<PostPage, AppError> {
+        let sort = match query.sort.unwrap_or(models::PostSort::Newest) {
+            models::PostSort::Newest => PostSort::Newest,
+            models::PostSort::Oldest => PostSort::Oldest,
+            models::PostSort::MostReplied => PostSort::MostReplied,
+        };
+        let after = query
+            .cursor
+            .as_deref()
+            .map(|c| cursor::decode(sort, c))
+            .transpose()
+            .map_err(|_| AppError::rejected("cursor", "invalid"))?;
+        let limit = query.limit.unwrap_or(20) as usize;
+        // 次のページがあるか判定するため1件多く取得する
+        let mut posts = self.posts.list(&PostQuery {
+            sort,
+            board_id,
+            user_id: query.user_id,
+            since: query.since,
+            until: query.until,
+            after,
+            limit: limit + 1,
+        })?;
+        let next_cursor = if posts.len() > limit {
+            posts.truncate(limit);
+            posts
+                .last()
+                .map(|post| cursor::encode(sort, &PostCursor::of(post)))
+        } else {
+            None
+        };
+        let link = next_cursor
+            .as_deref()
+            .map(|next| format!("<{}>; rel=\"next\"", next_page(path, query, limit, next)));
+        Ok(PostPage {
+            posts: self.with_authors(posts)?,
+            link,
+            next_cursor,
+        })
+    }
+
+    /// 利用者が参照できる投稿を読み出す。公開前の投稿は作成者以外には存在しないものとして扱う
+    fn find_visible(
+        &self,
+        user: &AuthenticatedUser,
+        id: PostId,
+    ) -> Result<Option<entity::Post>, RepositoryError> {
+        Ok(self
+            .posts
+            .find(id)?
+            .filter(|post| policy::can_view(user, post)))
+    }
+
+    /// 投稿のイベントを配信する。失敗しても投稿の操作は妨げない
+    ///
+    /// 公開前の投稿のイベントは配信せず、公開した時点で作成のイベントを配信する
+    fn publish(&self, kind: EventKind, post: entity::Post) {
+        if !post.is_published() {
+            return;
+        }
+        let result = self.thread_id(&post).and_then(|thread_id| {
+            let author = self.users.find(post.user_id)?;
+            Ok((thread_id, author.map(|user| user.name.into_string())))
+        });
+        match result {
+            Ok((thread_id, author_name)) => {
+                let event = match kind {
+                    EventKind::PostCreated => WebhookEvent::PostCreated,
+                    EventKind::PostUpdated => WebhookEvent::PostUpdated,
+                    EventKind::PostDeleted => WebhookEvent::PostDeleted,
+                    EventKind::ReplyCreated => WebhookEvent::ReplyCreated,
+                };
+                let model = post_model(post.clone(), author_name.clone());
+                self.events.publish(kind, thread_id, post, author_name);
+                self.notify_webhooks(event, Some(model), None);
+            }
+            Err(e) => tracing::warn!(post_id = %post.id, error = %e, "failed to publish event"),
+        }
+    }
+
+    /// Webhookの配信を記録する。失敗しても元の操作は妨げない
+    fn notify_webhooks(
+        &self,
+        event: WebhookEvent,
+        post: Option<models::Post>,
+        user: Option<entity::User>,
+    ) {
+        let payload = models::WebhookPayload {
+            id: Uuid::new_v4(),
+            r#type: webhook_event_model(event),
+            occurred_at: Utc::now(),
+            post,
+            // メールアドレスは外部に送らない
+            user: user.map(|user| models::User {
+                email: None,
+                ..user_model(user)
+            }),
+        };
+        let payload = serde_json::to_string(&payload).expect("webhook payload is serializable");
+        if let Err(e) = self.dispatcher.enqueue(event, &payload) {
+            tracing::warn!(%event, error = %e, "failed to enqueue webhook deliveries");
+        }
+    }
+
+    /// 投稿を変更して保存する。投稿の版を進める書き込みはすべてここを通す
+    ///
+    /// `actions`をすべて許可された利用者のみ変更できる。`change`は投稿を変更して保存のしかたを返し、
+    /// 現在の状態では変更できない場合は`None`を返す。
+    /// `If-Match`が一致しない場合と、読み出してから保存するまでに他の更新が保存された場合は
+    /// 上書きせず`PreconditionFailed`を返す。保存するまでに削除された場合は`NotFound`を返す
+    fn change_post(
+        &self,
+        user: &AuthenticatedUser,
+        post_id: PostId,
+        actions: &[PostAction],
+        if_match: Option<&str>,
+        change: impl FnOnce(&mut entity::Post) -> Result<Option<PostWrite>, AppError>,
+    ) -> Result<PostEdit, AppError> {
+        let Some(mut post) = self.find_visible(user, post_id)? else {
+            return Ok(PostEdit::NotFound);
+        };
+        if !actions
+            .iter()
+            .all(|&action| policy::can(user, action, &post))
+        {
+            return Ok(PostEdit::Forbidden);
+        }
+        if if_match.is_some_and(|if_match| !etag::if_match(if_match, post.version)) {
+            return Ok(PostEdit::PreconditionFailed);
+        }
+        let Some(write) = change(&mut post)? else {
+            return Ok(PostEdit::Conflict);
+        };
+        let saved = match write {
+            PostWrite::Edit => self.posts.edit(post.clone(), user.id),
+            PostWrite::Update | PostWrite::Publish => self.posts.update(post.clone()),
+        };
+        match saved {
+            Ok(true) => {}
+            Ok(false) => return Ok(PostEdit::NotFound),
+            Err(RepositoryError::Stale) => return Ok(PostEdit::PreconditionFailed),
+            Err(e) => return Err(e.into()),
+        }
+        let kind = match write {
+            PostWrite::Publish => EventKind::PostCreated,
+            PostWrite::Edit | PostWrite::Update => EventKind::PostUpdated,
+        };
+        self.publish(kind, post.clone());
+        Ok(PostEdit::Edited(post))
+    }
+
+    /// 投稿のタイトルと内容を編集し、編集者による版として記録する
+    ///
+    /// `changes`は編集前の投稿から新しいタイトルと内容を求める
+    fn edit_post(
+        &self,
+        user: &AuthenticatedUser,
+        post_id: PostId,
+        if_match: Option<&str>,
+        changes: impl FnOnce(&entity::Post) -> Result<(Title, Content), AppError>,
+    ) -> Result<PostEdit, AppError> {
+        self.change_post(user, post_id, &[PostAction::Edit], if_match, |post| {
+            let (title, content) = changes(post)?;
+            check_content(&self.board_of(post)?, &content)?;
+            post.edit(title, content);
+            Ok(Some(PostWrite::Edit))
+        })
+    }
+
+    /// 公開する日時を過ぎた公開予定の投稿を公開し、作成のイベントを配信する。公開した件数を返す
+    fn publish_due_posts(&self, now: DateTime<Utc>) -> Result<usize, RepositoryError> {
+        let published = self.posts.publish_due(now)?;
+        let count = published.len();
+        for post in published {
+            self.publish(EventKind::PostCreated, post);
+        }
+        Ok(count)
+    }
+
+    /// 保持する期間を過ぎたゴミ箱の投稿を完全に削除し、削除した件数を返す
+    fn purge_trash(&self, now: DateTime<Utc>) -> Result<usize, RepositoryError> {
+        let retention = self.config.posts.trash_retention.min(i64::MAX as u64) as i64;
+        // 期間が日時の範囲を超える場合は削除する投稿がない
+        match chrono::Duration::try_seconds(retention)
+            .and_then(|retention| now.checked_sub_signed(retention))
+        {
+            Some(before) => self.posts.purge_trash(before),
+            None => Ok(0),
+        }
+    }
+
+    /// ロールを変更する。変更前に発行したアクセストークンは失効させ、新しいロールで再発行させる
+    fn change_role(
+        &self,
+        user: &AuthenticatedUser,
+        user_id: UserId,
+        role: Role,
+    ) -> Result<RoleChange, AppError> {
+        if !policy::can_manage_roles(user) {
+            return Ok(RoleChange::Forbidden);
+        }
+        // 自分自身の権限を外して管理者が不在になることを防ぐ
+        if user_id == user.id {
+            return Err(AppError::rejected("userId", "self"));
+        }
+        let Some(updated) = self.users.set_role(user_id, role)? else {
+            return Ok(RoleChange::NotFound);
+        };
+        self.revocations.revoke_user(user_id, Utc::now())?;
+        tracing::info!(user_id, role = %role, by = user.id, "changed user role");
+        self.notify_webhooks(WebhookEvent::UserRoleChanged, None, Some(updated.clone()));
+        Ok(RoleChange::Changed(user_model(updated)))
+    }
+}
+
+/// `header_params`の`Authorization`を検証して利用者を求める。認証できない場合は
+/// `WWW-Authenticate`を付けた`$response::$unauthorized`の応答を返す
+macro_rules! authenticate {
+    ($api:expr, $header_params:expr, $response:ident::$unauthorized:ident) => {
+        match $api.authenticate($header_params.authorization.as_deref())? {
+            Ok(user) => user,
+            Err(www_authenticate) => return Ok($response::$unauthorized { www_authenticate }),
+        }
+    };
+}
+
+struct PostPage {
+    posts: Vec<models::Post>,
+    link: Option<String>,
+    next_cursor: Option<String>,
+}
+
+enum PostEdit {
+    Edited(entity::Post),
+    Forbidden,
+    NotFound,
+    /// 現在の状態では変更できない
+    Conflict,
+    PreconditionFailed,
+}
+
+/// 変更した投稿の保存のしかた
+enum PostWrite {
+    /// タイトルと内容の変更。編集者による版として記録する
+    Edit,
+    /// 版を記録しない状態の変更
+    Update,
+    /// 公開または公開予定への変更。作成のイベントを配信する
+    Publish,
+}
+
+enum RoleChange {
+    Changed(models::User),
+    Forbidden,
+    NotFound,
+}
+
+fn user_model(user: entity::User) -> models::User {
+    models::User {
+        id: Some(user.id),
+        name: Some(user.name.into_string()),
+        email: Some(user.email.into_string()),
+        password: None,
+        role: Some(role_model(user.role)),
+        created_at: Some(user.created_at),
+        last_login_at: user.last_login_at,
+    }
+}
+
+fn role_model(role: Role) -> models::Role {
+    match role {
+        Role::Member => models::Role::Member,
+        Role::Moderator => models::Role::Moderator,
+        Role::Admin => models::Role::Admin,
+    }
+}
+
+fn role(role: models::Role) -> Role {
+    match role {
+        models::Role::Member => Role::Member,
+        models::Role::Moderator => Role::Moderator,
+        models::Role::Admin => Role::Admin,
+    }
+}
+
+/// 投稿のタイトルと内容を検証する。ない項目は必須のエラーにする
+fn parse_post(title: Option<&str>, content: Option<&str>) -> Result<(Title, Content), AppError> {
+    post_fields(
+        title.map_or(Err(ValueError::Empty), Title::parse),
+        content.map_or(Err(ValueError::Empty), Content::parse),
+    )
+}
+
+/// JSON Merge Patchを適用した投稿のタイトルと内容を求める
+///
+/// `id`と`userId`は現在と同じ値のみ指定できる。タイトルと内容は必須のため`null`で消すことはできない
+fn apply_patch(
+    post: &entity::Post,
+    patch: &models::PostPatch,
+) -> Result<(Title, Content), AppError> {
+    let read_only: Vec<_> = [
+        (
+            "id",
+            patch.id.is_some_and(|id| id != Nullable::Present(post.id)),
+        ),
+        (
+            "userId",
+            patch
+                .user_id
+                .is_some_and(|user_id| user_id != Nullable::Present(post.user_id)),
+        ),
+    ]
+    .into_iter()
+    .filter_map(|(field, changed)| changed.then_some(field))
+    .collect();
+    if !read_only.is_empty() {
+        return Err(AppError::read_only(read_only));
+    }
+    let title = match &patch.title {
+        None => Ok(post.title.clone()),
+        Some(Nullable::Null) => Err(ValueError::Empty),
+        Some(Nullable::Present(title)) => Title::parse(title),
+    };
+    let content = match &patch.content {
+        None => Ok(post.content.clone()),
+        Some(Nullable::Null) => Err(ValueError::Empty),
+        Some(Nullable::Present(content)) => Content::parse(content),
+    };
+    post_fields(title, content)
+}
+
+/// 作成する投稿の公開状態と公開する日時を求める
+///
+/// `status`を省略した場合は、`publish_at`を指定すると公開予定、指定しないとすぐに公開する。
+/// 公開予定の投稿は現在より後の日時に限り、それ以外では日時を指定できない
+fn parse_publication(
+    status: Option<models::PostStatus>,
+    publish_at: Option<DateTime<Utc>>,
+    now: DateTime<Utc>,
+) -> Result<(PostStatus, Option<DateTime<Utc>>), AppError> {
+    let status = match status {
+        Some(status) => post_status(status),
+        None if publish_at.is_some() => PostStatus::Scheduled,
+        None => PostStatus::Published,
+    };
+    match (status, publish_at) {
+        (PostStatus::Scheduled, None) => Err(AppError::required("publishAt")),
+        (PostStatus::Scheduled, Some(at)) if at <= now => {
+            Err(AppError::rejected("publishAt", "past"))
+        }
+        (PostStatus::Draft | PostStatus::Published, Some(_)) => {
+            Err(AppError::rejected("publishAt", "not_allowed"))
+        }
+        (status, publish_at) => Ok((status, publish_at)),
+    }
+}
+
+/// 投稿の内容がボードの最大の文字数以下か確かめる
+fn check_content(board: &entity::Board, content: &Content) -> Result<(), AppError> {
+    board
+        .settings
+        .check_content(content)
+        .map_err(|e| AppError::invalid([("content", Some(&e))]))
+}
+
+/// ボードの作成と更新のリクエストを検証する。省略した設定は既定値にする
+fn parse_board(body: &models::AdminBoardsPostRequest) -> Result<BoardFields, AppError> {
+    let slug = BoardSlug::parse(&body.slug);
+    let name = BoardName::parse(&body.name);
+    // 空の説明は省略と同じに扱う
+    let description = body
+        .description
+        .as_deref()
+        .filter(|description| !description.trim().is_empty())
+        .map(BoardDescription::parse)
+        .transpose();
+    match (slug, name, description) {
+        (Ok(slug), Ok(name), Ok(description)) => Ok(BoardFields {
+            slug,
+            name,
+            description,
+            settings: board_settings(body.settings.as_ref()),
+        }),
+        (slug, name, description) => Err(AppError::invalid([
+            ("slug", slug.as_ref().err()),
+            ("name", name.as_ref().err()),
+            ("description", description.as_ref().err()),
+        ])),
+    }
+}
+
+struct BoardFields {
+    slug: BoardSlug,
+    name: BoardName,
+    description: Option<BoardDescription>,
+    settings: entity::BoardSettings,
+}
+
+fn board_settings(settings: Option<&models::BoardSettings>) -> entity::BoardSettings {
+    let defaults = entity::BoardSettings::default();
+    let Some(settings) = settings else {
+        return defaults;
+    };
+    entity::BoardSettings {
+        posting_role: settings.posting_role.map_or(defaults.posting_role, role),
+        allow_replies: settings.allow_replies.unwrap_or(defaults.allow_replies),
+        // 範囲はバリデーション済み
+        max_post_length: settings
+            .max_post_length
+            .map_or(defaults.max_post_length, |max| max as usize),
+    }
+}
+
+fn board_model(board: entity::Board) -> models::Board {
+    models::Board {
+        id: board.id,
+        slug: board.slug.into_string(),
+        name: board.name.into_string(),
+        description: board.description.map(BoardDescription::into_string),
+        settings: models::BoardSettings {
+            posting_role: Some(role_model(board.settings.posting_role)),
+            allow_replies: Some(board.settings.allow_replies),
+            max_post_length: Some(board.settings.max_post_length as i32),
+        },
+        created_at: board.created_at,
+        updated_at: board.updated_at,
+    }
+}
+
+fn post_fields(
+    title: Result<Title, ValueError>,
+    content: Result<Content, ValueError>,
+) -> Result<(Title, Content), AppError> {
+    match (title, content) {
+        (Ok(title), Ok(content)) => Ok((title, content)),
+        (title, content) => Err(AppError::invalid([
+            ("title", title.as_ref().err()),
+            ("content", content.as_ref().err()),
+        ])),
+    }
+}
+
+fn post_model(post: entity::Post, author_name: Option<String>) -> models::Post {
+    models::Post {
+        id: Some(post.id),
+        title: Some(post.title.into_string()),
+        content: Some(post.content.into_string()),
+        user_id: Some(post.user_id),
+        author_name,
+        locked: Some(post.locked),
+        pinned: Some(post.pinned),
+        parent_id: post.parent_id,
+        reply_count: Some(post.reply_count as i64),
+        created_at: Some(post.created_at),
+        updated_at: Some(post.updated_at),
+        edited_count: Some(post.edited_count as i64),
+        last_activity_at: Some(post.last_activity_at),
+        version: Some(post.version as i64),
+        deleted_at: post.deleted_at,
+        status: Some(post_status_model(post.status)),
+        publish_at: post.publish_at,
+        board_id: Some(post.board_id),
+    }
+}
+
+fn revision_model(
+    revision: entity::PostRevision,
+    editor_name: Option<String>,
+) -> models::PostRevision {
+    models::PostRevision {
+        number: revision.number as i32,
+        title: revision.title.into_string(),
+        content: revision.content.into_string(),
+        edited_by: revision.edited_by,
+        editor_name,
+        created_at: revision.created_at,
+    }
+}
+
+fn diff_line_models(lines: Vec<DiffLine>) -> Vec<models::DiffLine> {
+    lines
+        .into_iter()
+        .map(|line| {
+            let op = match line.op {
+                DiffOp::Equal => models::DiffOp::Equal,
+                DiffOp::Insert => models::DiffOp::Insert,
+                DiffOp::Delete => models::DiffOp::Delete,
+            };
+            models::DiffLine::new(op, line.text)
+        })
+        .collect()
+}
+
+fn event_model(event: &BoardEvent) -> models::BoardEvent {
+    models::BoardEvent {
+        id: event.id as i64,
+        r#type: match event.kind {
+            EventKind::PostCreated => models::BoardEventType::PostCreated,
+            EventKind::PostUpdated => models::BoardEventType::PostUpdated,
+            EventKind::PostDeleted => models::BoardEventType::PostDeleted,
+            EventKind::ReplyCreated => models::BoardEventType::ReplyCreated,
+        },
+        thread_id: event.thread_id,
+        post: post_model(event.post.clone(), event.author_name.clone()),
+        occurred_at: event.occurred_at,
+    }
+}
+
+fn post_status_model(status: PostStatus) -> models::PostStatus {
+    match status {
+        PostStatus::Draft => models::PostStatus::Draft,
+        PostStatus::Scheduled => models::PostStatus::Scheduled,
+        PostStatus::Published => models::PostStatus::Published,
+    }
+}
+
+fn post_status(status: models::PostStatus) -> PostStatus {
+    match status {
+        models::PostStatus::Draft => PostStatus::Draft,
+        models::PostStatus::Scheduled => PostStatus::Scheduled,
+        models::PostStatus::Published => PostStatus::Published,
+    }
+}
+
+fn webhook_event_model(event: WebhookEvent) -> models::WebhookEventType {
+    match event {
+        WebhookEvent::PostCreated => models::WebhookEventType::PostCreated,
+        WebhookEvent::PostUpdated => models::WebhookEventType::PostUpdated,
+        WebhookEvent::PostDeleted => models::WebhookEventType::PostDeleted,
+        WebhookEvent::ReplyCreated => models::WebhookEventType::ReplyCreated,
+        WebhookEvent::UserCreated => models::WebhookEventType::UserCreated,
+        WebhookEvent::UserRoleChanged => models::WebhookEventType::UserRoleChanged,
+    }
+}
+
+fn webhook_event(event: models::WebhookEventType) -> WebhookEvent {
+    match event {
+        models::WebhookEventType::PostCreated => WebhookEvent::PostCreated,
+        models::WebhookEventType::PostUpdated => WebhookEvent::PostUpdated,
+        models::WebhookEventType::PostDeleted => WebhookEvent::PostDeleted,
+        models::WebhookEventType::ReplyCreated => WebhookEvent::ReplyCreated,
+        models::WebhookEventType::UserCreated => WebhookEvent::UserCreated,
+        models::WebhookEventType::UserRoleChanged => WebhookEvent::UserRoleChanged,
+    }
+}
+
+fn delivery_status(status: models::WebhookDeliveryStatus) -> DeliveryStatus {
+    match status {
+        models::WebhookDeliveryStatus::Pending => DeliveryStatus::Pending,
+        models::WebhookDeliveryStatus::Succeeded => DeliveryStatus::Succeeded,
+        models::WebhookDeliveryStatus::Dead => DeliveryStatus::Dead,
+    }
+}
+
+/// 署名の鍵は登録時の応答にのみ含める
+fn webhook_model(webhook: entity::Webhook, with_secret: bool) -> models::Webhook {
+    models::Webhook {
+        id: webhook.id,
+        url: webhook.url.into_string(),
+        events: webhook
+            .events
+            .into_iter()
+            .map(webhook_event_model)
+            .collect(),
+        secret: with_secret.then_some(webhook.secret),
+        created_by: webhook.created_by,
+        created_at: webhook.created_at,
+    }
+}
+
+fn delivery_model(delivery: entity::WebhookDelivery) -> models::WebhookDelivery {
+    models::WebhookDelivery {
+        id: delivery.id,
+        webhook_id: delivery.webhook_id,
+        event: webhook_event_model(delivery.event),
+        status: match delivery.status {
+            DeliveryStatus::Pending => models::WebhookDeliveryStatus::Pending,
+            DeliveryStatus::Succeeded => models::WebhookDeliveryStatus::Succeeded,
+            DeliveryStatus::Dead => models::WebhookDeliveryStatus::Dead,
+        },
+        attempts: delivery.attempts as i32,
+        next_attempt_at: (delivery.status == DeliveryStatus::Pending)
+            .then_some(delivery.next_attempt_at),
+        last_attempt_at: delivery.last_attempt_at,
+        last_status_code: delivery.last_status_code.map(i32::from),
+        last_error: delivery.last_error,
+        created_at: delivery.created_at,
+    }
+}
+
+fn fragment_models(fragments: Vec<Fragment>) -> Vec<models::TextFragment> {
+    fragments
+        .into_iter()
+        .map(|fragment| models::TextFragment::new(fragment.text, fragment.highlighted))
+        .collect()
+}
+
+/// ハンドラーを生成しないエンドポイントの401応答
+fn unauthorized(www_authenticate: String) -> Response {
+    (
+        StatusCode::UNAUTHORIZED,
+        [(WWW_AUTHENTICATE, www_authenticate)],
+    )
+        .into_response()
+}
+
+/// ハンドラーを生成しないエンドポイントで`AppError`を返す。内部のエラーはログに残す
+fn error_response(error: AppError) -> Response {
+    let problem = error.problem();
+    if problem.status >= 500 {
+        tracing::error!(error = %error);
+    }
+    problem_response(problem)
+}
+
+/// `application/problem+json`のエラー応答
+fn problem_response(problem: models::Problem) -> Response {
+    let status =
+        StatusCode::from_u16(problem.status as u16).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+    (
+        status,
+        [(CONTENT_TYPE, "application/problem+json")],
+        axum::Json(problem),
+    )
+        .into_response()
+}
+
+/// 同じ条件で`path`から次のページを取得するURL
+fn next_page(path: &str, query: &PostsGetQueryParams, limit: usize, cursor: &str) -> String {
+    let mut params = vec![format!("limit={}", limit)];
+    if let Some(sort) = query.sort {
+        params.push(format!("sort={}", sort));
+    }
+    if let Some(user_id) = query.user_id {
+        params.push(format!("userId={}", user_id));
+    }
+    // `+`を含まないようUTCは`Z`で表す
+    if let Some(since) = query.since {
+        params.push(format!(
+            "since={}",
+            since.to_rfc3339_opts(SecondsFormat::AutoSi, true)
+        ));
+    }
+    if let Some(until) = query.until {
+        params.push(format!(
+            "until={}",
+            until.to_rfc3339_opts(SecondsFormat::AutoSi, true)
+        ));
+    }
+    params.push(format!("cursor={}", cursor));
+    format!("{}?{}", path, params.join("&"))
+}
+
+/// `X-Forwarded-For`の最後のアドレス。信頼するプロキシまたは`forwarded_for`が付けたもの
+fn client_ip(forwarded_for: Option<&str>) -> Option<IpAddr> {
+    forwarded_for?.rsplit(',').next()?.trim().parse().ok()
+}
+
+/// `Retry-After`に載せる秒数。端数は切り上げる
+fn retry_after(remaining: chrono::Duration) -> i64 {
+    (remaining.num_milliseconds() + 999).div_euclid(1000).max(1)
+}
+
+/// 信頼するプロキシがない場合、クライアントが送った`X-Forwarded-For`を接続元のアドレスで置き換える
+async fn forwarded_for(
+    State(trust_proxy): State<bool>,
+    mut request: Request,
+    next: Next,
+) -> Response {
+    if !trust_proxy {
+        let peer = request
+            .extensions()
+            .get::<ConnectInfo<SocketAddr>>()
+            .map(|ConnectInfo(addr)| addr.ip().to_string());
+        let headers = request.headers_mut();
+        headers.remove(X_FORWARDED_FOR);
+        if let Some(value) = peer.and_then(|peer| HeaderValue::from_str(&peer).ok()) {
+            headers.insert(X_FORWARDED_FOR, value);
+        }
+    }
+    next.run(request).await
+}
+
+/// 設定されたメールアドレスの既存ユーザーを管理者に昇格する
+fn promote_admins(users: &dyn UserRepository, config: &Config) -> Result<(), RepositoryError> {
+    for email in &config.users.admin_emails {
+        let Some(user) = users.find_by_email(&normalize_email(email))? else {
+            continue;
+        };
+        if user.role != Role::Admin {
+            users.set_role(user.id, Role::Admin)?;
+            tracing::info!(user_id = user.id, "promoted configured admin");
+        }
+    }
+    Ok(())
+}
+
+/// メモリ上のストレージは起動時に空のため、設定されたパスワードで管理者を作成する
+///
+/// 登録では管理者を付与しないため、これがメモリ上のストレージで管理者を得る唯一の経路になる
+fn create_admins(users: &dyn UserRepository, config: &Config) -> Result<(), AppError> {
+    let Some(password) = &config.users.admin_password else {
+        if !config.users.admin_emails.is_empty() {
+            tracing::warn!("users.admin_password is not set, no admin is created in memory");
+        }
+        return Ok(());
+    };
+    let password = Password::parse(password).expect("admin password is validated on load");
+    for email in &config.users.admin_emails {
+        let Ok(email) = Email::parse(email) else {
+            tracing::warn!(email, "skipped invalid admin email");
+            continue;
+        };
+        let user = NewUser::new(
+            Name::parse("admin").expect("name is valid"),
+            email,
+            password.clone(),
+            Role::Admin,
+            &config.argon2(),
+        )?;
+        match users.create(user) {
+            Ok(user) => tracing::info!(user_id = user.id, "created configured admin"),
+            Err(RepositoryError::Conflict) => {}
+            Err(e) => return Err(e.into()),
+        }
+    }
+    Ok(())
+}
+
 #[async_trait]
 impl Users for ApiImpl {
+    type Error = AppError;
+
     async fn users_post(
         &self,
         _method: Method,
         _host: Host,
         _cookies: CookieJar,
         body: Option<models::UsersPostRequest>,
-    ) -> Result<UsersPostResponse, String> {
-        let body = body.ok_or("body is required")?;
-        body.validate().map_err(|e| e.to_string())?;
-        // user idはusersの長さ+1
-        let user = User::new(
-            self.users.lock().unwrap().len() as i64 + 1,
-            body.name.clone(),
-            body.email.clone(),
-            body.password.clone(),
-        )
-        .map_err(|e| e.to_string())?;
-        println!("{:?}", user);
+    ) -> Result<UsersPostResponse, AppError> {
+        let body = body.ok_or(AppError::MissingBody)?;
+        body.validate()?;
+        let (name, email, password) = match (
+            Name::parse(&body.name),
+            Email::parse(&body.email),
+            Password::parse(&body.password),
+        ) {
+            (Ok(name), Ok(email), Ok(password)) => (name, email, password),
+            (name, email, password) => {
+                return Err(AppError::invalid([
+                    ("name", name.as_ref().err()),
+                    ("email", email.as_ref().err()),
+                    ("password", password.as_ref().err()),
+                ]))
+            }
+        };
+        let violations = password::check(&self.config.password, &password, &name, &email);
+        if !violations.is_empty() {
+            return Err(AppError::weak_password(&violations));
+        }
+        let user = NewUser::new(name, email, password, Role::Member, &self.config.argon2())?;
 
-        let mut users = self.users.lock().unwrap();
-        users.push(user.clone());
+        // user idはリポジトリが採番する
+        let user = match self.users.create(user) {
+            Ok(user) => user,
+            Err(RepositoryError::Conflict) => return Ok(UsersPostResponse::Status409),
+            Err(e) => return Err(e.into()),
+        };
+        self.notify_webhooks(WebhookEvent::UserCreated, None, Some(user.clone()));
 
         Ok(UsersPostResponse::Status201_UserCreatedSuccessfully(
-            models::User {
-                id: Some(user.id),
-                name: Some(body.name),
-                email: Some(body.email),
-                password: None,
-            },
+            user_model(user),
         ))
     }
 }
 
 #[async_trait]
 impl Posts for ApiImpl {
+    async fn me_drafts_get(
+        &self,
+        _method: Method,
+        _host: Host,
+        _cookies: CookieJar,
+        header_params: MeDraftsGetHeaderParams,
+        query_params: MeDraftsGetQueryParams,
+    ) -> Result<MeDraftsGetResponse, AppError> {
+        let user = authenticate!(self, header_params, MeDraftsGetResponse::Status401);
+        // 範囲はバリデーション済み
+        let limit = query_params.limit.unwrap_or(20) as usize;
+        let offset = query_params.offset.unwrap_or(0) as usize;
+        let posts = self.posts.list_drafts(user.id, limit, offset)?;
+        Ok(MeDraftsGetResponse::Status200_ListOfPosts(
+            self.with_authors(posts)?,
+        ))
+    }
+
+    async fn me_trash_get(
+        &self,
+        _method: Method,
+        _host: Host,
+        _cookies: CookieJar,
+        header_params: MeTrashGetHeaderParams,
+        query_params: MeTrashGetQueryParams,
+    ) -> Result<MeTrashGetResponse, AppError> {
+        let user = authenticate!(self, header_params, MeTrashGetResponse::Status401);
+        // 範囲はバリデーション済み
+        let limit = query_params.limit.unwrap_or(20) as usize;
+        let offset = query_params.offset.unwrap_or(0) as usize;
+        let posts = self.posts.list_trash(user.id, limit, offset)?;
+        Ok(MeTrashGetResponse::Status200_ListOfPosts(
+            self.with_authors(posts)?,
+        ))
+    }
+
+    type Error = AppError;
+
     async fn posts_get(
         &self,
         _method: Method,
         _host: Host,
         _cookies: CookieJar,
         header_params: PostsGetHeaderParams,
-    ) -> Result<PostsGetResponse, String> {
-        println!("{:?}", _host);
-        let jwt = header_params.authorization.replace("Bearer ", "");
-        match jwt::validate_token(SECRET.as_ref(), &jwt) {
-            Ok(_) => {}
-            Err(_) => return Ok(PostsGetResponse::Status401),
-        };
-        let posts_locked = self.posts.lock().unwrap();
-        let posts = posts_locked
-            .iter()
-            .map(|post| models::Post {
-                id: Some(post.id),
-                title: Some(post.title.clone()),
-                content: Some(post.content.clone()),
-                user_id: Some(post.user_id),
-            })
-            .collect();
-        Ok(PostsGetResponse::Status200_ListOfPosts(posts))
+        query_params: PostsGetQueryParams,
+    ) -> Result<PostsGetResponse, AppError> {
+        let _user = authenticate!(self, header_params, PostsGetResponse::Status401);
+        let page = self.list_page(&query_params, None, "/posts")?;
+        Ok(PostsGetResponse::Status200_ListOfPosts {
+            body: page.posts,
+            link: page.link,
+            x_next_cursor: page.next_cursor,
+        })
     }
 
     async fn posts_post(
@@ -114,29 +1217,32 @@ impl Posts for ApiImpl {
         _cookies: CookieJar,
         header_params: PostsPostHeaderParams,
         body: std::option::Option<openapi::models::PostsPostRequest>,
-    ) -> Result<PostsPostResponse, String> {
-        println!("{:?}", body);
-        let jwt = header_params.authorization.replace("Bearer ", "");
-
-        let Ok(jwt) = jwt::validate_token(SECRET.as_ref(), &jwt) else {
-            return Ok(PostsPostResponse::Status401);
-        };
-        let body = body.ok_or("body is required")?;
-        body.validate().map_err(|e| e.to_string())?;
-        let post = entity::Post {
-            id: PostId::new_v4(),
-            user_id: jwt.uid.parse().unwrap(),
-            title: body.title.clone(),
-            content: body.content.clone(),
-        };
-        let mut posts_locked = self.posts.lock().unwrap();
-        posts_locked.push(post.clone());
-        Ok(PostsPostResponse::Status201_PostCreated(models::Post {
-            id: Some(post.id),
-            title: Some(post.title),
-            content: Some(post.content),
-            user_id: Some(post.user_id),
-        }))
+    ) -> Result<PostsPostResponse, AppError> {
+        let user = authenticate!(self, header_params, PostsPostResponse::Status401);
+        let body = body.ok_or(AppError::MissingBody)?;
+        body.validate()?;
+        let board_id = body.board_id.ok_or_else(|| AppError::required("boardId"))?;
+        let Some(board) = self.boards.find(board_id)? else {
+            return Err(AppError::rejected("boardId", "not_found"));
+        };
+        if !policy::can_post(&user, &board) {
+            return Ok(PostsPostResponse::Status403);
+        }
+        let (title, content) = parse_post(Some(&body.title), Some(&body.content))?;
+        check_content(&board, &content)?;
+        let post = match parse_publication(body.status, body.publish_at, Utc::now())? {
+            (PostStatus::Published, _) => {
+                entity::Post::new(user.id, board.id, title, content, None)
+            }
+            (_, publish_at) => {
+                entity::Post::unpublished(user.id, board.id, title, content, publish_at)
+            }
+        };
+        self.posts.insert(post.clone())?;
+        self.publish(EventKind::PostCreated, post.clone());
+        Ok(PostsPostResponse::Status201_PostCreated(
+            self.with_author(post)?,
+        ))
     }
 
     async fn posts_post_id_delete(
@@ -146,51 +1252,250 @@ impl Posts for ApiImpl {
         _cookies: CookieJar,
         header_params: PostsPostIdDeleteHeaderParams,
         path_params: PostsPostIdDeletePathParams,
-    ) -> Result<PostsPostIdDeleteResponse, String> {
-        let jwt = header_params.authorization.replace("Bearer ", "");
-        let Ok(jwt) = jwt::validate_token(SECRET.as_ref(), &jwt) else {
-            return Ok(PostsPostIdDeleteResponse::Status401_Unauthorized);
-        };
+    ) -> Result<PostsPostIdDeleteResponse, AppError> {
+        let user = authenticate!(
+            self,
+            header_params,
+            PostsPostIdDeleteResponse::Status401_Unauthorized
+        );
         let post_id = path_params.post_id;
-        let mut posts_locked = self.posts.lock().unwrap();
-        let post = posts_locked.iter().find(|post| post.id == post_id);
-        if let Some(post) = post {
-            if post.user_id == jwt.uid.parse::<i64>().unwrap() {
-                posts_locked.retain(|post| post.id != post_id);
+        let post = self.find_visible(&user, post_id)?;
+        if let Some(mut post) = post {
+            if policy::can(&user, PostAction::Delete, &post) {
+                if let Some(if_match) = header_params.if_match.as_deref() {
+                    if !etag::if_match(if_match, post.version) {
+                        return Ok(PostsPostIdDeleteResponse::Status412_PreconditionFailed);
+                    }
+                }
+                post.trash(user.id);
+                // 読み出してから保存するまでに他の更新が保存された場合も削除しない
+                match self.posts.trash(post.clone()) {
+                    Err(RepositoryError::Stale) => {
+                        return Ok(PostsPostIdDeleteResponse::Status412_PreconditionFailed)
+                    }
+                    result => result?,
+                };
+                self.publish(EventKind::PostDeleted, post);
                 Ok(PostsPostIdDeleteResponse::Status204_NoContent)
             } else {
-                Ok(PostsPostIdDeleteResponse::Status401_Unauthorized)
+                Ok(PostsPostIdDeleteResponse::Status403_Forbidden)
             }
         } else {
             Ok(PostsPostIdDeleteResponse::Status404_NotFound)
         }
     }
 
-    async fn posts_post_id_get(
+    async fn posts_post_id_diff_get(
         &self,
         _method: Method,
         _host: Host,
         _cookies: CookieJar,
-        header_params: PostsPostIdGetHeaderParams,
-        path_params: PostsPostIdGetPathParams,
-    ) -> Result<PostsPostIdGetResponse, String> {
-        let jwt = header_params.authorization.replace("Bearer ", "");
-        let Ok(_jwt) = jwt::validate_token(SECRET.as_ref(), &jwt) else {
-            return Ok(PostsPostIdGetResponse::Status401);
-        };
+        header_params: PostsPostIdDiffGetHeaderParams,
+        path_params: PostsPostIdDiffGetPathParams,
+        query_params: PostsPostIdDiffGetQueryParams,
+    ) -> Result<PostsPostIdDiffGetResponse, AppError> {
+        let user = authenticate!(self, header_params, PostsPostIdDiffGetResponse::Status401);
         let post_id = path_params.post_id;
-        let posts_locked = self.posts.lock().unwrap();
-        let post = posts_locked.iter().find(|post| post.id == post_id);
-        if let Some(post) = post {
-            Ok(PostsPostIdGetResponse::Status200_PostCreated(Post {
-                id: Some(post.id),
-                title: Some(post.title.clone()),
-                content: Some(post.content.clone()),
-                user_id: Some(post.user_id),
-            }))
-        } else {
-            Ok(PostsPostIdGetResponse::Status404)
+        let Some(post) = self.find_visible(&user, post_id)? else {
+            return Ok(PostsPostIdDiffGetResponse::Status404);
+        };
+        // 範囲はバリデーション済み
+        let from = query_params.from as u32;
+        let to = query_params
+            .to
+            .map_or(post.edited_count + 1, |to| to as u32);
+        let (Some(from), Some(to)) = (
+            self.posts.find_revision(post_id, from)?,
+            self.posts.find_revision(post_id, to)?,
+        ) else {
+            return Ok(PostsPostIdDiffGetResponse::Status404);
+        };
+        Ok(PostsPostIdDiffGetResponse::Status200(
+            models::RevisionDiff {
+                from: from.number as i32,
+                to: to.number as i32,
+                title: diff_line_models(diff::diff_lines(from.title.as_str(), to.title.as_str())),
+                content: diff_line_models(diff::diff_lines(
+                    from.content.as_str(),
+                    to.content.as_str(),
+                )),
+            },
+        ))
+    }
+
+    async fn posts_post_id_get(
+        &self,
+        _method: Method,
+        _host: Host,
+        _cookies: CookieJar,
+        header_params: PostsPostIdGetHeaderParams,
+        path_params: PostsPostIdGetPathParams,
+    ) -> Result<PostsPostIdGetResponse, AppError> {
+        let user = authenticate!(self, header_params, PostsPostIdGetResponse::Status401);
+        let post_id = path_params.post_id;
+        let post = self.find_visible(&user, post_id)?;
+        if let Some(post) = post {
+            let e_tag = etag::entity_tag(post.version);
+            if let Some(if_none_match) = header_params.if_none_match.as_deref() {
+                if etag::if_none_match(if_none_match, post.version) {
+                    return Ok(PostsPostIdGetResponse::Status304 { e_tag });
+                }
+            }
+            Ok(PostsPostIdGetResponse::Status200_PostCreated {
+                body: self.with_author(post)?,
+                e_tag,
+            })
+        } else {
+            Ok(PostsPostIdGetResponse::Status404)
+        }
+    }
+
+    async fn posts_post_id_moderation_put(
+        &self,
+        _method: Method,
+        _host: Host,
+        _cookies: CookieJar,
+        header_params: PostsPostIdModerationPutHeaderParams,
+        path_params: PostsPostIdModerationPutPathParams,
+        body: Option<models::PostsPostIdModerationPutRequest>,
+    ) -> Result<PostsPostIdModerationPutResponse, AppError> {
+        let user = authenticate!(
+            self,
+            header_params,
+            PostsPostIdModerationPutResponse::Status401
+        );
+        let body = body.ok_or(AppError::MissingBody)?;
+        let mut actions = vec![];
+        if body.locked.is_some() {
+            actions.push(PostAction::Lock);
+        }
+        if body.pinned.is_some() {
+            actions.push(PostAction::Pin);
+        }
+        // 権限の確認が空になり誰でもバージョンを進められないよう、変更のない要求は受け付けない
+        if actions.is_empty() {
+            return Err(AppError::rejected("locked", "no_changes"));
+        }
+        let if_match = header_params.if_match.as_deref();
+        Ok(
+            match self.change_post(&user, path_params.post_id, &actions, if_match, |post| {
+                post.moderate(body.locked, body.pinned);
+                Ok(Some(PostWrite::Update))
+            })? {
+                PostEdit::Edited(post) => {
+                    PostsPostIdModerationPutResponse::Status200_PostCreated(self.with_author(post)?)
+                }
+                PostEdit::Forbidden => PostsPostIdModerationPutResponse::Status403,
+                PostEdit::NotFound => PostsPostIdModerationPutResponse::Status404,
+                PostEdit::Conflict => {
+                    unreachable!("moderation does not depend on the post's state")
+                }
+                PostEdit::PreconditionFailed => PostsPostIdModerationPutResponse::Status412,
+            },
+        )
+    }
+
+    async fn posts_post_id_patch(
+        &self,
+        _method: Method,
+        _host: Host,
+        _cookies: CookieJar,
+        header_params: PostsPostIdPatchHeaderParams,
+        path_params: PostsPostIdPatchPathParams,
+        body: Option<models::PostPatch>,
+    ) -> Result<PostsPostIdPatchResponse, AppError> {
+        let user = authenticate!(self, header_params, PostsPostIdPatchResponse::Status401);
+        let body = body.ok_or(AppError::MissingBody)?;
+        body.validate()?;
+        let if_match = header_params.if_match.as_deref();
+        Ok(
+            match self.edit_post(&user, path_params.post_id, if_match, |post| {
+                apply_patch(post, &body)
+            })? {
+                PostEdit::Edited(post) => PostsPostIdPatchResponse::Status200_PostCreated {
+                    e_tag: etag::entity_tag(post.version),
+                    body: self.with_author(post)?,
+                },
+                PostEdit::Forbidden => PostsPostIdPatchResponse::Status403,
+                PostEdit::NotFound => PostsPostIdPatchResponse::Status404,
+                PostEdit::Conflict => unreachable!("editing does not depend on the post's state"),
+                PostEdit::PreconditionFailed => PostsPostIdPatchResponse::Status412,
+            },
+        )
+    }
+
+    async fn posts_post_id_publish_post(
+        &self,
+        _method: Method,
+        _host: Host,
+        _cookies: CookieJar,
+        header_params: PostsPostIdPublishPostHeaderParams,
+        path_params: PostsPostIdPublishPostPathParams,
+        body: Option<models::PostsPostIdPublishPostRequest>,
+    ) -> Result<PostsPostIdPublishPostResponse, AppError> {
+        let user = authenticate!(
+            self,
+            header_params,
+            PostsPostIdPublishPostResponse::Status401
+        );
+        let publish_at = body.and_then(|body| body.publish_at);
+        let if_match = header_params.if_match.as_deref();
+        let edit = self.change_post(
+            &user,
+            path_params.post_id,
+            &[PostAction::Publish],
+            if_match,
+            |post| {
+                if post.is_published() {
+                    return Ok(None);
+                }
+                let now = Utc::now();
+                match publish_at {
+                    Some(at) if at <= now => return Err(AppError::rejected("publishAt", "past")),
+                    Some(at) => post.schedule(at),
+                    None => post.publish(now),
+                }
+                Ok(Some(PostWrite::Publish))
+            },
+        )?;
+        Ok(match edit {
+            PostEdit::Edited(post) => PostsPostIdPublishPostResponse::Status200_PostCreated {
+                e_tag: etag::entity_tag(post.version),
+                body: self.with_author(post)?,
+            },
+            PostEdit::Forbidden => PostsPostIdPublishPostResponse::Status403,
+            PostEdit::NotFound => PostsPostIdPublishPostResponse::Status404,
+            PostEdit::Conflict => PostsPostIdPublishPostResponse::Status409,
+            PostEdit::PreconditionFailed => PostsPostIdPublishPostResponse::Status412,
+        })
+    }
+
+    async fn posts_post_id_purge_post(
+        &self,
+        _method: Method,
+        _host: Host,
+        _cookies: CookieJar,
+        header_params: PostsPostIdPurgePostHeaderParams,
+        path_params: PostsPostIdPurgePostPathParams,
+    ) -> Result<PostsPostIdPurgePostResponse, AppError> {
+        let user = authenticate!(self, header_params, PostsPostIdPurgePostResponse::Status401);
+        let post_id = path_params.post_id;
+        let post = match self.posts.find(post_id)? {
+            Some(post) => Some(post),
+            None => self.posts.find_in_trash(post_id)?,
+        };
+        let Some(post) = post.filter(|post| policy::can_view(&user, post)) else {
+            return Ok(PostsPostIdPurgePostResponse::Status404);
+        };
+        if !policy::can(&user, PostAction::Purge, &post) {
+            return Ok(PostsPostIdPurgePostResponse::Status403);
+        }
+        self.posts.delete(post_id)?;
+        // ゴミ箱の投稿は移した時点で削除を通知している
+        if post.deleted_at.is_none() {
+            self.publish(EventKind::PostDeleted, post);
         }
+        Ok(PostsPostIdPurgePostResponse::Status204)
     }
 
     async fn posts_post_id_put(
@@ -201,91 +1506,4196 @@ impl Posts for ApiImpl {
         header_params: PostsPostIdPutHeaderParams,
         path_params: PostsPostIdPutPathParams,
         body: Option<Post>,
-    ) -> Result<PostsPostIdPutResponse, String> {
-        let jwt = header_params.authorization.replace("Bearer ", "");
-        let Ok(jwt) = jwt::validate_token(SECRET.as_ref(), &jwt) else {
-            return Ok(PostsPostIdPutResponse::Status401);
+    ) -> Result<PostsPostIdPutResponse, AppError> {
+        let user = authenticate!(self, header_params, PostsPostIdPutResponse::Status401);
+        let post_id = path_params.post_id;
+        let body = body.ok_or(AppError::MissingBody)?;
+        body.validate()?;
+        let (title, content) = parse_post(body.title.as_deref(), body.content.as_deref())?;
+        let if_match = header_params.if_match.as_deref();
+        Ok(
+            match self.edit_post(&user, post_id, if_match, |_| Ok((title, content)))? {
+                PostEdit::Edited(post) => PostsPostIdPutResponse::Status200_PostCreated {
+                    e_tag: etag::entity_tag(post.version),
+                    body: self.with_author(post)?,
+                },
+                PostEdit::Forbidden => PostsPostIdPutResponse::Status403,
+                PostEdit::NotFound => PostsPostIdPutResponse::Status404,
+                PostEdit::Conflict => unreachable!("editing does not depend on the post's state"),
+                PostEdit::PreconditionFailed => PostsPostIdPutResponse::Status412,
+            },
+        )
+    }
+
+    async fn posts_post_id_replies_get(
+        &self,
+        _method: Method,
+        _host: Host,
+        _cookies: CookieJar,
+        header_params: PostsPostIdRepliesGetHeaderParams,
+        path_params: PostsPostIdRepliesGetPathParams,
+        query_params: PostsPostIdRepliesGetQueryParams,
+    ) -> Result<PostsPostIdRepliesGetResponse, AppError> {
+        let user = authenticate!(
+            self,
+            header_params,
+            PostsPostIdRepliesGetResponse::Status401
+        );
+        let post_id = path_params.post_id;
+        if self.find_visible(&user, post_id)?.is_none() {
+            return Ok(PostsPostIdRepliesGetResponse::Status404);
+        }
+        // 範囲はバリデーション済み
+        let limit = query_params.limit.unwrap_or(20) as usize;
+        let offset = query_params.offset.unwrap_or(0) as usize;
+        let replies = self.posts.list_replies(post_id, limit, offset)?;
+        Ok(PostsPostIdRepliesGetResponse::Status200_ListOfPosts(
+            self.with_authors(replies)?,
+        ))
+    }
+
+    async fn posts_post_id_replies_post(
+        &self,
+        _method: Method,
+        _host: Host,
+        _cookies: CookieJar,
+        header_params: PostsPostIdRepliesPostHeaderParams,
+        path_params: PostsPostIdRepliesPostPathParams,
+        body: Option<models::PostsPostRequest>,
+    ) -> Result<PostsPostIdRepliesPostResponse, AppError> {
+        let user = authenticate!(
+            self,
+            header_params,
+            PostsPostIdRepliesPostResponse::Status401
+        );
+        let body = body.ok_or(AppError::MissingBody)?;
+        body.validate()?;
+        // 返信は返信先の投稿と同じボードに属する
+        if body.board_id.is_some() {
+            return Err(AppError::rejected("boardId", "not_allowed"));
+        }
+        // 返信は作成と同時に公開する
+        if body.publish_at.is_some() {
+            return Err(AppError::rejected("publishAt", "not_allowed"));
+        }
+        if body
+            .status
+            .is_some_and(|status| status != models::PostStatus::Published)
+        {
+            return Err(AppError::rejected("status", "not_allowed"));
+        }
+        let Some(parent) = self.find_visible(&user, path_params.post_id)? else {
+            return Ok(PostsPostIdRepliesPostResponse::Status404);
+        };
+        let board = self.board_of(&parent)?;
+        if !policy::can(&user, PostAction::Reply, &parent) || !board.settings.allow_replies {
+            return Ok(PostsPostIdRepliesPostResponse::Status403);
+        }
+        if self.depth(&parent)? + 1 > self.config.posts.max_reply_depth {
+            return Err(AppError::rejected("postId", "max_depth"));
+        }
+        let (title, content) = parse_post(Some(&body.title), Some(&body.content))?;
+        check_content(&board, &content)?;
+        let reply = entity::Post::new(user.id, board.id, title, content, Some(parent.id));
+        self.posts.insert(reply.clone())?;
+        self.publish(EventKind::ReplyCreated, reply.clone());
+        Ok(PostsPostIdRepliesPostResponse::Status201_PostCreated(
+            self.with_author(reply)?,
+        ))
+    }
+
+    async fn posts_post_id_restore_post(
+        &self,
+        _method: Method,
+        _host: Host,
+        _cookies: CookieJar,
+        header_params: PostsPostIdRestorePostHeaderParams,
+        path_params: PostsPostIdRestorePostPathParams,
+    ) -> Result<PostsPostIdRestorePostResponse, AppError> {
+        let user = authenticate!(
+            self,
+            header_params,
+            PostsPostIdRestorePostResponse::Status401
+        );
+        let Some(mut post) = self
+            .posts
+            .find_in_trash(path_params.post_id)?
+            .filter(|post| policy::can_view(&user, post))
+        else {
+            return Ok(PostsPostIdRestorePostResponse::Status404);
+        };
+        if !policy::can(&user, PostAction::Restore, &post) {
+            return Ok(PostsPostIdRestorePostResponse::Status403);
+        }
+        post.restore();
+        self.posts.restore(post.clone())?;
+        // 一緒に戻した返信を数え直す
+        let post = self.posts.find(post.id)?.unwrap_or(post);
+        self.publish(EventKind::PostUpdated, post.clone());
+        Ok(PostsPostIdRestorePostResponse::Status200_PostCreated {
+            e_tag: etag::entity_tag(post.version),
+            body: self.with_author(post)?,
+        })
+    }
+
+    async fn posts_post_id_revisions_get(
+        &self,
+        _method: Method,
+        _host: Host,
+        _cookies: CookieJar,
+        header_params: PostsPostIdRevisionsGetHeaderParams,
+        path_params: PostsPostIdRevisionsGetPathParams,
+        query_params: PostsPostIdRevisionsGetQueryParams,
+    ) -> Result<PostsPostIdRevisionsGetResponse, AppError> {
+        let user = authenticate!(
+            self,
+            header_params,
+            PostsPostIdRevisionsGetResponse::Status401
+        );
+        let post_id = path_params.post_id;
+        if self.find_visible(&user, post_id)?.is_none() {
+            return Ok(PostsPostIdRevisionsGetResponse::Status404);
+        }
+        // 範囲はバリデーション済み
+        let limit = query_params.limit.unwrap_or(20) as usize;
+        let offset = query_params.offset.unwrap_or(0) as usize;
+        let revisions = self.posts.list_revisions(post_id, limit, offset)?;
+        Ok(PostsPostIdRevisionsGetResponse::Status200(
+            self.with_editors(revisions)?,
+        ))
+    }
+
+    async fn posts_post_id_revisions_number_get(
+        &self,
+        _method: Method,
+        _host: Host,
+        _cookies: CookieJar,
+        header_params: PostsPostIdRevisionsNumberGetHeaderParams,
+        path_params: PostsPostIdRevisionsNumberGetPathParams,
+    ) -> Result<PostsPostIdRevisionsNumberGetResponse, AppError> {
+        let user = authenticate!(
+            self,
+            header_params,
+            PostsPostIdRevisionsNumberGetResponse::Status401
+        );
+        // ゴミ箱の投稿と、公開前の投稿の作成者以外には版を返さない
+        if self.find_visible(&user, path_params.post_id)?.is_none() {
+            return Ok(PostsPostIdRevisionsNumberGetResponse::Status404);
+        }
+        // 範囲はバリデーション済み
+        let number = path_params.number as u32;
+        let Some(revision) = self.posts.find_revision(path_params.post_id, number)? else {
+            return Ok(PostsPostIdRevisionsNumberGetResponse::Status404);
         };
+        let mut revisions = self.with_editors(vec![revision])?;
+        Ok(PostsPostIdRevisionsNumberGetResponse::Status200(
+            revisions.remove(0),
+        ))
+    }
+
+    async fn posts_post_id_revisions_number_revert_post(
+        &self,
+        _method: Method,
+        _host: Host,
+        _cookies: CookieJar,
+        header_params: PostsPostIdRevisionsNumberRevertPostHeaderParams,
+        path_params: PostsPostIdRevisionsNumberRevertPostPathParams,
+    ) -> Result<PostsPostIdRevisionsNumberRevertPostResponse, AppError> {
+        let user = authenticate!(
+            self,
+            header_params,
+            PostsPostIdRevisionsNumberRevertPostResponse::Status401
+        );
         let post_id = path_params.post_id;
-        let body = body.ok_or("body is required")?;
-        body.validate().map_err(|e| e.to_string())?;
-        let mut posts_locked = self.posts.lock().unwrap();
-        let post = posts_locked.iter_mut().find(|post| post.id == post_id);
-        if let Some(post) = post {
-            if post.user_id == jwt.uid.parse::<i64>().unwrap() {
-                post.title = body.title.clone().unwrap();
-                post.content = body.content.clone().unwrap();
-                Ok(PostsPostIdPutResponse::Status200_PostCreated(
-                    models::Post {
-                        id: Some(post.id),
-                        title: Some(post.title.clone()),
-                        content: Some(post.content.clone()),
-                        user_id: Some(post.user_id),
-                    },
-                ))
-            } else {
-                Ok(PostsPostIdPutResponse::Status401)
+        // 範囲はバリデーション済み
+        let number = path_params.number as u32;
+        let Some(revision) = self.posts.find_revision(post_id, number)? else {
+            return Ok(PostsPostIdRevisionsNumberRevertPostResponse::Status404);
+        };
+        let if_match = header_params.if_match.as_deref();
+        let edit = self.change_post(&user, post_id, &[PostAction::Revert], if_match, |post| {
+            // 過去の版の後にボードの最大の文字数を変更した場合もある
+            check_content(&self.board_of(post)?, &revision.content)?;
+            // 過去の版を書き換えず、その内容で新しい版を作る
+            post.edit(revision.title, revision.content);
+            Ok(Some(PostWrite::Edit))
+        })?;
+        Ok(match edit {
+            PostEdit::Edited(post) => {
+                PostsPostIdRevisionsNumberRevertPostResponse::Status200_PostCreated(
+                    self.with_author(post)?,
+                )
             }
-        } else {
-            Ok(PostsPostIdPutResponse::Status404)
+            PostEdit::Forbidden => PostsPostIdRevisionsNumberRevertPostResponse::Status403,
+            PostEdit::NotFound => PostsPostIdRevisionsNumberRevertPostResponse::Status404,
+            PostEdit::Conflict => unreachable!("reverting does not depend on the post's state"),
+            PostEdit::PreconditionFailed => PostsPostIdRevisionsNumberRevertPostResponse::Status412,
+        })
+    }
+
+    async fn posts_search_get(
+        &self,
+        _method: Method,
+        _host: Host,
+        _cookies: CookieJar,
+        header_params: PostsSearchGetHeaderParams,
+        query_params: PostsSearchGetQueryParams,
+    ) -> Result<PostsSearchGetResponse, AppError> {
+        let _user = authenticate!(self, header_params, PostsSearchGetResponse::Status401);
+        let query = SearchQuery::parse(&query_params.q)
+            .ok_or_else(|| AppError::rejected("q", "no_terms"))?;
+        // 範囲はバリデーション済み
+        let limit = query_params.limit.unwrap_or(20) as usize;
+        let offset = query_params.offset.unwrap_or(0) as usize;
+        let candidates = self.posts.search(query.terms(), search::MAX_CANDIDATES)?;
+        let hits: Vec<_> = search::rank(&query, candidates, Utc::now())
+            .into_iter()
+            .skip(offset)
+            .take(limit)
+            .collect();
+        let posts = self.with_authors(hits.iter().map(|hit| hit.post.clone()).collect())?;
+        let results = hits
+            .into_iter()
+            .zip(posts)
+            .map(|(hit, post)| models::PostSearchResult {
+                title: fragment_models(query.highlight(hit.post.title.as_str())),
+                snippet: fragment_models(query.snippet(hit.post.content.as_str())),
+                score: hit.score,
+                post,
+            })
+            .collect();
+        Ok(PostsSearchGetResponse::Status200(results))
+    }
+}
+
+#[async_trait]
+impl Admin for ApiImpl {
+    type Error = AppError;
+
+    async fn admin_boards_post(
+        &self,
+        _method: Method,
+        _host: Host,
+        _cookies: CookieJar,
+        header_params: AdminBoardsPostHeaderParams,
+        body: Option<models::AdminBoardsPostRequest>,
+    ) -> Result<AdminBoardsPostResponse, AppError> {
+        let user = authenticate!(self, header_params, AdminBoardsPostResponse::Status401);
+        if !policy::can_manage_boards(&user) {
+            return Ok(AdminBoardsPostResponse::Status403);
+        }
+        let body = body.ok_or(AppError::MissingBody)?;
+        body.validate()?;
+        let fields = parse_board(&body)?;
+        let board = entity::Board::new(
+            fields.slug,
+            fields.name,
+            fields.description,
+            fields.settings,
+        );
+        match self.boards.insert(board.clone()) {
+            Err(RepositoryError::Conflict) => return Ok(AdminBoardsPostResponse::Status409),
+            result => result?,
+        };
+        tracing::info!(board_id = %board.id, slug = %board.slug, by = user.id, "created board");
+        Ok(AdminBoardsPostResponse::Status201(board_model(board)))
+    }
+
+    async fn admin_boards_slug_delete(
+        &self,
+        _method: Method,
+        _host: Host,
+        _cookies: CookieJar,
+        header_params: AdminBoardsSlugDeleteHeaderParams,
+        path_params: AdminBoardsSlugDeletePathParams,
+    ) -> Result<AdminBoardsSlugDeleteResponse, AppError> {
+        let user = authenticate!(
+            self,
+            header_params,
+            AdminBoardsSlugDeleteResponse::Status401
+        );
+        if !policy::can_manage_boards(&user) {
+            return Ok(AdminBoardsSlugDeleteResponse::Status403);
+        }
+        let Some(board) = self.boards.find_by_slug(&path_params.slug)? else {
+            return Ok(AdminBoardsSlugDeleteResponse::Status404);
+        };
+        if self.posts.has_posts_in(board.id)? {
+            return Ok(AdminBoardsSlugDeleteResponse::Status409);
+        }
+        // ゴミ箱の投稿は保持期間を待たずにボードと一緒に削除する
+        let purged = self.posts.purge_trash_in(board.id)?;
+        match self.boards.delete(board.id) {
+            Ok(true) => {}
+            Ok(false) => return Ok(AdminBoardsSlugDeleteResponse::Status404),
+            // 確かめてから削除するまでに投稿が作成された
+            Err(RepositoryError::Conflict) => return Ok(AdminBoardsSlugDeleteResponse::Status409),
+            Err(e) => return Err(e.into()),
+        }
+        tracing::info!(
+            board_id = %board.id,
+            slug = %board.slug,
+            purged,
+            by = user.id,
+            "deleted board"
+        );
+        Ok(AdminBoardsSlugDeleteResponse::Status204)
+    }
+
+    async fn admin_boards_slug_put(
+        &self,
+        _method: Method,
+        _host: Host,
+        _cookies: CookieJar,
+        header_params: AdminBoardsSlugPutHeaderParams,
+        path_params: AdminBoardsSlugPutPathParams,
+        body: Option<models::AdminBoardsPostRequest>,
+    ) -> Result<AdminBoardsSlugPutResponse, AppError> {
+        let user = authenticate!(self, header_params, AdminBoardsSlugPutResponse::Status401);
+        if !policy::can_manage_boards(&user) {
+            return Ok(AdminBoardsSlugPutResponse::Status403);
+        }
+        let body = body.ok_or(AppError::MissingBody)?;
+        body.validate()?;
+        let fields = parse_board(&body)?;
+        let Some(mut board) = self.boards.find_by_slug(&path_params.slug)? else {
+            return Ok(AdminBoardsSlugPutResponse::Status404);
+        };
+        board.update(
+            fields.slug,
+            fields.name,
+            fields.description,
+            fields.settings,
+        );
+        match self.boards.update(board.clone()) {
+            Ok(true) => {}
+            Ok(false) => return Ok(AdminBoardsSlugPutResponse::Status404),
+            Err(RepositoryError::Conflict) => return Ok(AdminBoardsSlugPutResponse::Status409),
+            Err(e) => return Err(e.into()),
+        }
+        tracing::info!(board_id = %board.id, slug = %board.slug, by = user.id, "updated board");
+        Ok(AdminBoardsSlugPutResponse::Status200(board_model(board)))
+    }
+
+    async fn admin_users_user_id_role_delete(
+        &self,
+        _method: Method,
+        _host: Host,
+        _cookies: CookieJar,
+        header_params: AdminUsersUserIdRoleDeleteHeaderParams,
+        path_params: AdminUsersUserIdRoleDeletePathParams,
+    ) -> Result<AdminUsersUserIdRoleDeleteResponse, AppError> {
+        let user = authenticate!(
+            self,
+            header_params,
+            AdminUsersUserIdRoleDeleteResponse::Status401
+        );
+        Ok(
+            match self.change_role(&user, path_params.user_id, Role::Member)? {
+                RoleChange::Changed(user) => {
+                    AdminUsersUserIdRoleDeleteResponse::Status200_RoleUpdated(user)
+                }
+                RoleChange::Forbidden => AdminUsersUserIdRoleDeleteResponse::Status403,
+                RoleChange::NotFound => AdminUsersUserIdRoleDeleteResponse::Status404,
+            },
+        )
+    }
+
+    async fn admin_users_user_id_role_put(
+        &self,
+        _method: Method,
+        _host: Host,
+        _cookies: CookieJar,
+        header_params: AdminUsersUserIdRolePutHeaderParams,
+        path_params: AdminUsersUserIdRolePutPathParams,
+        body: Option<models::AdminUsersUserIdRolePutRequest>,
+    ) -> Result<AdminUsersUserIdRolePutResponse, AppError> {
+        let user = authenticate!(
+            self,
+            header_params,
+            AdminUsersUserIdRolePutResponse::Status401
+        );
+        let body = body.ok_or(AppError::MissingBody)?;
+        Ok(
+            match self.change_role(&user, path_params.user_id, role(body.role))? {
+                RoleChange::Changed(user) => {
+                    AdminUsersUserIdRolePutResponse::Status200_RoleUpdated(user)
+                }
+                RoleChange::Forbidden => AdminUsersUserIdRolePutResponse::Status403,
+                RoleChange::NotFound => AdminUsersUserIdRolePutResponse::Status404,
+            },
+        )
+    }
+
+    async fn admin_webhook_deliveries_delivery_id_retry_post(
+        &self,
+        _method: Method,
+        _host: Host,
+        _cookies: CookieJar,
+        header_params: AdminWebhookDeliveriesDeliveryIdRetryPostHeaderParams,
+        path_params: AdminWebhookDeliveriesDeliveryIdRetryPostPathParams,
+    ) -> Result<AdminWebhookDeliveriesDeliveryIdRetryPostResponse, AppError> {
+        let user = authenticate!(
+            self,
+            header_params,
+            AdminWebhookDeliveriesDeliveryIdRetryPostResponse::Status401
+        );
+        if !policy::can_manage_webhooks(&user) {
+            return Ok(AdminWebhookDeliveriesDeliveryIdRetryPostResponse::Status403);
+        }
+        let Some(mut delivery) = self.webhooks.find_delivery(path_params.delivery_id)? else {
+            return Ok(AdminWebhookDeliveriesDeliveryIdRetryPostResponse::Status404);
+        };
+        if delivery.status != DeliveryStatus::Dead {
+            return Ok(AdminWebhookDeliveriesDeliveryIdRetryPostResponse::Status409);
+        }
+        // 試行の回数を戻し、再び上限まで再試行する
+        delivery.status = DeliveryStatus::Pending;
+        delivery.attempts = 0;
+        delivery.next_attempt_at = Utc::now();
+        if !self.webhooks.update_delivery(delivery.clone())? {
+            return Ok(AdminWebhookDeliveriesDeliveryIdRetryPostResponse::Status404);
+        }
+        self.dispatcher.wake();
+        tracing::info!(delivery_id = %delivery.id, by = user.id, "retrying webhook delivery");
+        Ok(AdminWebhookDeliveriesDeliveryIdRetryPostResponse::Status200(delivery_model(delivery)))
+    }
+
+    async fn admin_webhook_deliveries_get(
+        &self,
+        _method: Method,
+        _host: Host,
+        _cookies: CookieJar,
+        header_params: AdminWebhookDeliveriesGetHeaderParams,
+        query_params: AdminWebhookDeliveriesGetQueryParams,
+    ) -> Result<AdminWebhookDeliveriesGetResponse, AppError> {
+        let user = authenticate!(
+            self,
+            header_params,
+            AdminWebhookDeliveriesGetResponse::Status401
+        );
+        if !policy::can_manage_webhooks(&user) {
+            return Ok(AdminWebhookDeliveriesGetResponse::Status403);
+        }
+        let deliveries = self.webhooks.list_deliveries(&DeliveryQuery {
+            webhook_id: None,
+            status: query_params.status.map(delivery_status),
+            limit: query_params.limit.unwrap_or(50) as usize,
+        })?;
+        Ok(AdminWebhookDeliveriesGetResponse::Status200(
+            deliveries.into_iter().map(delivery_model).collect(),
+        ))
+    }
+
+    async fn admin_webhooks_get(
+        &self,
+        _method: Method,
+        _host: Host,
+        _cookies: CookieJar,
+        header_params: AdminWebhooksGetHeaderParams,
+    ) -> Result<AdminWebhooksGetResponse, AppError> {
+        let user = authenticate!(self, header_params, AdminWebhooksGetResponse::Status401);
+        if !policy::can_manage_webhooks(&user) {
+            return Ok(AdminWebhooksGetResponse::Status403);
+        }
+        let webhooks = self.webhooks.list()?;
+        Ok(AdminWebhooksGetResponse::Status200(
+            webhooks
+                .into_iter()
+                .map(|webhook| webhook_model(webhook, false))
+                .collect(),
+        ))
+    }
+
+    async fn admin_webhooks_post(
+        &self,
+        _method: Method,
+        _host: Host,
+        _cookies: CookieJar,
+        header_params: AdminWebhooksPostHeaderParams,
+        body: Option<models::AdminWebhooksPostRequest>,
+    ) -> Result<AdminWebhooksPostResponse, AppError> {
+        let user = authenticate!(self, header_params, AdminWebhooksPostResponse::Status401);
+        if !policy::can_manage_webhooks(&user) {
+            return Ok(AdminWebhooksPostResponse::Status403);
+        }
+        let body = body.ok_or(AppError::MissingBody)?;
+        body.validate()?;
+        let url =
+            WebhookUrl::parse(&body.url).map_err(|e| AppError::invalid([("url", Some(&e))]))?;
+        let mut events: Vec<WebhookEvent> = Vec::new();
+        for event in body
+            .events
+            .unwrap_or_default()
+            .into_iter()
+            .map(webhook_event)
+        {
+            if !events.contains(&event) {
+                events.push(event);
+            }
+        }
+        let webhook = entity::Webhook::new(url, events, webhook::generate_secret(), user.id);
+        self.webhooks.insert(webhook.clone())?;
+        tracing::info!(webhook_id = %webhook.id, by = user.id, "registered webhook");
+        Ok(AdminWebhooksPostResponse::Status201(webhook_model(
+            webhook, true,
+        )))
+    }
+
+    async fn admin_webhooks_webhook_id_delete(
+        &self,
+        _method: Method,
+        _host: Host,
+        _cookies: CookieJar,
+        header_params: AdminWebhooksWebhookIdDeleteHeaderParams,
+        path_params: AdminWebhooksWebhookIdDeletePathParams,
+    ) -> Result<AdminWebhooksWebhookIdDeleteResponse, AppError> {
+        let user = authenticate!(
+            self,
+            header_params,
+            AdminWebhooksWebhookIdDeleteResponse::Status401
+        );
+        if !policy::can_manage_webhooks(&user) {
+            return Ok(AdminWebhooksWebhookIdDeleteResponse::Status403);
+        }
+        if !self.webhooks.delete(path_params.webhook_id)? {
+            return Ok(AdminWebhooksWebhookIdDeleteResponse::Status404);
+        }
+        tracing::info!(webhook_id = %path_params.webhook_id, by = user.id, "deleted webhook");
+        Ok(AdminWebhooksWebhookIdDeleteResponse::Status204)
+    }
+
+    async fn admin_webhooks_webhook_id_deliveries_get(
+        &self,
+        _method: Method,
+        _host: Host,
+        _cookies: CookieJar,
+        header_params: AdminWebhooksWebhookIdDeliveriesGetHeaderParams,
+        path_params: AdminWebhooksWebhookIdDeliveriesGetPathParams,
+        query_params: AdminWebhooksWebhookIdDeliveriesGetQueryParams,
+    ) -> Result<AdminWebhooksWebhookIdDeliveriesGetResponse, AppError> {
+        let user = authenticate!(
+            self,
+            header_params,
+            AdminWebhooksWebhookIdDeliveriesGetResponse::Status401
+        );
+        if !policy::can_manage_webhooks(&user) {
+            return Ok(AdminWebhooksWebhookIdDeliveriesGetResponse::Status403);
         }
+        if self.webhooks.find(path_params.webhook_id)?.is_none() {
+            return Ok(AdminWebhooksWebhookIdDeliveriesGetResponse::Status404);
+        }
+        let deliveries = self.webhooks.list_deliveries(&DeliveryQuery {
+            webhook_id: Some(path_params.webhook_id),
+            status: query_params.status.map(delivery_status),
+            limit: query_params.limit.unwrap_or(50) as usize,
+        })?;
+        Ok(AdminWebhooksWebhookIdDeliveriesGetResponse::Status200(
+            deliveries.into_iter().map(delivery_model).collect(),
+        ))
     }
 }
 
 #[async_trait]
 impl Auth for ApiImpl {
+    type Error = AppError;
+
+    async fn auth_logout_all_post(
+        &self,
+        _method: Method,
+        _host: Host,
+        _cookies: CookieJar,
+        header_params: AuthLogoutAllPostHeaderParams,
+    ) -> Result<AuthLogoutAllPostResponse, AppError> {
+        let user = authenticate!(self, header_params, AuthLogoutAllPostResponse::Status401);
+        self.revocations.revoke_user(user.id, Utc::now())?;
+        self.refresh_tokens.revoke_user(user.id)?;
+        Ok(AuthLogoutAllPostResponse::Status204)
+    }
+
+    async fn auth_logout_post(
+        &self,
+        _method: Method,
+        _host: Host,
+        _cookies: CookieJar,
+        header_params: AuthLogoutPostHeaderParams,
+        body: Option<models::AuthLogoutPostRequest>,
+    ) -> Result<AuthLogoutPostResponse, AppError> {
+        let user = authenticate!(self, header_params, AuthLogoutPostResponse::Status401);
+        self.revocations.revoke(&user.jti, user.expires_at)?;
+        if let Some(presented) = body.and_then(|body| body.refresh_token) {
+            // 他人のリフレッシュトークンは使用済みにも失効にもしない
+            let token = self
+                .refresh_tokens
+                .find(&refresh_token::hash_token(&presented))?;
+            if let Some(token) = token.filter(|token| token.user_id == user.id) {
+                self.refresh_tokens.revoke_family(token.family_id)?;
+            }
+        }
+        Ok(AuthLogoutPostResponse::Status204)
+    }
+
     async fn auth_post(
         &self,
         _method: Method,
         _host: Host,
         _cookies: CookieJar,
+        header_params: AuthPostHeaderParams,
         body: Option<models::Auth>,
-    ) -> Result<AuthPostResponse, String> {
+    ) -> Result<AuthPostResponse, AppError> {
         let (email, password) = match body {
             Some(body) => (body.email, body.password),
-            None => return Err("body is required".to_string()),
+            None => return Err(AppError::MissingBody),
         };
 
-        let email = email.ok_or("Email is required")?;
-        let password = password.ok_or("Password is required")?;
+        let email = email.ok_or_else(|| AppError::required("email"))?;
+        let password = password.ok_or_else(|| AppError::required("password"))?;
 
-        let users_locked = self.users.lock().unwrap();
-        let user = users_locked.iter().find(|user| user.email == email);
+        let email = normalize_email(&email);
+        let mut keys = vec![ThrottleKey::Account(email.clone())];
+        if let Some(ip) = client_ip(header_params.x_forwarded_for.as_deref()) {
+            keys.push(ThrottleKey::Ip(ip));
+        }
+        let now = Utc::now();
+        let attempt = match self.login_throttle.reserve(&keys, now) {
+            Ok(attempt) => attempt,
+            Err(remaining) => {
+                return Ok(AuthPostResponse::Status429 {
+                    retry_after: retry_after(remaining),
+                })
+            }
+        };
 
-        match user {
-            Some(user) => {
-                let password_hash =
-                    PasswordHash::new(&user.password).map_err(|_| "Invalid password hash")?;
-                let argon2 = Argon2::default();
+        // 登録時の規則を満たさないパスワードは照合するまでもなく一致しない
+        let Ok(password) = Password::parse(&password) else {
+            self.log_login_failure(attempt);
+            return Err(AppError::InvalidCredentials);
+        };
+        let user = self.users.find_by_email(&email)?;
+        let argon2 = self.config.argon2();
+        // 登録されていないメールアドレスでも同じだけ時間がかかるよう、ダミーのハッシュと照合する
+        let stored_hash = match &user {
+            Some(user) => user.password.as_str(),
+            None => self.dummy_hash(&argon2)?,
+        };
+        let password_hash = PasswordHash::new(stored_hash).map_err(|_| AppError::PasswordHash)?;
+        let verified = argon2
+            .verify_password(password.as_str().as_bytes(), &password_hash)
+            .is_ok();
 
-                match argon2.verify_password(password.as_bytes(), &password_hash) {
-                    Ok(_) => {
-                        let token = jwt::create_token(SECRET.as_ref(), &user.id.to_string())
-                            .map_err(|e| e.to_string())?;
-                        Ok(AuthPostResponse::Status200_AuthenticationSuccessful(
-                            models::Token { token: Some(token) },
-                        ))
-                    }
-                    Err(_) => Ok(AuthPostResponse::Status400),
+        match &user {
+            Some(user) if verified => {
+                self.login_throttle.record_success(&attempt);
+                if password::needs_rehash(&password_hash, argon2.params()) {
+                    self.rehash_password(user.id, &password, &argon2);
                 }
+                self.users.record_login(user.id, now)?;
+                // ログインごとに新しいリフレッシュトークンの系列を開始する
+                let token = self.issue_tokens(user.id, user.role, Uuid::new_v4())?;
+                Ok(AuthPostResponse::Status200_AuthenticationSuccessful(token))
+            }
+            _ => {
+                self.log_login_failure(attempt);
+                Err(AppError::InvalidCredentials)
             }
-            None => Ok(AuthPostResponse::Status400),
         }
     }
-}
 
-#[tokio::main]
+    async fn auth_refresh_post(
+        &self,
+        _method: Method,
+        _host: Host,
+        _cookies: CookieJar,
+        body: Option<models::AuthRefreshPostRequest>,
+    ) -> Result<AuthRefreshPostResponse, AppError> {
+        let body = body.ok_or(AppError::MissingBody)?;
+        let (user_id, refresh_token) = match refresh_token::rotate(
+            self.refresh_tokens.as_ref(),
+            &body.refresh_token,
+            self.config.refresh_token_lifetime(),
+        ) {
+            Ok(rotated) => rotated,
+            Err(RefreshError::Repository(e)) => return Err(e.into()),
+            Err(_) => return Ok(AuthRefreshPostResponse::Status401),
+        };
+        // ロールはリフレッシュの時点の値をトークンに載せる
+        let Some(user) = self.users.find(user_id)? else {
+            return Ok(AuthRefreshPostResponse::Status401);
+        };
+        let token = self.keys.create_token(
+            &user.id.to_string(),
+            user.role,
+            self.config.access_token_lifetime(),
+        )?;
+        Ok(AuthRefreshPostResponse::Status200_AuthenticationSuccessful(
+            models::Token {
+                token: Some(token),
+                refresh_token: Some(refresh_token),
+                expires_in: Some(self.config.access_token_lifetime().as_secs() as i64),
+            },
+        ))
+    }
+
+    async fn get_jwks(
+        &self,
+        _method: Method,
+        _host: Host,
+        _cookies: CookieJar,
+    ) -> Result<GetJwksResponse, AppError> {
+        let keys = self
+            .keys
+            .jwks()
+            .into_iter()
+            .map(|jwk| models::Jwk {
+                kty: jwk.kty.to_string(),
+                kid: jwk.kid,
+                r#use: "sig".to_string(),
+                alg: jwk.alg.to_string(),
+                crv: jwk.crv.map(str::to_string),
+                n: jwk.n,
+                e: jwk.e,
+                x: jwk.x,
+                y: jwk.y,
+            })
+            .collect();
+        Ok(GetJwksResponse::Status200(models::JwkSet { keys }))
+    }
+}
+
+#[async_trait]
+impl Boards for ApiImpl {
+    type Error = AppError;
+
+    async fn boards_get(
+        &self,
+        _method: Method,
+        _host: Host,
+        _cookies: CookieJar,
+        header_params: BoardsGetHeaderParams,
+    ) -> Result<BoardsGetResponse, AppError> {
+        let _user = authenticate!(self, header_params, BoardsGetResponse::Status401);
+        let boards = self.boards.list()?;
+        Ok(BoardsGetResponse::Status200(
+            boards.into_iter().map(board_model).collect(),
+        ))
+    }
+
+    async fn boards_slug_get(
+        &self,
+        _method: Method,
+        _host: Host,
+        _cookies: CookieJar,
+        header_params: BoardsSlugGetHeaderParams,
+        path_params: BoardsSlugGetPathParams,
+    ) -> Result<BoardsSlugGetResponse, AppError> {
+        let _user = authenticate!(self, header_params, BoardsSlugGetResponse::Status401);
+        Ok(match self.boards.find_by_slug(&path_params.slug)? {
+            Some(board) => BoardsSlugGetResponse::Status200(board_model(board)),
+            None => BoardsSlugGetResponse::Status404,
+        })
+    }
+
+    async fn boards_slug_posts_get(
+        &self,
+        _method: Method,
+        _host: Host,
+        _cookies: CookieJar,
+        header_params: BoardsSlugPostsGetHeaderParams,
+        path_params: BoardsSlugPostsGetPathParams,
+        query_params: BoardsSlugPostsGetQueryParams,
+    ) -> Result<BoardsSlugPostsGetResponse, AppError> {
+        let _user = authenticate!(self, header_params, BoardsSlugPostsGetResponse::Status401);
+        let Some(board) = self.boards.find_by_slug(&path_params.slug)? else {
+            return Ok(BoardsSlugPostsGetResponse::Status404);
+        };
+        // `/posts`と同じ条件で絞り込む
+        let query = PostsGetQueryParams {
+            limit: query_params.limit,
+            cursor: query_params.cursor,
+            sort: query_params.sort,
+            user_id: query_params.user_id,
+            since: query_params.since,
+            until: query_params.until,
+        };
+        let path = format!("/boards/{}/posts", board.slug);
+        let page = self.list_page(&query, Some(board.id), &path)?;
+        Ok(BoardsSlugPostsGetResponse::Status200_ListOfPosts {
+            body: page.posts,
+            link: page.link,
+            x_next_cursor: page.next_cursor,
+        })
+    }
+}
+
+/// 有効期限を過ぎた失効記録を定期的に削除する
+fn spawn_revocation_purge(revocations: Arc<dyn RevocationRepository>) {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(std::time::Duration::from_secs(10 * 60));
+        loop {
+            interval.tick().await;
+            match revocations.purge_expired(Utc::now()) {
+                Ok(0) => {}
+                Ok(purged) => tracing::debug!(purged, "purged expired token revocations"),
+                Err(e) => tracing::warn!(error = %e, "failed to purge token revocations"),
+            }
+        }
+    });
+}
+
+fn spawn_login_throttle_purge(login_throttle: Arc<LoginThrottle>) {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(std::time::Duration::from_secs(10 * 60));
+        loop {
+            interval.tick().await;
+            let purged = login_throttle.purge_expired(Utc::now());
+            if purged > 0 {
+                tracing::debug!(purged, "purged expired login failures");
+            }
+        }
+    });
+}
+
+/// Webhookの配信と、保持する期間を過ぎた配信の記録の削除を続ける
+fn spawn_webhook_dispatcher(dispatcher: Arc<WebhookDispatcher>) {
+    let purge = dispatcher.clone();
+    tokio::spawn(async move { dispatcher.run().await });
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(std::time::Duration::from_secs(60 * 60));
+        loop {
+            interval.tick().await;
+            match purge.purge(Utc::now()) {
+                Ok(0) => {}
+                Ok(purged) => tracing::debug!(purged, "purged webhook deliveries"),
+                Err(e) => tracing::warn!(error = %e, "failed to purge webhook deliveries"),
+            }
+        }
+    });
+}
+
+/// 保持する期間を過ぎたゴミ箱の投稿を定期的に完全に削除する
+fn spawn_trash_purge(api: ApiImpl) {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(std::time::Duration::from_secs(60 * 60));
+        loop {
+            interval.tick().await;
+            match api.purge_trash(Utc::now()) {
+                Ok(0) => {}
+                Ok(purged) => tracing::debug!(purged, "purged trashed posts"),
+                Err(e) => tracing::warn!(error = %e, "failed to purge trashed posts"),
+            }
+        }
+    });
+}
+
+/// 公開予定の投稿を、公開する日時を過ぎたものから定期的に公開する
+fn spawn_scheduled_publisher(api: ApiImpl) {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(std::time::Duration::from_secs(30));
+        loop {
+            interval.tick().await;
+            match api.publish_due_posts(Utc::now()) {
+                Ok(0) => {}
+                Ok(published) => tracing::debug!(published, "published scheduled posts"),
+                Err(e) => tracing::warn!(error = %e, "failed to publish scheduled posts"),
+            }
+        }
+    });
+}
+
+#[tokio::main]
 async fn main() {
+    let cli = Cli::parse();
+    let config = match Config::load(&cli) {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("invalid configuration: {}", e);
+            std::process::exit(1);
+        }
+    };
     tracing_subscriber::fmt()
-        .with_max_level(tracing::Level::TRACE)
+        .with_max_level(config.log_level().expect("log level is validated on load"))
         .init();
-    let users = Arc::new(Mutex::new(Vec::new()));
-    let posts = Arc::new(Mutex::new(Vec::new()));
-    let api = ApiImpl { users, posts };
-    let router = new(api);
+    if config.dev {
+        tracing::warn!("running in development mode, do not use in production");
+    }
+
+    let keys = match KeyRing::from_config(&config) {
+        Ok(keys) => keys,
+        Err(e) => {
+            eprintln!("invalid JWT keys: {}", e);
+            std::process::exit(1);
+        }
+    };
+
+    let bind = config.server.bind;
+    let api = match config.storage.backend {
+        StorageBackend::Sqlite => {
+            let db = match Database::open(&config.storage.path) {
+                Ok(db) => db,
+                Err(e) => {
+                    eprintln!(
+                        "failed to open database {}: {}",
+                        config.storage.path.display(),
+                        e
+                    );
+                    std::process::exit(1);
+                }
+            };
+            tracing::info!(path = %config.storage.path.display(), "using SQLite storage");
+            ApiImpl::sqlite(db, config, keys)
+        }
+        StorageBackend::Memory => {
+            tracing::warn!("using in-memory storage, data will be lost on restart");
+            let api = ApiImpl::in_memory(config, keys);
+            create_admins(api.users.as_ref(), &api.config).expect("failed to create admins");
+            api
+        }
+    };
+    promote_admins(api.users.as_ref(), &api.config).expect("failed to promote admins");
+    spawn_revocation_purge(api.revocations.clone());
+    spawn_login_throttle_purge(api.login_throttle.clone());
+    spawn_webhook_dispatcher(api.dispatcher.clone());
+    spawn_trash_purge(api.clone());
+    spawn_scheduled_publisher(api.clone());
+    let trust_proxy = api.config.server.trust_proxy;
+    let router = new(api.clone())
+        .merge(sse::router(api.clone()))
+        .merge(ws::router(api))
+        .layer(middleware::from_fn_with_state(trust_proxy, forwarded_for));
 
-    let listener = tokio::net::TcpListener::bind("127.0.0.1:8080")
+    let listener = tokio::net::TcpListener::bind(bind)
         .await
         .expect("failed to bind to address");
-    axum::serve(listener, router).await.unwrap();
+    tracing::info!(%bind, "listening");
+    axum::serve(
+        listener,
+        router.into_make_service_with_connect_info::<SocketAddr>(),
+    )
+    .await
+    .unwrap();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    pub(crate) fn api() -> ApiImpl {
+        let config = Config {
+            dev: true,
+            jwt: config::JwtConfig {
+                secret: Some("0123456789abcdef0123456789abcdef".to_string()),
+                ..Default::default()
+            },
+            users: config::UsersConfig {
+                admin_emails: vec!["admin@example.com".to_string()],
+                admin_password: Some("password".to_string()),
+            },
+            ..Default::default()
+        };
+        let keys = KeyRing::from_config(&config).unwrap();
+        let api = ApiImpl::in_memory(config, keys);
+        create_admins(api.users.as_ref(), &api.config).unwrap();
+        api
+    }
+
+    pub(crate) fn general_board(api: &ApiImpl) -> BoardId {
+        api.boards.find_by_slug("general").unwrap().unwrap().id
+    }
+
+    async fn login(api: &ApiImpl, email: &str) -> models::Token {
+        let _ = api
+            .users_post(
+                Method::POST,
+                Host("localhost".to_string()),
+                CookieJar::new(),
+                Some(models::UsersPostRequest::new(
+                    "name".to_string(),
+                    email.to_string(),
+                    "password".to_string(),
+                )),
+            )
+            .await
+            .unwrap();
+        let AuthPostResponse::Status200_AuthenticationSuccessful(token) = api
+            .auth_post(
+                Method::POST,
+                Host("localhost".to_string()),
+                CookieJar::new(),
+                AuthPostHeaderParams {
+                    x_forwarded_for: None,
+                },
+                Some(models::Auth {
+                    email: Some(email.to_string()),
+                    password: Some("password".to_string()),
+                }),
+            )
+            .await
+            .unwrap()
+        else {
+            panic!("authentication failed");
+        };
+        token
+    }
+
+    async fn sign_up(api: &ApiImpl, email: &str) -> String {
+        let token = login(api, email).await;
+        format!("Bearer {}", token.token.unwrap())
+    }
+
+    /// 検証エラーの項目とコードの組
+    fn rejected_fields(error: AppError) -> Vec<(String, String)> {
+        let problem = error.problem();
+        assert_eq!(problem.code, models::ErrorCode::ValidationFailed);
+        problem
+            .errors
+            .unwrap()
+            .into_iter()
+            .map(|error| (error.field, error.code))
+            .collect()
+    }
+
+    async fn list_posts(api: &ApiImpl, authorization: Option<&str>) -> PostsGetResponse {
+        api.posts_get(
+            Method::GET,
+            Host("localhost".to_string()),
+            CookieJar::new(),
+            PostsGetHeaderParams {
+                authorization: authorization.map(str::to_string),
+            },
+            PostsGetQueryParams {
+                limit: None,
+                cursor: None,
+                sort: None,
+                user_id: None,
+                since: None,
+                until: None,
+            },
+        )
+        .await
+        .unwrap()
+    }
+
+    async fn create_post(api: &ApiImpl, authorization: &str) -> models::Post {
+        let PostsPostResponse::Status201_PostCreated(created) = api
+            .posts_post(
+                Method::POST,
+                Host("localhost".to_string()),
+                CookieJar::new(),
+                PostsPostHeaderParams {
+                    authorization: Some(authorization.to_string()),
+                },
+                Some(models::PostsPostRequest {
+                    board_id: Some(general_board(api)),
+                    ..models::PostsPostRequest::new("title".to_string(), "content".to_string())
+                }),
+            )
+            .await
+            .unwrap()
+        else {
+            panic!("post was not created");
+        };
+        created
+    }
+
+    async fn get_post(
+        api: &ApiImpl,
+        authorization: &str,
+        post_id: PostId,
+        if_none_match: Option<&str>,
+    ) -> PostsPostIdGetResponse {
+        api.posts_post_id_get(
+            Method::GET,
+            Host("localhost".to_string()),
+            CookieJar::new(),
+            PostsPostIdGetHeaderParams {
+                authorization: Some(authorization.to_string()),
+                if_none_match: if_none_match.map(str::to_string),
+            },
+            PostsPostIdGetPathParams { post_id },
+        )
+        .await
+        .unwrap()
+    }
+
+    async fn reply(
+        api: &ApiImpl,
+        authorization: &str,
+        post_id: PostId,
+        board_id: Option<BoardId>,
+    ) -> Result<PostsPostIdRepliesPostResponse, AppError> {
+        api.posts_post_id_replies_post(
+            Method::POST,
+            Host("localhost".to_string()),
+            CookieJar::new(),
+            PostsPostIdRepliesPostHeaderParams {
+                authorization: Some(authorization.to_string()),
+            },
+            PostsPostIdRepliesPostPathParams { post_id },
+            Some(models::PostsPostRequest {
+                board_id,
+                ..models::PostsPostRequest::new("re".to_string(), "content".to_string())
+            }),
+        )
+        .await
+    }
+
+    async fn update_post(
+        api: &ApiImpl,
+        authorization: &str,
+        post_id: PostId,
+        if_match: Option<&str>,
+    ) -> PostsPostIdPutResponse {
+        api.posts_post_id_put(
+            Method::PUT,
+            Host("localhost".to_string()),
+            CookieJar::new(),
+            PostsPostIdPutHeaderParams {
+                authorization: Some(authorization.to_string()),
+                if_match: if_match.map(str::to_string),
+            },
+            PostsPostIdPutPathParams { post_id },
+            Some(Post {
+                title: Some("updated".to_string()),
+                content: Some("updated".to_string()),
+                ..Post::new()
+            }),
+        )
+        .await
+        .unwrap()
+    }
+
+    fn user_id(api: &ApiImpl, email: &str) -> UserId {
+        api.users.find_by_email(email).unwrap().unwrap().id
+    }
+
+    mod auth {
+        use super::*;
+
+        async fn refresh(api: &ApiImpl, refresh_token: &str) -> AuthRefreshPostResponse {
+            api.auth_refresh_post(
+                Method::POST,
+                Host("localhost".to_string()),
+                CookieJar::new(),
+                Some(models::AuthRefreshPostRequest::new(
+                    refresh_token.to_string(),
+                )),
+            )
+            .await
+            .unwrap()
+        }
+
+        async fn authenticate(
+            api: &ApiImpl,
+            email: &str,
+            password: &str,
+            forwarded_for: Option<&str>,
+        ) -> Result<AuthPostResponse, models::ErrorCode> {
+            api.auth_post(
+                Method::POST,
+                Host("localhost".to_string()),
+                CookieJar::new(),
+                AuthPostHeaderParams {
+                    x_forwarded_for: forwarded_for.map(str::to_string),
+                },
+                Some(models::Auth {
+                    email: Some(email.to_string()),
+                    password: Some(password.to_string()),
+                }),
+            )
+            .await
+            .map_err(|e| e.problem().code)
+        }
+
+        fn throttled_api(max_failures_per_account: u32, max_failures_per_ip: u32) -> ApiImpl {
+            let mut api = api();
+            api.login_throttle = Arc::new(LoginThrottle::new(config::LoginConfig {
+                max_failures_per_account,
+                max_failures_per_ip,
+                ..Default::default()
+            }));
+            api
+        }
+
+        #[tokio::test]
+        async fn test_account_lockout() {
+            let api = throttled_api(2, 100);
+            sign_up(&api, "a@example.com").await;
+
+            let first = authenticate(&api, "a@example.com", "wrong password", None).await;
+            let second = authenticate(&api, "a@example.com", "wrong password", None).await;
+            let locked = authenticate(&api, "A@example.com", "password", None).await;
+
+            assert_eq!(first, Err(models::ErrorCode::InvalidCredentials));
+            assert_eq!(second, Err(models::ErrorCode::InvalidCredentials));
+            assert_eq!(locked, Ok(AuthPostResponse::Status429 { retry_after: 30 }));
+            assert_eq!(
+                authenticate(&api, "b@example.com", "password", None).await,
+                Err(models::ErrorCode::InvalidCredentials)
+            );
+        }
+
+        #[tokio::test]
+        async fn test_unknown_email_is_locked_out() {
+            let api = throttled_api(1, 100);
+
+            let unknown = authenticate(&api, "nobody@example.com", "password", None).await;
+            let locked = authenticate(&api, "nobody@example.com", "password", None).await;
+
+            assert_eq!(unknown, Err(models::ErrorCode::InvalidCredentials));
+            assert!(matches!(locked, Ok(AuthPostResponse::Status429 { .. })));
+        }
+
+        #[tokio::test]
+        async fn test_ip_lockout() {
+            let api = throttled_api(100, 2);
+            sign_up(&api, "c@example.com").await;
+            let ip = Some("203.0.113.9, 192.0.2.1");
+
+            assert_eq!(
+                authenticate(&api, "a@example.com", "password", ip).await,
+                Err(models::ErrorCode::InvalidCredentials)
+            );
+            assert_eq!(
+                authenticate(&api, "b@example.com", "password", ip).await,
+                Err(models::ErrorCode::InvalidCredentials)
+            );
+
+            assert!(matches!(
+                authenticate(&api, "c@example.com", "password", ip).await,
+                Ok(AuthPostResponse::Status429 { .. })
+            ));
+            assert!(matches!(
+                authenticate(&api, "c@example.com", "password", Some("192.0.2.2")).await,
+                Ok(AuthPostResponse::Status200_AuthenticationSuccessful(_))
+            ));
+        }
+
+        #[test]
+        fn test_client_ip() {
+            assert_eq!(
+                client_ip(Some("203.0.113.9, 2001:db8::1")),
+                Some("2001:db8::1".parse().unwrap())
+            );
+            assert_eq!(client_ip(Some("unknown")), None);
+            assert_eq!(client_ip(None), None);
+            assert_eq!(retry_after(chrono::Duration::milliseconds(1500)), 2);
+        }
+
+        #[tokio::test]
+        async fn test_refresh_rotates_token() {
+            let api = api();
+            let token = login(&api, "a@example.com").await;
+
+            let AuthRefreshPostResponse::Status200_AuthenticationSuccessful(refreshed) =
+                refresh(&api, token.refresh_token.as_ref().unwrap()).await
+            else {
+                panic!("refresh failed");
+            };
+
+            assert!(refreshed.token.is_some());
+            assert_ne!(refreshed.refresh_token, token.refresh_token);
+            assert_eq!(refreshed.expires_in, Some(15 * 60));
+        }
+
+        #[tokio::test]
+        async fn test_refresh_token_reuse_revokes_family() {
+            let api = api();
+            let token = login(&api, "a@example.com").await;
+            let original = token.refresh_token.unwrap();
+            let AuthRefreshPostResponse::Status200_AuthenticationSuccessful(refreshed) =
+                refresh(&api, &original).await
+            else {
+                panic!("refresh failed");
+            };
+
+            let reused = refresh(&api, &original).await;
+            let latest = refresh(&api, &refreshed.refresh_token.unwrap()).await;
+
+            assert_eq!(reused, AuthRefreshPostResponse::Status401);
+            assert_eq!(latest, AuthRefreshPostResponse::Status401);
+        }
+
+        #[tokio::test]
+        async fn test_jwks_verifies_issued_tokens() {
+            let config = Config {
+                jwt: config::JwtConfig {
+                    keys: vec![config::JwtKeyConfig {
+                        kid: "es".to_string(),
+                        algorithm: config::KeyAlgorithm::ES256,
+                        private_key_file: concat!(
+                            env!("CARGO_MANIFEST_DIR"),
+                            "/testdata/jwt/es256.pem"
+                        )
+                        .into(),
+                        activate_at: None,
+                        retire_at: None,
+                    }],
+                    ..Default::default()
+                },
+                ..Default::default()
+            };
+            let keys = KeyRing::from_config(&config).unwrap();
+            let api = ApiImpl::in_memory(config, keys);
+            let token = login(&api, "a@example.com").await;
+
+            let GetJwksResponse::Status200(jwks) = api
+                .get_jwks(Method::GET, Host("localhost".to_string()), CookieJar::new())
+                .await
+                .unwrap();
+
+            let jwk = &jwks.keys[0];
+            let decoding = jsonwebtoken::DecodingKey::from_ec_components(
+                jwk.x.as_deref().unwrap(),
+                jwk.y.as_deref().unwrap(),
+            )
+            .unwrap();
+            let claims = jsonwebtoken::decode::<service::jwt::Claims>(
+                &token.token.unwrap(),
+                &decoding,
+                &jsonwebtoken::Validation::new(jsonwebtoken::Algorithm::ES256),
+            )
+            .unwrap()
+            .claims;
+            assert_eq!((jwk.kid.as_str(), jwk.r#use.as_str()), ("es", "sig"));
+            assert_eq!(claims.uid, "1");
+        }
+
+        #[tokio::test]
+        async fn test_logout_revokes_access_token() {
+            let api = api();
+            let token = login(&api, "a@example.com").await;
+            let authorization = format!("Bearer {}", token.token.unwrap());
+
+            let result = api
+                .auth_logout_post(
+                    Method::POST,
+                    Host("localhost".to_string()),
+                    CookieJar::new(),
+                    AuthLogoutPostHeaderParams {
+                        authorization: Some(authorization.clone()),
+                    },
+                    Some(models::AuthLogoutPostRequest {
+                        refresh_token: token.refresh_token.clone(),
+                    }),
+                )
+                .await
+                .unwrap();
+
+            assert_eq!(result, AuthLogoutPostResponse::Status204);
+            assert!(matches!(
+                list_posts(&api, Some(&authorization)).await,
+                PostsGetResponse::Status401 { .. }
+            ));
+            assert_eq!(
+                refresh(&api, &token.refresh_token.unwrap()).await,
+                AuthRefreshPostResponse::Status401
+            );
+        }
+
+        #[tokio::test]
+        async fn test_logout_ignores_others_refresh_token() {
+            let api = api();
+            let owner = login(&api, "a@example.com").await;
+            let other = login(&api, "b@example.com").await;
+
+            let result = api
+                .auth_logout_post(
+                    Method::POST,
+                    Host("localhost".to_string()),
+                    CookieJar::new(),
+                    AuthLogoutPostHeaderParams {
+                        authorization: Some(format!("Bearer {}", other.token.unwrap())),
+                    },
+                    Some(models::AuthLogoutPostRequest {
+                        refresh_token: owner.refresh_token.clone(),
+                    }),
+                )
+                .await
+                .unwrap();
+
+            assert_eq!(result, AuthLogoutPostResponse::Status204);
+            assert!(matches!(
+                refresh(&api, &owner.refresh_token.unwrap()).await,
+                AuthRefreshPostResponse::Status200_AuthenticationSuccessful(_)
+            ));
+        }
+
+        #[tokio::test]
+        async fn test_logout_all_revokes_every_session() {
+            let api = api();
+            let first = login(&api, "a@example.com").await;
+            let second = login(&api, "a@example.com").await;
+
+            let result = api
+                .auth_logout_all_post(
+                    Method::POST,
+                    Host("localhost".to_string()),
+                    CookieJar::new(),
+                    AuthLogoutAllPostHeaderParams {
+                        authorization: Some(format!("Bearer {}", first.token.unwrap())),
+                    },
+                )
+                .await
+                .unwrap();
+
+            assert_eq!(result, AuthLogoutAllPostResponse::Status204);
+            assert!(matches!(
+                list_posts(&api, Some(&format!("Bearer {}", second.token.unwrap()))).await,
+                PostsGetResponse::Status401 { .. }
+            ));
+            assert_eq!(
+                refresh(&api, &second.refresh_token.unwrap()).await,
+                AuthRefreshPostResponse::Status401
+            );
+        }
+    }
+
+    mod users {
+        use super::*;
+
+        #[tokio::test]
+        async fn test_errors_are_problems() {
+            let api = api();
+
+            let missing_body = api
+                .users_post(
+                    Method::POST,
+                    Host("localhost".to_string()),
+                    CookieJar::new(),
+                    None,
+                )
+                .await
+                .unwrap_err()
+                .problem();
+            let missing_password = api
+                .auth_post(
+                    Method::POST,
+                    Host("localhost".to_string()),
+                    CookieJar::new(),
+                    AuthPostHeaderParams {
+                        x_forwarded_for: None,
+                    },
+                    Some(models::Auth {
+                        email: Some("a@example.com".to_string()),
+                        password: None,
+                    }),
+                )
+                .await
+                .unwrap_err()
+                .problem();
+
+            assert_eq!(missing_body.status, 400);
+            assert_eq!(missing_body.code, models::ErrorCode::MissingBody);
+            assert_eq!(missing_password.code, models::ErrorCode::ValidationFailed);
+            assert_eq!(
+                missing_password
+                    .errors
+                    .unwrap()
+                    .iter()
+                    .map(|error| (error.field.as_str(), error.code.as_str()))
+                    .collect::<Vec<_>>(),
+                vec![("password", "required")]
+            );
+        }
+
+        #[tokio::test]
+        async fn test_invalid_values_are_rejected() {
+            let api = api();
+
+            let problem = api
+                .users_post(
+                    Method::POST,
+                    Host("localhost".to_string()),
+                    CookieJar::new(),
+                    Some(models::UsersPostRequest::new(
+                        "  ".to_string(),
+                        "not-an-email".to_string(),
+                        "password".to_string(),
+                    )),
+                )
+                .await
+                .unwrap_err()
+                .problem();
+
+            assert_eq!(
+                problem
+                    .errors
+                    .unwrap()
+                    .iter()
+                    .map(|error| (error.field.as_str(), error.code.as_str()))
+                    .collect::<Vec<_>>(),
+                vec![("email", "email"), ("name", "required")]
+            );
+            assert!(api.users.find_by_email("not-an-email").unwrap().is_none());
+        }
+
+        #[tokio::test]
+        async fn test_duplicate_email_is_conflict() {
+            let api = api();
+            sign_up(&api, "a@example.com").await;
+
+            let result = api
+                .users_post(
+                    Method::POST,
+                    Host("localhost".to_string()),
+                    CookieJar::new(),
+                    Some(models::UsersPostRequest::new(
+                        "other".to_string(),
+                        " A@Example.com".to_string(),
+                        "password".to_string(),
+                    )),
+                )
+                .await
+                .unwrap();
+
+            assert_eq!(result, UsersPostResponse::Status409);
+        }
+
+        #[tokio::test]
+        async fn test_login_is_recorded() {
+            let api = api();
+            let created = Utc::now();
+
+            login(&api, "a@example.com").await;
+
+            let user = api.users.find_by_email("a@example.com").unwrap().unwrap();
+            let model = user_model(user);
+            assert!(model.created_at.unwrap() >= created);
+            assert!(model.last_login_at.unwrap() >= model.created_at.unwrap());
+            assert_eq!(model.password, None);
+        }
+
+        #[tokio::test]
+        async fn test_weak_password_is_rejected() {
+            let mut api = api();
+            let mut config = (*api.config).clone();
+            config.password.require_digit = true;
+            config.password.deny_list = ["password".to_string()].into();
+            api.config = Arc::new(config);
+
+            let problem = api
+                .users_post(
+                    Method::POST,
+                    Host("localhost".to_string()),
+                    CookieJar::new(),
+                    Some(models::UsersPostRequest::new(
+                        "name".to_string(),
+                        "a@example.com".to_string(),
+                        "Password".to_string(),
+                    )),
+                )
+                .await
+                .unwrap_err()
+                .problem();
+
+            assert_eq!(
+                problem
+                    .errors
+                    .unwrap()
+                    .iter()
+                    .map(|error| (error.field.as_str(), error.code.as_str()))
+                    .collect::<Vec<_>>(),
+                vec![("password", "characters"), ("password", "common")]
+            );
+        }
+
+        #[tokio::test]
+        async fn test_weak_hash_is_upgraded_on_login() {
+            let api = api();
+            let weak = Argon2::new(
+                argon2::Algorithm::Argon2id,
+                argon2::Version::V0x13,
+                argon2::Params::new(1024, 1, 1, None).unwrap(),
+            );
+            let user = api
+                .users
+                .create(
+                    NewUser::new(
+                        Name::parse("name").unwrap(),
+                        Email::parse("a@example.com").unwrap(),
+                        Password::parse("password").unwrap(),
+                        Role::Member,
+                        &weak,
+                    )
+                    .unwrap(),
+                )
+                .unwrap();
+
+            login(&api, "a@example.com").await;
+
+            let upgraded = api.users.find(user.id).unwrap().unwrap().password;
+            assert_ne!(upgraded, user.password);
+            assert!(!password::needs_rehash(
+                &PasswordHash::new(&upgraded).unwrap(),
+                api.config.argon2().params()
+            ));
+            // 再ハッシュしたパスワードでもログインできる
+            login(&api, "a@example.com").await;
+        }
+
+        #[tokio::test]
+        async fn test_auth_with_differently_cased_email() {
+            let api = api();
+            sign_up(&api, "a@example.com").await;
+
+            let result = api
+                .auth_post(
+                    Method::POST,
+                    Host("localhost".to_string()),
+                    CookieJar::new(),
+                    AuthPostHeaderParams {
+                        x_forwarded_for: None,
+                    },
+                    Some(models::Auth {
+                        email: Some("A@EXAMPLE.COM".to_string()),
+                        password: Some("password".to_string()),
+                    }),
+                )
+                .await
+                .unwrap();
+
+            assert!(matches!(
+                result,
+                AuthPostResponse::Status200_AuthenticationSuccessful(_)
+            ));
+        }
+    }
+
+    mod posts {
+        use super::*;
+
+        #[tokio::test]
+        async fn test_create_and_list() {
+            let api = api();
+            let authorization = sign_up(&api, "a@example.com").await;
+
+            let PostsPostResponse::Status201_PostCreated(created) = api
+                .posts_post(
+                    Method::POST,
+                    Host("localhost".to_string()),
+                    CookieJar::new(),
+                    PostsPostHeaderParams {
+                        authorization: Some(authorization.clone()),
+                    },
+                    Some(models::PostsPostRequest {
+                        board_id: Some(general_board(&api)),
+                        ..models::PostsPostRequest::new("title".to_string(), "content".to_string())
+                    }),
+                )
+                .await
+                .unwrap()
+            else {
+                panic!("post was not created");
+            };
+            let PostsGetResponse::Status200_ListOfPosts { body: posts, .. } =
+                list_posts(&api, Some(&authorization)).await
+            else {
+                panic!("posts were not listed");
+            };
+
+            assert_eq!(posts, vec![created]);
+        }
+
+        #[tokio::test]
+        async fn test_missing_authorization_is_challenged() {
+            let api = api();
+
+            let result = list_posts(&api, None).await;
+
+            assert_eq!(
+                result,
+                PostsGetResponse::Status401 {
+                    www_authenticate: "Bearer realm=\"board\"".to_string()
+                }
+            );
+        }
+
+        #[tokio::test]
+        async fn test_scheme_is_case_insensitive() {
+            let api = api();
+            let authorization = sign_up(&api, "a@example.com").await;
+            let authorization = authorization.replacen("Bearer", "bearer", 1);
+
+            let result = list_posts(&api, Some(&authorization)).await;
+
+            assert!(matches!(
+                result,
+                PostsGetResponse::Status200_ListOfPosts { .. }
+            ));
+        }
+
+        #[tokio::test]
+        async fn test_invalid_token_is_challenged() {
+            let api = api();
+
+            let result = list_posts(&api, Some("Bearer not.a.jwt")).await;
+
+            let PostsGetResponse::Status401 { www_authenticate } = result else {
+                panic!("token was accepted");
+            };
+            assert!(www_authenticate.contains("error=\"invalid_token\""));
+        }
+
+        async fn moderate(
+            api: &ApiImpl,
+            authorization: &str,
+            post_id: PostId,
+            locked: Option<bool>,
+            pinned: Option<bool>,
+        ) -> PostsPostIdModerationPutResponse {
+            api.posts_post_id_moderation_put(
+                Method::PUT,
+                Host("localhost".to_string()),
+                CookieJar::new(),
+                PostsPostIdModerationPutHeaderParams {
+                    authorization: Some(authorization.to_string()),
+                    if_match: None,
+                },
+                PostsPostIdModerationPutPathParams { post_id },
+                Some(models::PostsPostIdModerationPutRequest { locked, pinned }),
+            )
+            .await
+            .unwrap()
+        }
+
+        #[tokio::test]
+        async fn test_other_users_post_is_forbidden() {
+            let api = api();
+            let owner = sign_up(&api, "a@example.com").await;
+            let other = sign_up(&api, "b@example.com").await;
+            let post_id = create_post(&api, &owner).await.id.unwrap();
+
+            let updated = update_post(&api, &other, post_id, None).await;
+            let deleted = api
+                .posts_post_id_delete(
+                    Method::DELETE,
+                    Host("localhost".to_string()),
+                    CookieJar::new(),
+                    PostsPostIdDeleteHeaderParams {
+                        authorization: Some(other),
+                        if_match: None,
+                    },
+                    PostsPostIdDeletePathParams { post_id },
+                )
+                .await
+                .unwrap();
+
+            assert_eq!(updated, PostsPostIdPutResponse::Status403);
+            assert_eq!(deleted, PostsPostIdDeleteResponse::Status403_Forbidden);
+        }
+
+        #[tokio::test]
+        async fn test_edit_records_metadata() {
+            let api = api();
+            let owner = sign_up(&api, "a@example.com").await;
+            let created = create_post(&api, &owner).await;
+
+            let PostsPostIdPutResponse::Status200_PostCreated { body: updated, .. } =
+                update_post(&api, &owner, created.id.unwrap(), None).await
+            else {
+                panic!("post was not updated");
+            };
+
+            assert_eq!(created.author_name.as_deref(), Some("name"));
+            assert_eq!(created.edited_count, Some(0));
+            assert_eq!(created.updated_at, created.created_at);
+            assert_eq!(updated.author_name.as_deref(), Some("name"));
+            assert_eq!(updated.edited_count, Some(1));
+            assert_eq!(updated.created_at, created.created_at);
+            assert!(updated.updated_at > created.updated_at);
+        }
+
+        #[tokio::test]
+        async fn test_values_are_normalized() {
+            let api = api();
+            let authorization = sign_up(&api, "a@example.com").await;
+            let create = |title: &str| {
+                api.posts_post(
+                    Method::POST,
+                    Host("localhost".to_string()),
+                    CookieJar::new(),
+                    PostsPostHeaderParams {
+                        authorization: Some(authorization.clone()),
+                    },
+                    Some(models::PostsPostRequest {
+                        board_id: Some(general_board(&api)),
+                        ..models::PostsPostRequest::new(title.to_string(), "content\n".to_string())
+                    }),
+                )
+            };
+
+            let PostsPostResponse::Status201_PostCreated(created) =
+                create("  Cafe\u{301}  ").await.unwrap()
+            else {
+                panic!("post was not created");
+            };
+            let blank = create(" ").await.unwrap_err().problem();
+
+            assert_eq!(created.title.as_deref(), Some("Caf\u{e9}"));
+            assert_eq!(created.content.as_deref(), Some("content"));
+            assert_eq!(blank.errors.unwrap()[0].field, "title");
+        }
+
+        #[tokio::test]
+        async fn test_moderator_locks_and_pins() {
+            let api = api();
+            let owner = sign_up(&api, "a@example.com").await;
+            sign_up(&api, "mod@example.com").await;
+            api.users
+                .set_role(user_id(&api, "mod@example.com"), Role::Moderator)
+                .unwrap();
+            let moderator = sign_up(&api, "mod@example.com").await;
+            let first = create_post(&api, &owner).await.id.unwrap();
+            let second = create_post(&api, &owner).await.id.unwrap();
+
+            let forbidden = moderate(&api, &owner, second, None, Some(true)).await;
+            let pinned = moderate(&api, &moderator, second, None, Some(true)).await;
+            let locked = moderate(&api, &moderator, first, Some(true), None).await;
+            let PostsGetResponse::Status200_ListOfPosts { body: posts, .. } =
+                list_posts(&api, Some(&owner)).await
+            else {
+                panic!("posts were not listed");
+            };
+
+            assert_eq!(forbidden, PostsPostIdModerationPutResponse::Status403);
+            assert!(matches!(
+                pinned,
+                PostsPostIdModerationPutResponse::Status200_PostCreated(models::Post {
+                    pinned: Some(true),
+                    ..
+                })
+            ));
+            assert!(matches!(
+                locked,
+                PostsPostIdModerationPutResponse::Status200_PostCreated(models::Post {
+                    locked: Some(true),
+                    ..
+                })
+            ));
+            assert_eq!(
+                posts
+                    .iter()
+                    .map(|post| post.id.unwrap())
+                    .collect::<Vec<_>>(),
+                vec![second, first]
+            );
+            assert_eq!(
+                update_post(&api, &owner, first, None).await,
+                PostsPostIdPutResponse::Status403
+            );
+            assert!(matches!(
+                update_post(&api, &moderator, first, None).await,
+                PostsPostIdPutResponse::Status200_PostCreated { .. }
+            ));
+        }
+
+        #[tokio::test]
+        async fn test_moderation_without_changes_is_rejected() {
+            let api = api();
+            let owner = sign_up(&api, "a@example.com").await;
+            let member = sign_up(&api, "b@example.com").await;
+            let post_id = create_post(&api, &owner).await.id.unwrap();
+
+            let result = api
+                .posts_post_id_moderation_put(
+                    Method::PUT,
+                    Host("localhost".to_string()),
+                    CookieJar::new(),
+                    PostsPostIdModerationPutHeaderParams {
+                        authorization: Some(member),
+                        if_match: None,
+                    },
+                    PostsPostIdModerationPutPathParams { post_id },
+                    Some(models::PostsPostIdModerationPutRequest::new()),
+                )
+                .await;
+
+            assert_eq!(
+                rejected_fields(result.unwrap_err()),
+                vec![("locked".to_string(), "no_changes".to_string())]
+            );
+            assert_eq!(api.posts.find(post_id).unwrap().unwrap().version, 1);
+        }
+    }
+
+    mod replies {
+        use super::*;
+
+        async fn reply_id(api: &ApiImpl, authorization: &str, post_id: PostId) -> PostId {
+            let PostsPostIdRepliesPostResponse::Status201_PostCreated(created) =
+                reply(api, authorization, post_id, None).await.unwrap()
+            else {
+                panic!("reply was not created");
+            };
+            created.id.unwrap()
+        }
+
+        #[tokio::test]
+        async fn test_reply_and_list() {
+            let api = api();
+            let authorization = sign_up(&api, "a@example.com").await;
+            let post_id = create_post(&api, &authorization).await.id.unwrap();
+            let first = reply_id(&api, &authorization, post_id).await;
+            let second = reply_id(&api, &authorization, post_id).await;
+
+            let replies = api
+                .posts_post_id_replies_get(
+                    Method::GET,
+                    Host("localhost".to_string()),
+                    CookieJar::new(),
+                    PostsPostIdRepliesGetHeaderParams {
+                        authorization: Some(authorization.clone()),
+                    },
+                    PostsPostIdRepliesGetPathParams { post_id },
+                    PostsPostIdRepliesGetQueryParams {
+                        limit: Some(1),
+                        offset: Some(1),
+                    },
+                )
+                .await
+                .unwrap();
+            let PostsGetResponse::Status200_ListOfPosts { body: posts, .. } =
+                list_posts(&api, Some(&authorization)).await
+            else {
+                panic!("posts were not listed");
+            };
+
+            let PostsPostIdRepliesGetResponse::Status200_ListOfPosts(replies) = replies else {
+                panic!("replies were not listed");
+            };
+            assert_eq!(replies.len(), 1);
+            assert_eq!(replies[0].id, Some(second));
+            assert_eq!(replies[0].parent_id, Some(post_id));
+            assert_ne!(first, second);
+            assert_eq!(posts.len(), 1);
+            assert_eq!(posts[0].reply_count, Some(2));
+        }
+
+        #[tokio::test]
+        async fn test_reply_depth_is_limited() {
+            let api = api();
+            let authorization = sign_up(&api, "a@example.com").await;
+            let mut parent = create_post(&api, &authorization).await.id.unwrap();
+            for _ in 0..api.config.posts.max_reply_depth {
+                parent = reply_id(&api, &authorization, parent).await;
+            }
+
+            let result = reply(&api, &authorization, parent, None).await;
+
+            assert_eq!(
+                rejected_fields(result.unwrap_err()),
+                vec![("postId".to_string(), "max_depth".to_string())]
+            );
+        }
+
+        #[tokio::test]
+        async fn test_reply_to_missing_or_locked_post() {
+            let api = api();
+            let authorization = sign_up(&api, "a@example.com").await;
+            let post_id = create_post(&api, &authorization).await.id.unwrap();
+            let mut post = api.posts.find(post_id).unwrap().unwrap();
+            post.moderate(Some(true), None);
+            api.posts.update(post).unwrap();
+
+            assert_eq!(
+                reply(&api, &authorization, post_id, None).await.unwrap(),
+                PostsPostIdRepliesPostResponse::Status403
+            );
+            assert_eq!(
+                reply(&api, &authorization, PostId::new_v4(), None)
+                    .await
+                    .unwrap(),
+                PostsPostIdRepliesPostResponse::Status404
+            );
+        }
+
+        #[tokio::test]
+        async fn test_events_carry_thread_id() {
+            let api = api();
+            let authorization = sign_up(&api, "a@example.com").await;
+            let mut subscription = api
+                .events
+                .subscribe(None, service::events::EventFilter::default());
+            let post_id = create_post(&api, &authorization).await.id.unwrap();
+            let first = reply_id(&api, &authorization, post_id).await;
+            let nested = reply_id(&api, &authorization, first).await;
+
+            let deleted = api
+                .posts_post_id_delete(
+                    Method::DELETE,
+                    Host("localhost".to_string()),
+                    CookieJar::new(),
+                    PostsPostIdDeleteHeaderParams {
+                        authorization: Some(authorization.clone()),
+                        if_match: None,
+                    },
+                    PostsPostIdDeletePathParams { post_id: first },
+                )
+                .await
+                .unwrap();
+
+            assert_eq!(deleted, PostsPostIdDeleteResponse::Status204_NoContent);
+            let mut events = Vec::new();
+            for _ in 0..4 {
+                let event = subscription.next().await.unwrap();
+                assert_eq!(event.thread_id, post_id);
+                assert_eq!(event.author_name.as_deref(), Some("name"));
+                events.push((event.kind, event.post.id));
+            }
+            assert_eq!(
+                events,
+                vec![
+                    (EventKind::PostCreated, post_id),
+                    (EventKind::ReplyCreated, first),
+                    (EventKind::ReplyCreated, nested),
+                    (EventKind::PostDeleted, first),
+                ]
+            );
+        }
+    }
+
+    mod search {
+        use super::*;
+
+        async fn post(api: &ApiImpl, authorization: &str, title: &str, content: &str) -> PostId {
+            let PostsPostResponse::Status201_PostCreated(created) = api
+                .posts_post(
+                    Method::POST,
+                    Host("localhost".to_string()),
+                    CookieJar::new(),
+                    PostsPostHeaderParams {
+                        authorization: Some(authorization.to_string()),
+                    },
+                    Some(models::PostsPostRequest {
+                        board_id: Some(general_board(api)),
+                        ..models::PostsPostRequest::new(title.to_string(), content.to_string())
+                    }),
+                )
+                .await
+                .unwrap()
+            else {
+                panic!("post was not created");
+            };
+            created.id.unwrap()
+        }
+
+        async fn search(api: &ApiImpl, authorization: &str, q: &str) -> PostsSearchGetResponse {
+            api.posts_search_get(
+                Method::GET,
+                Host("localhost".to_string()),
+                CookieJar::new(),
+                PostsSearchGetHeaderParams {
+                    authorization: Some(authorization.to_string()),
+                },
+                PostsSearchGetQueryParams {
+                    q: q.to_string(),
+                    limit: None,
+                    offset: None,
+                },
+            )
+            .await
+            .unwrap()
+        }
+
+        #[tokio::test]
+        async fn test_search_japanese_posts() {
+            let api = api();
+            let authorization = sign_up(&api, "a@example.com").await;
+            let in_content = post(
+                &api,
+                &authorization,
+                "お知らせ",
+                "明日は全文検索の勉強会です",
+            )
+            .await;
+            let in_title = post(&api, &authorization, "全文検索について", "索引の作り方").await;
+            post(&api, &authorization, "雑談", "検索と全文は別々").await;
+
+            let PostsSearchGetResponse::Status200(results) =
+                search(&api, &authorization, "全文検索").await
+            else {
+                panic!("search failed");
+            };
+
+            // タイトルに含む投稿を先に並べる
+            let ids: Vec<_> = results.iter().map(|r| r.post.id.unwrap()).collect();
+            assert_eq!(ids, [in_title, in_content]);
+            assert_eq!(
+                results[0].title,
+                [
+                    models::TextFragment::new("全文検索".to_string(), true),
+                    models::TextFragment::new("について".to_string(), false),
+                ]
+            );
+            assert_eq!(
+                results[1].snippet,
+                [
+                    models::TextFragment::new("明日は".to_string(), false),
+                    models::TextFragment::new("全文検索".to_string(), true),
+                    models::TextFragment::new("の勉強会です".to_string(), false),
+                ]
+            );
+        }
+
+        #[tokio::test]
+        async fn test_index_follows_delete() {
+            let api = api();
+            let authorization = sign_up(&api, "a@example.com").await;
+            let post_id = post(&api, &authorization, "title", "Hello World").await;
+
+            assert!(matches!(
+                search(&api, &authorization, "\"hello world\"").await,
+                PostsSearchGetResponse::Status200(results) if results.len() == 1
+            ));
+            let deleted = api
+                .posts_post_id_delete(
+                    Method::DELETE,
+                    Host("localhost".to_string()),
+                    CookieJar::new(),
+                    PostsPostIdDeleteHeaderParams {
+                        authorization: Some(authorization.clone()),
+                        if_match: None,
+                    },
+                    PostsPostIdDeletePathParams { post_id },
+                )
+                .await
+                .unwrap();
+            assert_eq!(deleted, PostsPostIdDeleteResponse::Status204_NoContent);
+
+            assert_eq!(
+                search(&api, &authorization, "hello").await,
+                PostsSearchGetResponse::Status200(vec![])
+            );
+        }
+
+        #[tokio::test]
+        async fn test_query_without_terms() {
+            let api = api();
+            let authorization = sign_up(&api, "a@example.com").await;
+
+            let problem = api
+                .posts_search_get(
+                    Method::GET,
+                    Host("localhost".to_string()),
+                    CookieJar::new(),
+                    PostsSearchGetHeaderParams {
+                        authorization: Some(authorization),
+                    },
+                    PostsSearchGetQueryParams {
+                        q: "、。!?".to_string(),
+                        limit: None,
+                        offset: None,
+                    },
+                )
+                .await
+                .unwrap_err()
+                .problem();
+
+            assert_eq!(problem.status, 400);
+            assert_eq!(problem.code, models::ErrorCode::ValidationFailed);
+            let errors = problem.errors.unwrap();
+            assert_eq!(errors[0].field, "q");
+            assert_eq!(errors[0].code, "no_terms");
+        }
+    }
+
+    mod revisions {
+        use super::*;
+
+        async fn edit(
+            api: &ApiImpl,
+            authorization: &str,
+            post_id: PostId,
+            content: &str,
+        ) -> PostsPostIdPutResponse {
+            api.posts_post_id_put(
+                Method::PUT,
+                Host("localhost".to_string()),
+                CookieJar::new(),
+                PostsPostIdPutHeaderParams {
+                    authorization: Some(authorization.to_string()),
+                    if_match: None,
+                },
+                PostsPostIdPutPathParams { post_id },
+                Some(Post {
+                    title: Some("title".to_string()),
+                    content: Some(content.to_string()),
+                    ..Post::new()
+                }),
+            )
+            .await
+            .unwrap()
+        }
+
+        async fn list_revisions(
+            api: &ApiImpl,
+            authorization: &str,
+            post_id: PostId,
+        ) -> Vec<models::PostRevision> {
+            let PostsPostIdRevisionsGetResponse::Status200(revisions) = api
+                .posts_post_id_revisions_get(
+                    Method::GET,
+                    Host("localhost".to_string()),
+                    CookieJar::new(),
+                    PostsPostIdRevisionsGetHeaderParams {
+                        authorization: Some(authorization.to_string()),
+                    },
+                    PostsPostIdRevisionsGetPathParams { post_id },
+                    PostsPostIdRevisionsGetQueryParams {
+                        limit: None,
+                        offset: None,
+                    },
+                )
+                .await
+                .unwrap()
+            else {
+                panic!("revisions were not listed");
+            };
+            revisions
+        }
+
+        async fn revert(
+            api: &ApiImpl,
+            authorization: &str,
+            post_id: PostId,
+            number: i32,
+        ) -> PostsPostIdRevisionsNumberRevertPostResponse {
+            api.posts_post_id_revisions_number_revert_post(
+                Method::POST,
+                Host("localhost".to_string()),
+                CookieJar::new(),
+                PostsPostIdRevisionsNumberRevertPostHeaderParams {
+                    authorization: Some(authorization.to_string()),
+                    if_match: None,
+                },
+                PostsPostIdRevisionsNumberRevertPostPathParams { post_id, number },
+            )
+            .await
+            .unwrap()
+        }
+
+        #[tokio::test]
+        async fn test_edit_records_revisions() {
+            let api = api();
+            let author = sign_up(&api, "a@example.com").await;
+            let moderator = sign_up(&api, "admin@example.com").await;
+            let post_id = create_post(&api, &author).await.id.unwrap();
+
+            assert!(matches!(
+                edit(&api, &moderator, post_id, "edited").await,
+                PostsPostIdPutResponse::Status200_PostCreated { .. }
+            ));
+
+            let revisions = list_revisions(&api, &author, post_id).await;
+            let numbers: Vec<_> = revisions.iter().map(|r| r.number).collect();
+            assert_eq!(numbers, [1, 2]);
+            assert_eq!(revisions[0].content, "content");
+            assert_eq!(revisions[0].edited_by, user_id(&api, "a@example.com"));
+            assert_eq!(revisions[1].content, "edited");
+            assert_eq!(revisions[1].edited_by, user_id(&api, "admin@example.com"));
+            assert_eq!(revisions[1].editor_name.as_deref(), Some("admin"));
+
+            let revision = api
+                .posts_post_id_revisions_number_get(
+                    Method::GET,
+                    Host("localhost".to_string()),
+                    CookieJar::new(),
+                    PostsPostIdRevisionsNumberGetHeaderParams {
+                        authorization: Some(author.clone()),
+                    },
+                    PostsPostIdRevisionsNumberGetPathParams { post_id, number: 3 },
+                )
+                .await
+                .unwrap();
+            assert_eq!(revision, PostsPostIdRevisionsNumberGetResponse::Status404);
+        }
+
+        #[tokio::test]
+        async fn test_diff_against_latest() {
+            let api = api();
+            let authorization = sign_up(&api, "a@example.com").await;
+            let post_id = create_post(&api, &authorization).await.id.unwrap();
+            let _ = edit(&api, &authorization, post_id, "content\nadded").await;
+
+            let diff = api
+                .posts_post_id_diff_get(
+                    Method::GET,
+                    Host("localhost".to_string()),
+                    CookieJar::new(),
+                    PostsPostIdDiffGetHeaderParams {
+                        authorization: Some(authorization.clone()),
+                    },
+                    PostsPostIdDiffGetPathParams { post_id },
+                    PostsPostIdDiffGetQueryParams { from: 1, to: None },
+                )
+                .await
+                .unwrap();
+
+            assert_eq!(
+                diff,
+                PostsPostIdDiffGetResponse::Status200(models::RevisionDiff {
+                    from: 1,
+                    to: 2,
+                    title: vec![models::DiffLine::new(
+                        models::DiffOp::Equal,
+                        "title".to_string()
+                    )],
+                    content: vec![
+                        models::DiffLine::new(models::DiffOp::Equal, "content".to_string()),
+                        models::DiffLine::new(models::DiffOp::Insert, "added".to_string()),
+                    ],
+                })
+            );
+        }
+
+        #[tokio::test]
+        async fn test_revert_creates_new_revision() {
+            let api = api();
+            let author = sign_up(&api, "a@example.com").await;
+            let other = sign_up(&api, "b@example.com").await;
+            let post_id = create_post(&api, &author).await.id.unwrap();
+            let _ = edit(&api, &author, post_id, "edited").await;
+
+            assert_eq!(
+                revert(&api, &other, post_id, 1).await,
+                PostsPostIdRevisionsNumberRevertPostResponse::Status403
+            );
+            assert_eq!(
+                revert(&api, &author, post_id, 5).await,
+                PostsPostIdRevisionsNumberRevertPostResponse::Status404
+            );
+            let PostsPostIdRevisionsNumberRevertPostResponse::Status200_PostCreated(post) =
+                revert(&api, &author, post_id, 1).await
+            else {
+                panic!("post was not reverted");
+            };
+
+            assert_eq!(post.content.as_deref(), Some("content"));
+            assert_eq!(post.edited_count, Some(2));
+            let revisions = list_revisions(&api, &author, post_id).await;
+            let contents: Vec<_> = revisions.iter().map(|r| r.content.as_str()).collect();
+            assert_eq!(contents, ["content", "edited", "content"]);
+        }
+    }
+
+    mod conditional_requests {
+        use super::*;
+
+        #[tokio::test]
+        async fn test_get_returns_not_modified() {
+            let api = api();
+            let authorization = sign_up(&api, "a@example.com").await;
+            let post_id = create_post(&api, &authorization).await.id.unwrap();
+
+            let PostsPostIdGetResponse::Status200_PostCreated { body, e_tag } =
+                get_post(&api, &authorization, post_id, None).await
+            else {
+                panic!("post was not found");
+            };
+            assert_eq!(e_tag, "\"1\"");
+            assert_eq!(body.version, Some(1));
+
+            assert_eq!(
+                get_post(&api, &authorization, post_id, Some(&e_tag)).await,
+                PostsPostIdGetResponse::Status304 {
+                    e_tag: e_tag.clone()
+                }
+            );
+            let _ = update_post(&api, &authorization, post_id, None).await;
+            assert!(matches!(
+                get_post(&api, &authorization, post_id, Some(&e_tag)).await,
+                PostsPostIdGetResponse::Status200_PostCreated { e_tag, .. } if e_tag == "\"2\""
+            ));
+        }
+
+        #[tokio::test]
+        async fn test_put_requires_matching_version() {
+            let api = api();
+            let authorization = sign_up(&api, "a@example.com").await;
+            let post_id = create_post(&api, &authorization).await.id.unwrap();
+
+            let PostsPostIdPutResponse::Status200_PostCreated { e_tag, .. } =
+                update_post(&api, &authorization, post_id, Some("\"1\"")).await
+            else {
+                panic!("post was not updated");
+            };
+            assert_eq!(e_tag, "\"2\"");
+
+            // 古いバージョンを前提にした更新は上書きしない
+            assert_eq!(
+                update_post(&api, &authorization, post_id, Some("\"1\"")).await,
+                PostsPostIdPutResponse::Status412
+            );
+            assert!(matches!(
+                update_post(&api, &authorization, post_id, Some("*")).await,
+                PostsPostIdPutResponse::Status200_PostCreated { .. }
+            ));
+            let post = api.posts.find(post_id).unwrap().unwrap();
+            assert_eq!(post.version, 3);
+            assert_eq!(post.edited_count, 2);
+        }
+
+        #[tokio::test]
+        async fn test_delete_requires_matching_version() {
+            let api = api();
+            let authorization = sign_up(&api, "a@example.com").await;
+            let post_id = create_post(&api, &authorization).await.id.unwrap();
+            let delete = |if_match: &str| {
+                api.posts_post_id_delete(
+                    Method::DELETE,
+                    Host("localhost".to_string()),
+                    CookieJar::new(),
+                    PostsPostIdDeleteHeaderParams {
+                        authorization: Some(authorization.clone()),
+                        if_match: Some(if_match.to_string()),
+                    },
+                    PostsPostIdDeletePathParams { post_id },
+                )
+            };
+
+            assert_eq!(
+                delete("\"2\"").await.unwrap(),
+                PostsPostIdDeleteResponse::Status412_PreconditionFailed
+            );
+            assert!(api.posts.find(post_id).unwrap().is_some());
+            assert_eq!(
+                delete("\"1\"").await.unwrap(),
+                PostsPostIdDeleteResponse::Status204_NoContent
+            );
+        }
+
+        #[tokio::test]
+        async fn test_post_deleted_before_saving_is_not_found() {
+            let api = api();
+            let authorization = sign_up(&api, "a@example.com").await;
+            let post_id = create_post(&api, &authorization).await.id.unwrap();
+            let user = api.authenticate(Some(&authorization)).unwrap().unwrap();
+            let mut subscription = api
+                .events
+                .subscribe(None, service::events::EventFilter::default());
+
+            let result = api
+                .change_post(&user, post_id, &[PostAction::Edit], None, |post| {
+                    api.posts.delete(post.id).unwrap();
+                    Ok(Some(PostWrite::Update))
+                })
+                .unwrap();
+
+            assert!(matches!(result, PostEdit::NotFound));
+            assert!(tokio::time::timeout(
+                std::time::Duration::from_millis(50),
+                subscription.next()
+            )
+            .await
+            .is_err());
+        }
+
+        #[tokio::test]
+        async fn test_state_changes_require_matching_version() {
+            let api = api();
+            let authorization = sign_up(&api, "a@example.com").await;
+            let admin = sign_up(&api, "admin@example.com").await;
+            let post_id = create_post(&api, &authorization).await.id.unwrap();
+            let _ = update_post(&api, &authorization, post_id, None).await;
+            let draft = entity::Post::unpublished(
+                user_id(&api, "a@example.com"),
+                general_board(&api),
+                Title::parse("draft").unwrap(),
+                Content::parse("draft").unwrap(),
+                None,
+            );
+            api.posts.insert(draft.clone()).unwrap();
+            let stale = Some("\"1\"".to_string());
+
+            let moderated = api
+                .posts_post_id_moderation_put(
+                    Method::PUT,
+                    Host("localhost".to_string()),
+                    CookieJar::new(),
+                    PostsPostIdModerationPutHeaderParams {
+                        authorization: Some(admin),
+                        if_match: stale.clone(),
+                    },
+                    PostsPostIdModerationPutPathParams { post_id },
+                    Some(models::PostsPostIdModerationPutRequest {
+                        locked: Some(true),
+                        pinned: None,
+                    }),
+                )
+                .await
+                .unwrap();
+            let reverted = api
+                .posts_post_id_revisions_number_revert_post(
+                    Method::POST,
+                    Host("localhost".to_string()),
+                    CookieJar::new(),
+                    PostsPostIdRevisionsNumberRevertPostHeaderParams {
+                        authorization: Some(authorization.clone()),
+                        if_match: stale.clone(),
+                    },
+                    PostsPostIdRevisionsNumberRevertPostPathParams { post_id, number: 1 },
+                )
+                .await
+                .unwrap();
+            let published = api
+                .posts_post_id_publish_post(
+                    Method::POST,
+                    Host("localhost".to_string()),
+                    CookieJar::new(),
+                    PostsPostIdPublishPostHeaderParams {
+                        authorization: Some(authorization.clone()),
+                        if_match: Some("\"2\"".to_string()),
+                    },
+                    PostsPostIdPublishPostPathParams { post_id: draft.id },
+                    None,
+                )
+                .await
+                .unwrap();
+
+            assert_eq!(moderated, PostsPostIdModerationPutResponse::Status412);
+            assert_eq!(
+                reverted,
+                PostsPostIdRevisionsNumberRevertPostResponse::Status412
+            );
+            assert_eq!(published, PostsPostIdPublishPostResponse::Status412);
+            let post = api.posts.find(post_id).unwrap().unwrap();
+            assert_eq!(post.version, 2);
+            assert!(!post.locked);
+            assert!(!api.posts.find(draft.id).unwrap().unwrap().is_published());
+        }
+    }
+
+    mod merge_patch {
+        use super::*;
+
+        async fn patch_post(
+            api: &ApiImpl,
+            authorization: &str,
+            post_id: PostId,
+            if_match: Option<&str>,
+            patch: models::PostPatch,
+        ) -> Result<PostsPostIdPatchResponse, AppError> {
+            api.posts_post_id_patch(
+                Method::PATCH,
+                Host("localhost".to_string()),
+                CookieJar::new(),
+                PostsPostIdPatchHeaderParams {
+                    authorization: Some(authorization.to_string()),
+                    if_match: if_match.map(str::to_string),
+                },
+                PostsPostIdPatchPathParams { post_id },
+                Some(patch),
+            )
+            .await
+        }
+
+        #[tokio::test]
+        async fn test_updates_only_given_fields() {
+            let api = api();
+            let authorization = sign_up(&api, "a@example.com").await;
+            let post_id = create_post(&api, &authorization).await.id.unwrap();
+
+            let PostsPostIdPatchResponse::Status200_PostCreated { body, e_tag } = patch_post(
+                &api,
+                &authorization,
+                post_id,
+                Some("\"1\""),
+                models::PostPatch {
+                    title: Some(Nullable::Present("patched".to_string())),
+                    ..models::PostPatch::new()
+                },
+            )
+            .await
+            .unwrap() else {
+                panic!("post was not patched");
+            };
+
+            assert_eq!(e_tag, "\"2\"");
+            assert_eq!(body.title.as_deref(), Some("patched"));
+            assert_eq!(body.content.as_deref(), Some("content"));
+            let post = api.posts.find(post_id).unwrap().unwrap();
+            assert_eq!(post.edited_count, 1);
+            assert_eq!(post.content.as_str(), "content");
+        }
+
+        #[tokio::test]
+        async fn test_unchanged_read_only_fields_are_accepted() {
+            let api = api();
+            let authorization = sign_up(&api, "a@example.com").await;
+            let created = create_post(&api, &authorization).await;
+
+            let result = patch_post(
+                &api,
+                &authorization,
+                created.id.unwrap(),
+                None,
+                models::PostPatch {
+                    id: created.id.map(Nullable::Present),
+                    user_id: created.user_id.map(Nullable::Present),
+                    content: Some(Nullable::Present("patched".to_string())),
+                    ..models::PostPatch::new()
+                },
+            )
+            .await;
+
+            assert!(matches!(
+                result,
+                Ok(PostsPostIdPatchResponse::Status200_PostCreated { .. })
+            ));
+        }
+
+        #[tokio::test]
+        async fn test_read_only_fields_are_rejected() {
+            let api = api();
+            let authorization = sign_up(&api, "a@example.com").await;
+            let post_id = create_post(&api, &authorization).await.id.unwrap();
+
+            let error = patch_post(
+                &api,
+                &authorization,
+                post_id,
+                None,
+                models::PostPatch {
+                    id: Some(Nullable::Present(uuid::Uuid::new_v4())),
+                    user_id: Some(Nullable::Null),
+                    title: Some(Nullable::Present("patched".to_string())),
+                    ..models::PostPatch::new()
+                },
+            )
+            .await
+            .unwrap_err();
+
+            assert_eq!(
+                rejected_fields(error),
+                [
+                    ("id".to_string(), "read_only".to_string()),
+                    ("userId".to_string(), "read_only".to_string())
+                ]
+            );
+            let post = api.posts.find(post_id).unwrap().unwrap();
+            assert_eq!(post.title.as_str(), "title");
+            assert_eq!(post.version, 1);
+        }
+
+        #[tokio::test]
+        async fn test_null_cannot_remove_required_fields() {
+            let api = api();
+            let authorization = sign_up(&api, "a@example.com").await;
+            let post_id = create_post(&api, &authorization).await.id.unwrap();
+
+            let error = patch_post(
+                &api,
+                &authorization,
+                post_id,
+                None,
+                models::PostPatch {
+                    title: Some(Nullable::Null),
+                    ..models::PostPatch::new()
+                },
+            )
+            .await
+            .unwrap_err();
+
+            assert_eq!(
+                rejected_fields(error),
+                [("title".to_string(), "required".to_string())]
+            );
+        }
+
+        #[tokio::test]
+        async fn test_preconditions_and_ownership() {
+            let api = api();
+            let authorization = sign_up(&api, "a@example.com").await;
+            let other = sign_up(&api, "b@example.com").await;
+            let post_id = create_post(&api, &authorization).await.id.unwrap();
+            let patch = || models::PostPatch {
+                title: Some(Nullable::Present("patched".to_string())),
+                ..models::PostPatch::new()
+            };
+
+            assert_eq!(
+                patch_post(&api, &authorization, post_id, Some("\"2\""), patch())
+                    .await
+                    .unwrap(),
+                PostsPostIdPatchResponse::Status412
+            );
+            assert_eq!(
+                patch_post(&api, &other, post_id, None, patch())
+                    .await
+                    .unwrap(),
+                PostsPostIdPatchResponse::Status403
+            );
+            assert_eq!(
+                patch_post(&api, &authorization, PostId::new_v4(), None, patch())
+                    .await
+                    .unwrap(),
+                PostsPostIdPatchResponse::Status404
+            );
+        }
+
+        #[tokio::test]
+        async fn test_put_with_missing_fields_is_bad_request() {
+            let api = api();
+            let authorization = sign_up(&api, "a@example.com").await;
+            let post_id = create_post(&api, &authorization).await.id.unwrap();
+
+            let problem = api
+                .posts_post_id_put(
+                    Method::PUT,
+                    Host("localhost".to_string()),
+                    CookieJar::new(),
+                    PostsPostIdPutHeaderParams {
+                        authorization: Some(authorization.clone()),
+                        if_match: None,
+                    },
+                    PostsPostIdPutPathParams { post_id },
+                    Some(Post {
+                        title: Some("updated".to_string()),
+                        ..Post::new()
+                    }),
+                )
+                .await
+                .unwrap_err()
+                .problem();
+
+            assert_eq!(problem.status, 400);
+            assert_eq!(problem.code, models::ErrorCode::ValidationFailed);
+        }
+    }
+
+    mod trash {
+        use super::*;
+
+        async fn delete_post(api: &ApiImpl, authorization: &str, post_id: PostId) {
+            let deleted = api
+                .posts_post_id_delete(
+                    Method::DELETE,
+                    Host("localhost".to_string()),
+                    CookieJar::new(),
+                    PostsPostIdDeleteHeaderParams {
+                        authorization: Some(authorization.to_string()),
+                        if_match: None,
+                    },
+                    PostsPostIdDeletePathParams { post_id },
+                )
+                .await
+                .unwrap();
+            assert_eq!(deleted, PostsPostIdDeleteResponse::Status204_NoContent);
+        }
+
+        async fn list_trash(api: &ApiImpl, authorization: &str) -> Vec<models::Post> {
+            let MeTrashGetResponse::Status200_ListOfPosts(posts) = api
+                .me_trash_get(
+                    Method::GET,
+                    Host("localhost".to_string()),
+                    CookieJar::new(),
+                    MeTrashGetHeaderParams {
+                        authorization: Some(authorization.to_string()),
+                    },
+                    MeTrashGetQueryParams {
+                        limit: None,
+                        offset: None,
+                    },
+                )
+                .await
+                .unwrap()
+            else {
+                panic!("trash was not listed");
+            };
+            posts
+        }
+
+        async fn restore_post(
+            api: &ApiImpl,
+            authorization: &str,
+            post_id: PostId,
+        ) -> PostsPostIdRestorePostResponse {
+            api.posts_post_id_restore_post(
+                Method::POST,
+                Host("localhost".to_string()),
+                CookieJar::new(),
+                PostsPostIdRestorePostHeaderParams {
+                    authorization: Some(authorization.to_string()),
+                },
+                PostsPostIdRestorePostPathParams { post_id },
+            )
+            .await
+            .unwrap()
+        }
+
+        async fn purge_post(
+            api: &ApiImpl,
+            authorization: &str,
+            post_id: PostId,
+        ) -> PostsPostIdPurgePostResponse {
+            api.posts_post_id_purge_post(
+                Method::POST,
+                Host("localhost".to_string()),
+                CookieJar::new(),
+                PostsPostIdPurgePostHeaderParams {
+                    authorization: Some(authorization.to_string()),
+                },
+                PostsPostIdPurgePostPathParams { post_id },
+            )
+            .await
+            .unwrap()
+        }
+
+        #[tokio::test]
+        async fn test_delete_moves_post_to_trash() {
+            let api = api();
+            let authorization = sign_up(&api, "a@example.com").await;
+            let post_id = create_post(&api, &authorization).await.id.unwrap();
+
+            delete_post(&api, &authorization, post_id).await;
+
+            assert!(api.posts.find(post_id).unwrap().is_none());
+            let trash = list_trash(&api, &authorization).await;
+            assert_eq!(trash.len(), 1);
+            assert_eq!(trash[0].id, Some(post_id));
+            assert!(trash[0].deleted_at.is_some());
+            let other = sign_up(&api, "b@example.com").await;
+            assert!(list_trash(&api, &other).await.is_empty());
+        }
+
+        #[tokio::test]
+        async fn test_revisions_of_trashed_post_are_hidden() {
+            let api = api();
+            let authorization = sign_up(&api, "a@example.com").await;
+            let post_id = create_post(&api, &authorization).await.id.unwrap();
+            delete_post(&api, &authorization, post_id).await;
+            let other = sign_up(&api, "b@example.com").await;
+
+            for authorization in [&authorization, &other] {
+                let revision = api
+                    .posts_post_id_revisions_number_get(
+                        Method::GET,
+                        Host("localhost".to_string()),
+                        CookieJar::new(),
+                        PostsPostIdRevisionsNumberGetHeaderParams {
+                            authorization: Some(authorization.clone()),
+                        },
+                        PostsPostIdRevisionsNumberGetPathParams { post_id, number: 1 },
+                    )
+                    .await
+                    .unwrap();
+
+                assert_eq!(revision, PostsPostIdRevisionsNumberGetResponse::Status404);
+            }
+        }
+
+        #[tokio::test]
+        async fn test_restore_returns_post() {
+            let api = api();
+            let authorization = sign_up(&api, "a@example.com").await;
+            let post_id = create_post(&api, &authorization).await.id.unwrap();
+            delete_post(&api, &authorization, post_id).await;
+
+            let PostsPostIdRestorePostResponse::Status200_PostCreated { body, e_tag } =
+                restore_post(&api, &authorization, post_id).await
+            else {
+                panic!("post was not restored");
+            };
+
+            assert_eq!(e_tag, "\"3\"");
+            assert_eq!(body.deleted_at, None);
+            assert!(api.posts.find(post_id).unwrap().is_some());
+            assert!(list_trash(&api, &authorization).await.is_empty());
+            assert_eq!(
+                restore_post(&api, &authorization, post_id).await,
+                PostsPostIdRestorePostResponse::Status404
+            );
+        }
+
+        #[tokio::test]
+        async fn test_member_cannot_restore_moderated_post() {
+            let api = api();
+            let admin = sign_up(&api, "admin@example.com").await;
+            let owner = sign_up(&api, "a@example.com").await;
+            let post_id = create_post(&api, &owner).await.id.unwrap();
+            delete_post(&api, &admin, post_id).await;
+
+            assert!(list_trash(&api, &owner).await.is_empty());
+            assert_eq!(
+                restore_post(&api, &owner, post_id).await,
+                PostsPostIdRestorePostResponse::Status403
+            );
+            assert!(matches!(
+                restore_post(&api, &admin, post_id).await,
+                PostsPostIdRestorePostResponse::Status200_PostCreated { .. }
+            ));
+        }
+
+        #[tokio::test]
+        async fn test_purge_requires_moderator() {
+            let api = api();
+            let admin = sign_up(&api, "admin@example.com").await;
+            let owner = sign_up(&api, "a@example.com").await;
+            let post_id = create_post(&api, &owner).await.id.unwrap();
+            delete_post(&api, &owner, post_id).await;
+
+            assert_eq!(
+                purge_post(&api, &owner, post_id).await,
+                PostsPostIdPurgePostResponse::Status403
+            );
+            assert_eq!(
+                purge_post(&api, &admin, post_id).await,
+                PostsPostIdPurgePostResponse::Status204
+            );
+            assert!(api.posts.find_in_trash(post_id).unwrap().is_none());
+            assert_eq!(
+                purge_post(&api, &admin, post_id).await,
+                PostsPostIdPurgePostResponse::Status404
+            );
+        }
+
+        #[tokio::test]
+        async fn test_purge_trash_after_retention() {
+            let api = api();
+            let authorization = sign_up(&api, "a@example.com").await;
+            let post_id = create_post(&api, &authorization).await.id.unwrap();
+            let kept = create_post(&api, &authorization).await.id.unwrap();
+            delete_post(&api, &authorization, post_id).await;
+            let retention =
+                chrono::Duration::try_seconds(api.config.posts.trash_retention as i64).unwrap();
+
+            assert_eq!(api.purge_trash(Utc::now()).unwrap(), 0);
+            assert_eq!(
+                api.purge_trash(Utc::now() + retention + chrono::Duration::seconds(1))
+                    .unwrap(),
+                1
+            );
+            assert!(list_trash(&api, &authorization).await.is_empty());
+            assert!(api.posts.find(kept).unwrap().is_some());
+        }
+    }
+
+    mod drafts {
+        use super::*;
+
+        async fn create(
+            api: &ApiImpl,
+            authorization: &str,
+            status: Option<models::PostStatus>,
+            publish_at: Option<DateTime<Utc>>,
+        ) -> Result<PostsPostResponse, AppError> {
+            api.posts_post(
+                Method::POST,
+                Host("localhost".to_string()),
+                CookieJar::new(),
+                PostsPostHeaderParams {
+                    authorization: Some(authorization.to_string()),
+                },
+                Some(models::PostsPostRequest {
+                    board_id: Some(general_board(api)),
+                    status,
+                    publish_at,
+                    ..models::PostsPostRequest::new("title".to_string(), "content".to_string())
+                }),
+            )
+            .await
+        }
+
+        async fn create_draft(api: &ApiImpl, authorization: &str) -> PostId {
+            let PostsPostResponse::Status201_PostCreated(created) =
+                create(api, authorization, Some(models::PostStatus::Draft), None)
+                    .await
+                    .unwrap()
+            else {
+                panic!("draft was not created");
+            };
+            assert_eq!(created.status, Some(models::PostStatus::Draft));
+            created.id.unwrap()
+        }
+
+        async fn list_drafts(api: &ApiImpl, authorization: &str) -> Vec<models::Post> {
+            let MeDraftsGetResponse::Status200_ListOfPosts(posts) = api
+                .me_drafts_get(
+                    Method::GET,
+                    Host("localhost".to_string()),
+                    CookieJar::new(),
+                    MeDraftsGetHeaderParams {
+                        authorization: Some(authorization.to_string()),
+                    },
+                    MeDraftsGetQueryParams {
+                        limit: None,
+                        offset: None,
+                    },
+                )
+                .await
+                .unwrap()
+            else {
+                panic!("drafts were not listed");
+            };
+            posts
+        }
+
+        async fn publish_post(
+            api: &ApiImpl,
+            authorization: &str,
+            post_id: PostId,
+            publish_at: Option<DateTime<Utc>>,
+        ) -> Result<PostsPostIdPublishPostResponse, AppError> {
+            api.posts_post_id_publish_post(
+                Method::POST,
+                Host("localhost".to_string()),
+                CookieJar::new(),
+                PostsPostIdPublishPostHeaderParams {
+                    authorization: Some(authorization.to_string()),
+                    if_match: None,
+                },
+                PostsPostIdPublishPostPathParams { post_id },
+                Some(models::PostsPostIdPublishPostRequest { publish_at }),
+            )
+            .await
+        }
+
+        #[tokio::test]
+        async fn test_draft_is_visible_only_to_author() {
+            let api = api();
+            let author = sign_up(&api, "a@example.com").await;
+            let admin = sign_up(&api, "admin@example.com").await;
+            let post_id = create_draft(&api, &author).await;
+
+            assert!(matches!(
+                get_post(&api, &author, post_id, None).await,
+                PostsPostIdGetResponse::Status200_PostCreated { .. }
+            ));
+            assert_eq!(
+                get_post(&api, &admin, post_id, None).await,
+                PostsPostIdGetResponse::Status404
+            );
+            let PostsGetResponse::Status200_ListOfPosts { body, .. } =
+                list_posts(&api, Some(&author)).await
+            else {
+                panic!("posts were not listed");
+            };
+            assert!(body.is_empty());
+            let drafts = list_drafts(&api, &author).await;
+            assert_eq!(drafts.len(), 1);
+            assert_eq!(drafts[0].id, Some(post_id));
+            assert!(list_drafts(&api, &admin).await.is_empty());
+            // 下書きの作成ではイベントを配信しない
+            let published = create_post(&api, &author).await.id.unwrap();
+            let event = api
+                .events
+                .subscribe(Some(0), service::events::EventFilter::default())
+                .next()
+                .await
+                .unwrap();
+            assert_eq!(
+                (event.kind, event.post.id),
+                (EventKind::PostCreated, published)
+            );
+        }
+
+        #[tokio::test]
+        async fn test_publish_draft() {
+            let api = api();
+            let author = sign_up(&api, "a@example.com").await;
+            let other = sign_up(&api, "b@example.com").await;
+            let post_id = create_draft(&api, &author).await;
+
+            assert_eq!(
+                publish_post(&api, &other, post_id, None).await.unwrap(),
+                PostsPostIdPublishPostResponse::Status404
+            );
+            let PostsPostIdPublishPostResponse::Status200_PostCreated { body, e_tag } =
+                publish_post(&api, &author, post_id, None).await.unwrap()
+            else {
+                panic!("draft was not published");
+            };
+
+            assert_eq!(e_tag, "\"2\"");
+            assert_eq!(body.status, Some(models::PostStatus::Published));
+            assert!(matches!(
+                get_post(&api, &other, post_id, None).await,
+                PostsPostIdGetResponse::Status200_PostCreated { .. }
+            ));
+            assert!(list_drafts(&api, &author).await.is_empty());
+            let mut subscription = api
+                .events
+                .subscribe(Some(0), service::events::EventFilter::default());
+            let event = subscription.next().await.unwrap();
+            assert_eq!(
+                (event.kind, event.post.id),
+                (EventKind::PostCreated, post_id)
+            );
+            assert_eq!(
+                publish_post(&api, &author, post_id, None).await.unwrap(),
+                PostsPostIdPublishPostResponse::Status409
+            );
+        }
+
+        #[tokio::test]
+        async fn test_scheduled_post_is_published_when_due() {
+            let api = api();
+            let author = sign_up(&api, "a@example.com").await;
+            let publish_at = Utc::now() + chrono::Duration::hours(1);
+            let PostsPostResponse::Status201_PostCreated(created) =
+                create(&api, &author, None, Some(publish_at)).await.unwrap()
+            else {
+                panic!("post was not scheduled");
+            };
+            let post_id = created.id.unwrap();
+
+            assert_eq!(created.status, Some(models::PostStatus::Scheduled));
+            assert_eq!(created.publish_at, Some(publish_at));
+            assert_eq!(api.publish_due_posts(Utc::now()).unwrap(), 0);
+            assert_eq!(
+                api.publish_due_posts(publish_at + chrono::Duration::seconds(1))
+                    .unwrap(),
+                1
+            );
+            let post = api.posts.find(post_id).unwrap().unwrap();
+            assert!(post.is_published());
+            assert_eq!(post.created_at, publish_at);
+            let event = api
+                .events
+                .subscribe(Some(0), service::events::EventFilter::default())
+                .next()
+                .await
+                .unwrap();
+            assert_eq!(
+                (event.kind, event.post.id),
+                (EventKind::PostCreated, post_id)
+            );
+        }
+
+        #[tokio::test]
+        async fn test_invalid_publication_is_rejected() {
+            let api = api();
+            let author = sign_up(&api, "a@example.com").await;
+            let past = Utc::now() - chrono::Duration::minutes(1);
+            let field = |field: &str, code: &str| vec![(field.to_string(), code.to_string())];
+
+            assert_eq!(
+                rejected_fields(
+                    create(&api, &author, Some(models::PostStatus::Scheduled), None)
+                        .await
+                        .unwrap_err()
+                ),
+                field("publishAt", "required")
+            );
+            assert_eq!(
+                rejected_fields(create(&api, &author, None, Some(past)).await.unwrap_err()),
+                field("publishAt", "past")
+            );
+            assert_eq!(
+                rejected_fields(
+                    create(&api, &author, Some(models::PostStatus::Draft), Some(past))
+                        .await
+                        .unwrap_err()
+                ),
+                field("publishAt", "not_allowed")
+            );
+            let post_id = create_draft(&api, &author).await;
+            assert_eq!(
+                rejected_fields(
+                    publish_post(&api, &author, post_id, Some(past))
+                        .await
+                        .unwrap_err()
+                ),
+                field("publishAt", "past")
+            );
+        }
+    }
+
+    mod pagination {
+        use super::*;
+
+        fn query() -> PostsGetQueryParams {
+            PostsGetQueryParams {
+                limit: None,
+                cursor: None,
+                sort: None,
+                user_id: None,
+                since: None,
+                until: None,
+            }
+        }
+
+        async fn page(
+            api: &ApiImpl,
+            authorization: &str,
+            query_params: PostsGetQueryParams,
+        ) -> PostsGetResponse {
+            api.posts_get(
+                Method::GET,
+                Host("localhost".to_string()),
+                CookieJar::new(),
+                PostsGetHeaderParams {
+                    authorization: Some(authorization.to_string()),
+                },
+                query_params,
+            )
+            .await
+            .unwrap()
+        }
+
+        #[tokio::test]
+        async fn test_follow_next_cursor() {
+            let api = api();
+            let authorization = sign_up(&api, "a@example.com").await;
+            let mut created = vec![];
+            for _ in 0..3 {
+                created.push(create_post(&api, &authorization).await.id.unwrap());
+            }
+
+            let PostsGetResponse::Status200_ListOfPosts {
+                body: first,
+                link,
+                x_next_cursor: Some(next),
+            } = page(
+                &api,
+                &authorization,
+                PostsGetQueryParams {
+                    limit: Some(2),
+                    ..query()
+                },
+            )
+            .await
+            else {
+                panic!("first page has no next cursor");
+            };
+            let last = page(
+                &api,
+                &authorization,
+                PostsGetQueryParams {
+                    limit: Some(2),
+                    cursor: Some(next.clone()),
+                    ..query()
+                },
+            )
+            .await;
+
+            let ids = |posts: &[Post]| posts.iter().map(|p| p.id.unwrap()).collect::<Vec<_>>();
+            assert_eq!(ids(&first), vec![created[2], created[1]]);
+            assert_eq!(
+                link,
+                Some(format!("</posts?limit=2&cursor={}>; rel=\"next\"", next))
+            );
+            let PostsGetResponse::Status200_ListOfPosts {
+                body: last,
+                link: None,
+                x_next_cursor: None,
+            } = last
+            else {
+                panic!("last page has a next cursor");
+            };
+            assert_eq!(ids(&last), vec![created[0]]);
+        }
+
+        #[tokio::test]
+        async fn test_sort_and_filter() {
+            let api = api();
+            let a = sign_up(&api, "a@example.com").await;
+            let b = sign_up(&api, "b@example.com").await;
+            let first = create_post(&api, &a).await;
+            create_post(&api, &b).await;
+            let third = create_post(&api, &a).await;
+
+            let result = page(
+                &api,
+                &a,
+                PostsGetQueryParams {
+                    sort: Some(models::PostSort::Oldest),
+                    user_id: first.user_id,
+                    since: first.created_at,
+                    ..query()
+                },
+            )
+            .await;
+
+            let PostsGetResponse::Status200_ListOfPosts { body: posts, .. } = result else {
+                panic!("posts were not listed");
+            };
+            assert_eq!(posts, vec![first, third]);
+        }
+
+        #[tokio::test]
+        async fn test_invalid_cursor_is_bad_request() {
+            let api = api();
+            let authorization = sign_up(&api, "a@example.com").await;
+            create_post(&api, &authorization).await;
+            create_post(&api, &authorization).await;
+            let PostsGetResponse::Status200_ListOfPosts {
+                x_next_cursor: Some(next),
+                ..
+            } = page(
+                &api,
+                &authorization,
+                PostsGetQueryParams {
+                    limit: Some(1),
+                    ..query()
+                },
+            )
+            .await
+            else {
+                panic!("first page has no next cursor");
+            };
+
+            for (sort, cursor) in [
+                (None, "not a cursor".to_string()),
+                (Some(models::PostSort::Oldest), next),
+            ] {
+                let result = api
+                    .posts_get(
+                        Method::GET,
+                        Host("localhost".to_string()),
+                        CookieJar::new(),
+                        PostsGetHeaderParams {
+                            authorization: Some(authorization.clone()),
+                        },
+                        PostsGetQueryParams {
+                            sort,
+                            cursor: Some(cursor),
+                            ..query()
+                        },
+                    )
+                    .await;
+
+                assert_eq!(
+                    rejected_fields(result.unwrap_err()),
+                    vec![("cursor".to_string(), "invalid".to_string())]
+                );
+            }
+        }
+    }
+
+    mod admin {
+        use super::*;
+
+        async fn grant(
+            api: &ApiImpl,
+            authorization: &str,
+            user_id: UserId,
+            role: models::Role,
+        ) -> Result<AdminUsersUserIdRolePutResponse, AppError> {
+            api.admin_users_user_id_role_put(
+                Method::PUT,
+                Host("localhost".to_string()),
+                CookieJar::new(),
+                AdminUsersUserIdRolePutHeaderParams {
+                    authorization: Some(authorization.to_string()),
+                },
+                AdminUsersUserIdRolePutPathParams { user_id },
+                Some(models::AdminUsersUserIdRolePutRequest::new(role)),
+            )
+            .await
+        }
+
+        #[tokio::test]
+        async fn test_configured_admin_grants_role() {
+            let api = api();
+            let admin = sign_up(&api, "admin@example.com").await;
+            let member = sign_up(&api, "a@example.com").await;
+            let member_id = user_id(&api, "a@example.com");
+
+            let result = grant(&api, &admin, member_id, models::Role::Moderator)
+                .await
+                .unwrap();
+
+            let AdminUsersUserIdRolePutResponse::Status200_RoleUpdated(user) = result else {
+                panic!("role was not granted");
+            };
+            assert_eq!(user.role, Some(models::Role::Moderator));
+            // 旧ロールのトークンは失効する
+            assert!(matches!(
+                list_posts(&api, Some(&member)).await,
+                PostsGetResponse::Status401 { .. }
+            ));
+        }
+
+        #[tokio::test]
+        async fn test_member_cannot_grant_role() {
+            let api = api();
+            let member = sign_up(&api, "a@example.com").await;
+            let member_id = user_id(&api, "a@example.com");
+
+            let result = grant(&api, &member, member_id, models::Role::Admin)
+                .await
+                .unwrap();
+
+            assert_eq!(result, AdminUsersUserIdRolePutResponse::Status403);
+        }
+
+        #[tokio::test]
+        async fn test_revoke_role() {
+            let api = api();
+            let admin = sign_up(&api, "admin@example.com").await;
+            sign_up(&api, "a@example.com").await;
+            let member_id = user_id(&api, "a@example.com");
+            api.users.set_role(member_id, Role::Moderator).unwrap();
+
+            let result = api
+                .admin_users_user_id_role_delete(
+                    Method::DELETE,
+                    Host("localhost".to_string()),
+                    CookieJar::new(),
+                    AdminUsersUserIdRoleDeleteHeaderParams {
+                        authorization: Some(admin.clone()),
+                    },
+                    AdminUsersUserIdRoleDeletePathParams { user_id: member_id },
+                )
+                .await
+                .unwrap();
+
+            assert!(matches!(
+                result,
+                AdminUsersUserIdRoleDeleteResponse::Status200_RoleUpdated(models::User {
+                    role: Some(models::Role::Member),
+                    ..
+                })
+            ));
+            let own = grant(
+                &api,
+                &admin,
+                user_id(&api, "admin@example.com"),
+                models::Role::Member,
+            )
+            .await;
+            assert_eq!(
+                rejected_fields(own.unwrap_err()),
+                vec![("userId".to_string(), "self".to_string())]
+            );
+            assert_eq!(
+                grant(&api, &admin, 999, models::Role::Member)
+                    .await
+                    .unwrap(),
+                AdminUsersUserIdRolePutResponse::Status404
+            );
+        }
+
+        #[tokio::test]
+        async fn test_missing_role_is_problem() {
+            let api = api();
+            let admin = sign_up(&api, "admin@example.com").await;
+            sign_up(&api, "a@example.com").await;
+
+            let problem = api
+                .admin_users_user_id_role_put(
+                    Method::PUT,
+                    Host("localhost".to_string()),
+                    CookieJar::new(),
+                    AdminUsersUserIdRolePutHeaderParams {
+                        authorization: Some(admin),
+                    },
+                    AdminUsersUserIdRolePutPathParams {
+                        user_id: user_id(&api, "a@example.com"),
+                    },
+                    None,
+                )
+                .await
+                .unwrap_err()
+                .problem();
+
+            assert_eq!(problem.status, 400);
+            assert_eq!(problem.code, models::ErrorCode::MissingBody);
+        }
+
+        #[tokio::test]
+        async fn test_sign_up_does_not_grant_admin() {
+            let mut api = api();
+            let mut config = (*api.config).clone();
+            config.users.admin_emails = vec!["owner@example.com".to_string()];
+            api.config = Arc::new(config);
+
+            let UsersPostResponse::Status201_UserCreatedSuccessfully(user) = api
+                .users_post(
+                    Method::POST,
+                    Host("localhost".to_string()),
+                    CookieJar::new(),
+                    Some(models::UsersPostRequest::new(
+                        "name".to_string(),
+                        "owner@example.com".to_string(),
+                        "password".to_string(),
+                    )),
+                )
+                .await
+                .unwrap()
+            else {
+                panic!("sign up failed");
+            };
+
+            assert_eq!(user.role, Some(models::Role::Member));
+        }
+
+        #[test]
+        fn test_promote_admins() {
+            let mut api = api();
+            let mut config = (*api.config).clone();
+            config.users.admin_emails = vec!["Owner@Example.com".to_string()];
+            api.config = Arc::new(config);
+            let user = api
+                .users
+                .create(
+                    NewUser::new(
+                        Name::parse("name").unwrap(),
+                        Email::parse("owner@example.com").unwrap(),
+                        Password::parse("password").unwrap(),
+                        Role::Member,
+                        &Argon2::default(),
+                    )
+                    .unwrap(),
+                )
+                .unwrap();
+
+            promote_admins(api.users.as_ref(), &api.config).unwrap();
+
+            assert_eq!(api.users.find(user.id).unwrap().unwrap().role, Role::Admin);
+        }
+
+        #[tokio::test]
+        async fn test_create_admins_in_memory() {
+            let api = api();
+            create_admins(api.users.as_ref(), &api.config).unwrap();
+
+            let admin = api
+                .users
+                .find_by_email("admin@example.com")
+                .unwrap()
+                .unwrap();
+            let token = login(&api, "admin@example.com").await;
+
+            assert_eq!(admin.role, Role::Admin);
+            assert!(token.token.is_some());
+        }
+    }
+
+    mod webhooks {
+        use super::*;
+
+        async fn register(
+            api: &ApiImpl,
+            authorization: &str,
+            url: &str,
+        ) -> Result<AdminWebhooksPostResponse, AppError> {
+            api.admin_webhooks_post(
+                Method::POST,
+                Host("localhost".to_string()),
+                CookieJar::new(),
+                AdminWebhooksPostHeaderParams {
+                    authorization: Some(authorization.to_string()),
+                },
+                Some(models::AdminWebhooksPostRequest::new(url.to_string())),
+            )
+            .await
+        }
+
+        async fn retry(
+            api: &ApiImpl,
+            authorization: &str,
+            delivery_id: Uuid,
+        ) -> AdminWebhookDeliveriesDeliveryIdRetryPostResponse {
+            api.admin_webhook_deliveries_delivery_id_retry_post(
+                Method::POST,
+                Host("localhost".to_string()),
+                CookieJar::new(),
+                AdminWebhookDeliveriesDeliveryIdRetryPostHeaderParams {
+                    authorization: Some(authorization.to_string()),
+                },
+                AdminWebhookDeliveriesDeliveryIdRetryPostPathParams { delivery_id },
+            )
+            .await
+            .unwrap()
+        }
+
+        #[tokio::test]
+        async fn test_member_cannot_manage_webhooks() {
+            let api = api();
+            let member = sign_up(&api, "a@example.com").await;
+
+            let result = register(&api, &member, "https://example.com/hook").await;
+
+            assert_eq!(result.unwrap(), AdminWebhooksPostResponse::Status403);
+        }
+
+        #[tokio::test]
+        async fn test_register_list_and_delete() {
+            let api = api();
+            let admin = sign_up(&api, "admin@example.com").await;
+
+            let AdminWebhooksPostResponse::Status201(created) =
+                register(&api, &admin, "https://example.com/hook")
+                    .await
+                    .unwrap()
+            else {
+                panic!("webhook was not registered");
+            };
+            assert!(created.secret.is_some());
+
+            let AdminWebhooksGetResponse::Status200(webhooks) = api
+                .admin_webhooks_get(
+                    Method::GET,
+                    Host("localhost".to_string()),
+                    CookieJar::new(),
+                    AdminWebhooksGetHeaderParams {
+                        authorization: Some(admin.clone()),
+                    },
+                )
+                .await
+                .unwrap()
+            else {
+                panic!("webhooks were not listed");
+            };
+            // 一覧には署名の鍵を含めない
+            assert_eq!(webhooks.len(), 1);
+            assert_eq!(webhooks[0].id, created.id);
+            assert_eq!(webhooks[0].secret, None);
+
+            let delete = || {
+                api.admin_webhooks_webhook_id_delete(
+                    Method::DELETE,
+                    Host("localhost".to_string()),
+                    CookieJar::new(),
+                    AdminWebhooksWebhookIdDeleteHeaderParams {
+                        authorization: Some(admin.clone()),
+                    },
+                    AdminWebhooksWebhookIdDeletePathParams {
+                        webhook_id: created.id,
+                    },
+                )
+            };
+            assert_eq!(
+                delete().await.unwrap(),
+                AdminWebhooksWebhookIdDeleteResponse::Status204
+            );
+            assert_eq!(
+                delete().await.unwrap(),
+                AdminWebhooksWebhookIdDeleteResponse::Status404
+            );
+        }
+
+        #[tokio::test]
+        async fn test_invalid_url() {
+            let api = api();
+            let admin = sign_up(&api, "admin@example.com").await;
+
+            let result = register(&api, &admin, "ftp://example.com/hook").await;
+
+            assert!(matches!(result, Err(AppError::Validation(_))));
+        }
+
+        #[tokio::test]
+        async fn test_post_creation_enqueues_delivery() {
+            let api = api();
+            let admin = sign_up(&api, "admin@example.com").await;
+            let AdminWebhooksPostResponse::Status201(webhook) =
+                register(&api, &admin, "https://example.com/hook")
+                    .await
+                    .unwrap()
+            else {
+                panic!("webhook was not registered");
+            };
+
+            let post = create_post(&api, &admin).await;
+
+            let deliveries = api
+                .webhooks
+                .list_deliveries(&DeliveryQuery {
+                    webhook_id: Some(webhook.id),
+                    status: None,
+                    limit: 10,
+                })
+                .unwrap();
+            assert_eq!(deliveries.len(), 1);
+            assert_eq!(deliveries[0].event, WebhookEvent::PostCreated);
+            assert_eq!(deliveries[0].status, DeliveryStatus::Pending);
+            let payload: serde_json::Value = serde_json::from_str(&deliveries[0].payload).unwrap();
+            assert_eq!(payload["type"], "post_created");
+            assert_eq!(payload["post"]["id"], post.id.unwrap().to_string());
+        }
+
+        #[tokio::test]
+        async fn test_retry_dead_delivery() {
+            let api = api();
+            let admin = sign_up(&api, "admin@example.com").await;
+            let AdminWebhooksPostResponse::Status201(webhook) =
+                register(&api, &admin, "https://example.com/hook")
+                    .await
+                    .unwrap()
+            else {
+                panic!("webhook was not registered");
+            };
+            let mut delivery =
+                entity::WebhookDelivery::new(webhook.id, WebhookEvent::PostCreated, "{}".into());
+            delivery.status = DeliveryStatus::Dead;
+            delivery.attempts = 8;
+            api.webhooks.insert_delivery(delivery.clone()).unwrap();
+
+            let AdminWebhookDeliveriesDeliveryIdRetryPostResponse::Status200(retried) =
+                retry(&api, &admin, delivery.id).await
+            else {
+                panic!("delivery was not retried");
+            };
+            assert_eq!(retried.status, models::WebhookDeliveryStatus::Pending);
+            assert_eq!(retried.attempts, 0);
+            // 配信待ちの状態では再試行できない
+            assert_eq!(
+                retry(&api, &admin, delivery.id).await,
+                AdminWebhookDeliveriesDeliveryIdRetryPostResponse::Status409
+            );
+            assert_eq!(
+                retry(&api, &admin, Uuid::new_v4()).await,
+                AdminWebhookDeliveriesDeliveryIdRetryPostResponse::Status404
+            );
+        }
+    }
+
+    mod boards {
+        use super::*;
+
+        fn board_request(
+            slug: &str,
+            settings: Option<models::BoardSettings>,
+        ) -> models::AdminBoardsPostRequest {
+            models::AdminBoardsPostRequest {
+                settings,
+                ..models::AdminBoardsPostRequest::new(slug.to_string(), "name".to_string())
+            }
+        }
+
+        async fn create_board(
+            api: &ApiImpl,
+            authorization: &str,
+            request: models::AdminBoardsPostRequest,
+        ) -> Result<AdminBoardsPostResponse, AppError> {
+            api.admin_boards_post(
+                Method::POST,
+                Host("localhost".to_string()),
+                CookieJar::new(),
+                AdminBoardsPostHeaderParams {
+                    authorization: Some(authorization.to_string()),
+                },
+                Some(request),
+            )
+            .await
+        }
+
+        async fn delete_board(
+            api: &ApiImpl,
+            authorization: &str,
+            slug: &str,
+        ) -> AdminBoardsSlugDeleteResponse {
+            api.admin_boards_slug_delete(
+                Method::DELETE,
+                Host("localhost".to_string()),
+                CookieJar::new(),
+                AdminBoardsSlugDeleteHeaderParams {
+                    authorization: Some(authorization.to_string()),
+                },
+                AdminBoardsSlugDeletePathParams {
+                    slug: slug.to_string(),
+                },
+            )
+            .await
+            .unwrap()
+        }
+
+        async fn post_to(
+            api: &ApiImpl,
+            authorization: &str,
+            board_id: Option<BoardId>,
+            content: &str,
+        ) -> Result<PostsPostResponse, AppError> {
+            api.posts_post(
+                Method::POST,
+                Host("localhost".to_string()),
+                CookieJar::new(),
+                PostsPostHeaderParams {
+                    authorization: Some(authorization.to_string()),
+                },
+                Some(models::PostsPostRequest {
+                    board_id,
+                    ..models::PostsPostRequest::new("title".to_string(), content.to_string())
+                }),
+            )
+            .await
+        }
+
+        async fn board_posts(
+            api: &ApiImpl,
+            authorization: &str,
+            slug: &str,
+            limit: Option<i32>,
+        ) -> BoardsSlugPostsGetResponse {
+            api.boards_slug_posts_get(
+                Method::GET,
+                Host("localhost".to_string()),
+                CookieJar::new(),
+                BoardsSlugPostsGetHeaderParams {
+                    authorization: Some(authorization.to_string()),
+                },
+                BoardsSlugPostsGetPathParams {
+                    slug: slug.to_string(),
+                },
+                BoardsSlugPostsGetQueryParams {
+                    limit,
+                    cursor: None,
+                    sort: None,
+                    user_id: None,
+                    since: None,
+                    until: None,
+                },
+            )
+            .await
+            .unwrap()
+        }
+
+        #[tokio::test]
+        async fn test_member_cannot_manage_boards() {
+            let api = api();
+            let member = sign_up(&api, "a@example.com").await;
+
+            let result = create_board(&api, &member, board_request("rust", None)).await;
+
+            assert_eq!(result.unwrap(), AdminBoardsPostResponse::Status403);
+            assert_eq!(
+                delete_board(&api, &member, "general").await,
+                AdminBoardsSlugDeleteResponse::Status403
+            );
+        }
+
+        #[tokio::test]
+        async fn test_create_update_and_delete() {
+            let api = api();
+            let admin = sign_up(&api, "admin@example.com").await;
+
+            let AdminBoardsPostResponse::Status201(created) =
+                create_board(&api, &admin, board_request("rust", None))
+                    .await
+                    .unwrap()
+            else {
+                panic!("board was not created");
+            };
+            assert_eq!(created.settings.posting_role, Some(models::Role::Member));
+            assert_eq!(created.settings.allow_replies, Some(true));
+            assert_eq!(
+                created.settings.max_post_length,
+                Some(Content::MAX_LENGTH as i32)
+            );
+            assert_eq!(
+                create_board(&api, &admin, board_request("rust", None))
+                    .await
+                    .unwrap(),
+                AdminBoardsPostResponse::Status409
+            );
+
+            let AdminBoardsSlugPutResponse::Status200(updated) = api
+                .admin_boards_slug_put(
+                    Method::PUT,
+                    Host("localhost".to_string()),
+                    CookieJar::new(),
+                    AdminBoardsSlugPutHeaderParams {
+                        authorization: Some(admin.clone()),
+                    },
+                    AdminBoardsSlugPutPathParams {
+                        slug: "rust".to_string(),
+                    },
+                    Some(models::AdminBoardsPostRequest {
+                        description: Some("Rustの話題".to_string()),
+                        ..board_request("rust-lang", None)
+                    }),
+                )
+                .await
+                .unwrap()
+            else {
+                panic!("board was not updated");
+            };
+            assert_eq!(updated.id, created.id);
+            assert_eq!(updated.description.as_deref(), Some("Rustの話題"));
+
+            let BoardsGetResponse::Status200(boards) = api
+                .boards_get(
+                    Method::GET,
+                    Host("localhost".to_string()),
+                    CookieJar::new(),
+                    BoardsGetHeaderParams {
+                        authorization: Some(admin.clone()),
+                    },
+                )
+                .await
+                .unwrap()
+            else {
+                panic!("boards were not listed");
+            };
+            assert_eq!(
+                boards.iter().map(|b| b.slug.as_str()).collect::<Vec<_>>(),
+                ["general", "rust-lang"]
+            );
+            let get = |slug: &str| {
+                api.boards_slug_get(
+                    Method::GET,
+                    Host("localhost".to_string()),
+                    CookieJar::new(),
+                    BoardsSlugGetHeaderParams {
+                        authorization: Some(admin.clone()),
+                    },
+                    BoardsSlugGetPathParams {
+                        slug: slug.to_string(),
+                    },
+                )
+            };
+            assert_eq!(get("rust").await.unwrap(), BoardsSlugGetResponse::Status404);
+            assert_eq!(
+                get("rust-lang").await.unwrap(),
+                BoardsSlugGetResponse::Status200(updated)
+            );
+
+            assert_eq!(
+                delete_board(&api, &admin, "rust-lang").await,
+                AdminBoardsSlugDeleteResponse::Status204
+            );
+            assert_eq!(
+                delete_board(&api, &admin, "rust-lang").await,
+                AdminBoardsSlugDeleteResponse::Status404
+            );
+        }
+
+        #[tokio::test]
+        async fn test_invalid_board_is_rejected() {
+            let api = api();
+            let admin = sign_up(&api, "admin@example.com").await;
+
+            let result = create_board(&api, &admin, board_request("Rust Lang", None)).await;
+
+            assert_eq!(
+                rejected_fields(result.unwrap_err()),
+                [("slug".to_string(), "slug".to_string())]
+            );
+            let result = create_board(
+                &api,
+                &admin,
+                board_request(
+                    "rust",
+                    Some(models::BoardSettings {
+                        max_post_length: Some(0),
+                        ..models::BoardSettings::new()
+                    }),
+                ),
+            )
+            .await;
+            assert!(matches!(result, Err(AppError::Validation(_))));
+        }
+
+        #[tokio::test]
+        async fn test_board_with_posts_is_not_deleted() {
+            let api = api();
+            let admin = sign_up(&api, "admin@example.com").await;
+            let post = create_post(&api, &admin).await;
+
+            assert_eq!(post.board_id, Some(general_board(&api)));
+            assert_eq!(
+                delete_board(&api, &admin, "general").await,
+                AdminBoardsSlugDeleteResponse::Status409
+            );
+        }
+
+        #[tokio::test]
+        async fn test_trashed_posts_do_not_keep_board() {
+            let api = api();
+            let admin = sign_up(&api, "admin@example.com").await;
+            let post_id = create_post(&api, &admin).await.id.unwrap();
+            let deleted = api
+                .posts_post_id_delete(
+                    Method::DELETE,
+                    Host("localhost".to_string()),
+                    CookieJar::new(),
+                    PostsPostIdDeleteHeaderParams {
+                        authorization: Some(admin.clone()),
+                        if_match: None,
+                    },
+                    PostsPostIdDeletePathParams { post_id },
+                )
+                .await
+                .unwrap();
+
+            assert_eq!(deleted, PostsPostIdDeleteResponse::Status204_NoContent);
+            assert_eq!(
+                delete_board(&api, &admin, "general").await,
+                AdminBoardsSlugDeleteResponse::Status204
+            );
+            assert!(api.posts.find_in_trash(post_id).unwrap().is_none());
+        }
+
+        #[tokio::test]
+        async fn test_post_requires_existing_board() {
+            let api = api();
+            let member = sign_up(&api, "a@example.com").await;
+
+            let missing = post_to(&api, &member, None, "content").await;
+            let unknown = post_to(&api, &member, Some(Uuid::new_v4()), "content").await;
+
+            assert_eq!(
+                rejected_fields(missing.unwrap_err()),
+                [("boardId".to_string(), "required".to_string())]
+            );
+            assert_eq!(
+                rejected_fields(unknown.unwrap_err()),
+                [("boardId".to_string(), "not_found".to_string())]
+            );
+        }
+
+        #[tokio::test]
+        async fn test_board_settings_are_enforced() {
+            let api = api();
+            let admin = sign_up(&api, "admin@example.com").await;
+            let member = sign_up(&api, "a@example.com").await;
+            let AdminBoardsPostResponse::Status201(news) = create_board(
+                &api,
+                &admin,
+                board_request(
+                    "news",
+                    Some(models::BoardSettings {
+                        posting_role: Some(models::Role::Moderator),
+                        allow_replies: Some(false),
+                        max_post_length: Some(10),
+                    }),
+                ),
+            )
+            .await
+            .unwrap() else {
+                panic!("board was not created");
+            };
+
+            assert_eq!(
+                post_to(&api, &member, Some(news.id), "content")
+                    .await
+                    .unwrap(),
+                PostsPostResponse::Status403
+            );
+            let too_long = post_to(&api, &admin, Some(news.id), "too long content").await;
+            assert_eq!(
+                rejected_fields(too_long.unwrap_err()),
+                [("content".to_string(), "length".to_string())]
+            );
+            let PostsPostResponse::Status201_PostCreated(post) =
+                post_to(&api, &admin, Some(news.id), "content")
+                    .await
+                    .unwrap()
+            else {
+                panic!("post was not created");
+            };
+            // 返信を受け付けないボードには管理者も返信できない
+            for authorization in [&member, &admin] {
+                assert_eq!(
+                    reply(&api, authorization, post.id.unwrap(), None)
+                        .await
+                        .unwrap(),
+                    PostsPostIdRepliesPostResponse::Status403
+                );
+            }
+        }
+
+        #[tokio::test]
+        async fn test_reply_belongs_to_parent_board() {
+            let api = api();
+            let authorization = sign_up(&api, "a@example.com").await;
+            let post_id = create_post(&api, &authorization).await.id.unwrap();
+
+            let with_board = reply(&api, &authorization, post_id, Some(general_board(&api))).await;
+            let PostsPostIdRepliesPostResponse::Status201_PostCreated(created) =
+                reply(&api, &authorization, post_id, None).await.unwrap()
+            else {
+                panic!("reply was not created");
+            };
+
+            assert_eq!(
+                rejected_fields(with_board.unwrap_err()),
+                [("boardId".to_string(), "not_allowed".to_string())]
+            );
+            assert_eq!(created.board_id, Some(general_board(&api)));
+        }
+
+        #[tokio::test]
+        async fn test_edit_follows_updated_max_length() {
+            let api = api();
+            let admin = sign_up(&api, "admin@example.com").await;
+            let post_id = create_post(&api, &admin).await.id.unwrap();
+            let mut general = api.boards.find(general_board(&api)).unwrap().unwrap();
+            general.settings.max_post_length = 5;
+            api.boards.update(general).unwrap();
+
+            let result = api
+                .posts_post_id_put(
+                    Method::PUT,
+                    Host("localhost".to_string()),
+                    CookieJar::new(),
+                    PostsPostIdPutHeaderParams {
+                        authorization: Some(admin.clone()),
+                        if_match: None,
+                    },
+                    PostsPostIdPutPathParams { post_id },
+                    Some(Post {
+                        title: Some("title".to_string()),
+                        content: Some("content".to_string()),
+                        ..Post::new()
+                    }),
+                )
+                .await;
+
+            assert_eq!(
+                rejected_fields(result.unwrap_err()),
+                [("content".to_string(), "length".to_string())]
+            );
+        }
+
+        #[tokio::test]
+        async fn test_list_board_posts() {
+            let api = api();
+            let admin = sign_up(&api, "admin@example.com").await;
+            let AdminBoardsPostResponse::Status201(rust) =
+                create_board(&api, &admin, board_request("rust", None))
+                    .await
+                    .unwrap()
+            else {
+                panic!("board was not created");
+            };
+            create_post(&api, &admin).await;
+            let mut created = vec![];
+            for _ in 0..2 {
+                let PostsPostResponse::Status201_PostCreated(post) =
+                    post_to(&api, &admin, Some(rust.id), "content")
+                        .await
+                        .unwrap()
+                else {
+                    panic!("post was not created");
+                };
+                created.push(post.id.unwrap());
+            }
+
+            let BoardsSlugPostsGetResponse::Status200_ListOfPosts {
+                body: posts,
+                link,
+                x_next_cursor: Some(next),
+            } = board_posts(&api, &admin, "rust", Some(1)).await
+            else {
+                panic!("first page has no next cursor");
+            };
+
+            assert_eq!(posts.len(), 1);
+            assert_eq!(posts[0].id, Some(created[1]));
+            assert_eq!(
+                link,
+                Some(format!(
+                    "</boards/rust/posts?limit=1&cursor={}>; rel=\"next\"",
+                    next
+                ))
+            );
+            let BoardsSlugPostsGetResponse::Status200_ListOfPosts { body: all, .. } =
+                board_posts(&api, &admin, "rust", None).await
+            else {
+                panic!("posts were not listed");
+            };
+            assert_eq!(
+                all.iter().map(|p| p.id.unwrap()).collect::<Vec<_>>(),
+                vec![created[1], created[0]]
+            );
+            assert_eq!(
+                board_posts(&api, &admin, "missing", None).await,
+                BoardsSlugPostsGetResponse::Status404
+            );
+        }
+    }
 }
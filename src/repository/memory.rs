@@ -0,0 +1,1178 @@
+use super::{
+    BoardRepository, DeliveryQuery, PostCursor, PostQuery, PostRepository, RefreshTokenRepository,
+    RepositoryError, RevocationRepository, SearchCandidate, UserRepository, WebhookRepository,
+};
+use crate::entity::{
+    Board, NewUser, Post, PostRevision, RefreshToken, User, Webhook, WebhookDelivery,
+};
+use crate::service::search;
+use crate::value_object::{BoardId, DeliveryStatus, PostId, PostStatus, Role, UserId};
+use chrono::{DateTime, Utc};
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use std::sync::{Mutex, MutexGuard};
+use uuid::Uuid;
+
+fn lock<T>(mutex: &Mutex<T>) -> Result<MutexGuard<'_, T>, RepositoryError> {
+    mutex.lock().map_err(|_e| RepositoryError::Poisoned)
+}
+
+#[derive(Debug, Default)]
+pub struct InMemoryUserRepository {
+    users: Mutex<Vec<User>>,
+}
+
+impl InMemoryUserRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl UserRepository for InMemoryUserRepository {
+    fn create(&self, user: NewUser) -> Result<User, RepositoryError> {
+        // 採番と重複チェックを同じロックの中で行う
+        let mut users = lock(&self.users)?;
+        if users.iter().any(|u| u.email == user.email) {
+            return Err(RepositoryError::Conflict);
+        }
+        let id = users.iter().map(|u| u.id).max().unwrap_or(0) + 1;
+        let user = User::new(id, user);
+        users.push(user.clone());
+        Ok(user)
+    }
+
+    fn find(&self, id: UserId) -> Result<Option<User>, RepositoryError> {
+        Ok(lock(&self.users)?
+            .iter()
+            .find(|user| user.id == id)
+            .cloned())
+    }
+
+    fn find_by_email(&self, email: &str) -> Result<Option<User>, RepositoryError> {
+        Ok(lock(&self.users)?
+            .iter()
+            .find(|user| user.email == email)
+            .cloned())
+    }
+
+    fn set_role(&self, id: UserId, role: Role) -> Result<Option<User>, RepositoryError> {
+        Ok(lock(&self.users)?
+            .iter_mut()
+            .find(|user| user.id == id)
+            .map(|user| {
+                user.role = role;
+                user.clone()
+            }))
+    }
+
+    fn record_login(&self, id: UserId, at: DateTime<Utc>) -> Result<(), RepositoryError> {
+        if let Some(user) = lock(&self.users)?.iter_mut().find(|user| user.id == id) {
+            user.last_login_at = Some(at);
+        }
+        Ok(())
+    }
+
+    fn set_password(&self, id: UserId, password: &str) -> Result<(), RepositoryError> {
+        if let Some(user) = lock(&self.users)?.iter_mut().find(|user| user.id == id) {
+            user.password = password.to_string();
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct InMemoryPostRepository {
+    posts: Mutex<Vec<Post>>,
+    /// 全文検索の索引。`posts`のロックを取得してからロックする
+    index: Mutex<TermIndex>,
+    /// `posts`のロックを取得してからロックする
+    revisions: Mutex<Vec<PostRevision>>,
+}
+
+impl InMemoryPostRepository {
+    /// ロックした投稿の中で投稿を置き換える
+    fn replace(&self, posts: &mut [Post], post: Post) -> Result<bool, RepositoryError> {
+        match posts.iter_mut().find(|p| p.id == post.id) {
+            Some(current) if current.version + 1 != post.version => Err(RepositoryError::Stale),
+            Some(current) => {
+                let (parent_id, at) = (post.parent_id, post.last_activity_at);
+                lock(&self.index)?.insert(&post);
+                *current = post;
+                touch_ancestors(posts, parent_id, at);
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+}
+
+/// 語から投稿ごとの重みを引く転置索引
+#[derive(Debug, Default)]
+struct TermIndex {
+    postings: HashMap<String, HashMap<PostId, u32>>,
+    /// 索引から取り除く際に参照する、投稿ごとの語
+    terms: HashMap<PostId, Vec<String>>,
+}
+
+impl TermIndex {
+    fn insert(&mut self, post: &Post) {
+        self.remove(post.id);
+        let weights = search::index_terms(post);
+        self.terms
+            .insert(post.id, weights.keys().cloned().collect());
+        for (term, weight) in weights {
+            self.postings
+                .entry(term)
+                .or_default()
+                .insert(post.id, weight);
+        }
+    }
+
+    fn remove(&mut self, id: PostId) {
+        for term in self.terms.remove(&id).unwrap_or_default() {
+            if let Some(posting) = self.postings.get_mut(&term) {
+                posting.remove(&id);
+                if posting.is_empty() {
+                    self.postings.remove(&term);
+                }
+            }
+        }
+    }
+
+    /// すべての語を含む投稿と、語ごとの重み
+    fn lookup(&self, terms: &[String]) -> Vec<(PostId, Vec<u32>)> {
+        let Some((first, rest)) = terms.split_first() else {
+            return Vec::new();
+        };
+        let Some(posting) = self.postings.get(first) else {
+            return Vec::new();
+        };
+        posting
+            .iter()
+            .filter_map(|(&id, &weight)| {
+                let mut weights = vec![weight];
+                for term in rest {
+                    weights.push(*self.postings.get(term)?.get(&id)?);
+                }
+                Some((id, weights))
+            })
+            .collect()
+    }
+}
+
+impl InMemoryPostRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// 読み出す投稿に直接の返信の数を設定する
+fn with_reply_count(posts: &[Post], post: &Post) -> Post {
+    Post {
+        reply_count: posts
+            .iter()
+            .filter(|reply| reply.parent_id == Some(post.id) && reply.deleted_at.is_none())
+            .count(),
+        ..post.clone()
+    }
+}
+
+/// ゴミ箱にあり、親の投稿と一緒に移されたのではない投稿か
+fn in_trash(posts: &[Post], post: &Post) -> bool {
+    post.deleted_at.is_some()
+        && !posts
+            .iter()
+            .any(|parent| Some(parent.id) == post.parent_id && parent.deleted_at.is_some())
+}
+
+/// `id`の配下の返信のうち`follow`を満たすものを返す。満たさない返信の配下はたどらない
+fn descendants(posts: &[Post], id: PostId, follow: impl Fn(&Post) -> bool) -> Vec<PostId> {
+    let mut found = Vec::new();
+    let mut targets = vec![id];
+    while let Some(target) = targets.pop() {
+        for reply in posts
+            .iter()
+            .filter(|post| post.parent_id == Some(target) && follow(post))
+        {
+            found.push(reply.id);
+            targets.push(reply.id);
+        }
+    }
+    found
+}
+
+/// 投稿と配下のすべての返信を、索引と版とともに削除する
+fn remove_tree(
+    posts: &mut Vec<Post>,
+    index: &mut TermIndex,
+    revisions: &mut Vec<PostRevision>,
+    id: PostId,
+) {
+    let mut targets = descendants(posts, id, |_| true);
+    targets.push(id);
+    posts.retain(|post| !targets.contains(&post.id));
+    revisions.retain(|revision| !targets.contains(&revision.post_id));
+    for target in targets {
+        index.remove(target);
+    }
+}
+
+/// `parent_id`から順に祖先をたどり、`last_activity_at`を`at`まで進める
+fn touch_ancestors(posts: &mut [Post], mut parent_id: Option<PostId>, at: DateTime<Utc>) {
+    while let Some(id) = parent_id {
+        let Some(parent) = posts.iter_mut().find(|post| post.id == id) else {
+            break;
+        };
+        parent.last_activity_at = parent.last_activity_at.max(at);
+        parent_id = parent.parent_id;
+    }
+}
+
+impl PostRepository for InMemoryPostRepository {
+    fn list(&self, query: &PostQuery) -> Result<Vec<Post>, RepositoryError> {
+        let posts = lock(&self.posts)?;
+        let mut found: Vec<Post> = posts
+            .iter()
+            .filter(|post| query.matches(post))
+            .map(|post| with_reply_count(&posts, post))
+            .collect();
+        found.sort_by(|a, b| query.sort.compare(&PostCursor::of(a), &PostCursor::of(b)));
+        if let Some(after) = &query.after {
+            found.retain(|post| query.sort.compare(&PostCursor::of(post), after).is_gt());
+        }
+        found.truncate(query.limit);
+        Ok(found)
+    }
+
+    fn list_replies(
+        &self,
+        parent_id: PostId,
+        limit: usize,
+        offset: usize,
+    ) -> Result<Vec<Post>, RepositoryError> {
+        let posts = lock(&self.posts)?;
+        Ok(posts
+            .iter()
+            .filter(|post| post.parent_id == Some(parent_id) && post.deleted_at.is_none())
+            .skip(offset)
+            .take(limit)
+            .map(|post| with_reply_count(&posts, post))
+            .collect())
+    }
+
+    fn find(&self, id: PostId) -> Result<Option<Post>, RepositoryError> {
+        let posts = lock(&self.posts)?;
+        Ok(posts
+            .iter()
+            .find(|post| post.id == id && post.deleted_at.is_none())
+            .map(|post| with_reply_count(&posts, post)))
+    }
+
+    fn insert(&self, post: Post) -> Result<(), RepositoryError> {
+        let mut posts = lock(&self.posts)?;
+        lock(&self.index)?.insert(&post);
+        lock(&self.revisions)?.push(PostRevision::of(&post, post.user_id));
+        touch_ancestors(&mut posts, post.parent_id, post.last_activity_at);
+        posts.push(post);
+        Ok(())
+    }
+
+    fn update(&self, post: Post) -> Result<bool, RepositoryError> {
+        let mut posts = lock(&self.posts)?;
+        self.replace(&mut posts, post)
+    }
+
+    fn edit(&self, post: Post, editor: UserId) -> Result<bool, RepositoryError> {
+        let mut posts = lock(&self.posts)?;
+        let revision = PostRevision::of(&post, editor);
+        if !self.replace(&mut posts, post)? {
+            return Ok(false);
+        }
+        lock(&self.revisions)?.push(revision);
+        Ok(true)
+    }
+
+    fn trash(&self, post: Post) -> Result<bool, RepositoryError> {
+        let mut posts = lock(&self.posts)?;
+        let (id, deleted_at, deleted_by) = (post.id, post.deleted_at, post.deleted_by);
+        if !self.replace(&mut posts, post)? {
+            return Ok(false);
+        }
+        // 先にゴミ箱に移された返信とその配下は、移された日時を変えない
+        let replies = descendants(&posts, id, |reply| reply.deleted_at.is_none());
+        for reply in posts.iter_mut().filter(|post| replies.contains(&post.id)) {
+            reply.deleted_at = deleted_at;
+            reply.deleted_by = deleted_by;
+            reply.version += 1;
+        }
+        Ok(true)
+    }
+
+    fn restore(&self, post: Post) -> Result<bool, RepositoryError> {
+        let mut posts = lock(&self.posts)?;
+        let id = post.id;
+        let Some(deleted_at) = posts
+            .iter()
+            .find(|post| post.id == id)
+            .and_then(|post| post.deleted_at)
+        else {
+            return Ok(false);
+        };
+        if !self.replace(&mut posts, post)? {
+            return Ok(false);
+        }
+        let replies = descendants(&posts, id, |reply| reply.deleted_at == Some(deleted_at));
+        for reply in posts.iter_mut().filter(|post| replies.contains(&post.id)) {
+            reply.deleted_at = None;
+            reply.deleted_by = None;
+            reply.version += 1;
+        }
+        Ok(true)
+    }
+
+    fn find_in_trash(&self, id: PostId) -> Result<Option<Post>, RepositoryError> {
+        let posts = lock(&self.posts)?;
+        Ok(posts
+            .iter()
+            .find(|post| post.id == id && in_trash(&posts, post))
+            .map(|post| with_reply_count(&posts, post)))
+    }
+
+    fn list_trash(
+        &self,
+        deleted_by: UserId,
+        limit: usize,
+        offset: usize,
+    ) -> Result<Vec<Post>, RepositoryError> {
+        let posts = lock(&self.posts)?;
+        let mut found: Vec<&Post> = posts
+            .iter()
+            .filter(|post| post.deleted_by == Some(deleted_by) && in_trash(&posts, post))
+            .collect();
+        found.sort_by_key(|post| Reverse((post.deleted_at, post.id)));
+        Ok(found
+            .into_iter()
+            .skip(offset)
+            .take(limit)
+            .map(|post| with_reply_count(&posts, post))
+            .collect())
+    }
+
+    fn list_drafts(
+        &self,
+        user_id: UserId,
+        limit: usize,
+        offset: usize,
+    ) -> Result<Vec<Post>, RepositoryError> {
+        let posts = lock(&self.posts)?;
+        let mut found: Vec<&Post> = posts
+            .iter()
+            .filter(|post| {
+                post.user_id == user_id && !post.is_published() && post.deleted_at.is_none()
+            })
+            .collect();
+        found.sort_by_key(|post| Reverse((post.updated_at, post.id)));
+        Ok(found
+            .into_iter()
+            .skip(offset)
+            .take(limit)
+            .map(|post| with_reply_count(&posts, post))
+            .collect())
+    }
+
+    fn publish_due(&self, now: DateTime<Utc>) -> Result<Vec<Post>, RepositoryError> {
+        let mut posts = lock(&self.posts)?;
+        let mut published = Vec::new();
+        for post in posts
+            .iter_mut()
+            .filter(|post| post.status == PostStatus::Scheduled && post.deleted_at.is_none())
+        {
+            if let Some(at) = post.publish_at.filter(|at| *at <= now) {
+                post.publish(at);
+                published.push(post.clone());
+            }
+        }
+        Ok(published)
+    }
+
+    fn has_posts_in(&self, board_id: BoardId) -> Result<bool, RepositoryError> {
+        Ok(lock(&self.posts)?
+            .iter()
+            .any(|post| post.board_id == board_id && post.deleted_at.is_none()))
+    }
+
+    fn purge_trash_in(&self, board_id: BoardId) -> Result<usize, RepositoryError> {
+        let mut posts = lock(&self.posts)?;
+        let mut index = lock(&self.index)?;
+        let mut revisions = lock(&self.revisions)?;
+        let count = posts.len();
+        let trashed: Vec<PostId> = posts
+            .iter()
+            .filter(|post| post.board_id == board_id && post.deleted_at.is_some())
+            .map(|post| post.id)
+            .collect();
+        for id in trashed {
+            remove_tree(&mut posts, &mut index, &mut revisions, id);
+        }
+        Ok(count - posts.len())
+    }
+
+    fn purge_trash(&self, before: DateTime<Utc>) -> Result<usize, RepositoryError> {
+        let mut posts = lock(&self.posts)?;
+        let mut index = lock(&self.index)?;
+        let mut revisions = lock(&self.revisions)?;
+        let count = posts.len();
+        let expired: Vec<PostId> = posts
+            .iter()
+            .filter(|post| post.deleted_at.is_some_and(|at| at < before))
+            .map(|post| post.id)
+            .collect();
+        for id in expired {
+            remove_tree(&mut posts, &mut index, &mut revisions, id);
+        }
+        Ok(count - posts.len())
+    }
+
+    fn delete(&self, id: PostId) -> Result<bool, RepositoryError> {
+        let mut posts = lock(&self.posts)?;
+        let mut index = lock(&self.index)?;
+        let mut revisions = lock(&self.revisions)?;
+        let before = posts.len();
+        remove_tree(&mut posts, &mut index, &mut revisions, id);
+        Ok(posts.len() != before)
+    }
+
+    fn list_revisions(
+        &self,
+        post_id: PostId,
+        limit: usize,
+        offset: usize,
+    ) -> Result<Vec<PostRevision>, RepositoryError> {
+        // 版は番号順に追加される
+        Ok(lock(&self.revisions)?
+            .iter()
+            .filter(|revision| revision.post_id == post_id)
+            .skip(offset)
+            .take(limit)
+            .cloned()
+            .collect())
+    }
+
+    fn find_revision(
+        &self,
+        post_id: PostId,
+        number: u32,
+    ) -> Result<Option<PostRevision>, RepositoryError> {
+        Ok(lock(&self.revisions)?
+            .iter()
+            .find(|revision| revision.post_id == post_id && revision.number == number)
+            .cloned())
+    }
+
+    fn search(
+        &self,
+        terms: &[String],
+        limit: usize,
+    ) -> Result<Vec<SearchCandidate>, RepositoryError> {
+        let posts = lock(&self.posts)?;
+        let mut found: Vec<(&Post, Vec<u32>)> = lock(&self.index)?
+            .lookup(terms)
+            .into_iter()
+            .filter_map(|(id, weights)| {
+                let post = posts.iter().find(|post| {
+                    post.id == id && post.deleted_at.is_none() && post.is_published()
+                })?;
+                Some((post, weights))
+            })
+            .collect();
+        found.sort_by_key(|(post, _)| Reverse((post.created_at, post.id)));
+        found.truncate(limit);
+        Ok(found
+            .into_iter()
+            .map(|(post, weights)| SearchCandidate {
+                post: with_reply_count(&posts, post),
+                weights,
+            })
+            .collect())
+    }
+}
+
+#[derive(Debug)]
+pub struct InMemoryBoardRepository {
+    boards: Mutex<Vec<Board>>,
+}
+
+impl InMemoryBoardRepository {
+    /// SQLiteと同じく既定のボードがある状態で始める
+    pub fn new() -> Self {
+        Self {
+            boards: Mutex::new(vec![Board::general()]),
+        }
+    }
+}
+
+impl Default for InMemoryBoardRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BoardRepository for InMemoryBoardRepository {
+    fn insert(&self, board: Board) -> Result<(), RepositoryError> {
+        let mut boards = lock(&self.boards)?;
+        if boards.iter().any(|b| b.slug == board.slug) {
+            return Err(RepositoryError::Conflict);
+        }
+        boards.push(board);
+        Ok(())
+    }
+
+    fn list(&self) -> Result<Vec<Board>, RepositoryError> {
+        let mut boards = lock(&self.boards)?.clone();
+        boards.sort_by(|a, b| a.slug.as_str().cmp(b.slug.as_str()));
+        Ok(boards)
+    }
+
+    fn find(&self, id: BoardId) -> Result<Option<Board>, RepositoryError> {
+        Ok(lock(&self.boards)?.iter().find(|b| b.id == id).cloned())
+    }
+
+    fn find_by_slug(&self, slug: &str) -> Result<Option<Board>, RepositoryError> {
+        Ok(lock(&self.boards)?.iter().find(|b| b.slug == slug).cloned())
+    }
+
+    fn update(&self, board: Board) -> Result<bool, RepositoryError> {
+        let mut boards = lock(&self.boards)?;
+        if boards
+            .iter()
+            .any(|b| b.id != board.id && b.slug == board.slug)
+        {
+            return Err(RepositoryError::Conflict);
+        }
+        match boards.iter_mut().find(|b| b.id == board.id) {
+            Some(existing) => {
+                *existing = board;
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    fn delete(&self, id: BoardId) -> Result<bool, RepositoryError> {
+        let mut boards = lock(&self.boards)?;
+        let before = boards.len();
+        boards.retain(|b| b.id != id);
+        Ok(boards.len() != before)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct InMemoryRefreshTokenRepository {
+    tokens: Mutex<Vec<RefreshToken>>,
+}
+
+impl InMemoryRefreshTokenRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl RefreshTokenRepository for InMemoryRefreshTokenRepository {
+    fn insert(&self, token: RefreshToken) -> Result<(), RepositoryError> {
+        lock(&self.tokens)?.push(token);
+        Ok(())
+    }
+
+    fn find(&self, token_hash: &str) -> Result<Option<RefreshToken>, RepositoryError> {
+        Ok(lock(&self.tokens)?
+            .iter()
+            .find(|token| token.token_hash == token_hash)
+            .cloned())
+    }
+
+    fn consume(&self, token_hash: &str) -> Result<Option<RefreshToken>, RepositoryError> {
+        let mut tokens = lock(&self.tokens)?;
+        Ok(tokens
+            .iter_mut()
+            .find(|token| token.token_hash == token_hash)
+            .map(|token| {
+                let before = token.clone();
+                token.used = true;
+                before
+            }))
+    }
+
+    fn revoke_family(&self, family_id: Uuid) -> Result<(), RepositoryError> {
+        lock(&self.tokens)?
+            .iter_mut()
+            .filter(|token| token.family_id == family_id)
+            .for_each(|token| token.revoked = true);
+        Ok(())
+    }
+
+    fn revoke_user(&self, user_id: UserId) -> Result<(), RepositoryError> {
+        lock(&self.tokens)?
+            .iter_mut()
+            .filter(|token| token.user_id == user_id)
+            .for_each(|token| token.revoked = true);
+        Ok(())
+    }
+}
+
+/// 失効させたjtiをトークンの有効期限まで保持する
+#[derive(Debug, Default)]
+pub struct InMemoryRevocationRepository {
+    tokens: Mutex<HashMap<String, DateTime<Utc>>>,
+    users: Mutex<HashMap<UserId, DateTime<Utc>>>,
+}
+
+impl InMemoryRevocationRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl RevocationRepository for InMemoryRevocationRepository {
+    fn revoke(&self, jti: &str, expires_at: DateTime<Utc>) -> Result<(), RepositoryError> {
+        let mut tokens = lock(&self.tokens)?;
+        let now = Utc::now();
+        tokens.retain(|_, expires_at| *expires_at > now);
+        tokens.insert(jti.to_string(), expires_at);
+        Ok(())
+    }
+
+    fn is_revoked(&self, jti: &str) -> Result<bool, RepositoryError> {
+        Ok(lock(&self.tokens)?
+            .get(jti)
+            .is_some_and(|expires_at| *expires_at > Utc::now()))
+    }
+
+    fn revoke_user(&self, user_id: UserId, before: DateTime<Utc>) -> Result<(), RepositoryError> {
+        lock(&self.users)?.insert(user_id, before);
+        Ok(())
+    }
+
+    fn user_revoked_before(
+        &self,
+        user_id: UserId,
+    ) -> Result<Option<DateTime<Utc>>, RepositoryError> {
+        Ok(lock(&self.users)?.get(&user_id).copied())
+    }
+
+    fn purge_expired(&self, now: DateTime<Utc>) -> Result<usize, RepositoryError> {
+        let mut tokens = lock(&self.tokens)?;
+        let before = tokens.len();
+        tokens.retain(|_, expires_at| *expires_at > now);
+        Ok(before - tokens.len())
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct InMemoryWebhookRepository {
+    webhooks: Mutex<Vec<Webhook>>,
+    /// 作成順
+    deliveries: Mutex<Vec<WebhookDelivery>>,
+}
+
+impl InMemoryWebhookRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl WebhookRepository for InMemoryWebhookRepository {
+    fn insert(&self, webhook: Webhook) -> Result<(), RepositoryError> {
+        lock(&self.webhooks)?.push(webhook);
+        Ok(())
+    }
+
+    fn list(&self) -> Result<Vec<Webhook>, RepositoryError> {
+        Ok(lock(&self.webhooks)?.clone())
+    }
+
+    fn find(&self, id: Uuid) -> Result<Option<Webhook>, RepositoryError> {
+        Ok(lock(&self.webhooks)?.iter().find(|w| w.id == id).cloned())
+    }
+
+    fn delete(&self, id: Uuid) -> Result<bool, RepositoryError> {
+        let mut webhooks = lock(&self.webhooks)?;
+        let before = webhooks.len();
+        webhooks.retain(|w| w.id != id);
+        if webhooks.len() == before {
+            return Ok(false);
+        }
+        lock(&self.deliveries)?.retain(|d| d.webhook_id != id);
+        Ok(true)
+    }
+
+    fn insert_delivery(&self, delivery: WebhookDelivery) -> Result<(), RepositoryError> {
+        lock(&self.deliveries)?.push(delivery);
+        Ok(())
+    }
+
+    fn update_delivery(&self, delivery: WebhookDelivery) -> Result<bool, RepositoryError> {
+        let mut deliveries = lock(&self.deliveries)?;
+        match deliveries.iter_mut().find(|d| d.id == delivery.id) {
+            Some(existing) => {
+                *existing = delivery;
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    fn find_delivery(&self, id: Uuid) -> Result<Option<WebhookDelivery>, RepositoryError> {
+        Ok(lock(&self.deliveries)?.iter().find(|d| d.id == id).cloned())
+    }
+
+    fn list_deliveries(
+        &self,
+        query: &DeliveryQuery,
+    ) -> Result<Vec<WebhookDelivery>, RepositoryError> {
+        Ok(lock(&self.deliveries)?
+            .iter()
+            .rev()
+            .filter(|d| query.matches(d))
+            .take(query.limit)
+            .cloned()
+            .collect())
+    }
+
+    fn due_deliveries(
+        &self,
+        now: DateTime<Utc>,
+        limit: usize,
+    ) -> Result<Vec<WebhookDelivery>, RepositoryError> {
+        let mut due: Vec<WebhookDelivery> = lock(&self.deliveries)?
+            .iter()
+            .filter(|d| d.status == DeliveryStatus::Pending && d.next_attempt_at <= now)
+            .cloned()
+            .collect();
+        due.sort_by_key(|d| d.next_attempt_at);
+        due.truncate(limit);
+        Ok(due)
+    }
+
+    fn purge_deliveries(&self, before: DateTime<Utc>) -> Result<usize, RepositoryError> {
+        let mut deliveries = lock(&self.deliveries)?;
+        let count = deliveries.len();
+        deliveries.retain(|d| d.status != DeliveryStatus::Succeeded || d.created_at >= before);
+        Ok(count - deliveries.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entity::{BoardSettings, GENERAL_BOARD_ID};
+    use crate::repository::PostSort;
+    use crate::value_object::{BoardName, BoardSlug, Content, Email, Name, Title};
+    use std::sync::Arc;
+
+    fn post(user_id: i64) -> Post {
+        Post {
+            id: Uuid::new_v4(),
+            user_id,
+            board_id: Uuid::nil(),
+            title: Title::parse("title").unwrap(),
+            content: Content::parse("content").unwrap(),
+            locked: false,
+            pinned: false,
+            parent_id: None,
+            reply_count: 0,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            edited_count: 0,
+            last_activity_at: Utc::now(),
+            version: 1,
+            deleted_at: None,
+            deleted_by: None,
+            status: PostStatus::Published,
+            publish_at: None,
+        }
+    }
+
+    mod user_repository {
+        use super::*;
+
+        fn new_user(email: &str) -> NewUser {
+            NewUser {
+                name: Name::parse("name").unwrap(),
+                email: Email::parse(email).unwrap(),
+                password: "hash".to_string(),
+                role: Role::Member,
+                created_at: Utc::now(),
+            }
+        }
+
+        #[test]
+        fn test_create_concurrently_allocates_unique_ids() {
+            let repo = Arc::new(InMemoryUserRepository::new());
+            let handles: Vec<_> = (0..16)
+                .map(|i| {
+                    let repo = repo.clone();
+                    std::thread::spawn(move || {
+                        repo.create(new_user(&format!("{}@example.com", i)))
+                            .unwrap()
+                            .id
+                    })
+                })
+                .collect();
+
+            let mut ids: Vec<_> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+            ids.sort();
+
+            assert_eq!(ids, (1..=16).collect::<Vec<_>>());
+        }
+
+        #[test]
+        fn test_create_duplicate_email() {
+            let repo = InMemoryUserRepository::new();
+            repo.create(new_user("a@example.com")).unwrap();
+
+            let result = repo.create(new_user("a@example.com"));
+
+            assert!(matches!(result, Err(RepositoryError::Conflict)));
+        }
+    }
+
+    mod revocation_repository {
+        use super::*;
+        use chrono::Duration;
+
+        #[test]
+        fn test_revoked_until_expiry() {
+            let repo = InMemoryRevocationRepository::new();
+            repo.revoke("live", Utc::now() + Duration::minutes(5))
+                .unwrap();
+            repo.revoke("expired", Utc::now() - Duration::seconds(1))
+                .unwrap();
+
+            assert!(repo.is_revoked("live").unwrap());
+            assert!(!repo.is_revoked("expired").unwrap());
+            assert!(!repo.is_revoked("unknown").unwrap());
+        }
+
+        #[test]
+        fn test_purge_expired() {
+            let repo = InMemoryRevocationRepository::new();
+            repo.revoke("jti", Utc::now() + Duration::minutes(5))
+                .unwrap();
+
+            let purged = repo
+                .purge_expired(Utc::now() + Duration::minutes(10))
+                .unwrap();
+
+            assert_eq!(purged, 1);
+            assert!(!repo.is_revoked("jti").unwrap());
+        }
+    }
+
+    mod post_repository {
+        use super::*;
+
+        #[test]
+        fn test_update_and_delete() {
+            let repo = InMemoryPostRepository::new();
+            let mut p = post(1);
+            repo.insert(p.clone()).unwrap();
+
+            p.edit(Title::parse("updated").unwrap(), p.content.clone());
+            assert!(repo.update(p.clone()).unwrap());
+            assert_eq!(repo.find(p.id).unwrap().unwrap().title, "updated");
+
+            assert!(repo.delete(p.id).unwrap());
+            assert!(!repo.delete(p.id).unwrap());
+            assert!(repo.find(p.id).unwrap().is_none());
+        }
+
+        #[test]
+        fn test_update_missing() {
+            let repo = InMemoryPostRepository::new();
+
+            assert!(!repo.update(post(1)).unwrap());
+            assert!(repo.list(&PostQuery::default()).unwrap().is_empty());
+        }
+
+        #[test]
+        fn test_update_rejects_stale_version() {
+            let repo = InMemoryPostRepository::new();
+            let p = post(1);
+            repo.insert(p.clone()).unwrap();
+            let mut first = p.clone();
+            let mut second = p.clone();
+            first.moderate(Some(true), None);
+            second.moderate(None, Some(true));
+
+            assert!(repo.update(first).unwrap());
+            assert!(matches!(repo.update(second), Err(RepositoryError::Stale)));
+            let found = repo.find(p.id).unwrap().unwrap();
+            assert!(found.locked);
+            assert!(!found.pinned);
+            assert_eq!(found.version, 2);
+        }
+
+        #[test]
+        fn test_replies() {
+            let repo = InMemoryPostRepository::new();
+            let root = post(1);
+            let reply = Post {
+                parent_id: Some(root.id),
+                ..post(2)
+            };
+            let nested = Post {
+                parent_id: Some(reply.id),
+                last_activity_at: root.last_activity_at + chrono::Duration::minutes(1),
+                ..post(1)
+            };
+            repo.insert(root.clone()).unwrap();
+            repo.insert(reply.clone()).unwrap();
+            repo.insert(nested.clone()).unwrap();
+
+            let roots = repo.list(&PostQuery::default()).unwrap();
+            assert_eq!(roots.len(), 1);
+            assert_eq!(roots[0].reply_count, 1);
+            assert_eq!(roots[0].last_activity_at, nested.last_activity_at);
+            assert_eq!(repo.list_replies(root.id, 10, 0).unwrap()[0].id, reply.id);
+            assert!(repo.list_replies(root.id, 10, 1).unwrap().is_empty());
+
+            assert!(repo.delete(root.id).unwrap());
+            assert!(repo.find(reply.id).unwrap().is_none());
+            assert!(repo.find(nested.id).unwrap().is_none());
+        }
+
+        #[test]
+        fn test_trash_and_restore() {
+            let repo = InMemoryPostRepository::new();
+            let root = post(1);
+            let reply = Post {
+                parent_id: Some(root.id),
+                ..post(2)
+            };
+            repo.insert(root.clone()).unwrap();
+            repo.insert(reply.clone()).unwrap();
+
+            let mut trashed = root.clone();
+            trashed.trash(2);
+            assert!(repo.trash(trashed.clone()).unwrap());
+            assert!(repo.find(root.id).unwrap().is_none());
+            assert!(repo.find(reply.id).unwrap().is_none());
+            assert!(repo.list(&PostQuery::default()).unwrap().is_empty());
+            assert!(repo.find_in_trash(reply.id).unwrap().is_none());
+            assert!(repo.find_in_trash(root.id).unwrap().is_some());
+            assert_eq!(repo.list_trash(2, 10, 0).unwrap()[0].id, root.id);
+            assert!(repo.list_trash(1, 10, 0).unwrap().is_empty());
+
+            trashed.restore();
+            assert!(repo.restore(trashed).unwrap());
+            assert!(repo.find_in_trash(root.id).unwrap().is_none());
+            assert_eq!(repo.find(root.id).unwrap().unwrap().reply_count, 1);
+            assert_eq!(repo.find(reply.id).unwrap().unwrap().version, 3);
+        }
+
+        #[test]
+        fn test_purge_trash() {
+            let repo = InMemoryPostRepository::new();
+            let root = post(1);
+            let reply = Post {
+                parent_id: Some(root.id),
+                ..post(2)
+            };
+            let kept = post(1);
+            repo.insert(root.clone()).unwrap();
+            repo.insert(reply.clone()).unwrap();
+            repo.insert(kept.clone()).unwrap();
+            let mut trashed = root.clone();
+            trashed.trash(1);
+            repo.trash(trashed.clone()).unwrap();
+            let deleted_at = trashed.deleted_at.unwrap();
+
+            assert_eq!(repo.purge_trash(deleted_at).unwrap(), 0);
+            assert_eq!(
+                repo.purge_trash(deleted_at + chrono::Duration::seconds(1))
+                    .unwrap(),
+                2
+            );
+            assert!(repo.find_in_trash(root.id).unwrap().is_none());
+            assert!(repo.find(kept.id).unwrap().is_some());
+        }
+
+        #[test]
+        fn test_drafts_and_scheduled_publishing() {
+            let repo = InMemoryPostRepository::new();
+            let now = Utc::now();
+            let published = post(1);
+            let draft = Post {
+                status: PostStatus::Draft,
+                ..post(1)
+            };
+            let due = Post {
+                status: PostStatus::Scheduled,
+                publish_at: Some(now - chrono::Duration::minutes(1)),
+                ..post(1)
+            };
+            let later = Post {
+                status: PostStatus::Scheduled,
+                publish_at: Some(now + chrono::Duration::minutes(1)),
+                ..post(1)
+            };
+            for p in [&published, &draft, &due, &later] {
+                repo.insert(p.clone()).unwrap();
+            }
+
+            assert_eq!(repo.list(&PostQuery::default()).unwrap().len(), 1);
+            assert_eq!(repo.list_drafts(1, 10, 0).unwrap().len(), 3);
+            assert!(repo.list_drafts(2, 10, 0).unwrap().is_empty());
+            assert!(repo.find(draft.id).unwrap().is_some());
+
+            let publishing = repo.publish_due(now).unwrap();
+            assert_eq!(publishing.len(), 1);
+            assert_eq!(publishing[0].id, due.id);
+            let found = repo.find(due.id).unwrap().unwrap();
+            assert!(found.is_published());
+            assert_eq!(found.created_at, due.publish_at.unwrap());
+            assert_eq!(found.version, 2);
+            assert_eq!(repo.list(&PostQuery::default()).unwrap().len(), 2);
+            assert_eq!(repo.list_drafts(1, 10, 0).unwrap().len(), 2);
+            assert!(repo.publish_due(now).unwrap().is_empty());
+        }
+
+        #[test]
+        fn test_list_pages_with_cursor() {
+            let repo = InMemoryPostRepository::new();
+            let base = Utc::now();
+            let posts: Vec<Post> = (0..5)
+                .map(|i| Post {
+                    created_at: base + chrono::Duration::seconds(i),
+                    ..post(i % 2 + 1)
+                })
+                .collect();
+            for p in &posts {
+                repo.insert(p.clone()).unwrap();
+            }
+            let query = PostQuery {
+                limit: 2,
+                ..PostQuery::default()
+            };
+
+            let first = repo.list(&query).unwrap();
+            let second = repo
+                .list(&PostQuery {
+                    after: Some(PostCursor::of(&first[1])),
+                    ..query.clone()
+                })
+                .unwrap();
+            let oldest_of_user = repo
+                .list(&PostQuery {
+                    sort: PostSort::Oldest,
+                    user_id: Some(2),
+                    since: Some(posts[1].created_at),
+                    ..query.clone()
+                })
+                .unwrap();
+
+            let ids = |posts: &[Post]| posts.iter().map(|p| p.id).collect::<Vec<_>>();
+            assert_eq!(ids(&first), vec![posts[4].id, posts[3].id]);
+            assert_eq!(ids(&second), vec![posts[2].id, posts[1].id]);
+            assert_eq!(ids(&oldest_of_user), vec![posts[1].id, posts[3].id]);
+        }
+
+        #[test]
+        fn test_list_by_board() {
+            let repo = InMemoryPostRepository::new();
+            let board_id = Uuid::new_v4();
+            let other = post(1);
+            let in_board = Post {
+                board_id,
+                ..post(1)
+            };
+            repo.insert(other.clone()).unwrap();
+            repo.insert(in_board.clone()).unwrap();
+
+            let found = repo
+                .list(&PostQuery {
+                    board_id: Some(board_id),
+                    ..PostQuery::default()
+                })
+                .unwrap();
+
+            assert_eq!(found.len(), 1);
+            assert_eq!(found[0].id, in_board.id);
+            assert_eq!(repo.list(&PostQuery::default()).unwrap().len(), 2);
+            assert!(repo.has_posts_in(board_id).unwrap());
+            assert!(!repo.has_posts_in(Uuid::new_v4()).unwrap());
+        }
+
+        #[test]
+        fn test_trash_does_not_keep_board() {
+            let repo = InMemoryPostRepository::new();
+            let board_id = Uuid::new_v4();
+            let mut trashed = Post {
+                board_id,
+                ..post(1)
+            };
+            repo.insert(trashed.clone()).unwrap();
+            repo.insert(post(1)).unwrap();
+            trashed.trash(1);
+            repo.trash(trashed.clone()).unwrap();
+
+            assert!(!repo.has_posts_in(board_id).unwrap());
+            assert_eq!(repo.purge_trash_in(board_id).unwrap(), 1);
+            assert!(repo.find_in_trash(trashed.id).unwrap().is_none());
+            assert_eq!(repo.list(&PostQuery::default()).unwrap().len(), 1);
+        }
+    }
+
+    mod board_repository {
+        use super::*;
+
+        fn board(slug: &str) -> Board {
+            Board::new(
+                BoardSlug::parse(slug).unwrap(),
+                BoardName::parse("name").unwrap(),
+                None,
+                BoardSettings::default(),
+            )
+        }
+
+        #[test]
+        fn test_crud() {
+            let repo = InMemoryBoardRepository::new();
+            let mut general = repo.find(GENERAL_BOARD_ID).unwrap().unwrap();
+            repo.insert(board("rust")).unwrap();
+
+            general.settings.allow_replies = false;
+            assert!(repo.update(general.clone()).unwrap());
+
+            let slugs: Vec<_> = repo
+                .list()
+                .unwrap()
+                .into_iter()
+                .map(|b| b.slug.into_string())
+                .collect();
+            assert_eq!(slugs, vec!["general", "rust"]);
+            let found = repo.find_by_slug("general").unwrap().unwrap();
+            assert_eq!(found.id, general.id);
+            assert!(!found.settings.allow_replies);
+            assert!(repo.delete(general.id).unwrap());
+            assert!(repo.find(general.id).unwrap().is_none());
+            assert!(!repo.delete(general.id).unwrap());
+            assert!(!repo.update(general).unwrap());
+        }
+
+        #[test]
+        fn test_duplicate_slug() {
+            let repo = InMemoryBoardRepository::new();
+            let mut rust = board("rust");
+            repo.insert(rust.clone()).unwrap();
+
+            rust.slug = BoardSlug::parse("general").unwrap();
+
+            assert!(matches!(
+                repo.insert(board("general")),
+                Err(RepositoryError::Conflict)
+            ));
+            assert!(matches!(repo.update(rust), Err(RepositoryError::Conflict)));
+        }
+    }
+}
@@ -0,0 +1,300 @@
+pub mod memory;
+pub mod sqlite;
+
+use crate::entity::{
+    Board, NewUser, Post, PostRevision, RefreshToken, User, Webhook, WebhookDelivery,
+};
+use crate::value_object::{BoardId, DeliveryStatus, PostId, Role, UserId};
+use chrono::{DateTime, Utc};
+use std::cmp::Ordering;
+use thiserror::Error;
+use uuid::Uuid;
+
+#[derive(Debug, Error)]
+pub enum RepositoryError {
+    #[error("Storage lock was poisoned")]
+    Poisoned,
+    #[error(transparent)]
+    Sqlite(#[from] rusqlite::Error),
+    #[error("Record already exists")]
+    Conflict,
+    #[error("Corrupted record: {0}")]
+    Corrupted(String),
+    #[error("Record was modified by another update")]
+    Stale,
+    #[error("Several users have the email address {0} once normalized; change or remove all but one before migrating")]
+    DuplicateEmail(String),
+}
+
+/// 投稿一覧の並び順。いずれの場合もピン留めされた投稿を先頭に置く
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PostSort {
+    #[default]
+    Newest,
+    Oldest,
+    MostReplied,
+}
+
+/// 並び順の中での投稿の位置。次のページはこの位置より後ろの投稿から始まる
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PostCursor {
+    pub pinned: bool,
+    pub reply_count: usize,
+    pub created_at: DateTime<Utc>,
+    pub id: PostId,
+}
+
+impl PostCursor {
+    pub fn of(post: &Post) -> Self {
+        Self {
+            pinned: post.pinned,
+            reply_count: post.reply_count,
+            created_at: post.created_at,
+            id: post.id,
+        }
+    }
+}
+
+impl PostSort {
+    /// 並び順で`a`が`b`より前にあれば`Ordering::Less`を返す。idで同順位を解消するため全順序になる
+    pub fn compare(self, a: &PostCursor, b: &PostCursor) -> Ordering {
+        let key = match self {
+            PostSort::Newest => (b.created_at, b.id).cmp(&(a.created_at, a.id)),
+            PostSort::Oldest => (a.created_at, a.id).cmp(&(b.created_at, b.id)),
+            PostSort::MostReplied => {
+                (b.reply_count, b.created_at, b.id).cmp(&(a.reply_count, a.created_at, a.id))
+            }
+        };
+        b.pinned.cmp(&a.pinned).then(key)
+    }
+}
+
+/// スレッドの先頭の投稿の検索条件
+#[derive(Debug, Clone)]
+pub struct PostQuery {
+    pub sort: PostSort,
+    pub board_id: Option<BoardId>,
+    pub user_id: Option<UserId>,
+    /// この日時以降に作成された投稿に絞り込む
+    pub since: Option<DateTime<Utc>>,
+    /// この日時より前に作成された投稿に絞り込む
+    pub until: Option<DateTime<Utc>>,
+    /// 前のページの最後の投稿の位置
+    pub after: Option<PostCursor>,
+    pub limit: usize,
+}
+
+impl Default for PostQuery {
+    fn default() -> Self {
+        Self {
+            sort: PostSort::default(),
+            board_id: None,
+            user_id: None,
+            since: None,
+            until: None,
+            after: None,
+            limit: 20,
+        }
+    }
+}
+
+impl PostQuery {
+    /// `after`と`limit`以外の条件に一致するか。ゴミ箱の投稿と公開前の投稿は一致しない
+    pub fn matches(&self, post: &Post) -> bool {
+        post.parent_id.is_none()
+            && post.deleted_at.is_none()
+            && post.is_published()
+            && self.board_id.is_none_or(|id| post.board_id == id)
+            && self.user_id.is_none_or(|id| post.user_id == id)
+            && self.since.is_none_or(|since| post.created_at >= since)
+            && self.until.is_none_or(|until| post.created_at < until)
+    }
+}
+
+/// 全文検索の候補
+#[derive(Debug, Clone)]
+pub struct SearchCandidate {
+    pub post: Post,
+    /// 検索した語の重み。語と同じ順に並ぶ
+    pub weights: Vec<u32>,
+}
+
+/// Webhookの配信の記録の検索条件
+#[derive(Debug, Clone)]
+pub struct DeliveryQuery {
+    pub webhook_id: Option<Uuid>,
+    pub status: Option<DeliveryStatus>,
+    pub limit: usize,
+}
+
+impl DeliveryQuery {
+    /// `limit`以外の条件に一致するか
+    pub fn matches(&self, delivery: &WebhookDelivery) -> bool {
+        self.webhook_id.is_none_or(|id| delivery.webhook_id == id)
+            && self.status.is_none_or(|status| delivery.status == status)
+    }
+}
+
+pub trait UserRepository: Send + Sync {
+    /// idを採番してユーザーを保存する。メールアドレスが登録済みの場合は`RepositoryError::Conflict`
+    fn create(&self, user: NewUser) -> Result<User, RepositoryError>;
+    fn find(&self, id: UserId) -> Result<Option<User>, RepositoryError>;
+    fn find_by_email(&self, email: &str) -> Result<Option<User>, RepositoryError>;
+    /// ロールを変更し、変更後のユーザーを返す。対象が存在しない場合は`None`
+    fn set_role(&self, id: UserId, role: Role) -> Result<Option<User>, RepositoryError>;
+    /// ログインした日時を記録する。対象が存在しない場合は何もしない
+    fn record_login(&self, id: UserId, at: DateTime<Utc>) -> Result<(), RepositoryError>;
+    /// パスワードのハッシュを置き換える。対象が存在しない場合は何もしない
+    fn set_password(&self, id: UserId, password: &str) -> Result<(), RepositoryError>;
+}
+
+/// ゴミ箱の投稿は`find_in_trash`と`list_trash`以外では読み出さず、返信の数にも含めない
+///
+/// 公開前の投稿は`find`と`list_drafts`で読み出す。一覧と検索には含めない
+pub trait PostRepository: Send + Sync {
+    /// 条件に一致するスレッドの先頭の投稿(返信以外)を並び順に最大`limit`件返す
+    fn list(&self, query: &PostQuery) -> Result<Vec<Post>, RepositoryError>;
+    /// 直接の返信を作成順に返す
+    fn list_replies(
+        &self,
+        parent_id: PostId,
+        limit: usize,
+        offset: usize,
+    ) -> Result<Vec<Post>, RepositoryError>;
+    fn find(&self, id: PostId) -> Result<Option<Post>, RepositoryError>;
+    /// 投稿を保存し、作成者による版として記録する。返信の場合は祖先の`last_activity_at`を進める
+    fn insert(&self, post: Post) -> Result<(), RepositoryError>;
+    /// 既存の投稿を置き換え、祖先の`last_activity_at`を進める。対象が存在しない場合は`false`を返す
+    ///
+    /// `post.version`は保存されているバージョンの次でなければならず、他の更新が先に保存されていた場合は
+    /// `RepositoryError::Stale`を返す。版は記録しないため、タイトルや内容の編集には`edit`を使う
+    fn update(&self, post: Post) -> Result<bool, RepositoryError>;
+    /// 編集した投稿を`update`と同様に保存し、`editor`による版として記録する
+    fn edit(&self, post: Post, editor: UserId) -> Result<bool, RepositoryError>;
+    /// ゴミ箱に移した投稿を`update`と同様に保存し、ゴミ箱にない配下の返信も同じ日時で移す
+    fn trash(&self, post: Post) -> Result<bool, RepositoryError>;
+    /// ゴミ箱から戻した投稿を`update`と同様に保存し、投稿と一緒に移された配下の返信も戻す
+    fn restore(&self, post: Post) -> Result<bool, RepositoryError>;
+    /// ゴミ箱の投稿を返す。ゴミ箱にある投稿の配下の返信は、投稿と一緒に扱うため返さない
+    fn find_in_trash(&self, id: PostId) -> Result<Option<Post>, RepositoryError>;
+    /// `deleted_by`がゴミ箱に移した投稿を`find_in_trash`と同じ条件で、移した日時の新しい順に返す
+    fn list_trash(
+        &self,
+        deleted_by: UserId,
+        limit: usize,
+        offset: usize,
+    ) -> Result<Vec<Post>, RepositoryError>;
+    /// `user_id`が作成した公開前の投稿を、ゴミ箱の投稿を除いて更新した日時の新しい順に返す
+    fn list_drafts(
+        &self,
+        user_id: UserId,
+        limit: usize,
+        offset: usize,
+    ) -> Result<Vec<Post>, RepositoryError>;
+    /// 公開する日時が`now`以前の公開予定の投稿を`Post::publish`で公開し、公開した投稿を返す
+    ///
+    /// ゴミ箱の投稿は公開しない
+    fn publish_due(&self, now: DateTime<Utc>) -> Result<Vec<Post>, RepositoryError>;
+    /// ボードに属するゴミ箱にない投稿があるか。公開前の投稿と返信も含める
+    fn has_posts_in(&self, board_id: BoardId) -> Result<bool, RepositoryError>;
+    /// ボードに属するゴミ箱の投稿を完全に削除し、削除した件数を返す
+    fn purge_trash_in(&self, board_id: BoardId) -> Result<usize, RepositoryError>;
+    /// `before`より前にゴミ箱に移された投稿を完全に削除し、削除した件数を返す
+    fn purge_trash(&self, before: DateTime<Utc>) -> Result<usize, RepositoryError>;
+    /// ゴミ箱を経由せず、投稿と配下のすべての返信を完全に削除する。ゴミ箱の投稿も削除できる
+    ///
+    /// 対象が存在しない場合は`false`を返す
+    fn delete(&self, id: PostId) -> Result<bool, RepositoryError>;
+    /// 投稿の版を番号順に返す
+    fn list_revisions(
+        &self,
+        post_id: PostId,
+        limit: usize,
+        offset: usize,
+    ) -> Result<Vec<PostRevision>, RepositoryError>;
+    fn find_revision(
+        &self,
+        post_id: PostId,
+        number: u32,
+    ) -> Result<Option<PostRevision>, RepositoryError>;
+    /// 全文検索の索引ですべての語を含む投稿を、新しい順に最大`limit`件返す
+    ///
+    /// 索引は`insert`、`update`、`delete`で更新する。ゴミ箱の投稿と公開前の投稿は索引に残すが返さない
+    fn search(
+        &self,
+        terms: &[String],
+        limit: usize,
+    ) -> Result<Vec<SearchCandidate>, RepositoryError>;
+}
+
+/// 投稿をまとめるボード
+pub trait BoardRepository: Send + Sync {
+    /// スラッグが使われている場合は`RepositoryError::Conflict`
+    fn insert(&self, board: Board) -> Result<(), RepositoryError>;
+    /// スラッグの順に返す
+    fn list(&self) -> Result<Vec<Board>, RepositoryError>;
+    fn find(&self, id: BoardId) -> Result<Option<Board>, RepositoryError>;
+    fn find_by_slug(&self, slug: &str) -> Result<Option<Board>, RepositoryError>;
+    /// 既存のボードを置き換える。対象が存在しない場合は`false`を返し、
+    /// スラッグが他のボードで使われている場合は`RepositoryError::Conflict`
+    fn update(&self, board: Board) -> Result<bool, RepositoryError>;
+    /// 対象が存在しない場合は`false`を返す
+    ///
+    /// 投稿が属するボードは削除しないよう、先に`PostRepository::has_posts_in`で確かめ、
+    /// ゴミ箱の投稿は`PostRepository::purge_trash_in`で削除しておく
+    fn delete(&self, id: BoardId) -> Result<bool, RepositoryError>;
+}
+
+pub trait RefreshTokenRepository: Send + Sync {
+    fn insert(&self, token: RefreshToken) -> Result<(), RepositoryError>;
+    /// トークンを使用済みにせずに返す
+    fn find(&self, token_hash: &str) -> Result<Option<RefreshToken>, RepositoryError>;
+    /// トークンを使用済みにし、更新前の状態を返す。確認と更新は不可分に行う
+    fn consume(&self, token_hash: &str) -> Result<Option<RefreshToken>, RepositoryError>;
+    /// 系列に属するすべてのトークンを失効させる
+    fn revoke_family(&self, family_id: Uuid) -> Result<(), RepositoryError>;
+    /// ユーザーに発行したすべてのトークンを失効させる
+    fn revoke_user(&self, user_id: UserId) -> Result<(), RepositoryError>;
+}
+
+/// 有効期限前に失効させたアクセストークンの記録
+pub trait RevocationRepository: Send + Sync {
+    /// jtiを失効させる。`expires_at`を過ぎたエントリはトークン自体が無効になるため破棄してよい
+    fn revoke(&self, jti: &str, expires_at: DateTime<Utc>) -> Result<(), RepositoryError>;
+    fn is_revoked(&self, jti: &str) -> Result<bool, RepositoryError>;
+    /// ユーザーに対して`before`以前に発行されたトークンをすべて失効させる
+    fn revoke_user(&self, user_id: UserId, before: DateTime<Utc>) -> Result<(), RepositoryError>;
+    fn user_revoked_before(
+        &self,
+        user_id: UserId,
+    ) -> Result<Option<DateTime<Utc>>, RepositoryError>;
+    /// 有効期限を過ぎたエントリを削除し、削除した件数を返す
+    fn purge_expired(&self, now: DateTime<Utc>) -> Result<usize, RepositoryError>;
+}
+
+/// Webhookの送信先と配信の記録
+pub trait WebhookRepository: Send + Sync {
+    fn insert(&self, webhook: Webhook) -> Result<(), RepositoryError>;
+    /// 作成順に返す
+    fn list(&self) -> Result<Vec<Webhook>, RepositoryError>;
+    fn find(&self, id: Uuid) -> Result<Option<Webhook>, RepositoryError>;
+    /// 送信先と配信の記録を削除する。対象が存在しない場合は`false`を返す
+    fn delete(&self, id: Uuid) -> Result<bool, RepositoryError>;
+    fn insert_delivery(&self, delivery: WebhookDelivery) -> Result<(), RepositoryError>;
+    /// 既存の配信を置き換える。対象が存在しない場合は`false`を返す
+    fn update_delivery(&self, delivery: WebhookDelivery) -> Result<bool, RepositoryError>;
+    fn find_delivery(&self, id: Uuid) -> Result<Option<WebhookDelivery>, RepositoryError>;
+    /// 条件に一致する配信を新しい順に最大`limit`件返す
+    fn list_deliveries(
+        &self,
+        query: &DeliveryQuery,
+    ) -> Result<Vec<WebhookDelivery>, RepositoryError>;
+    /// `now`までに試行する予定の`Pending`の配信を予定の早い順に最大`limit`件返す
+    fn due_deliveries(
+        &self,
+        now: DateTime<Utc>,
+        limit: usize,
+    ) -> Result<Vec<WebhookDelivery>, RepositoryError>;
+    /// `before`より前に作成され配信に成功した記録を削除し、削除した件数を返す
+    fn purge_deliveries(&self, before: DateTime<Utc>) -> Result<usize, RepositoryError>;
+}
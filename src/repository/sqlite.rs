@@ -0,0 +1,2176 @@
+use super::{
+    BoardRepository, DeliveryQuery, PostQuery, PostRepository, PostSort, RefreshTokenRepository,
+    RepositoryError, RevocationRepository, SearchCandidate, UserRepository, WebhookRepository,
+};
+use crate::entity::{
+    Board, BoardSettings, NewUser, Post, PostRevision, RefreshToken, User, Webhook, WebhookDelivery,
+};
+use crate::service::search;
+use crate::value_object::{
+    normalize_email, BoardDescription, BoardId, BoardName, BoardSlug, Content, DeliveryStatus,
+    Email, Name, PostId, PostStatus, Role, Title, UserId, WebhookEvent, WebhookUrl,
+};
+use chrono::{DateTime, Utc};
+use rusqlite::{
+    params, params_from_iter, Connection, ErrorCode, OptionalExtension, Row, ToSql, Transaction,
+};
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::{Arc, Mutex, MutexGuard};
+use uuid::Uuid;
+
+/// 1つのスキーマの変更
+enum Migration {
+    Sql(&'static str),
+    /// SQLだけでは表せない変更
+    Rust(fn(&Transaction<'_>) -> Result<(), RepositoryError>),
+}
+
+/// `PRAGMA user_version`の値をインデックスとして順番に適用されるスキーマ
+const MIGRATIONS: &[Migration] = &[
+    Migration::Sql(include_str!("../../migrations/0001_init.sql")),
+    Migration::Rust(unique_email),
+    Migration::Sql(include_str!("../../migrations/0003_refresh_tokens.sql")),
+    Migration::Sql(include_str!("../../migrations/0004_revocations.sql")),
+    Migration::Sql(include_str!("../../migrations/0005_roles.sql")),
+    Migration::Sql(include_str!("../../migrations/0006_replies.sql")),
+    Migration::Sql(include_str!("../../migrations/0007_post_created_at.sql")),
+    Migration::Sql(include_str!("../../migrations/0008_timestamps.sql")),
+    Migration::Sql(include_str!("../../migrations/0009_webhooks.sql")),
+    Migration::Sql(include_str!("../../migrations/0010_search.sql")),
+    Migration::Sql(include_str!("../../migrations/0011_revisions.sql")),
+    Migration::Sql(include_str!("../../migrations/0012_post_versions.sql")),
+    Migration::Sql(include_str!("../../migrations/0013_trash.sql")),
+    Migration::Sql(include_str!("../../migrations/0014_post_status.sql")),
+    Migration::Sql(include_str!("../../migrations/0015_boards.sql")),
+    // 0002を小文字化のみのSQLで適用したデータベースの値をアプリケーションと同じ規則で正規化し直す
+    Migration::Rust(normalize_emails),
+];
+
+/// 複数のリポジトリで共有するSQLite接続
+#[derive(Clone)]
+pub struct Database {
+    conn: Arc<Mutex<Connection>>,
+}
+
+impl Database {
+    /// ファイルを開き(なければ作成し)、未適用のマイグレーションを実行する
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, RepositoryError> {
+        Self::from_connection(Connection::open(path)?)
+    }
+
+    #[cfg(test)]
+    pub fn open_in_memory() -> Result<Self, RepositoryError> {
+        Self::from_connection(Connection::open_in_memory()?)
+    }
+
+    fn from_connection(mut conn: Connection) -> Result<Self, RepositoryError> {
+        conn.pragma_update(None, "foreign_keys", true)?;
+        migrate(&mut conn)?;
+        reindex_posts(&mut conn)?;
+        Ok(Self {
+            conn: Arc::new(Mutex::new(conn)),
+        })
+    }
+
+    fn lock(&self) -> Result<MutexGuard<'_, Connection>, RepositoryError> {
+        self.conn.lock().map_err(|_e| RepositoryError::Poisoned)
+    }
+}
+
+fn migrate(conn: &mut Connection) -> Result<(), RepositoryError> {
+    let version: usize = conn.pragma_query_value(None, "user_version", |row| row.get(0))?;
+    for (index, migration) in MIGRATIONS.iter().enumerate().skip(version) {
+        let tx = conn.transaction()?;
+        match migration {
+            Migration::Sql(sql) => tx.execute_batch(sql)?,
+            Migration::Rust(migrate) => migrate(&tx)?,
+        }
+        tx.pragma_update(None, "user_version", index + 1)?;
+        tx.commit()?;
+        tracing::info!(version = index + 1, "applied database migration");
+    }
+    Ok(())
+}
+
+/// 既存のメールアドレスを正規化してから一意にする
+fn unique_email(tx: &Transaction<'_>) -> Result<(), RepositoryError> {
+    normalize_emails(tx)?;
+    tx.execute_batch(include_str!("../../migrations/0002_unique_email.sql"))?;
+    Ok(())
+}
+
+/// メールアドレスを`normalize_email`で正規化する。正規化すると重複するユーザーがいる場合は何も変更しない
+fn normalize_emails(tx: &Transaction<'_>) -> Result<(), RepositoryError> {
+    let users = {
+        let mut stmt = tx.prepare("SELECT id, email FROM users ORDER BY id")?;
+        let rows = stmt.query_map([], |row| Ok((row.get::<_, UserId>(0)?, row.get(1)?)))?;
+        rows.collect::<Result<Vec<(UserId, String)>, _>>()?
+    };
+    let mut seen = HashMap::new();
+    for (id, email) in &users {
+        let normalized = normalize_email(email);
+        if seen.insert(normalized.clone(), *id).is_some() {
+            return Err(RepositoryError::DuplicateEmail(normalized));
+        }
+    }
+    for (id, email) in users {
+        let normalized = normalize_email(&email);
+        if normalized != email {
+            tx.execute(
+                "UPDATE users SET email = ?1 WHERE id = ?2",
+                params![normalized, id],
+            )?;
+        }
+    }
+    Ok(())
+}
+
+/// 索引の版が古い投稿を索引し直す。マイグレーションの前に作成された投稿もここで索引する
+fn reindex_posts(conn: &mut Connection) -> Result<(), RepositoryError> {
+    let tx = conn.transaction()?;
+    let posts = {
+        let mut stmt = tx.prepare(&format!(
+            "SELECT {} FROM posts WHERE search_version < ?1",
+            POST_COLUMNS
+        ))?;
+        let rows = stmt.query_map(params![search::INDEX_VERSION], post_from_row)?;
+        rows.map(|row| row?).collect::<Result<Vec<_>, _>>()?
+    };
+    for post in &posts {
+        index_post(&tx, post)?;
+    }
+    tx.commit()?;
+    if !posts.is_empty() {
+        tracing::info!(posts = posts.len(), "rebuilt search index");
+    }
+    Ok(())
+}
+
+/// UNIQUE制約違反を`RepositoryError::Conflict`に変換する
+fn map_constraint(e: rusqlite::Error) -> RepositoryError {
+    match e.sqlite_error_code() {
+        Some(ErrorCode::ConstraintViolation) => RepositoryError::Conflict,
+        _ => RepositoryError::Sqlite(e),
+    }
+}
+
+pub struct SqliteUserRepository {
+    db: Database,
+}
+
+impl SqliteUserRepository {
+    pub fn new(db: Database) -> Self {
+        Self { db }
+    }
+}
+
+/// ユーザーを読み出す際の列
+const USER_COLUMNS: &str = "id, name, email, password, role, created_at, last_login_at";
+
+fn user_from_row(row: &Row<'_>) -> rusqlite::Result<Result<User, RepositoryError>> {
+    let role: String = row.get("role")?;
+    let role = match role.parse::<Role>() {
+        Ok(role) => role,
+        Err(e) => return Ok(Err(RepositoryError::Corrupted(e))),
+    };
+    Ok(Ok(User {
+        id: row.get("id")?,
+        name: Name::from_stored(row.get("name")?),
+        email: Email::from_stored(row.get("email")?),
+        password: row.get("password")?,
+        role,
+        created_at: row.get("created_at")?,
+        last_login_at: row.get("last_login_at")?,
+    }))
+}
+
+impl UserRepository for SqliteUserRepository {
+    fn create(&self, user: NewUser) -> Result<User, RepositoryError> {
+        let conn = self.db.lock()?;
+        // idはINTEGER PRIMARY KEYによりSQLiteが採番する
+        let id = conn
+            .query_row(
+                "INSERT INTO users (name, email, password, role, created_at)
+                 VALUES (?1, ?2, ?3, ?4, ?5) RETURNING id",
+                params![
+                    user.name.as_str(),
+                    user.email.as_str(),
+                    user.password,
+                    user.role.as_str(),
+                    user.created_at
+                ],
+                |row| row.get(0),
+            )
+            .map_err(map_constraint)?;
+        Ok(User::new(id, user))
+    }
+
+    fn find(&self, id: UserId) -> Result<Option<User>, RepositoryError> {
+        let conn = self.db.lock()?;
+        conn.query_row(
+            &format!("SELECT {} FROM users WHERE id = ?1", USER_COLUMNS),
+            params![id],
+            user_from_row,
+        )
+        .optional()?
+        .transpose()
+    }
+
+    fn find_by_email(&self, email: &str) -> Result<Option<User>, RepositoryError> {
+        let conn = self.db.lock()?;
+        conn.query_row(
+            &format!("SELECT {} FROM users WHERE email = ?1", USER_COLUMNS),
+            params![email],
+            user_from_row,
+        )
+        .optional()?
+        .transpose()
+    }
+
+    fn set_role(&self, id: UserId, role: Role) -> Result<Option<User>, RepositoryError> {
+        let conn = self.db.lock()?;
+        conn.query_row(
+            &format!(
+                "UPDATE users SET role = ?2 WHERE id = ?1 RETURNING {}",
+                USER_COLUMNS
+            ),
+            params![id, role.as_str()],
+            user_from_row,
+        )
+        .optional()?
+        .transpose()
+    }
+
+    fn record_login(&self, id: UserId, at: DateTime<Utc>) -> Result<(), RepositoryError> {
+        let conn = self.db.lock()?;
+        conn.execute(
+            "UPDATE users SET last_login_at = ?2 WHERE id = ?1",
+            params![id, at],
+        )?;
+        Ok(())
+    }
+
+    fn set_password(&self, id: UserId, password: &str) -> Result<(), RepositoryError> {
+        let conn = self.db.lock()?;
+        conn.execute(
+            "UPDATE users SET password = ?2 WHERE id = ?1",
+            params![id, password],
+        )?;
+        Ok(())
+    }
+}
+
+pub struct SqlitePostRepository {
+    db: Database,
+}
+
+impl SqlitePostRepository {
+    pub fn new(db: Database) -> Self {
+        Self { db }
+    }
+}
+
+/// UUIDはTEXTで保存しているため、読み出し時にここで戻す
+fn parse_uuid(value: &str) -> Result<Uuid, RepositoryError> {
+    Uuid::parse_str(value)
+        .map_err(|_e| RepositoryError::Corrupted(format!("invalid uuid: {}", value)))
+}
+
+/// 投稿を読み出す際の列。返信の数はここで集計する
+const POST_COLUMNS: &str =
+    "id, user_id, board_id, title, content, locked, pinned, parent_id, created_at, updated_at,
+     edited_count, last_activity_at, version, deleted_at, deleted_by, status, publish_at,
+     (SELECT COUNT(*) FROM posts AS replies
+      WHERE replies.parent_id = posts.id AND replies.deleted_at IS NULL) AS reply_count";
+
+/// ゴミ箱にあり、親の投稿と一緒に移されたのではない投稿の条件
+const IN_TRASH: &str = "deleted_at IS NOT NULL AND NOT EXISTS (
+     SELECT 1 FROM posts AS parents
+     WHERE parents.id = posts.parent_id AND parents.deleted_at IS NOT NULL)";
+
+fn post_from_row(row: &Row<'_>) -> rusqlite::Result<Result<Post, RepositoryError>> {
+    let id: String = row.get("id")?;
+    let board_id: String = row.get("board_id")?;
+    let parent_id: Option<String> = row.get("parent_id")?;
+    let (id, board_id, parent_id) = match (
+        parse_uuid(&id),
+        parse_uuid(&board_id),
+        parent_id.as_deref().map(parse_uuid),
+    ) {
+        (Ok(id), Ok(board_id), None) => (id, board_id, None),
+        (Ok(id), Ok(board_id), Some(Ok(parent_id))) => (id, board_id, Some(parent_id)),
+        (Err(e), _, _) | (_, Err(e), _) | (_, _, Some(Err(e))) => return Ok(Err(e)),
+    };
+    let status: String = row.get("status")?;
+    let status = match status.parse::<PostStatus>() {
+        Ok(status) => status,
+        Err(e) => return Ok(Err(RepositoryError::Corrupted(e))),
+    };
+    Ok(Ok(Post {
+        id,
+        user_id: row.get("user_id")?,
+        board_id,
+        title: Title::from_stored(row.get("title")?),
+        content: Content::from_stored(row.get("content")?),
+        locked: row.get("locked")?,
+        pinned: row.get("pinned")?,
+        parent_id,
+        reply_count: row.get("reply_count")?,
+        created_at: row.get("created_at")?,
+        updated_at: row.get("updated_at")?,
+        edited_count: row.get("edited_count")?,
+        last_activity_at: row.get("last_activity_at")?,
+        version: row.get("version")?,
+        deleted_at: row.get("deleted_at")?,
+        deleted_by: row.get("deleted_by")?,
+        status,
+        publish_at: row.get("publish_at")?,
+    }))
+}
+
+/// 版を読み出す際の列
+const REVISION_COLUMNS: &str = "post_id, number, title, content, edited_by, created_at";
+
+fn revision_from_row(row: &Row<'_>) -> rusqlite::Result<Result<PostRevision, RepositoryError>> {
+    let post_id: String = row.get("post_id")?;
+    let post_id = match parse_uuid(&post_id) {
+        Ok(post_id) => post_id,
+        Err(e) => return Ok(Err(e)),
+    };
+    Ok(Ok(PostRevision {
+        post_id,
+        number: row.get("number")?,
+        title: Title::from_stored(row.get("title")?),
+        content: Content::from_stored(row.get("content")?),
+        edited_by: row.get("edited_by")?,
+        created_at: row.get("created_at")?,
+    }))
+}
+
+fn insert_revision(conn: &Connection, revision: &PostRevision) -> rusqlite::Result<()> {
+    conn.execute(
+        "INSERT INTO post_revisions (post_id, number, title, content, edited_by, created_at)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+        params![
+            revision.post_id.to_string(),
+            revision.number,
+            revision.title.as_str(),
+            revision.content.as_str(),
+            revision.edited_by,
+            revision.created_at
+        ],
+    )?;
+    Ok(())
+}
+
+/// 既存の投稿を置き換え、索引と祖先の`last_activity_at`を更新する
+///
+/// 保存されているバージョンが`post.version`の1つ前でない場合は`RepositoryError::Stale`を返す
+fn replace_post(conn: &Connection, post: &Post) -> Result<bool, RepositoryError> {
+    let updated = conn.execute(
+        "UPDATE posts SET user_id = ?2, title = ?3, content = ?4, locked = ?5, pinned = ?6,
+             updated_at = ?7, edited_count = ?8, last_activity_at = ?9, version = ?10,
+             deleted_at = ?11, deleted_by = ?12, created_at = ?13, status = ?14, publish_at = ?15
+         WHERE id = ?1 AND version = ?10 - 1",
+        params![
+            post.id.to_string(),
+            post.user_id,
+            post.title.as_str(),
+            post.content.as_str(),
+            post.locked,
+            post.pinned,
+            post.updated_at,
+            post.edited_count,
+            post.last_activity_at,
+            post.version,
+            post.deleted_at,
+            post.deleted_by,
+            post.created_at,
+            post.status.as_str(),
+            post.publish_at
+        ],
+    )?;
+    if updated == 0 {
+        let exists: bool = conn.query_row(
+            "SELECT EXISTS (SELECT 1 FROM posts WHERE id = ?1)",
+            params![post.id.to_string()],
+            |row| row.get(0),
+        )?;
+        return if exists {
+            Err(RepositoryError::Stale)
+        } else {
+            Ok(false)
+        };
+    }
+    index_post(conn, post)?;
+    touch_ancestors(conn, post.id, post.last_activity_at)?;
+    Ok(true)
+}
+
+/// 投稿の索引を作り直す
+fn index_post(conn: &Connection, post: &Post) -> rusqlite::Result<()> {
+    let id = post.id.to_string();
+    conn.execute("DELETE FROM post_terms WHERE post_id = ?1", params![id])?;
+    let mut stmt =
+        conn.prepare_cached("INSERT INTO post_terms (term, post_id, weight) VALUES (?1, ?2, ?3)")?;
+    for (term, weight) in search::index_terms(post) {
+        stmt.execute(params![term, id, weight])?;
+    }
+    conn.execute(
+        "UPDATE posts SET search_version = ?2 WHERE id = ?1",
+        params![id, search::INDEX_VERSION],
+    )?;
+    Ok(())
+}
+
+/// `id`の祖先の`last_activity_at`を`at`まで進める
+fn touch_ancestors(conn: &Connection, id: PostId, at: DateTime<Utc>) -> rusqlite::Result<()> {
+    conn.execute(
+        "WITH RECURSIVE ancestors (id) AS (
+             SELECT parent_id FROM posts WHERE id = ?1
+             UNION ALL
+             SELECT posts.parent_id FROM posts JOIN ancestors ON posts.id = ancestors.id
+         )
+         UPDATE posts SET last_activity_at = ?2
+         WHERE id IN (SELECT id FROM ancestors) AND last_activity_at < ?2",
+        params![id.to_string(), at],
+    )?;
+    Ok(())
+}
+
+impl PostRepository for SqlitePostRepository {
+    fn list(&self, query: &PostQuery) -> Result<Vec<Post>, RepositoryError> {
+        let (order, key, op) = match query.sort {
+            PostSort::Newest => ("created_at DESC, id DESC", "(created_at, id)", "<"),
+            PostSort::Oldest => ("created_at, id", "(created_at, id)", ">"),
+            PostSort::MostReplied => (
+                "reply_count DESC, created_at DESC, id DESC",
+                "(reply_count, created_at, id)",
+                "<",
+            ),
+        };
+        let mut conditions = vec!["1 = 1"];
+        let mut values: Vec<Box<dyn ToSql>> = vec![];
+        if let Some(board_id) = query.board_id {
+            conditions.push("board_id = ?");
+            values.push(Box::new(board_id.to_string()));
+        }
+        if let Some(user_id) = query.user_id {
+            conditions.push("user_id = ?");
+            values.push(Box::new(user_id));
+        }
+        if let Some(since) = query.since {
+            conditions.push("created_at >= ?");
+            values.push(Box::new(since));
+        }
+        if let Some(until) = query.until {
+            conditions.push("created_at < ?");
+            values.push(Box::new(until));
+        }
+        let after;
+        if let Some(cursor) = &query.after {
+            // ピン留めは常に降順で、それ以外の列は並び順に従って比較する
+            after = format!(
+                "(pinned < ? OR (pinned = ? AND {} {} ({})))",
+                key,
+                op,
+                if query.sort == PostSort::MostReplied {
+                    "?, ?, ?"
+                } else {
+                    "?, ?"
+                }
+            );
+            conditions.push(&after);
+            values.push(Box::new(cursor.pinned));
+            values.push(Box::new(cursor.pinned));
+            if query.sort == PostSort::MostReplied {
+                values.push(Box::new(cursor.reply_count));
+            }
+            values.push(Box::new(cursor.created_at));
+            values.push(Box::new(cursor.id.to_string()));
+        }
+        values.push(Box::new(query.limit));
+
+        let conn = self.db.lock()?;
+        // 返信の数で比較できるよう、集計した列を持つ副問い合わせを絞り込む
+        let mut stmt = conn.prepare(&format!(
+            "SELECT * FROM (SELECT {} FROM posts
+                            WHERE parent_id IS NULL AND deleted_at IS NULL AND status = 'published')
+             WHERE {} ORDER BY pinned DESC, {} LIMIT ?",
+            POST_COLUMNS,
+            conditions.join(" AND "),
+            order
+        ))?;
+        let rows = stmt.query_map(params_from_iter(values), post_from_row)?;
+        rows.map(|row| row?).collect()
+    }
+
+    fn list_replies(
+        &self,
+        parent_id: PostId,
+        limit: usize,
+        offset: usize,
+    ) -> Result<Vec<Post>, RepositoryError> {
+        let conn = self.db.lock()?;
+        let mut stmt = conn.prepare(&format!(
+            "SELECT {} FROM posts WHERE parent_id = ?1 AND deleted_at IS NULL
+             ORDER BY rowid LIMIT ?2 OFFSET ?3",
+            POST_COLUMNS
+        ))?;
+        let rows = stmt.query_map(params![parent_id.to_string(), limit, offset], post_from_row)?;
+        rows.map(|row| row?).collect()
+    }
+
+    fn find(&self, id: PostId) -> Result<Option<Post>, RepositoryError> {
+        let conn = self.db.lock()?;
+        conn.query_row(
+            &format!(
+                "SELECT {} FROM posts WHERE id = ?1 AND deleted_at IS NULL",
+                POST_COLUMNS
+            ),
+            params![id.to_string()],
+            post_from_row,
+        )
+        .optional()?
+        .transpose()
+    }
+
+    fn insert(&self, post: Post) -> Result<(), RepositoryError> {
+        let mut conn = self.db.lock()?;
+        let tx = conn.transaction()?;
+        tx.execute(
+            "INSERT INTO posts
+                 (id, user_id, board_id, title, content, locked, pinned, parent_id, created_at,
+                  updated_at, edited_count, last_activity_at, version, deleted_at, deleted_by,
+                  status, publish_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17)",
+            params![
+                post.id.to_string(),
+                post.user_id,
+                post.board_id.to_string(),
+                post.title.as_str(),
+                post.content.as_str(),
+                post.locked,
+                post.pinned,
+                post.parent_id.map(|id| id.to_string()),
+                post.created_at,
+                post.updated_at,
+                post.edited_count,
+                post.last_activity_at,
+                post.version,
+                post.deleted_at,
+                post.deleted_by,
+                post.status.as_str(),
+                post.publish_at
+            ],
+        )?;
+        index_post(&tx, &post)?;
+        insert_revision(&tx, &PostRevision::of(&post, post.user_id))?;
+        touch_ancestors(&tx, post.id, post.last_activity_at)?;
+        tx.commit()?;
+        Ok(())
+    }
+
+    fn update(&self, post: Post) -> Result<bool, RepositoryError> {
+        let mut conn = self.db.lock()?;
+        let tx = conn.transaction()?;
+        let updated = replace_post(&tx, &post)?;
+        tx.commit()?;
+        Ok(updated)
+    }
+
+    fn edit(&self, post: Post, editor: UserId) -> Result<bool, RepositoryError> {
+        let mut conn = self.db.lock()?;
+        let tx = conn.transaction()?;
+        if !replace_post(&tx, &post)? {
+            return Ok(false);
+        }
+        insert_revision(&tx, &PostRevision::of(&post, editor))?;
+        tx.commit()?;
+        Ok(true)
+    }
+
+    fn trash(&self, post: Post) -> Result<bool, RepositoryError> {
+        let mut conn = self.db.lock()?;
+        let tx = conn.transaction()?;
+        if !replace_post(&tx, &post)? {
+            return Ok(false);
+        }
+        // 先にゴミ箱に移された返信とその配下は、移された日時を変えない
+        tx.execute(
+            "WITH RECURSIVE replies (id) AS (
+                 SELECT id FROM posts WHERE parent_id = ?1 AND deleted_at IS NULL
+                 UNION ALL
+                 SELECT posts.id FROM posts JOIN replies ON posts.parent_id = replies.id
+                 WHERE posts.deleted_at IS NULL
+             )
+             UPDATE posts SET deleted_at = ?2, deleted_by = ?3, version = version + 1
+             WHERE id IN (SELECT id FROM replies)",
+            params![post.id.to_string(), post.deleted_at, post.deleted_by],
+        )?;
+        tx.commit()?;
+        Ok(true)
+    }
+
+    fn restore(&self, post: Post) -> Result<bool, RepositoryError> {
+        let mut conn = self.db.lock()?;
+        let tx = conn.transaction()?;
+        let id = post.id.to_string();
+        // 保存されている値のまま比較するため、日時は文字列で読み出す
+        let deleted_at: Option<String> = tx
+            .query_row(
+                "SELECT deleted_at FROM posts WHERE id = ?1",
+                params![id],
+                |row| row.get(0),
+            )
+            .optional()?
+            .flatten();
+        let Some(deleted_at) = deleted_at else {
+            return Ok(false);
+        };
+        if !replace_post(&tx, &post)? {
+            return Ok(false);
+        }
+        tx.execute(
+            "WITH RECURSIVE replies (id) AS (
+                 SELECT id FROM posts WHERE parent_id = ?1 AND deleted_at = ?2
+                 UNION ALL
+                 SELECT posts.id FROM posts JOIN replies ON posts.parent_id = replies.id
+                 WHERE posts.deleted_at = ?2
+             )
+             UPDATE posts SET deleted_at = NULL, deleted_by = NULL, version = version + 1
+             WHERE id IN (SELECT id FROM replies)",
+            params![id, deleted_at],
+        )?;
+        tx.commit()?;
+        Ok(true)
+    }
+
+    fn find_in_trash(&self, id: PostId) -> Result<Option<Post>, RepositoryError> {
+        let conn = self.db.lock()?;
+        conn.query_row(
+            &format!(
+                "SELECT {} FROM posts WHERE id = ?1 AND {}",
+                POST_COLUMNS, IN_TRASH
+            ),
+            params![id.to_string()],
+            post_from_row,
+        )
+        .optional()?
+        .transpose()
+    }
+
+    fn list_trash(
+        &self,
+        deleted_by: UserId,
+        limit: usize,
+        offset: usize,
+    ) -> Result<Vec<Post>, RepositoryError> {
+        let conn = self.db.lock()?;
+        let mut stmt = conn.prepare(&format!(
+            "SELECT {} FROM posts WHERE deleted_by = ?1 AND {}
+             ORDER BY deleted_at DESC, id DESC LIMIT ?2 OFFSET ?3",
+            POST_COLUMNS, IN_TRASH
+        ))?;
+        let rows = stmt.query_map(params![deleted_by, limit, offset], post_from_row)?;
+        rows.map(|row| row?).collect()
+    }
+
+    fn list_drafts(
+        &self,
+        user_id: UserId,
+        limit: usize,
+        offset: usize,
+    ) -> Result<Vec<Post>, RepositoryError> {
+        let conn = self.db.lock()?;
+        let mut stmt = conn.prepare(&format!(
+            "SELECT {} FROM posts
+             WHERE user_id = ?1 AND status != 'published' AND deleted_at IS NULL
+             ORDER BY updated_at DESC, id DESC LIMIT ?2 OFFSET ?3",
+            POST_COLUMNS
+        ))?;
+        let rows = stmt.query_map(params![user_id, limit, offset], post_from_row)?;
+        rows.map(|row| row?).collect()
+    }
+
+    fn publish_due(&self, now: DateTime<Utc>) -> Result<Vec<Post>, RepositoryError> {
+        let mut conn = self.db.lock()?;
+        let tx = conn.transaction()?;
+        let due = {
+            let mut stmt = tx.prepare(&format!(
+                "SELECT {} FROM posts
+                 WHERE status = 'scheduled' AND publish_at <= ?1 AND deleted_at IS NULL",
+                POST_COLUMNS
+            ))?;
+            let rows = stmt.query_map(params![now], post_from_row)?;
+            rows.map(|row| row?).collect::<Result<Vec<_>, _>>()?
+        };
+        let mut published = Vec::new();
+        for mut post in due {
+            let Some(at) = post.publish_at else {
+                continue;
+            };
+            post.publish(at);
+            replace_post(&tx, &post)?;
+            published.push(post);
+        }
+        tx.commit()?;
+        Ok(published)
+    }
+
+    fn has_posts_in(&self, board_id: BoardId) -> Result<bool, RepositoryError> {
+        let conn = self.db.lock()?;
+        Ok(conn.query_row(
+            "SELECT EXISTS (SELECT 1 FROM posts WHERE board_id = ?1 AND deleted_at IS NULL)",
+            params![board_id.to_string()],
+            |row| row.get(0),
+        )?)
+    }
+
+    fn purge_trash_in(&self, board_id: BoardId) -> Result<usize, RepositoryError> {
+        let conn = self.db.lock()?;
+        // 返信は親と同じボードに属し、索引は外部キーのON DELETE CASCADEで削除される
+        let deleted = conn.execute(
+            "DELETE FROM posts WHERE board_id = ?1 AND deleted_at IS NOT NULL",
+            params![board_id.to_string()],
+        )?;
+        Ok(deleted)
+    }
+
+    fn purge_trash(&self, before: DateTime<Utc>) -> Result<usize, RepositoryError> {
+        let mut conn = self.db.lock()?;
+        let tx = conn.transaction()?;
+        // 配下の返信は投稿と同時かそれより前にゴミ箱に移されているため、条件に一致する行だけ数えればよい
+        let count: usize = tx.query_row(
+            "SELECT COUNT(*) FROM posts WHERE deleted_at < ?1",
+            params![before],
+            |row| row.get(0),
+        )?;
+        tx.execute("DELETE FROM posts WHERE deleted_at < ?1", params![before])?;
+        tx.commit()?;
+        Ok(count)
+    }
+
+    fn delete(&self, id: PostId) -> Result<bool, RepositoryError> {
+        let conn = self.db.lock()?;
+        // 返信と索引は外部キーのON DELETE CASCADEで削除される
+        let deleted = conn.execute("DELETE FROM posts WHERE id = ?1", params![id.to_string()])?;
+        Ok(deleted > 0)
+    }
+
+    fn list_revisions(
+        &self,
+        post_id: PostId,
+        limit: usize,
+        offset: usize,
+    ) -> Result<Vec<PostRevision>, RepositoryError> {
+        let conn = self.db.lock()?;
+        let mut stmt = conn.prepare(&format!(
+            "SELECT {} FROM post_revisions WHERE post_id = ?1 ORDER BY number LIMIT ?2 OFFSET ?3",
+            REVISION_COLUMNS
+        ))?;
+        let rows = stmt.query_map(
+            params![post_id.to_string(), limit, offset],
+            revision_from_row,
+        )?;
+        rows.map(|row| row?).collect()
+    }
+
+    fn find_revision(
+        &self,
+        post_id: PostId,
+        number: u32,
+    ) -> Result<Option<PostRevision>, RepositoryError> {
+        let conn = self.db.lock()?;
+        conn.query_row(
+            &format!(
+                "SELECT {} FROM post_revisions WHERE post_id = ?1 AND number = ?2",
+                REVISION_COLUMNS
+            ),
+            params![post_id.to_string(), number],
+            revision_from_row,
+        )
+        .optional()?
+        .transpose()
+    }
+
+    fn search(
+        &self,
+        terms: &[String],
+        limit: usize,
+    ) -> Result<Vec<SearchCandidate>, RepositoryError> {
+        if terms.is_empty() {
+            return Ok(Vec::new());
+        }
+        let placeholders = vec!["?"; terms.len()].join(", ");
+        let mut values: Vec<&dyn ToSql> = terms.iter().map(|term| term as &dyn ToSql).collect();
+        let count = terms.len();
+        values.push(&count);
+        values.push(&limit);
+
+        let conn = self.db.lock()?;
+        // 語は重複しないため、一致した行の数が語の数と等しい投稿はすべての語を含む
+        let mut stmt = conn.prepare(&format!(
+            "SELECT {} FROM posts WHERE deleted_at IS NULL AND status = 'published' AND id IN (
+                 SELECT post_id FROM post_terms WHERE term IN ({})
+                 GROUP BY post_id HAVING COUNT(*) = ?)
+             ORDER BY created_at DESC, id DESC LIMIT ?",
+            POST_COLUMNS, placeholders
+        ))?;
+        let rows = stmt.query_map(params_from_iter(values), post_from_row)?;
+        let posts = rows.map(|row| row?).collect::<Result<Vec<Post>, _>>()?;
+
+        let mut stmt =
+            conn.prepare("SELECT weight FROM post_terms WHERE term = ?1 AND post_id = ?2")?;
+        posts
+            .into_iter()
+            .map(|post| {
+                let id = post.id.to_string();
+                let weights = terms
+                    .iter()
+                    .map(|term| stmt.query_row(params![term, id], |row| row.get(0)))
+                    .collect::<rusqlite::Result<Vec<u32>>>()?;
+                Ok(SearchCandidate { post, weights })
+            })
+            .collect()
+    }
+}
+
+pub struct SqliteBoardRepository {
+    db: Database,
+}
+
+impl SqliteBoardRepository {
+    pub fn new(db: Database) -> Self {
+        Self { db }
+    }
+}
+
+const BOARD_COLUMNS: &str = "id, slug, name, description, posting_role, allow_replies,
+     max_post_length, created_at, updated_at";
+
+fn board_from_row(row: &Row<'_>) -> rusqlite::Result<Result<Board, RepositoryError>> {
+    let id: String = row.get("id")?;
+    let posting_role: String = row.get("posting_role")?;
+    let id = match parse_uuid(&id) {
+        Ok(id) => id,
+        Err(e) => return Ok(Err(e)),
+    };
+    let posting_role = match posting_role.parse::<Role>() {
+        Ok(role) => role,
+        Err(e) => return Ok(Err(RepositoryError::Corrupted(e))),
+    };
+    let description: Option<String> = row.get("description")?;
+    Ok(Ok(Board {
+        id,
+        slug: BoardSlug::from_stored(row.get("slug")?),
+        name: BoardName::from_stored(row.get("name")?),
+        description: description.map(BoardDescription::from_stored),
+        settings: BoardSettings {
+            posting_role,
+            allow_replies: row.get("allow_replies")?,
+            max_post_length: row.get("max_post_length")?,
+        },
+        created_at: row.get("created_at")?,
+        updated_at: row.get("updated_at")?,
+    }))
+}
+
+impl BoardRepository for SqliteBoardRepository {
+    fn insert(&self, board: Board) -> Result<(), RepositoryError> {
+        let conn = self.db.lock()?;
+        conn.execute(
+            &format!(
+                "INSERT INTO boards ({}) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+                BOARD_COLUMNS
+            ),
+            params![
+                board.id.to_string(),
+                board.slug.as_str(),
+                board.name.as_str(),
+                board.description.as_ref().map(BoardDescription::as_str),
+                board.settings.posting_role.as_str(),
+                board.settings.allow_replies,
+                board.settings.max_post_length,
+                board.created_at,
+                board.updated_at
+            ],
+        )
+        .map_err(map_constraint)?;
+        Ok(())
+    }
+
+    fn list(&self) -> Result<Vec<Board>, RepositoryError> {
+        let conn = self.db.lock()?;
+        let mut stmt = conn.prepare(&format!(
+            "SELECT {} FROM boards ORDER BY slug",
+            BOARD_COLUMNS
+        ))?;
+        let rows = stmt.query_map([], board_from_row)?;
+        rows.map(|row| row?).collect()
+    }
+
+    fn find(&self, id: BoardId) -> Result<Option<Board>, RepositoryError> {
+        let conn = self.db.lock()?;
+        conn.query_row(
+            &format!("SELECT {} FROM boards WHERE id = ?1", BOARD_COLUMNS),
+            params![id.to_string()],
+            board_from_row,
+        )
+        .optional()?
+        .transpose()
+    }
+
+    fn find_by_slug(&self, slug: &str) -> Result<Option<Board>, RepositoryError> {
+        let conn = self.db.lock()?;
+        conn.query_row(
+            &format!("SELECT {} FROM boards WHERE slug = ?1", BOARD_COLUMNS),
+            params![slug],
+            board_from_row,
+        )
+        .optional()?
+        .transpose()
+    }
+
+    fn update(&self, board: Board) -> Result<bool, RepositoryError> {
+        let conn = self.db.lock()?;
+        let updated = conn
+            .execute(
+                "UPDATE boards SET slug = ?2, name = ?3, description = ?4, posting_role = ?5,
+                     allow_replies = ?6, max_post_length = ?7, updated_at = ?8
+                 WHERE id = ?1",
+                params![
+                    board.id.to_string(),
+                    board.slug.as_str(),
+                    board.name.as_str(),
+                    board.description.as_ref().map(BoardDescription::as_str),
+                    board.settings.posting_role.as_str(),
+                    board.settings.allow_replies,
+                    board.settings.max_post_length,
+                    board.updated_at
+                ],
+            )
+            .map_err(map_constraint)?;
+        Ok(updated > 0)
+    }
+
+    fn delete(&self, id: BoardId) -> Result<bool, RepositoryError> {
+        let conn = self.db.lock()?;
+        // 投稿が属するボードは外部キーの制約違反になる
+        let deleted = conn
+            .execute("DELETE FROM boards WHERE id = ?1", params![id.to_string()])
+            .map_err(map_constraint)?;
+        Ok(deleted > 0)
+    }
+}
+
+pub struct SqliteRefreshTokenRepository {
+    db: Database,
+}
+
+impl SqliteRefreshTokenRepository {
+    pub fn new(db: Database) -> Self {
+        Self { db }
+    }
+}
+
+fn refresh_token_from_row(
+    row: &Row<'_>,
+) -> rusqlite::Result<Result<RefreshToken, RepositoryError>> {
+    let family_id: String = row.get("family_id")?;
+    let family_id = match parse_uuid(&family_id) {
+        Ok(id) => id,
+        Err(e) => return Ok(Err(e)),
+    };
+    Ok(Ok(RefreshToken {
+        token_hash: row.get("token_hash")?,
+        family_id,
+        user_id: row.get("user_id")?,
+        expires_at: row.get("expires_at")?,
+        used: row.get("used")?,
+        revoked: row.get("revoked")?,
+    }))
+}
+
+impl RefreshTokenRepository for SqliteRefreshTokenRepository {
+    fn insert(&self, token: RefreshToken) -> Result<(), RepositoryError> {
+        let conn = self.db.lock()?;
+        conn.execute(
+            "INSERT INTO refresh_tokens (token_hash, family_id, user_id, expires_at, used, revoked)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            params![
+                token.token_hash,
+                token.family_id.to_string(),
+                token.user_id,
+                token.expires_at,
+                token.used,
+                token.revoked
+            ],
+        )?;
+        Ok(())
+    }
+
+    fn find(&self, token_hash: &str) -> Result<Option<RefreshToken>, RepositoryError> {
+        let conn = self.db.lock()?;
+        let token = conn
+            .query_row(
+                "SELECT token_hash, family_id, user_id, expires_at, used, revoked
+                 FROM refresh_tokens WHERE token_hash = ?1",
+                params![token_hash],
+                refresh_token_from_row,
+            )
+            .optional()?
+            .transpose()?;
+        Ok(token)
+    }
+
+    fn consume(&self, token_hash: &str) -> Result<Option<RefreshToken>, RepositoryError> {
+        // 接続のロックを保持している間に読み出しと更新を行う
+        let conn = self.db.lock()?;
+        let token = conn
+            .query_row(
+                "SELECT token_hash, family_id, user_id, expires_at, used, revoked
+                 FROM refresh_tokens WHERE token_hash = ?1",
+                params![token_hash],
+                refresh_token_from_row,
+            )
+            .optional()?
+            .transpose()?;
+        if token.is_some() {
+            conn.execute(
+                "UPDATE refresh_tokens SET used = 1 WHERE token_hash = ?1",
+                params![token_hash],
+            )?;
+        }
+        Ok(token)
+    }
+
+    fn revoke_family(&self, family_id: Uuid) -> Result<(), RepositoryError> {
+        let conn = self.db.lock()?;
+        conn.execute(
+            "UPDATE refresh_tokens SET revoked = 1 WHERE family_id = ?1",
+            params![family_id.to_string()],
+        )?;
+        Ok(())
+    }
+
+    fn revoke_user(&self, user_id: UserId) -> Result<(), RepositoryError> {
+        let conn = self.db.lock()?;
+        conn.execute(
+            "UPDATE refresh_tokens SET revoked = 1 WHERE user_id = ?1",
+            params![user_id],
+        )?;
+        Ok(())
+    }
+}
+
+pub struct SqliteRevocationRepository {
+    db: Database,
+}
+
+impl SqliteRevocationRepository {
+    pub fn new(db: Database) -> Self {
+        Self { db }
+    }
+}
+
+impl RevocationRepository for SqliteRevocationRepository {
+    fn revoke(&self, jti: &str, expires_at: DateTime<Utc>) -> Result<(), RepositoryError> {
+        let conn = self.db.lock()?;
+        conn.execute(
+            "INSERT OR REPLACE INTO revoked_tokens (jti, expires_at) VALUES (?1, ?2)",
+            params![jti, expires_at],
+        )?;
+        Ok(())
+    }
+
+    fn is_revoked(&self, jti: &str) -> Result<bool, RepositoryError> {
+        let conn = self.db.lock()?;
+        let expires_at: Option<DateTime<Utc>> = conn
+            .query_row(
+                "SELECT expires_at FROM revoked_tokens WHERE jti = ?1",
+                params![jti],
+                |row| row.get(0),
+            )
+            .optional()?;
+        Ok(expires_at.is_some_and(|expires_at| expires_at > Utc::now()))
+    }
+
+    fn revoke_user(&self, user_id: UserId, before: DateTime<Utc>) -> Result<(), RepositoryError> {
+        let conn = self.db.lock()?;
+        conn.execute(
+            "INSERT OR REPLACE INTO user_revocations (user_id, revoked_before) VALUES (?1, ?2)",
+            params![user_id, before],
+        )?;
+        Ok(())
+    }
+
+    fn user_revoked_before(
+        &self,
+        user_id: UserId,
+    ) -> Result<Option<DateTime<Utc>>, RepositoryError> {
+        let conn = self.db.lock()?;
+        let before = conn
+            .query_row(
+                "SELECT revoked_before FROM user_revocations WHERE user_id = ?1",
+                params![user_id],
+                |row| row.get(0),
+            )
+            .optional()?;
+        Ok(before)
+    }
+
+    fn purge_expired(&self, now: DateTime<Utc>) -> Result<usize, RepositoryError> {
+        let conn = self.db.lock()?;
+        let purged = conn.execute(
+            "DELETE FROM revoked_tokens WHERE expires_at <= ?1",
+            params![now],
+        )?;
+        Ok(purged)
+    }
+}
+
+pub struct SqliteWebhookRepository {
+    db: Database,
+}
+
+impl SqliteWebhookRepository {
+    pub fn new(db: Database) -> Self {
+        Self { db }
+    }
+}
+
+const WEBHOOK_COLUMNS: &str = "id, url, secret, events, created_by, created_at";
+
+const DELIVERY_COLUMNS: &str = "id, webhook_id, event, payload, status, attempts, next_attempt_at,
+     last_attempt_at, last_status_code, last_error, created_at";
+
+fn webhook_from_row(row: &Row<'_>) -> rusqlite::Result<Result<Webhook, RepositoryError>> {
+    let id: String = row.get("id")?;
+    let events: String = row.get("events")?;
+    let id = match parse_uuid(&id) {
+        Ok(id) => id,
+        Err(e) => return Ok(Err(e)),
+    };
+    let events = match events
+        .split(',')
+        .filter(|event| !event.is_empty())
+        .map(str::parse)
+        .collect::<Result<Vec<WebhookEvent>, String>>()
+    {
+        Ok(events) => events,
+        Err(e) => return Ok(Err(RepositoryError::Corrupted(e))),
+    };
+    Ok(Ok(Webhook {
+        id,
+        url: WebhookUrl::from_stored(row.get("url")?),
+        secret: row.get("secret")?,
+        events,
+        created_by: row.get("created_by")?,
+        created_at: row.get("created_at")?,
+    }))
+}
+
+fn delivery_from_row(row: &Row<'_>) -> rusqlite::Result<Result<WebhookDelivery, RepositoryError>> {
+    let id: String = row.get("id")?;
+    let webhook_id: String = row.get("webhook_id")?;
+    let event: String = row.get("event")?;
+    let status: String = row.get("status")?;
+    let (id, webhook_id) = match (parse_uuid(&id), parse_uuid(&webhook_id)) {
+        (Ok(id), Ok(webhook_id)) => (id, webhook_id),
+        (Err(e), _) | (_, Err(e)) => return Ok(Err(e)),
+    };
+    let (event, status) = match (event.parse(), status.parse()) {
+        (Ok(event), Ok(status)) => (event, status),
+        (Err(e), _) | (_, Err(e)) => return Ok(Err(RepositoryError::Corrupted(e))),
+    };
+    Ok(Ok(WebhookDelivery {
+        id,
+        webhook_id,
+        event,
+        payload: row.get("payload")?,
+        status,
+        attempts: row.get("attempts")?,
+        next_attempt_at: row.get("next_attempt_at")?,
+        last_attempt_at: row.get("last_attempt_at")?,
+        last_status_code: row.get("last_status_code")?,
+        last_error: row.get("last_error")?,
+        created_at: row.get("created_at")?,
+    }))
+}
+
+impl WebhookRepository for SqliteWebhookRepository {
+    fn insert(&self, webhook: Webhook) -> Result<(), RepositoryError> {
+        let events: Vec<&str> = webhook.events.iter().map(WebhookEvent::as_str).collect();
+        let conn = self.db.lock()?;
+        conn.execute(
+            "INSERT INTO webhooks (id, url, secret, events, created_by, created_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            params![
+                webhook.id.to_string(),
+                webhook.url.as_str(),
+                webhook.secret,
+                events.join(","),
+                webhook.created_by,
+                webhook.created_at
+            ],
+        )
+        .map_err(map_constraint)?;
+        Ok(())
+    }
+
+    fn list(&self) -> Result<Vec<Webhook>, RepositoryError> {
+        let conn = self.db.lock()?;
+        let mut stmt = conn.prepare(&format!(
+            "SELECT {} FROM webhooks ORDER BY rowid",
+            WEBHOOK_COLUMNS
+        ))?;
+        let rows = stmt.query_map([], webhook_from_row)?;
+        rows.map(|row| row?).collect()
+    }
+
+    fn find(&self, id: Uuid) -> Result<Option<Webhook>, RepositoryError> {
+        let conn = self.db.lock()?;
+        conn.query_row(
+            &format!("SELECT {} FROM webhooks WHERE id = ?1", WEBHOOK_COLUMNS),
+            params![id.to_string()],
+            webhook_from_row,
+        )
+        .optional()?
+        .transpose()
+    }
+
+    fn delete(&self, id: Uuid) -> Result<bool, RepositoryError> {
+        let conn = self.db.lock()?;
+        // 配信の記録は外部キーのON DELETE CASCADEで削除される
+        let deleted = conn.execute(
+            "DELETE FROM webhooks WHERE id = ?1",
+            params![id.to_string()],
+        )?;
+        Ok(deleted > 0)
+    }
+
+    fn insert_delivery(&self, delivery: WebhookDelivery) -> Result<(), RepositoryError> {
+        let conn = self.db.lock()?;
+        conn.execute(
+            &format!(
+                "INSERT INTO webhook_deliveries ({})
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)",
+                DELIVERY_COLUMNS
+            ),
+            params![
+                delivery.id.to_string(),
+                delivery.webhook_id.to_string(),
+                delivery.event.as_str(),
+                delivery.payload,
+                delivery.status.as_str(),
+                delivery.attempts,
+                delivery.next_attempt_at,
+                delivery.last_attempt_at,
+                delivery.last_status_code,
+                delivery.last_error,
+                delivery.created_at
+            ],
+        )
+        .map_err(map_constraint)?;
+        Ok(())
+    }
+
+    fn update_delivery(&self, delivery: WebhookDelivery) -> Result<bool, RepositoryError> {
+        let conn = self.db.lock()?;
+        let updated = conn.execute(
+            "UPDATE webhook_deliveries
+             SET status = ?2, attempts = ?3, next_attempt_at = ?4, last_attempt_at = ?5,
+                 last_status_code = ?6, last_error = ?7
+             WHERE id = ?1",
+            params![
+                delivery.id.to_string(),
+                delivery.status.as_str(),
+                delivery.attempts,
+                delivery.next_attempt_at,
+                delivery.last_attempt_at,
+                delivery.last_status_code,
+                delivery.last_error
+            ],
+        )?;
+        Ok(updated > 0)
+    }
+
+    fn find_delivery(&self, id: Uuid) -> Result<Option<WebhookDelivery>, RepositoryError> {
+        let conn = self.db.lock()?;
+        conn.query_row(
+            &format!(
+                "SELECT {} FROM webhook_deliveries WHERE id = ?1",
+                DELIVERY_COLUMNS
+            ),
+            params![id.to_string()],
+            delivery_from_row,
+        )
+        .optional()?
+        .transpose()
+    }
+
+    fn list_deliveries(
+        &self,
+        query: &DeliveryQuery,
+    ) -> Result<Vec<WebhookDelivery>, RepositoryError> {
+        let mut conditions = vec!["1 = 1"];
+        let mut values: Vec<Box<dyn ToSql>> = vec![];
+        if let Some(webhook_id) = query.webhook_id {
+            conditions.push("webhook_id = ?");
+            values.push(Box::new(webhook_id.to_string()));
+        }
+        if let Some(status) = query.status {
+            conditions.push("status = ?");
+            values.push(Box::new(status.as_str()));
+        }
+        values.push(Box::new(query.limit));
+
+        let conn = self.db.lock()?;
+        let mut stmt = conn.prepare(&format!(
+            "SELECT {} FROM webhook_deliveries WHERE {} ORDER BY rowid DESC LIMIT ?",
+            DELIVERY_COLUMNS,
+            conditions.join(" AND ")
+        ))?;
+        let rows = stmt.query_map(params_from_iter(values), delivery_from_row)?;
+        rows.map(|row| row?).collect()
+    }
+
+    fn due_deliveries(
+        &self,
+        now: DateTime<Utc>,
+        limit: usize,
+    ) -> Result<Vec<WebhookDelivery>, RepositoryError> {
+        let conn = self.db.lock()?;
+        let mut stmt = conn.prepare(&format!(
+            "SELECT {} FROM webhook_deliveries WHERE status = ?1 AND next_attempt_at <= ?2
+             ORDER BY next_attempt_at LIMIT ?3",
+            DELIVERY_COLUMNS
+        ))?;
+        let rows = stmt.query_map(
+            params![DeliveryStatus::Pending.as_str(), now, limit],
+            delivery_from_row,
+        )?;
+        rows.map(|row| row?).collect()
+    }
+
+    fn purge_deliveries(&self, before: DateTime<Utc>) -> Result<usize, RepositoryError> {
+        let conn = self.db.lock()?;
+        let purged = conn.execute(
+            "DELETE FROM webhook_deliveries WHERE status = ?1 AND created_at < ?2",
+            params![DeliveryStatus::Succeeded.as_str(), before],
+        )?;
+        Ok(purged)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entity::GENERAL_BOARD_ID;
+
+    fn new_user(email: &str) -> NewUser {
+        NewUser {
+            name: Name::parse("name").unwrap(),
+            email: Email::parse(email).unwrap(),
+            password: "hash".to_string(),
+            role: Role::Member,
+            created_at: Utc::now(),
+        }
+    }
+
+    /// マイグレーションで作成される既定のボード
+    fn general_board(db: &Database) -> BoardId {
+        SqliteBoardRepository::new(db.clone())
+            .find_by_slug("general")
+            .unwrap()
+            .unwrap()
+            .id
+    }
+
+    mod migrate {
+        use super::*;
+
+        #[test]
+        fn test_migrate_sets_user_version() {
+            let db = Database::open_in_memory().unwrap();
+            let conn = db.lock().unwrap();
+            let version: usize = conn
+                .pragma_query_value(None, "user_version", |row| row.get(0))
+                .unwrap();
+
+            assert_eq!(version, MIGRATIONS.len());
+        }
+
+        #[test]
+        fn test_migrate_is_idempotent() {
+            let mut conn = Connection::open_in_memory().unwrap();
+            migrate(&mut conn).unwrap();
+            migrate(&mut conn).unwrap();
+        }
+
+        /// 最初のスキーマのみ適用し、指定したメールアドレスのユーザーを登録した接続
+        fn initial_schema(emails: &[&str]) -> Connection {
+            let conn = Connection::open_in_memory().unwrap();
+            let Migration::Sql(init) = MIGRATIONS[0] else {
+                unreachable!("the initial schema is SQL");
+            };
+            conn.execute_batch(init).unwrap();
+            conn.pragma_update(None, "user_version", 1).unwrap();
+            for email in emails {
+                conn.execute(
+                    "INSERT INTO users (name, email, password) VALUES ('name', ?1, 'hash')",
+                    params![email],
+                )
+                .unwrap();
+            }
+            conn
+        }
+
+        #[test]
+        fn test_emails_are_normalized_like_the_app() {
+            let mut conn = initial_schema(&[" A@Example.com", "E\u{301}MILE@example.com"]);
+
+            migrate(&mut conn).unwrap();
+
+            let emails: Vec<String> = conn
+                .prepare("SELECT email FROM users ORDER BY id")
+                .unwrap()
+                .query_map([], |row| row.get(0))
+                .unwrap()
+                .collect::<Result<_, _>>()
+                .unwrap();
+            assert_eq!(emails, ["a@example.com", "\u{e9}mile@example.com"]);
+        }
+
+        #[test]
+        fn test_duplicate_emails_stop_migration() {
+            let mut conn = initial_schema(&["a@example.com", "A@EXAMPLE.COM "]);
+
+            let result = migrate(&mut conn);
+
+            assert!(
+                matches!(result, Err(RepositoryError::DuplicateEmail(email)) if email == "a@example.com")
+            );
+            let version: usize = conn
+                .pragma_query_value(None, "user_version", |row| row.get(0))
+                .unwrap();
+            assert_eq!(version, 1);
+        }
+    }
+
+    mod user_repository {
+        use super::*;
+
+        #[test]
+        fn test_create_and_find_by_email() {
+            let repo = SqliteUserRepository::new(Database::open_in_memory().unwrap());
+            let first = repo.create(new_user("a@example.com")).unwrap();
+            let second = repo.create(new_user("b@example.com")).unwrap();
+
+            let found = repo.find_by_email("b@example.com").unwrap().unwrap();
+
+            assert_eq!(first.id, 1);
+            assert_eq!(second.id, 2);
+            assert_eq!(found.id, second.id);
+            assert!(repo.find_by_email("c@example.com").unwrap().is_none());
+        }
+
+        #[test]
+        fn test_set_role() {
+            let repo = SqliteUserRepository::new(Database::open_in_memory().unwrap());
+            let user = repo.create(new_user("a@example.com")).unwrap();
+
+            let updated = repo.set_role(user.id, Role::Moderator).unwrap().unwrap();
+
+            assert_eq!(updated.role, Role::Moderator);
+            assert_eq!(repo.find(user.id).unwrap().unwrap().role, Role::Moderator);
+            assert!(repo.set_role(user.id + 1, Role::Admin).unwrap().is_none());
+        }
+
+        #[test]
+        fn test_record_login() {
+            let repo = SqliteUserRepository::new(Database::open_in_memory().unwrap());
+            let user = repo.create(new_user("a@example.com")).unwrap();
+            let at = Utc::now();
+
+            repo.record_login(user.id, at).unwrap();
+
+            let found = repo.find(user.id).unwrap().unwrap();
+            assert_eq!(user.last_login_at, None);
+            assert_eq!(found.created_at, user.created_at);
+            assert_eq!(found.last_login_at, Some(at));
+        }
+
+        #[test]
+        fn test_set_password() {
+            let repo = SqliteUserRepository::new(Database::open_in_memory().unwrap());
+            let user = repo.create(new_user("a@example.com")).unwrap();
+
+            repo.set_password(user.id, "rehashed").unwrap();
+
+            assert_eq!(repo.find(user.id).unwrap().unwrap().password, "rehashed");
+        }
+
+        #[test]
+        fn test_create_duplicate_email() {
+            let repo = SqliteUserRepository::new(Database::open_in_memory().unwrap());
+            repo.create(new_user("a@example.com")).unwrap();
+
+            let result = repo.create(new_user("a@example.com"));
+
+            assert!(matches!(result, Err(RepositoryError::Conflict)));
+        }
+    }
+
+    mod post_repository {
+        use super::*;
+        use crate::repository::PostCursor;
+        use crate::service::search::SearchQuery;
+
+        #[test]
+        fn test_crud() {
+            let db = Database::open_in_memory().unwrap();
+            SqliteUserRepository::new(db.clone())
+                .create(new_user("a@example.com"))
+                .unwrap();
+            let board_id = general_board(&db);
+            let repo = SqlitePostRepository::new(db);
+            let mut post = Post {
+                id: Uuid::new_v4(),
+                user_id: 1,
+                board_id,
+                title: Title::parse("title").unwrap(),
+                content: Content::parse("content").unwrap(),
+                locked: false,
+                pinned: false,
+                parent_id: None,
+                reply_count: 0,
+                created_at: Utc::now(),
+                updated_at: Utc::now(),
+                edited_count: 0,
+                last_activity_at: Utc::now(),
+                version: 1,
+                deleted_at: None,
+                deleted_by: None,
+                status: PostStatus::Published,
+                publish_at: None,
+            };
+            repo.insert(post.clone()).unwrap();
+
+            post.edit(post.title.clone(), Content::parse("updated").unwrap());
+            assert!(repo.update(post.clone()).unwrap());
+            let found = repo.find(post.id).unwrap().unwrap();
+            assert_eq!(found.content, "updated");
+            assert_eq!(repo.list(&PostQuery::default()).unwrap().len(), 1);
+
+            assert!(repo.delete(post.id).unwrap());
+            assert!(repo.find(post.id).unwrap().is_none());
+        }
+
+        #[test]
+        fn test_search_index_follows_changes() {
+            let db = Database::open_in_memory().unwrap();
+            SqliteUserRepository::new(db.clone())
+                .create(new_user("a@example.com"))
+                .unwrap();
+            let board_id = general_board(&db);
+            let repo = SqlitePostRepository::new(db);
+            let terms = |q: &str| SearchQuery::parse(q).unwrap().terms().to_vec();
+            let mut post = Post::new(
+                1,
+                board_id,
+                Title::parse("東京の天気").unwrap(),
+                Content::parse("晴れ").unwrap(),
+                None,
+            );
+            let reply = Post::new(
+                1,
+                board_id,
+                Title::parse("返信").unwrap(),
+                Content::parse("東京は雨").unwrap(),
+                Some(post.id),
+            );
+            repo.insert(post.clone()).unwrap();
+            repo.insert(reply.clone()).unwrap();
+
+            let found = repo.search(&terms("東京"), 10).unwrap();
+            assert_eq!(
+                found.iter().map(|c| c.post.id).collect::<Vec<_>>(),
+                [reply.id, post.id]
+            );
+            assert_eq!(found[1].weights, [3]);
+
+            post.edit(
+                Title::parse("大阪の天気").unwrap(),
+                Content::parse("晴れ").unwrap(),
+            );
+            repo.update(post.clone()).unwrap();
+            assert_eq!(repo.search(&terms("東京"), 10).unwrap().len(), 1);
+            assert_eq!(repo.search(&terms("大阪 晴れ"), 10).unwrap().len(), 1);
+
+            repo.delete(post.id).unwrap();
+            assert!(repo.search(&terms("東京"), 10).unwrap().is_empty());
+        }
+
+        #[test]
+        fn test_update_rejects_stale_version() {
+            let db = Database::open_in_memory().unwrap();
+            SqliteUserRepository::new(db.clone())
+                .create(new_user("a@example.com"))
+                .unwrap();
+            let board_id = general_board(&db);
+            let repo = SqlitePostRepository::new(db);
+            let post = Post::new(
+                1,
+                board_id,
+                Title::parse("title").unwrap(),
+                Content::parse("content").unwrap(),
+                None,
+            );
+            repo.insert(post.clone()).unwrap();
+            let mut first = post.clone();
+            let mut second = post.clone();
+            first.moderate(Some(true), None);
+            second.edit(
+                Title::parse("title").unwrap(),
+                Content::parse("second").unwrap(),
+            );
+
+            assert!(repo.update(first).unwrap());
+            assert!(matches!(repo.edit(second, 1), Err(RepositoryError::Stale)));
+            let found = repo.find(post.id).unwrap().unwrap();
+            assert_eq!(found.version, 2);
+            assert_eq!(found.content, "content");
+            assert_eq!(repo.list_revisions(post.id, 10, 0).unwrap().len(), 1);
+        }
+
+        #[test]
+        fn test_edit_records_revisions() {
+            let db = Database::open_in_memory().unwrap();
+            let users = SqliteUserRepository::new(db.clone());
+            users.create(new_user("a@example.com")).unwrap();
+            let moderator = users.create(new_user("b@example.com")).unwrap();
+            let board_id = general_board(&db);
+            let repo = SqlitePostRepository::new(db);
+            let mut post = Post::new(
+                1,
+                board_id,
+                Title::parse("title").unwrap(),
+                Content::parse("first").unwrap(),
+                None,
+            );
+            repo.insert(post.clone()).unwrap();
+            post.edit(
+                Title::parse("title").unwrap(),
+                Content::parse("second").unwrap(),
+            );
+            assert!(repo.edit(post.clone(), moderator.id).unwrap());
+            // 版を記録しない更新
+            post.moderate(None, Some(true));
+            assert!(repo.update(post.clone()).unwrap());
+
+            let revisions = repo.list_revisions(post.id, 10, 0).unwrap();
+
+            assert_eq!(
+                revisions
+                    .iter()
+                    .map(|r| (r.number, r.content.as_str(), r.edited_by))
+                    .collect::<Vec<_>>(),
+                [(1, "first", 1), (2, "second", moderator.id)]
+            );
+            assert_eq!(
+                repo.find_revision(post.id, 2).unwrap().unwrap().content,
+                "second"
+            );
+            assert!(repo.find_revision(post.id, 3).unwrap().is_none());
+            repo.delete(post.id).unwrap();
+            assert!(repo.list_revisions(post.id, 10, 0).unwrap().is_empty());
+        }
+
+        #[test]
+        fn test_reindex_stale_posts() {
+            let db = Database::open_in_memory().unwrap();
+            SqliteUserRepository::new(db.clone())
+                .create(new_user("a@example.com"))
+                .unwrap();
+            let board_id = general_board(&db);
+            let repo = SqlitePostRepository::new(db.clone());
+            let post = Post::new(
+                1,
+                board_id,
+                Title::parse("title").unwrap(),
+                Content::parse("content").unwrap(),
+                None,
+            );
+            repo.insert(post.clone()).unwrap();
+            {
+                let conn = db.lock().unwrap();
+                conn.execute_batch("DELETE FROM post_terms; UPDATE posts SET search_version = 0")
+                    .unwrap();
+            }
+            assert!(repo
+                .search(&["content".to_string()], 10)
+                .unwrap()
+                .is_empty());
+
+            reindex_posts(&mut db.lock().unwrap()).unwrap();
+
+            assert_eq!(repo.search(&["content".to_string()], 10).unwrap().len(), 1);
+        }
+
+        #[test]
+        fn test_persists_across_connections() {
+            let dir = std::env::temp_dir().join(format!("board-app-{}", Uuid::new_v4()));
+            std::fs::create_dir_all(&dir).unwrap();
+            let path = dir.join("board.db");
+            let mut post = Post {
+                id: Uuid::new_v4(),
+                user_id: 1,
+                board_id: Uuid::nil(),
+                title: Title::parse("title").unwrap(),
+                content: Content::parse("content").unwrap(),
+                locked: false,
+                pinned: false,
+                parent_id: None,
+                reply_count: 0,
+                created_at: Utc::now(),
+                updated_at: Utc::now(),
+                edited_count: 0,
+                last_activity_at: Utc::now(),
+                version: 1,
+                deleted_at: None,
+                deleted_by: None,
+                status: PostStatus::Published,
+                publish_at: None,
+            };
+            {
+                let db = Database::open(&path).unwrap();
+                SqliteUserRepository::new(db.clone())
+                    .create(new_user("a@example.com"))
+                    .unwrap();
+                post.board_id = general_board(&db);
+                SqlitePostRepository::new(db).insert(post.clone()).unwrap();
+            }
+
+            let repo = SqlitePostRepository::new(Database::open(&path).unwrap());
+
+            assert_eq!(repo.find(post.id).unwrap().unwrap().title, "title");
+            std::fs::remove_dir_all(dir).unwrap();
+        }
+
+        #[test]
+        fn test_replies() {
+            let db = Database::open_in_memory().unwrap();
+            SqliteUserRepository::new(db.clone())
+                .create(new_user("a@example.com"))
+                .unwrap();
+            let board_id = general_board(&db);
+            let repo = SqlitePostRepository::new(db);
+            let root = Post {
+                id: Uuid::new_v4(),
+                user_id: 1,
+                board_id,
+                title: Title::parse("title").unwrap(),
+                content: Content::parse("content").unwrap(),
+                locked: false,
+                pinned: false,
+                parent_id: None,
+                reply_count: 0,
+                created_at: Utc::now(),
+                updated_at: Utc::now(),
+                edited_count: 0,
+                last_activity_at: Utc::now(),
+                version: 1,
+                deleted_at: None,
+                deleted_by: None,
+                status: PostStatus::Published,
+                publish_at: None,
+            };
+            let replies: Vec<_> = (0..3)
+                .map(|_| Post {
+                    id: Uuid::new_v4(),
+                    parent_id: Some(root.id),
+                    ..root.clone()
+                })
+                .collect();
+            let nested = Post {
+                id: Uuid::new_v4(),
+                parent_id: Some(replies[0].id),
+                last_activity_at: root.last_activity_at + chrono::Duration::minutes(1),
+                ..root.clone()
+            };
+            repo.insert(root.clone()).unwrap();
+            for reply in &replies {
+                repo.insert(reply.clone()).unwrap();
+            }
+            repo.insert(nested.clone()).unwrap();
+
+            let roots = repo.list(&PostQuery::default()).unwrap();
+            let page = repo.list_replies(root.id, 2, 1).unwrap();
+
+            assert_eq!(roots.len(), 1);
+            assert_eq!(roots[0].reply_count, 3);
+            assert_eq!(roots[0].last_activity_at, nested.last_activity_at);
+            assert_eq!(
+                page.iter().map(|post| post.id).collect::<Vec<_>>(),
+                vec![replies[1].id, replies[2].id]
+            );
+            assert_eq!(repo.find(replies[0].id).unwrap().unwrap().reply_count, 1);
+
+            assert!(repo.delete(root.id).unwrap());
+            assert!(repo.find(nested.id).unwrap().is_none());
+        }
+
+        #[test]
+        fn test_trash_restore_and_purge() {
+            let db = Database::open_in_memory().unwrap();
+            SqliteUserRepository::new(db.clone())
+                .create(new_user("a@example.com"))
+                .unwrap();
+            let board_id = general_board(&db);
+            let repo = SqlitePostRepository::new(db);
+            let terms = SearchQuery::parse("title").unwrap().terms().to_vec();
+            let root = Post::new(
+                1,
+                board_id,
+                Title::parse("title").unwrap(),
+                Content::parse("content").unwrap(),
+                None,
+            );
+            let reply = Post::new(
+                1,
+                board_id,
+                Title::parse("title").unwrap(),
+                Content::parse("reply").unwrap(),
+                Some(root.id),
+            );
+            repo.insert(root.clone()).unwrap();
+            repo.insert(reply.clone()).unwrap();
+
+            let mut trashed = root.clone();
+            trashed.trash(1);
+            assert!(repo.trash(trashed.clone()).unwrap());
+            assert!(repo.find(root.id).unwrap().is_none());
+            assert!(repo.find(reply.id).unwrap().is_none());
+            assert!(repo.list(&PostQuery::default()).unwrap().is_empty());
+            assert!(repo.search(&terms, 10).unwrap().is_empty());
+            assert!(repo.find_in_trash(reply.id).unwrap().is_none());
+            let found = repo.find_in_trash(root.id).unwrap().unwrap();
+            assert_eq!(found.deleted_by, Some(1));
+            assert_eq!(repo.list_trash(1, 10, 0).unwrap().len(), 1);
+
+            trashed.restore();
+            assert!(repo.restore(trashed.clone()).unwrap());
+            assert_eq!(repo.find(root.id).unwrap().unwrap().reply_count, 1);
+            assert_eq!(repo.find(reply.id).unwrap().unwrap().version, 3);
+            assert_eq!(repo.search(&terms, 10).unwrap().len(), 2);
+
+            trashed.trash(1);
+            repo.trash(trashed.clone()).unwrap();
+            let deleted_at = trashed.deleted_at.unwrap();
+            assert_eq!(repo.purge_trash(deleted_at).unwrap(), 0);
+            assert_eq!(
+                repo.purge_trash(deleted_at + chrono::Duration::seconds(1))
+                    .unwrap(),
+                2
+            );
+            assert!(repo.find_in_trash(root.id).unwrap().is_none());
+            assert!(repo.list_trash(1, 10, 0).unwrap().is_empty());
+        }
+
+        #[test]
+        fn test_drafts_and_scheduled_publishing() {
+            let db = Database::open_in_memory().unwrap();
+            SqliteUserRepository::new(db.clone())
+                .create(new_user("a@example.com"))
+                .unwrap();
+            let board_id = general_board(&db);
+            let repo = SqlitePostRepository::new(db);
+            let terms = SearchQuery::parse("title").unwrap().terms().to_vec();
+            let now = Utc::now();
+            let new_post = |publish_at| {
+                Post::unpublished(
+                    1,
+                    board_id,
+                    Title::parse("title").unwrap(),
+                    Content::parse("content").unwrap(),
+                    publish_at,
+                )
+            };
+            let draft = new_post(None);
+            let due = new_post(Some(now - chrono::Duration::minutes(1)));
+            let later = new_post(Some(now + chrono::Duration::minutes(1)));
+            for post in [&draft, &due, &later] {
+                repo.insert(post.clone()).unwrap();
+            }
+
+            assert!(repo.list(&PostQuery::default()).unwrap().is_empty());
+            assert!(repo.search(&terms, 10).unwrap().is_empty());
+            let drafts = repo.list_drafts(1, 10, 0).unwrap();
+            assert_eq!(drafts.len(), 3);
+            assert_eq!(
+                repo.find(later.id).unwrap().unwrap().status,
+                PostStatus::Scheduled
+            );
+
+            let published = repo.publish_due(now).unwrap();
+            assert_eq!(
+                published.iter().map(|post| post.id).collect::<Vec<_>>(),
+                [due.id]
+            );
+            let found = repo.find(due.id).unwrap().unwrap();
+            assert!(found.is_published());
+            assert_eq!(found.publish_at, None);
+            assert_eq!(found.created_at, due.publish_at.unwrap());
+            assert_eq!(found.version, 2);
+            assert_eq!(repo.list(&PostQuery::default()).unwrap().len(), 1);
+            assert_eq!(repo.search(&terms, 10).unwrap().len(), 1);
+            assert_eq!(repo.list_drafts(1, 10, 0).unwrap().len(), 2);
+            assert!(repo.publish_due(now).unwrap().is_empty());
+        }
+
+        #[test]
+        fn test_list_pages_with_cursor() {
+            let db = Database::open_in_memory().unwrap();
+            SqliteUserRepository::new(db.clone())
+                .create(new_user("a@example.com"))
+                .unwrap();
+            let board_id = general_board(&db);
+            let repo = SqlitePostRepository::new(db);
+            let base = Utc::now();
+            let posts: Vec<_> = (0..4)
+                .map(|i| Post {
+                    id: Uuid::new_v4(),
+                    user_id: 1,
+                    board_id,
+                    title: Title::parse("title").unwrap(),
+                    content: Content::parse("content").unwrap(),
+                    locked: false,
+                    pinned: i == 0,
+                    parent_id: None,
+                    reply_count: 0,
+                    created_at: base + chrono::Duration::seconds(i),
+                    updated_at: base,
+                    edited_count: 0,
+                    last_activity_at: base,
+                    version: 1,
+                    deleted_at: None,
+                    deleted_by: None,
+                    status: PostStatus::Published,
+                    publish_at: None,
+                })
+                .collect();
+            for post in &posts {
+                repo.insert(post.clone()).unwrap();
+            }
+            repo.insert(Post {
+                id: Uuid::new_v4(),
+                parent_id: Some(posts[1].id),
+                ..posts[3].clone()
+            })
+            .unwrap();
+            let ids = |query: &PostQuery| {
+                repo.list(query)
+                    .unwrap()
+                    .iter()
+                    .map(|post| post.id)
+                    .collect::<Vec<_>>()
+            };
+            let query = PostQuery {
+                sort: PostSort::MostReplied,
+                limit: 2,
+                ..PostQuery::default()
+            };
+
+            let first = repo.list(&query).unwrap();
+            let rest = PostQuery {
+                after: Some(PostCursor::of(&first[1])),
+                ..query.clone()
+            };
+
+            assert_eq!(
+                first.iter().map(|post| post.id).collect::<Vec<_>>(),
+                vec![posts[0].id, posts[1].id]
+            );
+            assert_eq!(ids(&rest), vec![posts[3].id, posts[2].id]);
+            assert_eq!(
+                ids(&PostQuery {
+                    sort: PostSort::Oldest,
+                    until: Some(posts[3].created_at),
+                    after: Some(PostCursor::of(&posts[0])),
+                    ..PostQuery::default()
+                }),
+                vec![posts[1].id, posts[2].id]
+            );
+        }
+    }
+    mod board_repository {
+        use super::*;
+
+        fn board(slug: &str) -> Board {
+            Board::new(
+                BoardSlug::parse(slug).unwrap(),
+                BoardName::parse("name").unwrap(),
+                Some(BoardDescription::parse("description").unwrap()),
+                BoardSettings::default(),
+            )
+        }
+
+        #[test]
+        fn test_crud() {
+            let repo = SqliteBoardRepository::new(Database::open_in_memory().unwrap());
+            let mut news = board("news");
+            repo.insert(news.clone()).unwrap();
+
+            news.update(
+                BoardSlug::parse("announcements").unwrap(),
+                BoardName::parse("Announcements").unwrap(),
+                None,
+                BoardSettings {
+                    posting_role: Role::Admin,
+                    allow_replies: false,
+                    max_post_length: 100,
+                },
+            );
+            assert!(repo.update(news.clone()).unwrap());
+
+            let slugs: Vec<_> = repo
+                .list()
+                .unwrap()
+                .into_iter()
+                .map(|b| b.slug.into_string())
+                .collect();
+            assert_eq!(slugs, vec!["announcements", "general"]);
+            assert_eq!(
+                repo.find_by_slug("general").unwrap().unwrap().id,
+                GENERAL_BOARD_ID
+            );
+            let found = repo.find(news.id).unwrap().unwrap();
+            assert_eq!(found.name, "Announcements");
+            assert!(found.description.is_none());
+            assert_eq!(found.settings, news.settings);
+            assert!(repo.find_by_slug("news").unwrap().is_none());
+            assert!(repo.delete(news.id).unwrap());
+            assert!(!repo.delete(news.id).unwrap());
+            assert!(!repo.update(news).unwrap());
+        }
+
+        #[test]
+        fn test_duplicate_slug() {
+            let repo = SqliteBoardRepository::new(Database::open_in_memory().unwrap());
+            let mut rust = board("rust");
+            repo.insert(rust.clone()).unwrap();
+
+            rust.slug = BoardSlug::parse("general").unwrap();
+
+            assert!(matches!(
+                repo.insert(board("general")),
+                Err(RepositoryError::Conflict)
+            ));
+            assert!(matches!(repo.update(rust), Err(RepositoryError::Conflict)));
+        }
+
+        #[test]
+        fn test_board_with_posts_is_not_deleted() {
+            let db = Database::open_in_memory().unwrap();
+            SqliteUserRepository::new(db.clone())
+                .create(new_user("a@example.com"))
+                .unwrap();
+            let repo = SqliteBoardRepository::new(db.clone());
+            let posts = SqlitePostRepository::new(db);
+            let rust = board("rust");
+            repo.insert(rust.clone()).unwrap();
+            let mut post = Post::new(
+                1,
+                rust.id,
+                Title::parse("title").unwrap(),
+                Content::parse("content").unwrap(),
+                None,
+            );
+            posts.insert(post.clone()).unwrap();
+            post.trash(1);
+            assert!(posts.has_posts_in(rust.id).unwrap());
+
+            posts.trash(post.clone()).unwrap();
+
+            assert!(!posts.has_posts_in(rust.id).unwrap());
+            assert!(matches!(
+                repo.delete(rust.id),
+                Err(RepositoryError::Conflict)
+            ));
+            assert_eq!(posts.purge_trash_in(rust.id).unwrap(), 1);
+            assert!(repo.delete(rust.id).unwrap());
+        }
+    }
+    mod refresh_token_repository {
+        use super::*;
+        use chrono::{Duration, Utc};
+
+        #[test]
+        fn test_consume_and_revoke_family() {
+            let db = Database::open_in_memory().unwrap();
+            let user = SqliteUserRepository::new(db.clone())
+                .create(new_user("a@example.com"))
+                .unwrap();
+            let repo = SqliteRefreshTokenRepository::new(db);
+            let family_id = Uuid::new_v4();
+            repo.insert(RefreshToken {
+                token_hash: "hash".to_string(),
+                family_id,
+                user_id: user.id,
+                expires_at: Utc::now() + Duration::days(1),
+                used: false,
+                revoked: false,
+            })
+            .unwrap();
+
+            let first = repo.consume("hash").unwrap().unwrap();
+            let second = repo.consume("hash").unwrap().unwrap();
+            repo.revoke_family(family_id).unwrap();
+            let third = repo.consume("hash").unwrap().unwrap();
+
+            assert!(!first.used);
+            assert_eq!(first.family_id, family_id);
+            assert!(second.used);
+            assert!(third.revoked);
+            assert!(repo.consume("missing").unwrap().is_none());
+        }
+    }
+    mod revocation_repository {
+        use super::*;
+        use chrono::Duration;
+
+        #[test]
+        fn test_revoke_and_purge() {
+            let repo = SqliteRevocationRepository::new(Database::open_in_memory().unwrap());
+            repo.revoke("jti", Utc::now() + Duration::minutes(5))
+                .unwrap();
+
+            assert!(repo.is_revoked("jti").unwrap());
+            assert!(!repo.is_revoked("other").unwrap());
+            assert_eq!(
+                repo.purge_expired(Utc::now() + Duration::minutes(10))
+                    .unwrap(),
+                1
+            );
+            assert!(!repo.is_revoked("jti").unwrap());
+        }
+
+        #[test]
+        fn test_user_revoked_before() {
+            let db = Database::open_in_memory().unwrap();
+            let user = SqliteUserRepository::new(db.clone())
+                .create(new_user("a@example.com"))
+                .unwrap();
+            let repo = SqliteRevocationRepository::new(db);
+            let before = Utc::now();
+
+            assert!(repo.user_revoked_before(user.id).unwrap().is_none());
+            repo.revoke_user(user.id, before).unwrap();
+
+            assert_eq!(repo.user_revoked_before(user.id).unwrap(), Some(before));
+        }
+    }
+}
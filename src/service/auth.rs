@@ -0,0 +1,229 @@
+use crate::repository::{RepositoryError, RevocationRepository};
+use crate::service::jwt::KeyRing;
+use crate::value_object::{Role, UserId};
+use chrono::{DateTime, Utc};
+use thiserror::Error;
+
+const REALM: &str = "board";
+
+/// 検証済みのアクセストークンから得た利用者
+#[derive(Debug, Clone, PartialEq)]
+pub struct AuthenticatedUser {
+    pub id: UserId,
+    pub role: Role,
+    pub jti: String,
+    pub expires_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Error)]
+pub enum AuthError {
+    #[error("Authorization header is missing")]
+    Missing,
+    #[error("Authorization header is malformed")]
+    Malformed,
+    #[error("Access token is invalid")]
+    InvalidToken,
+    #[error("Access token has been revoked")]
+    Revoked,
+    #[error(transparent)]
+    Repository(#[from] RepositoryError),
+}
+
+impl AuthError {
+    /// 401応答に付与する`WWW-Authenticate`ヘッダーの値 (RFC 6750 Section 3)
+    pub fn challenge(&self) -> String {
+        let error = match self {
+            // 認証情報が含まれない場合はエラーコードを返さない
+            AuthError::Missing => return bearer_challenge(),
+            AuthError::Malformed => "invalid_request",
+            _ => "invalid_token",
+        };
+        format!(
+            "Bearer realm=\"{}\", error=\"{}\", error_description=\"{}\"",
+            REALM, error, self
+        )
+    }
+}
+
+/// エラーコードを含まないチャレンジ
+pub fn bearer_challenge() -> String {
+    format!("Bearer realm=\"{}\"", REALM)
+}
+
+/// `Authorization`ヘッダーからBearerトークンを取り出す (RFC 6750 Section 2.1)
+///
+/// スキーム名は大文字小文字を区別しない。Bearer以外のスキームは認証情報なしとして扱う
+pub fn parse_bearer(authorization: Option<&str>) -> Result<&str, AuthError> {
+    let value = authorization.ok_or(AuthError::Missing)?.trim();
+    let (scheme, token) = value.split_once(' ').unwrap_or((value, ""));
+    if !scheme.eq_ignore_ascii_case("Bearer") {
+        return Err(AuthError::Missing);
+    }
+    let token = token.trim_start_matches(' ');
+    if is_token68(token) {
+        Ok(token)
+    } else {
+        Err(AuthError::Malformed)
+    }
+}
+
+/// token68 = 1*( ALPHA / DIGIT / "-" / "." / "_" / "~" / "+" / "/" ) *"="
+fn is_token68(token: &str) -> bool {
+    let body = token.trim_end_matches('=');
+    !body.is_empty()
+        && body
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '.' | '_' | '~' | '+' | '/'))
+}
+
+/// `Authorization`ヘッダーを検証し、失効していないトークンの利用者を返す
+pub fn authenticate(
+    keys: &KeyRing,
+    revocations: &dyn RevocationRepository,
+    authorization: Option<&str>,
+) -> Result<AuthenticatedUser, AuthError> {
+    let token = parse_bearer(authorization)?;
+    let claims = keys
+        .validate_token(token)
+        .map_err(|_e| AuthError::InvalidToken)?;
+    let id: UserId = claims.uid.parse().map_err(|_e| AuthError::InvalidToken)?;
+    let expires_at =
+        DateTime::from_timestamp(claims.exp as i64, 0).ok_or(AuthError::InvalidToken)?;
+
+    if revocations.is_revoked(&claims.jti)? {
+        return Err(AuthError::Revoked);
+    }
+    if revocations
+        .user_revoked_before(id)?
+        .is_some_and(|before| claims.issued_at_micros() <= before.timestamp_micros())
+    {
+        return Err(AuthError::Revoked);
+    }
+
+    Ok(AuthenticatedUser {
+        id,
+        role: claims.role,
+        jti: claims.jti,
+        expires_at,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repository::memory::InMemoryRevocationRepository;
+    use std::time::Duration;
+
+    const SECRET: &[u8] = b"0123456789abcdef0123456789abcdef";
+
+    fn bearer(uid: &str) -> String {
+        let token = KeyRing::from_secret(SECRET)
+            .create_token(uid, Role::Member, Duration::from_secs(60))
+            .unwrap();
+        format!("Bearer {}", token)
+    }
+
+    mod parse_bearer {
+        use super::*;
+
+        #[test]
+        fn test_scheme_is_case_insensitive() {
+            assert_eq!(parse_bearer(Some("bearer abc.def")).unwrap(), "abc.def");
+            assert_eq!(parse_bearer(Some("BEARER  abc==")).unwrap(), "abc==");
+        }
+
+        #[test]
+        fn test_missing_or_other_scheme() {
+            assert!(matches!(parse_bearer(None), Err(AuthError::Missing)));
+            assert!(matches!(
+                parse_bearer(Some("Basic dXNlcjpwYXNz")),
+                Err(AuthError::Missing)
+            ));
+        }
+
+        #[test]
+        fn test_malformed_token() {
+            for value in [
+                "Bearer",
+                "Bearer ",
+                "Bearer a b",
+                "Bearer a\"b",
+                "Bearer ==",
+            ] {
+                assert!(
+                    matches!(parse_bearer(Some(value)), Err(AuthError::Malformed)),
+                    "{}",
+                    value
+                );
+            }
+        }
+    }
+
+    mod authenticate {
+        use super::*;
+
+        #[test]
+        fn test_authenticate() {
+            let repo = InMemoryRevocationRepository::new();
+
+            let user =
+                authenticate(&KeyRing::from_secret(SECRET), &repo, Some(&bearer("42"))).unwrap();
+
+            assert_eq!(user.id, 42);
+            assert!(user.expires_at > Utc::now());
+        }
+
+        #[test]
+        fn test_non_numeric_uid_is_invalid() {
+            let repo = InMemoryRevocationRepository::new();
+
+            let result = authenticate(
+                &KeyRing::from_secret(SECRET),
+                &repo,
+                Some(&bearer("not-a-number")),
+            );
+
+            assert!(matches!(result, Err(AuthError::InvalidToken)));
+        }
+
+        #[test]
+        fn test_revoked_token() {
+            let repo = InMemoryRevocationRepository::new();
+            let authorization = bearer("42");
+            let user =
+                authenticate(&KeyRing::from_secret(SECRET), &repo, Some(&authorization)).unwrap();
+            repo.revoke(&user.jti, user.expires_at).unwrap();
+
+            let result = authenticate(&KeyRing::from_secret(SECRET), &repo, Some(&authorization));
+
+            assert!(matches!(result, Err(AuthError::Revoked)));
+        }
+
+        #[test]
+        fn test_user_revocation_is_sub_second() {
+            let repo = InMemoryRevocationRepository::new();
+            let issued_before = bearer("42");
+            repo.revoke_user(42, Utc::now()).unwrap();
+            let issued_after = bearer("42");
+
+            let before = authenticate(&KeyRing::from_secret(SECRET), &repo, Some(&issued_before));
+            let after = authenticate(&KeyRing::from_secret(SECRET), &repo, Some(&issued_after));
+
+            assert!(matches!(before, Err(AuthError::Revoked)));
+            assert_eq!(after.unwrap().id, 42);
+        }
+    }
+
+    mod challenge {
+        use super::*;
+
+        #[test]
+        fn test_challenge() {
+            assert_eq!(AuthError::Missing.challenge(), "Bearer realm=\"board\"");
+            assert_eq!(
+                AuthError::InvalidToken.challenge(),
+                "Bearer realm=\"board\", error=\"invalid_token\", error_description=\"Access token is invalid\""
+            );
+        }
+    }
+}
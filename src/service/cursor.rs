@@ -0,0 +1,120 @@
+use crate::repository::{PostCursor, PostSort};
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use chrono::{DateTime, SecondsFormat, Utc};
+use thiserror::Error;
+use uuid::Uuid;
+
+#[derive(Debug, Error)]
+pub enum CursorError {
+    #[error("Cursor is malformed")]
+    Malformed,
+    #[error("Cursor was issued for a different sort order")]
+    SortMismatch,
+}
+
+fn sort_name(sort: PostSort) -> &'static str {
+    match sort {
+        PostSort::Newest => "newest",
+        PostSort::Oldest => "oldest",
+        PostSort::MostReplied => "most-replied",
+    }
+}
+
+/// 並び順と位置をクライアントから見て不透明な文字列にする
+///
+/// 内容に依存されないよう、区切り文字で連結した値をbase64urlで包む
+pub fn encode(sort: PostSort, cursor: &PostCursor) -> String {
+    let raw = format!(
+        "{}|{}|{}|{}|{}",
+        sort_name(sort),
+        u8::from(cursor.pinned),
+        cursor.reply_count,
+        cursor
+            .created_at
+            .to_rfc3339_opts(SecondsFormat::AutoSi, true),
+        cursor.id
+    );
+    URL_SAFE_NO_PAD.encode(raw)
+}
+
+/// `encode`で作ったカーソルを戻す。別の並び順で発行したカーソルは受け付けない
+pub fn decode(sort: PostSort, cursor: &str) -> Result<PostCursor, CursorError> {
+    let raw = URL_SAFE_NO_PAD
+        .decode(cursor)
+        .ok()
+        .and_then(|bytes| String::from_utf8(bytes).ok())
+        .ok_or(CursorError::Malformed)?;
+    let [name, pinned, reply_count, created_at, id] = raw
+        .split('|')
+        .collect::<Vec<_>>()
+        .try_into()
+        .map_err(|_e| CursorError::Malformed)?;
+    if name != sort_name(sort) {
+        return Err(CursorError::SortMismatch);
+    }
+    let pinned = match pinned {
+        "0" => false,
+        "1" => true,
+        _ => return Err(CursorError::Malformed),
+    };
+    Ok(PostCursor {
+        pinned,
+        reply_count: reply_count.parse().map_err(|_e| CursorError::Malformed)?,
+        created_at: DateTime::parse_from_rfc3339(created_at)
+            .map_err(|_e| CursorError::Malformed)?
+            .with_timezone(&Utc),
+        id: Uuid::parse_str(id).map_err(|_e| CursorError::Malformed)?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cursor() -> PostCursor {
+        PostCursor {
+            pinned: true,
+            reply_count: 3,
+            created_at: Utc::now(),
+            id: Uuid::new_v4(),
+        }
+    }
+
+    mod decode {
+        use super::*;
+
+        #[test]
+        fn test_round_trip() {
+            let cursor = cursor();
+
+            let decoded = decode(
+                PostSort::MostReplied,
+                &encode(PostSort::MostReplied, &cursor),
+            );
+
+            assert_eq!(decoded.unwrap(), cursor);
+        }
+
+        #[test]
+        fn test_other_sort() {
+            let encoded = encode(PostSort::Newest, &cursor());
+
+            assert!(matches!(
+                decode(PostSort::Oldest, &encoded),
+                Err(CursorError::SortMismatch)
+            ));
+        }
+
+        #[test]
+        fn test_malformed() {
+            for value in ["", "not base64!", &URL_SAFE_NO_PAD.encode("newest|1|2")] {
+                assert!(
+                    matches!(decode(PostSort::Newest, value), Err(CursorError::Malformed)),
+                    "{}",
+                    value
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,172 @@
+/// 編集距離の上限。超える場合は最短の差分を求めず、すべての行を置き換えた差分にする
+const MAX_EDIT_DISTANCE: usize = 1000;
+
+/// 差分の行の種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiffOp {
+    Equal,
+    Insert,
+    Delete,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiffLine {
+    pub op: DiffOp,
+    pub text: String,
+}
+
+/// `old`から`new`への行単位の差分。行は改行を含まない
+pub fn diff_lines(old: &str, new: &str) -> Vec<DiffLine> {
+    let old: Vec<&str> = old.lines().collect();
+    let new: Vec<&str> = new.lines().collect();
+    let line = |op: DiffOp, text: &str| DiffLine {
+        op,
+        text: text.to_string(),
+    };
+    match shortest_edit(&old, &new) {
+        Some(edits) => edits
+            .into_iter()
+            .map(|edit| match edit {
+                Edit::Equal(i) => line(DiffOp::Equal, old[i]),
+                Edit::Delete(i) => line(DiffOp::Delete, old[i]),
+                Edit::Insert(j) => line(DiffOp::Insert, new[j]),
+            })
+            .collect(),
+        None => old
+            .iter()
+            .map(|text| line(DiffOp::Delete, text))
+            .chain(new.iter().map(|text| line(DiffOp::Insert, text)))
+            .collect(),
+    }
+}
+
+/// 差分の1行。値は`a`または`b`での位置
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Edit {
+    Equal(usize),
+    Delete(usize),
+    Insert(usize),
+}
+
+/// Myersのアルゴリズムで`a`を`b`にする最短の編集を求める。編集距離が上限を超える場合は`None`
+///
+/// 対角線`k = x - y`ごとに到達できる最も遠い`x`を編集距離`d`の順に広げ、
+/// 各`d`での状態を記録しておき、終点から逆にたどって編集を復元する
+fn shortest_edit<T: PartialEq>(a: &[T], b: &[T]) -> Option<Vec<Edit>> {
+    let (n, m) = (a.len() as isize, b.len() as isize);
+    let max = (a.len() + b.len()).min(MAX_EDIT_DISTANCE) as isize;
+    // `v[k + offset]`は対角線`k`で到達した最も遠い`x`
+    let offset = max + 1;
+    let mut v = vec![0_isize; 2 * offset as usize + 1];
+    let mut trace: Vec<Vec<isize>> = Vec::new();
+    let at = |k: isize| (k + offset) as usize;
+    let mut found = false;
+    'search: for d in 0..=max {
+        trace.push(v.clone());
+        for k in (-d..=d).step_by(2) {
+            let mut x = if k == -d || (k != d && v[at(k - 1)] < v[at(k + 1)]) {
+                v[at(k + 1)]
+            } else {
+                v[at(k - 1)] + 1
+            };
+            let mut y = x - k;
+            while x < n && y < m && a[x as usize] == b[y as usize] {
+                x += 1;
+                y += 1;
+            }
+            v[at(k)] = x;
+            if x >= n && y >= m {
+                found = true;
+                break 'search;
+            }
+        }
+    }
+    if !found {
+        return None;
+    }
+
+    let mut edits = Vec::new();
+    let (mut x, mut y) = (n, m);
+    for (d, v) in trace.iter().enumerate().rev() {
+        let d = d as isize;
+        let k = x - y;
+        let prev_k = if k == -d || (k != d && v[at(k - 1)] < v[at(k + 1)]) {
+            k + 1
+        } else {
+            k - 1
+        };
+        let prev_x = v[at(prev_k)];
+        let prev_y = prev_x - prev_k;
+        while x > prev_x && y > prev_y {
+            x -= 1;
+            y -= 1;
+            edits.push(Edit::Equal(x as usize));
+        }
+        if d > 0 {
+            if x == prev_x {
+                edits.push(Edit::Insert(prev_y as usize));
+            } else {
+                edits.push(Edit::Delete(prev_x as usize));
+            }
+        }
+        (x, y) = (prev_x, prev_y);
+    }
+    edits.reverse();
+    Some(edits)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn render(lines: &[DiffLine]) -> Vec<String> {
+        lines
+            .iter()
+            .map(|line| {
+                let sign = match line.op {
+                    DiffOp::Equal => ' ',
+                    DiffOp::Insert => '+',
+                    DiffOp::Delete => '-',
+                };
+                format!("{}{}", sign, line.text)
+            })
+            .collect()
+    }
+
+    mod diff_lines {
+        use super::*;
+
+        #[test]
+        fn test_changed_lines() {
+            let diff = diff_lines("a\nb\nc\nd", "a\nc\nx\nd\ne");
+
+            assert_eq!(render(&diff), [" a", "-b", " c", "+x", " d", "+e"]);
+        }
+
+        #[test]
+        fn test_empty_sides() {
+            assert_eq!(render(&diff_lines("", "a\nb")), ["+a", "+b"]);
+            assert_eq!(render(&diff_lines("a\nb", "")), ["-a", "-b"]);
+            assert!(diff_lines("", "").is_empty());
+        }
+
+        #[test]
+        fn test_identical() {
+            let diff = diff_lines("同じ\n行", "同じ\n行");
+
+            assert_eq!(render(&diff), [" 同じ", " 行"]);
+        }
+
+        #[test]
+        fn test_falls_back_beyond_max_distance() {
+            let old: Vec<String> = (0..MAX_EDIT_DISTANCE).map(|i| format!("a{}", i)).collect();
+            let new: Vec<String> = (0..MAX_EDIT_DISTANCE).map(|i| format!("b{}", i)).collect();
+
+            let diff = diff_lines(&old.join("\n"), &new.join("\n"));
+
+            assert_eq!(diff.len(), MAX_EDIT_DISTANCE * 2);
+            assert_eq!(diff[0].op, DiffOp::Delete);
+            assert_eq!(diff[MAX_EDIT_DISTANCE].op, DiffOp::Insert);
+        }
+    }
+}
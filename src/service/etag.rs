@@ -0,0 +1,58 @@
+/// 投稿のバージョンを表す強いエンティティタグ
+pub fn entity_tag(version: u32) -> String {
+    format!("\"{}\"", version)
+}
+
+/// `If-Match`がバージョンに一致するか判定する。RFC 9110に従い弱いタグは一致しない
+pub fn if_match(header: &str, version: u32) -> bool {
+    let current = entity_tag(version);
+    tags(header).any(|tag| tag == "*" || tag == current)
+}
+
+/// `If-None-Match`がバージョンに一致するか判定する。一致する場合は変更されていない
+///
+/// 弱い比較を行うため、`W/`の有無は区別しない
+pub fn if_none_match(header: &str, version: u32) -> bool {
+    let current = entity_tag(version);
+    tags(header).any(|tag| tag == "*" || tag.strip_prefix("W/").unwrap_or(tag) == current)
+}
+
+fn tags(header: &str) -> impl Iterator<Item = &str> {
+    header
+        .split(',')
+        .map(str::trim)
+        .filter(|tag| !tag.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod if_match {
+        use super::*;
+
+        #[test]
+        fn test_strong_comparison() {
+            assert!(if_match("\"3\"", 3));
+            assert!(if_match("\"1\", \"3\"", 3));
+            assert!(if_match("*", 3));
+            assert!(!if_match("\"2\"", 3));
+            assert!(!if_match("W/\"3\"", 3));
+            assert!(!if_match("3", 3));
+        }
+    }
+
+    mod if_none_match {
+        use super::*;
+
+        #[test]
+        fn test_weak_comparison() {
+            assert!(if_none_match("\"3\"", 3));
+            assert!(if_none_match("W/\"3\"", 3));
+            assert!(if_none_match("\"1\",W/\"3\"", 3));
+            assert!(if_none_match("*", 3));
+            assert!(!if_none_match("\"2\"", 3));
+            assert!(!if_none_match("", 3));
+        }
+    }
+}
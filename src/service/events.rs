@@ -0,0 +1,335 @@
+use crate::entity::Post;
+use crate::value_object::{PostId, UserId};
+use chrono::{DateTime, Utc};
+use std::collections::VecDeque;
+use std::fmt;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use tokio::sync::broadcast::{self, error::RecvError};
+
+/// 配信を待てる入力中の通知の数。遅れた購読者は古い通知を受け取らない
+const TYPING_CAPACITY: usize = 256;
+
+/// 配信するイベントの種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EventKind {
+    PostCreated,
+    PostUpdated,
+    PostDeleted,
+    ReplyCreated,
+}
+
+impl fmt::Display for EventKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            EventKind::PostCreated => "post_created",
+            EventKind::PostUpdated => "post_updated",
+            EventKind::PostDeleted => "post_deleted",
+            EventKind::ReplyCreated => "reply_created",
+        };
+        f.write_str(name)
+    }
+}
+
+/// 投稿に関するイベント
+#[derive(Debug, Clone)]
+pub struct BoardEvent {
+    /// 発生した順に増えるID
+    pub id: u64,
+    pub kind: EventKind,
+    /// 投稿が属するスレッドの先頭の投稿のID
+    pub thread_id: PostId,
+    /// イベントの時点の投稿。削除の場合は削除する前の内容
+    pub post: Post,
+    pub author_name: Option<String>,
+    pub occurred_at: DateTime<Utc>,
+}
+
+/// 入力中であることの通知。保持せず、その時点の購読者にのみ届ける
+#[derive(Debug, Clone, PartialEq)]
+pub struct Typing {
+    pub thread_id: PostId,
+    pub user_id: UserId,
+    pub user_name: Option<String>,
+}
+
+/// 購読するイベントの条件。指定しない条件はすべてに一致する
+#[derive(Debug, Clone, Copy, Default)]
+pub struct EventFilter {
+    pub thread_id: Option<PostId>,
+    /// 投稿を作成したユーザー
+    pub user_id: Option<UserId>,
+}
+
+impl EventFilter {
+    pub fn matches(&self, event: &BoardEvent) -> bool {
+        self.thread_id.is_none_or(|id| id == event.thread_id)
+            && self.user_id.is_none_or(|id| id == event.post.user_id)
+    }
+}
+
+#[derive(Debug)]
+struct Replay {
+    next_id: u64,
+    capacity: usize,
+    events: VecDeque<Arc<BoardEvent>>,
+}
+
+impl Replay {
+    /// 保持しているイベントのうち`last_id`より後のもの
+    fn since(&self, last_id: u64) -> VecDeque<Arc<BoardEvent>> {
+        self.events
+            .iter()
+            .filter(|event| event.id > last_id)
+            .cloned()
+            .collect()
+    }
+}
+
+/// 投稿のイベントを購読者に配信し、再送のために直近のイベントを保持する
+///
+/// イベントはプロセス内にのみ保持する。IDは起動時刻から始めるため、再起動をまたいでも減らない
+#[derive(Debug)]
+pub struct EventBus {
+    sender: broadcast::Sender<Arc<BoardEvent>>,
+    replay: Mutex<Replay>,
+    typing: broadcast::Sender<Typing>,
+}
+
+impl EventBus {
+    /// `replay_size`は1以上
+    pub fn new(replay_size: usize) -> Self {
+        let (sender, _) = broadcast::channel(replay_size);
+        let (typing, _) = broadcast::channel(TYPING_CAPACITY);
+        Self {
+            sender,
+            typing,
+            replay: Mutex::new(Replay {
+                next_id: Utc::now().timestamp_micros().max(1) as u64,
+                capacity: replay_size,
+                events: VecDeque::with_capacity(replay_size),
+            }),
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Replay> {
+        // 保持しているイベントは追加の途中で失敗しても壊れない
+        self.replay.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// イベントを配信し、そのIDを返す
+    pub fn publish(
+        &self,
+        kind: EventKind,
+        thread_id: PostId,
+        post: Post,
+        author_name: Option<String>,
+    ) -> u64 {
+        // 購読の開始と配信が前後しないよう、ロックしたまま送る
+        let mut replay = self.lock();
+        let event = Arc::new(BoardEvent {
+            id: replay.next_id,
+            kind,
+            thread_id,
+            post,
+            author_name,
+            occurred_at: Utc::now(),
+        });
+        replay.next_id += 1;
+        if replay.events.len() == replay.capacity {
+            replay.events.pop_front();
+        }
+        replay.events.push_back(event.clone());
+        // 購読者がいない場合は送れないが、再送用には保持している
+        let _ = self.sender.send(event.clone());
+        event.id
+    }
+
+    /// 購読を開始する。`last_event_id`を指定した場合は、保持している範囲でそれより後のイベントから受け取る
+    pub fn subscribe(
+        self: &Arc<Self>,
+        last_event_id: Option<u64>,
+        filter: EventFilter,
+    ) -> Subscription {
+        let replay = self.lock();
+        let receiver = self.sender.subscribe();
+        let (pending, last_id) = match last_event_id {
+            // 未来のIDを指定されても以降のイベントを受け取れるようにする
+            Some(id) => {
+                let last_id = id.min(replay.next_id - 1);
+                (replay.since(last_id), last_id)
+            }
+            None => (VecDeque::new(), replay.next_id - 1),
+        };
+        Subscription {
+            bus: self.clone(),
+            receiver,
+            pending,
+            last_id,
+            filter,
+        }
+    }
+
+    /// 入力中の通知を配信する。購読者がいない場合は捨てる
+    pub fn typing(&self, typing: Typing) {
+        let _ = self.typing.send(typing);
+    }
+
+    /// 入力中の通知の購読を開始する
+    pub fn subscribe_typing(&self) -> broadcast::Receiver<Typing> {
+        self.typing.subscribe()
+    }
+}
+
+/// 1つの購読。条件に一致するイベントをIDの順に1度ずつ返す
+#[derive(Debug)]
+pub struct Subscription {
+    bus: Arc<EventBus>,
+    receiver: broadcast::Receiver<Arc<BoardEvent>>,
+    /// 受信より先に返す再送分
+    pending: VecDeque<Arc<BoardEvent>>,
+    /// 返したか読み飛ばした最後のイベントのID
+    last_id: u64,
+    filter: EventFilter,
+}
+
+impl Subscription {
+    /// 次のイベントを待つ
+    pub async fn next(&mut self) -> Option<Arc<BoardEvent>> {
+        loop {
+            let event = match self.pending.pop_front() {
+                Some(event) => event,
+                None => match self.receiver.recv().await {
+                    Ok(event) => event,
+                    Err(RecvError::Lagged(skipped)) => {
+                        // 読み出しが遅れて取りこぼした分は、保持しているイベントで補う
+                        tracing::debug!(skipped, "event subscriber lagged");
+                        self.pending = self.bus.lock().since(self.last_id);
+                        continue;
+                    }
+                    Err(RecvError::Closed) => return None,
+                },
+            };
+            if event.id <= self.last_id {
+                continue;
+            }
+            self.last_id = event.id;
+            if self.filter.matches(&event) {
+                return Some(event);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::value_object::{Content, Title};
+    use uuid::Uuid;
+
+    fn post(user_id: UserId, parent_id: Option<PostId>) -> Post {
+        Post::new(
+            user_id,
+            Uuid::nil(),
+            Title::parse("title").unwrap(),
+            Content::parse("content").unwrap(),
+            parent_id,
+        )
+    }
+
+    fn publish(bus: &EventBus, post: Post) -> u64 {
+        let thread_id = post.parent_id.unwrap_or(post.id);
+        bus.publish(EventKind::PostCreated, thread_id, post, None)
+    }
+
+    async fn next_id(subscription: &mut Subscription) -> Option<u64> {
+        let next = tokio::time::timeout(std::time::Duration::from_millis(100), subscription.next());
+        next.await.ok().flatten().map(|event| event.id)
+    }
+
+    mod subscribe {
+        use super::*;
+
+        #[tokio::test]
+        async fn test_receives_published_events() {
+            let bus = Arc::new(EventBus::new(10));
+            let before = publish(&bus, post(1, None));
+            let mut subscription = bus.subscribe(None, EventFilter::default());
+
+            let id = publish(&bus, post(1, None));
+
+            assert!(id > before);
+            assert_eq!(next_id(&mut subscription).await, Some(id));
+            assert_eq!(next_id(&mut subscription).await, None);
+        }
+
+        #[tokio::test]
+        async fn test_replays_after_last_event_id() {
+            let bus = Arc::new(EventBus::new(3));
+            let ids: Vec<u64> = (0..5).map(|_| publish(&bus, post(1, None))).collect();
+
+            let mut subscription = bus.subscribe(Some(ids[3]), EventFilter::default());
+            assert_eq!(next_id(&mut subscription).await, Some(ids[4]));
+
+            // 保持していない範囲は再送できない
+            let mut subscription = bus.subscribe(Some(ids[0]), EventFilter::default());
+            assert_eq!(next_id(&mut subscription).await, Some(ids[2]));
+            assert_eq!(next_id(&mut subscription).await, Some(ids[3]));
+            assert_eq!(next_id(&mut subscription).await, Some(ids[4]));
+            assert_eq!(next_id(&mut subscription).await, None);
+        }
+
+        #[tokio::test]
+        async fn test_future_last_event_id() {
+            let bus = Arc::new(EventBus::new(3));
+            let mut subscription = bus.subscribe(Some(u64::MAX), EventFilter::default());
+
+            let id = publish(&bus, post(1, None));
+
+            assert_eq!(next_id(&mut subscription).await, Some(id));
+        }
+
+        #[tokio::test]
+        async fn test_filters_by_thread_and_author() {
+            let bus = Arc::new(EventBus::new(10));
+            let thread = post(1, None);
+            let mut by_thread = bus.subscribe(
+                None,
+                EventFilter {
+                    thread_id: Some(thread.id),
+                    ..Default::default()
+                },
+            );
+            let mut by_author = bus.subscribe(
+                None,
+                EventFilter {
+                    user_id: Some(2),
+                    ..Default::default()
+                },
+            );
+
+            let root = publish(&bus, thread.clone());
+            publish(&bus, post(2, None));
+            let reply = publish(&bus, post(2, Some(thread.id)));
+
+            assert_eq!(next_id(&mut by_thread).await, Some(root));
+            assert_eq!(next_id(&mut by_thread).await, Some(reply));
+            assert_eq!(next_id(&mut by_thread).await, None);
+            assert_eq!(next_id(&mut by_author).await, Some(root + 1));
+            assert_eq!(next_id(&mut by_author).await, Some(reply));
+        }
+
+        #[tokio::test]
+        async fn test_lagged_subscriber_catches_up() {
+            let bus = Arc::new(EventBus::new(4));
+            let mut subscription = bus.subscribe(None, EventFilter::default());
+
+            let ids: Vec<u64> = (0..6).map(|_| publish(&bus, post(1, None))).collect();
+
+            // 保持している直近の4件を順に1度ずつ受け取る
+            for id in &ids[2..] {
+                assert_eq!(next_id(&mut subscription).await, Some(*id));
+            }
+            assert_eq!(next_id(&mut subscription).await, None);
+        }
+    }
+}
@@ -1,8 +1,22 @@
 /// Code from https://github.com/toms74209200/todo-axum/blob/master/todo/src/jwt/jwt.rs (MIT)
-use chrono::Utc;
-use jsonwebtoken::{decode, encode, Algorithm, DecodingKey, EncodingKey, Header, Validation};
+use crate::config::{Config, JwtKeyConfig, KeyAlgorithm};
+use crate::value_object::Role;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use chrono::{DateTime, Utc};
+use jsonwebtoken::{
+    decode, decode_header, encode, Algorithm, DecodingKey, EncodingKey, Header, Validation,
+};
+use ring::rand::SystemRandom;
+use ring::signature::{
+    EcdsaKeyPair, Ed25519KeyPair, KeyPair, RsaKeyPair, RsaPublicKeyComponents,
+    ECDSA_P256_SHA256_FIXED_SIGNING,
+};
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::path::PathBuf;
 use std::time::Duration;
+use thiserror::Error;
 use uuid::Uuid;
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -11,47 +25,298 @@ pub struct Claims {
     pub iat: usize,
     pub jti: String,
     pub uid: String,
+    /// ロール導入前に発行されたトークンには含まれない
+    #[serde(default)]
+    pub role: Role,
+    /// 発行日時(マイクロ秒)。同じ秒内の失効と区別するための非公開クレーム
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub iat_us: Option<i64>,
 }
 
 impl Claims {
-    pub fn new(uid: String, jti: String) -> Self {
+    pub fn new(uid: String, role: Role, jti: String, lifetime: Duration) -> Self {
         let now = Utc::now();
-        let exp = now + Duration::from_secs(24 * 60 * 60);
+        let exp = now + lifetime;
         Self {
             exp: exp.timestamp() as usize,
             iat: now.timestamp() as usize,
             jti,
             uid,
+            role,
+            iat_us: Some(now.timestamp_micros()),
         }
     }
+
+    /// 発行日時(マイクロ秒)。`iat_us`を含まないトークンは秒の精度で扱う
+    pub fn issued_at_micros(&self) -> i64 {
+        self.iat_us
+            .unwrap_or_else(|| (self.iat as i64).saturating_mul(1_000_000))
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum KeyError {
+    #[error("Failed to read key file {path}: {source}")]
+    Read {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    #[error("Key file {path} does not contain a valid {algorithm:?} private key")]
+    InvalidKey {
+        path: PathBuf,
+        algorithm: KeyAlgorithm,
+    },
+    #[error("No JWT signing key is active")]
+    NoActiveKey,
+    #[error(transparent)]
+    Jwt(#[from] jsonwebtoken::errors::Error),
 }
 
-pub fn create_token(
-    secret: &[u8],
-    uid: &String,
-) -> Result<std::string::String, jsonwebtoken::errors::Error> {
-    let jti = Uuid::new_v4().to_string();
-    let claims = Claims::new(uid.to_string(), jti);
-    let mut header = Header::new(Algorithm::HS256);
-    header.kid = Some("kid".to_owned());
-    encode(&header, &claims, &EncodingKey::from_secret(secret))
+/// JWKSで公開する公開鍵 (RFC 7517, RFC 7518 Section 6, RFC 8037)
+#[derive(Debug, Clone, PartialEq)]
+pub struct PublicJwk {
+    pub kty: &'static str,
+    pub kid: String,
+    pub alg: &'static str,
+    pub crv: Option<&'static str>,
+    pub n: Option<String>,
+    pub e: Option<String>,
+    pub x: Option<String>,
+    pub y: Option<String>,
 }
 
-pub fn validate_token(secret: &[u8], token: &str) -> Result<Claims, jsonwebtoken::errors::Error> {
-    let decoded = decode::<Claims>(
-        token,
-        &DecodingKey::from_secret(secret),
-        &Validation::new(Algorithm::HS256),
-    )?;
-    if (decoded.claims.exp >= Utc::now().timestamp() as usize)
-        && (decoded.claims.iat <= Utc::now().timestamp() as usize)
-    {
-        Ok(decoded.claims)
-    } else {
-        Err(jsonwebtoken::errors::Error::from(
-            jsonwebtoken::errors::ErrorKind::InvalidToken,
-        ))
+#[derive(Clone)]
+struct Key {
+    kid: String,
+    algorithm: Algorithm,
+    encoding: EncodingKey,
+    decoding: DecodingKey,
+    /// 共有シークレットの鍵は公開しない
+    jwk: Option<PublicJwk>,
+    activate_at: Option<DateTime<Utc>>,
+    retire_at: Option<DateTime<Utc>>,
+}
+
+impl Key {
+    fn is_retired(&self, now: DateTime<Utc>) -> bool {
+        self.retire_at.is_some_and(|retire_at| retire_at <= now)
     }
+
+    fn is_active(&self, now: DateTime<Utc>) -> bool {
+        !self.is_retired(now)
+            && self
+                .activate_at
+                .is_none_or(|activate_at| activate_at <= now)
+    }
+}
+
+/// トークンの署名・検証に使う鍵の集合
+///
+/// 署名には有効化済みの鍵のうち最も新しいものを使い、検証にはヘッダーのkidで選んだ鍵を使う。
+/// 署名に使わなくなった鍵もretire_atまでは検証に使う
+#[derive(Clone)]
+pub struct KeyRing {
+    keys: Vec<Key>,
+}
+
+impl KeyRing {
+    /// 設定に鍵があれば読み込み、なければ共有シークレットを使う
+    pub fn from_config(config: &Config) -> Result<Self, KeyError> {
+        if config.jwt.keys.is_empty() {
+            Ok(Self::from_secret(config.jwt_secret()))
+        } else {
+            Self::load(&config.jwt.keys)
+        }
+    }
+
+    /// 共有シークレットによるHS256の鍵。kidはシークレットのハッシュから導出する
+    pub fn from_secret(secret: &[u8]) -> Self {
+        let kid = hex::encode(&Sha256::digest(secret)[..8]);
+        Self {
+            keys: vec![Key {
+                kid,
+                algorithm: Algorithm::HS256,
+                encoding: EncodingKey::from_secret(secret),
+                decoding: DecodingKey::from_secret(secret),
+                jwk: None,
+                activate_at: None,
+                retire_at: None,
+            }],
+        }
+    }
+
+    /// 設定された秘密鍵を読み込む。現時点で署名に使える鍵がなければエラー
+    pub fn load(configs: &[JwtKeyConfig]) -> Result<Self, KeyError> {
+        let keys = configs
+            .iter()
+            .map(load_key)
+            .collect::<Result<Vec<_>, _>>()?;
+        let ring = Self { keys };
+        ring.signing_key(Utc::now())?;
+        Ok(ring)
+    }
+
+    fn signing_key(&self, now: DateTime<Utc>) -> Result<&Key, KeyError> {
+        self.keys
+            .iter()
+            .filter(|key| key.is_active(now))
+            // 同時刻なら設定で先に書かれた鍵を使う
+            .rev()
+            .max_by_key(|key| key.activate_at)
+            .ok_or(KeyError::NoActiveKey)
+    }
+
+    pub fn create_token(
+        &self,
+        uid: &str,
+        role: Role,
+        lifetime: Duration,
+    ) -> Result<String, KeyError> {
+        let key = self.signing_key(Utc::now())?;
+        let jti = Uuid::new_v4().to_string();
+        let claims = Claims::new(uid.to_string(), role, jti, lifetime);
+        let mut header = Header::new(key.algorithm);
+        header.kid = Some(key.kid.clone());
+        Ok(encode(&header, &claims, &key.encoding)?)
+    }
+
+    pub fn validate_token(&self, token: &str) -> Result<Claims, jsonwebtoken::errors::Error> {
+        let invalid =
+            || jsonwebtoken::errors::Error::from(jsonwebtoken::errors::ErrorKind::InvalidToken);
+        let now = Utc::now();
+        let kid = decode_header(token)?.kid.ok_or_else(invalid)?;
+        let key = self
+            .keys
+            .iter()
+            .find(|key| key.kid == kid && !key.is_retired(now))
+            .ok_or_else(invalid)?;
+        // ヘッダーのalgではなく鍵のアルゴリズムで検証する
+        let decoded = decode::<Claims>(token, &key.decoding, &Validation::new(key.algorithm))?;
+        if (decoded.claims.exp >= now.timestamp() as usize)
+            && (decoded.claims.iat <= now.timestamp() as usize)
+        {
+            Ok(decoded.claims)
+        } else {
+            Err(invalid())
+        }
+    }
+
+    /// 検証に使える公開鍵。検証側が事前に取得できるよう有効化前の鍵も含める
+    pub fn jwks(&self) -> Vec<PublicJwk> {
+        let now = Utc::now();
+        self.keys
+            .iter()
+            .filter(|key| !key.is_retired(now))
+            .filter_map(|key| key.jwk.clone())
+            .collect()
+    }
+}
+
+fn load_key(config: &JwtKeyConfig) -> Result<Key, KeyError> {
+    let path = &config.private_key_file;
+    let pem = std::fs::read(path).map_err(|source| KeyError::Read {
+        path: path.clone(),
+        source,
+    })?;
+    let invalid = || KeyError::InvalidKey {
+        path: path.clone(),
+        algorithm: config.algorithm,
+    };
+    let (algorithm, encoding, jwk) = match config.algorithm {
+        KeyAlgorithm::RS256 => (
+            Algorithm::RS256,
+            EncodingKey::from_rsa_pem(&pem).map_err(|_e| invalid())?,
+            rsa_jwk(&config.kid, &pem).ok_or_else(invalid)?,
+        ),
+        KeyAlgorithm::ES256 => (
+            Algorithm::ES256,
+            EncodingKey::from_ec_pem(&pem).map_err(|_e| invalid())?,
+            ec_jwk(&config.kid, &pem).ok_or_else(invalid)?,
+        ),
+        KeyAlgorithm::EdDSA => (
+            Algorithm::EdDSA,
+            EncodingKey::from_ed_pem(&pem).map_err(|_e| invalid())?,
+            ed_jwk(&config.kid, &pem).ok_or_else(invalid)?,
+        ),
+    };
+    let decoding = decoding_key(&jwk)?;
+    Ok(Key {
+        kid: config.kid.clone(),
+        algorithm,
+        encoding,
+        decoding,
+        jwk: Some(jwk),
+        activate_at: config.activate_at,
+        retire_at: config.retire_at,
+    })
+}
+
+fn decoding_key(jwk: &PublicJwk) -> Result<DecodingKey, KeyError> {
+    let component = |value: &Option<String>| value.clone().unwrap_or_default();
+    Ok(match jwk.kty {
+        "RSA" => DecodingKey::from_rsa_components(&component(&jwk.n), &component(&jwk.e))?,
+        "EC" => DecodingKey::from_ec_components(&component(&jwk.x), &component(&jwk.y))?,
+        _ => DecodingKey::from_ed_components(&component(&jwk.x))?,
+    })
+}
+
+fn rsa_jwk(kid: &str, pem: &[u8]) -> Option<PublicJwk> {
+    let pem = pem::parse(pem).ok()?;
+    // PKCS#1 ("RSA PRIVATE KEY") と PKCS#8 ("PRIVATE KEY") の両方を受け付ける
+    let key_pair = match pem.tag() {
+        "RSA PRIVATE KEY" => RsaKeyPair::from_der(pem.contents()),
+        _ => RsaKeyPair::from_pkcs8(pem.contents()),
+    }
+    .ok()?;
+    let public = RsaPublicKeyComponents::<Vec<u8>>::from(key_pair.public());
+    Some(PublicJwk {
+        kty: "RSA",
+        kid: kid.to_string(),
+        alg: "RS256",
+        crv: None,
+        n: Some(URL_SAFE_NO_PAD.encode(public.n)),
+        e: Some(URL_SAFE_NO_PAD.encode(public.e)),
+        x: None,
+        y: None,
+    })
+}
+
+fn ec_jwk(kid: &str, pem: &[u8]) -> Option<PublicJwk> {
+    let pem = pem::parse(pem).ok()?;
+    let key_pair = EcdsaKeyPair::from_pkcs8(
+        &ECDSA_P256_SHA256_FIXED_SIGNING,
+        pem.contents(),
+        &SystemRandom::new(),
+    )
+    .ok()?;
+    // 非圧縮形式の点 0x04 || x || y
+    let point = key_pair.public_key().as_ref();
+    let (x, y) = point.get(1..)?.split_at(32);
+    Some(PublicJwk {
+        kty: "EC",
+        kid: kid.to_string(),
+        alg: "ES256",
+        crv: Some("P-256"),
+        n: None,
+        e: None,
+        x: Some(URL_SAFE_NO_PAD.encode(x)),
+        y: Some(URL_SAFE_NO_PAD.encode(y)),
+    })
+}
+
+fn ed_jwk(kid: &str, pem: &[u8]) -> Option<PublicJwk> {
+    let pem = pem::parse(pem).ok()?;
+    let key_pair = Ed25519KeyPair::from_pkcs8_maybe_unchecked(pem.contents()).ok()?;
+    Some(PublicJwk {
+        kty: "OKP",
+        kid: kid.to_string(),
+        alg: "EdDSA",
+        crv: Some("Ed25519"),
+        n: None,
+        e: None,
+        x: Some(URL_SAFE_NO_PAD.encode(key_pair.public_key().as_ref())),
+        y: None,
+    })
 }
 
 #[cfg(test)]
@@ -59,28 +324,89 @@ mod tests {
     use jsonwebtoken::{decode, DecodingKey, Validation};
 
     use super::*;
+    use std::path::Path;
+
+    const SECRET: &[u8] = b"0123456789abcdef0123456789abcdef";
+
+    fn key_config(kid: &str, algorithm: KeyAlgorithm, file: &str) -> JwtKeyConfig {
+        JwtKeyConfig {
+            kid: kid.to_string(),
+            algorithm,
+            private_key_file: Path::new(env!("CARGO_MANIFEST_DIR"))
+                .join("testdata/jwt")
+                .join(file),
+            activate_at: None,
+            retire_at: None,
+        }
+    }
 
     mod create_token {
         use super::*;
 
         #[test]
         fn test_create_token() {
-            let secret = Uuid::new_v4().to_string();
+            let keys = KeyRing::from_secret(SECRET);
             let uid = Uuid::new_v4().to_string();
-            let token = create_token(secret.as_ref(), &uid).unwrap();
+            let token = keys
+                .create_token(&uid, Role::Moderator, Duration::from_secs(60))
+                .unwrap();
 
             let decoded = decode::<Claims>(
                 &token,
-                &DecodingKey::from_secret(secret.as_ref()),
+                &DecodingKey::from_secret(SECRET),
                 &Validation::new(Algorithm::HS256),
             )
             .unwrap();
 
             assert_eq!(token.split('.').count(), 3);
             assert_eq!(decoded.claims.uid, uid);
+            assert_eq!(decoded.claims.role, Role::Moderator);
             assert!(decoded.claims.exp > Utc::now().timestamp() as usize);
             assert!(decoded.claims.iat <= Utc::now().timestamp() as usize);
             assert!(!decoded.claims.jti.is_empty());
+            assert_ne!(decoded.header.kid.as_deref(), Some("kid"));
+        }
+
+        #[test]
+        fn test_iat_is_integer() {
+            let token = KeyRing::from_secret(SECRET)
+                .create_token("1", Role::Member, Duration::from_secs(60))
+                .unwrap();
+            let payload = URL_SAFE_NO_PAD.decode(token.split('.').nth(1).unwrap());
+            let payload: serde_json::Value = serde_json::from_slice(&payload.unwrap()).unwrap();
+
+            let iat = payload["iat"].as_u64().unwrap();
+            let iat_us = payload["iat_us"].as_i64().unwrap();
+            assert_eq!(iat as i64, iat_us / 1_000_000);
+        }
+
+        #[test]
+        fn test_signs_with_newest_active_key() {
+            let mut old = key_config("old", KeyAlgorithm::RS256, "rs256.pem");
+            old.activate_at = Some(Utc::now() - chrono::Duration::days(30));
+            let mut current = key_config("current", KeyAlgorithm::ES256, "es256.pem");
+            current.activate_at = Some(Utc::now() - chrono::Duration::days(1));
+            let mut next = key_config("next", KeyAlgorithm::EdDSA, "eddsa.pem");
+            next.activate_at = Some(Utc::now() + chrono::Duration::days(1));
+            let keys = KeyRing::load(&[old, current, next]).unwrap();
+
+            let token = keys
+                .create_token("1", Role::Member, Duration::from_secs(60))
+                .unwrap();
+
+            let header = decode_header(&token).unwrap();
+            assert_eq!(header.kid.as_deref(), Some("current"));
+            assert_eq!(header.alg, Algorithm::ES256);
+        }
+
+        #[test]
+        fn test_no_active_key() {
+            let mut retired = key_config("retired", KeyAlgorithm::ES256, "es256.pem");
+            retired.retire_at = Some(Utc::now() - chrono::Duration::seconds(1));
+
+            let result = KeyRing::load(&[retired]);
+
+            assert!(matches!(result, Err(KeyError::NoActiveKey)));
         }
     }
 
@@ -89,57 +415,148 @@ mod tests {
 
         #[test]
         fn test_validate_token_normal() {
-            let secret = Uuid::new_v4().to_string();
+            let keys = KeyRing::from_secret(SECRET);
             let uid = Uuid::new_v4().to_string();
-            let mut header = Header::new(Algorithm::HS256);
-            header.kid = Some("kid".to_owned());
-            let jti = Uuid::new_v4().to_string();
-            let claims = Claims::new(uid.to_string(), jti.clone());
-            let token = encode(&header, &claims, &EncodingKey::from_secret(secret.as_ref()));
+            let token = keys
+                .create_token(&uid, Role::Member, Duration::from_secs(60))
+                .unwrap();
 
-            let claims = validate_token(secret.as_ref(), &token.unwrap()).unwrap();
+            let claims = keys.validate_token(&token).unwrap();
 
             assert_eq!(&claims.uid, &uid);
-            assert_eq!(&claims.jti, &jti);
+            assert!(!claims.jti.is_empty());
         }
 
         #[test]
         fn test_validate_token_invalid_secret() {
-            let secret = Uuid::new_v4().to_string();
-            let uid = Uuid::new_v4().to_string();
-            let mut header = Header::new(Algorithm::HS256);
-            header.kid = Some("kid".to_owned());
-            let jti = Uuid::new_v4().to_string();
-            let claims = Claims::new(uid.to_string(), jti.clone());
-            let token = encode(&header, &claims, &EncodingKey::from_secret(secret.as_ref()));
+            let keys = KeyRing::from_secret(SECRET);
+            let token = keys
+                .create_token("1", Role::Member, Duration::from_secs(60))
+                .unwrap();
 
-            let mut invalid_secret = secret.clone();
-            invalid_secret.push_str("invalid");
-            let result = validate_token(invalid_secret.as_ref(), &token.unwrap());
+            let mut invalid_secret = SECRET.to_vec();
+            invalid_secret.extend_from_slice(b"invalid");
+            let result = KeyRing::from_secret(&invalid_secret).validate_token(&token);
 
             assert!(result.is_err());
         }
 
         #[test]
         fn test_validate_token_too_old() {
-            let secret = Uuid::new_v4().to_string();
-            let uid = Uuid::new_v4().to_string();
+            let keys = KeyRing::from_secret(SECRET);
             let mut header = Header::new(Algorithm::HS256);
-            header.kid = Some("kid".to_owned());
-            let jti = Uuid::new_v4().to_string();
+            header.kid = Some(keys.keys[0].kid.clone());
             let now = Utc::now() - Duration::from_secs(20);
             let exp = now + Duration::from_secs(10);
             let claims = Claims {
                 exp: exp.timestamp() as usize,
                 iat: now.timestamp() as usize,
-                jti,
-                uid,
+                jti: Uuid::new_v4().to_string(),
+                uid: Uuid::new_v4().to_string(),
+                role: Role::Member,
+                iat_us: None,
             };
-            let token = encode(&header, &claims, &EncodingKey::from_secret(secret.as_ref()));
+            let token = encode(&header, &claims, &EncodingKey::from_secret(SECRET));
 
-            let result = validate_token(secret.as_ref(), &token.unwrap());
+            let result = keys.validate_token(&token.unwrap());
 
             assert!(result.is_err());
         }
+
+        #[test]
+        fn test_each_algorithm_round_trips() {
+            for (algorithm, file) in [
+                (KeyAlgorithm::RS256, "rs256.pem"),
+                (KeyAlgorithm::ES256, "es256.pem"),
+                (KeyAlgorithm::EdDSA, "eddsa.pem"),
+            ] {
+                let keys = KeyRing::load(&[key_config("k", algorithm, file)]).unwrap();
+                let token = keys
+                    .create_token("1", Role::Member, Duration::from_secs(60))
+                    .unwrap();
+
+                assert_eq!(keys.validate_token(&token).unwrap().uid, "1", "{}", file);
+            }
+        }
+
+        #[test]
+        fn test_rotated_key_still_verifies_until_retired() {
+            let old = key_config("old", KeyAlgorithm::RS256, "rs256.pem");
+            let token = KeyRing::load(std::slice::from_ref(&old))
+                .unwrap()
+                .create_token("1", Role::Member, Duration::from_secs(60))
+                .unwrap();
+            let mut current = key_config("current", KeyAlgorithm::ES256, "es256.pem");
+            current.activate_at = Some(Utc::now() - chrono::Duration::seconds(1));
+
+            let rotated = KeyRing::load(&[old.clone(), current.clone()]).unwrap();
+            let mut retired_old = old;
+            retired_old.retire_at = Some(Utc::now() - chrono::Duration::seconds(1));
+            let retired = KeyRing::load(&[retired_old, current]).unwrap();
+
+            assert!(rotated.validate_token(&token).is_ok());
+            assert!(retired.validate_token(&token).is_err());
+        }
+
+        #[test]
+        fn test_unknown_kid() {
+            let keys = KeyRing::from_secret(SECRET);
+            let mut header = Header::new(Algorithm::HS256);
+            header.kid = Some("kid".to_owned());
+            let claims = Claims::new(
+                "1".to_string(),
+                Role::Member,
+                "jti".to_string(),
+                Duration::from_secs(60),
+            );
+            let token = encode(&header, &claims, &EncodingKey::from_secret(SECRET)).unwrap();
+
+            assert!(keys.validate_token(&token).is_err());
+        }
+
+        #[test]
+        fn test_token_without_role_is_member() {
+            #[derive(Serialize)]
+            struct LegacyClaims {
+                exp: usize,
+                iat: usize,
+                jti: String,
+                uid: String,
+            }
+            let keys = KeyRing::from_secret(SECRET);
+            let mut header = Header::new(Algorithm::HS256);
+            header.kid = Some(keys.keys[0].kid.clone());
+            let now = Utc::now();
+            let claims = LegacyClaims {
+                exp: (now + Duration::from_secs(60)).timestamp() as usize,
+                iat: now.timestamp() as usize,
+                jti: Uuid::new_v4().to_string(),
+                uid: "1".to_string(),
+            };
+            let token = encode(&header, &claims, &EncodingKey::from_secret(SECRET)).unwrap();
+
+            assert_eq!(keys.validate_token(&token).unwrap().role, Role::Member);
+        }
+    }
+
+    mod jwks {
+        use super::*;
+
+        #[test]
+        fn test_jwks_publishes_asymmetric_keys() {
+            let keys = KeyRing::load(&[
+                key_config("rsa", KeyAlgorithm::RS256, "rs256.pem"),
+                key_config("ec", KeyAlgorithm::ES256, "es256.pem"),
+                key_config("ed", KeyAlgorithm::EdDSA, "eddsa.pem"),
+            ])
+            .unwrap();
+
+            let jwks = keys.jwks();
+
+            let kinds: Vec<_> = jwks.iter().map(|jwk| (jwk.kid.as_str(), jwk.kty)).collect();
+            assert_eq!(kinds, vec![("rsa", "RSA"), ("ec", "EC"), ("ed", "OKP")]);
+            assert_eq!(jwks[0].e.as_deref(), Some("AQAB"));
+            assert!(KeyRing::from_secret(SECRET).jwks().is_empty());
+        }
     }
 }
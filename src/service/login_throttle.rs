@@ -0,0 +1,335 @@
+use crate::config::LoginConfig;
+use chrono::{DateTime, Duration, Utc};
+use std::collections::HashMap;
+use std::fmt;
+use std::net::IpAddr;
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+/// ログインの失敗を数える単位
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ThrottleKey {
+    /// 正規化したメールアドレス。登録されていないアドレスも同じく数える
+    Account(String),
+    Ip(IpAddr),
+}
+
+impl fmt::Display for ThrottleKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ThrottleKey::Account(email) => write!(f, "account:{}", email),
+            ThrottleKey::Ip(ip) => write!(f, "ip:{}", ip),
+        }
+    }
+}
+
+/// 失敗が続いたために新たにロックしたキー
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Lockout {
+    pub key: ThrottleKey,
+    pub failures: u32,
+    pub until: DateTime<Utc>,
+}
+
+/// 照合の前に失敗として数えたログインの試行
+#[derive(Debug)]
+pub struct Attempt {
+    keys: Vec<ThrottleKey>,
+    /// 数える前の各キーのロックの期限。成功した場合に戻す
+    previous: Vec<Option<DateTime<Utc>>>,
+    /// この試行が失敗した場合に新たにロックするキー
+    pub lockouts: Vec<Lockout>,
+}
+
+#[derive(Debug, Clone)]
+struct Failures {
+    count: u32,
+    last_failure: DateTime<Utc>,
+    locked_until: Option<DateTime<Utc>>,
+}
+
+impl Failures {
+    /// 最後の失敗またはロックの解除から`window`が経ったか
+    fn is_expired(&self, now: DateTime<Utc>, window: Duration) -> bool {
+        let since = self
+            .locked_until
+            .map_or(self.last_failure, |until| until.max(self.last_failure));
+        since + window <= now
+    }
+}
+
+/// アカウントとクライアントのアドレスごとにログインの失敗を数え、続いた場合はロックする
+///
+/// 回数はプロセス内に保持するため、再起動すると戻る
+#[derive(Debug)]
+pub struct LoginThrottle {
+    config: LoginConfig,
+    failures: Mutex<HashMap<ThrottleKey, Failures>>,
+}
+
+impl LoginThrottle {
+    pub fn new(config: LoginConfig) -> Self {
+        Self {
+            config,
+            failures: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, HashMap<ThrottleKey, Failures>> {
+        // 回数の記録が途中で失敗しても、残りの記録は使える
+        self.failures.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn max_failures(&self, key: &ThrottleKey) -> u32 {
+        match key {
+            ThrottleKey::Account(_) => self.config.max_failures_per_account,
+            ThrottleKey::Ip(_) => self.config.max_failures_per_ip,
+        }
+    }
+
+    /// `max`回目の失敗から始まり、失敗するたびに倍になるロックの時間
+    fn lockout(&self, failures: u32, max: u32) -> Duration {
+        let doublings = (failures - max).min(31);
+        let seconds = self
+            .config
+            .lockout
+            .saturating_mul(1 << doublings)
+            .min(self.config.max_lockout);
+        // 上限は設定の読み込み時に検証済み
+        Duration::seconds(seconds as i64)
+    }
+
+    fn window(&self) -> Duration {
+        Duration::seconds(self.config.failure_window as i64)
+    }
+
+    /// ログインを試みる前に失敗として数える。いずれかのキーがロックされている場合は数えず、
+    /// すべて解除されるまでの時間を返す
+    ///
+    /// 照合の間に同時に試みた分も回数に含めるため、照合より先に数える。
+    /// 成功した場合は`record_success`で取り消す
+    pub fn reserve(&self, keys: &[ThrottleKey], now: DateTime<Utc>) -> Result<Attempt, Duration> {
+        let window = self.window();
+        let mut failures = self.lock();
+        if let Some(until) = keys
+            .iter()
+            .filter_map(|key| failures.get(key)?.locked_until)
+            .filter(|until| *until > now)
+            .max()
+        {
+            return Err(until - now);
+        }
+        let mut attempt = Attempt {
+            keys: keys.to_vec(),
+            previous: Vec::with_capacity(keys.len()),
+            lockouts: Vec::new(),
+        };
+        for key in keys {
+            let max = self.max_failures(key);
+            let entry = failures.entry(key.clone()).or_insert(Failures {
+                count: 0,
+                last_failure: now,
+                locked_until: None,
+            });
+            if entry.is_expired(now, window) {
+                entry.count = 0;
+                entry.locked_until = None;
+            }
+            attempt.previous.push(entry.locked_until);
+            entry.count += 1;
+            entry.last_failure = now;
+            if entry.count >= max {
+                let until = now + self.lockout(entry.count, max);
+                entry.locked_until = Some(until);
+                attempt.lockouts.push(Lockout {
+                    key: key.clone(),
+                    failures: entry.count,
+                    until,
+                });
+            }
+        }
+        Ok(attempt)
+    }
+
+    /// ログインに成功した試行を取り消す。アカウントの失敗の回数は戻し、アドレスは数えた分を引く
+    pub fn record_success(&self, attempt: &Attempt) {
+        let mut failures = self.lock();
+        for (key, previous) in attempt.keys.iter().zip(&attempt.previous) {
+            if let ThrottleKey::Account(_) = key {
+                failures.remove(key);
+                continue;
+            }
+            let Some(entry) = failures.get_mut(key) else {
+                continue;
+            };
+            entry.count = entry.count.saturating_sub(1);
+            // この試行でロックした場合のみ元に戻す
+            if attempt
+                .lockouts
+                .iter()
+                .any(|lockout| lockout.key == *key && entry.locked_until == Some(lockout.until))
+            {
+                entry.locked_until = *previous;
+            }
+        }
+    }
+
+    /// 回数を戻してよいキーを削除し、削除した数を返す
+    pub fn purge_expired(&self, now: DateTime<Utc>) -> usize {
+        let window = self.window();
+        let mut failures = self.lock();
+        let before = failures.len();
+        failures.retain(|_, entry| !entry.is_expired(now, window));
+        before - failures.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn throttle() -> LoginThrottle {
+        LoginThrottle::new(LoginConfig {
+            max_failures_per_account: 3,
+            max_failures_per_ip: 5,
+            lockout: 10,
+            max_lockout: 60,
+            failure_window: 300,
+        })
+    }
+
+    fn account() -> ThrottleKey {
+        ThrottleKey::Account("a@example.com".to_string())
+    }
+
+    fn fail(throttle: &LoginThrottle, keys: &[ThrottleKey], now: DateTime<Utc>) -> Vec<Lockout> {
+        throttle.reserve(keys, now).unwrap().lockouts
+    }
+
+    mod reserve {
+        use super::*;
+
+        #[test]
+        fn test_locks_after_max_failures() {
+            let throttle = throttle();
+            let now = Utc::now();
+
+            assert!(fail(&throttle, &[account()], now).is_empty());
+            assert!(fail(&throttle, &[account()], now).is_empty());
+            let lockouts = fail(&throttle, &[account()], now);
+
+            assert_eq!(
+                lockouts,
+                vec![Lockout {
+                    key: account(),
+                    failures: 3,
+                    until: now + Duration::seconds(10),
+                }]
+            );
+            assert_eq!(
+                throttle.reserve(&[account()], now).unwrap_err(),
+                Duration::seconds(10)
+            );
+            assert!(throttle
+                .reserve(&[account()], now + Duration::seconds(10))
+                .is_ok());
+        }
+
+        #[test]
+        fn test_counts_attempts_in_progress() {
+            let throttle = throttle();
+            let now = Utc::now();
+
+            // 照合が終わる前の試行も上限に含める
+            let attempts: Vec<_> = (0..3)
+                .map(|_| throttle.reserve(&[account()], now).unwrap())
+                .collect();
+
+            assert!(throttle.reserve(&[account()], now).is_err());
+            assert_eq!(attempts[2].lockouts.len(), 1);
+        }
+
+        #[test]
+        fn test_backoff_doubles_up_to_max() {
+            let throttle = throttle();
+            let mut now = Utc::now();
+            let mut lockouts = Vec::new();
+
+            for _ in 0..7 {
+                if let Some(lockout) = fail(&throttle, &[account()], now).pop() {
+                    lockouts.push((lockout.until - now).num_seconds());
+                    now = lockout.until;
+                }
+            }
+
+            assert_eq!(lockouts, vec![10, 20, 40, 60, 60]);
+        }
+
+        #[test]
+        fn test_keys_are_counted_separately() {
+            let throttle = throttle();
+            let now = Utc::now();
+            let ip = ThrottleKey::Ip("192.0.2.1".parse().unwrap());
+
+            for i in 0..4 {
+                let email = ThrottleKey::Account(format!("{}@example.com", i));
+                fail(&throttle, &[email, ip.clone()], now);
+            }
+            let lockouts = fail(&throttle, &[account(), ip.clone()], now);
+
+            assert_eq!(lockouts.len(), 1);
+            assert_eq!(lockouts[0].key, ip);
+            assert!(throttle.reserve(&[account(), ip], now).is_err());
+            assert!(throttle.reserve(&[account()], now).is_ok());
+        }
+
+        #[test]
+        fn test_failures_expire() {
+            let throttle = throttle();
+            let now = Utc::now();
+            fail(&throttle, &[account()], now);
+            fail(&throttle, &[account()], now);
+
+            let later = now + Duration::seconds(300);
+
+            assert!(fail(&throttle, &[account()], later).is_empty());
+            assert_eq!(throttle.purge_expired(later + Duration::seconds(300)), 1);
+        }
+    }
+
+    mod record_success {
+        use super::*;
+
+        #[test]
+        fn test_resets_failures() {
+            let throttle = throttle();
+            let now = Utc::now();
+            fail(&throttle, &[account()], now);
+            fail(&throttle, &[account()], now);
+            let attempt = throttle.reserve(&[account()], now).unwrap();
+
+            throttle.record_success(&attempt);
+
+            assert!(fail(&throttle, &[account()], now).is_empty());
+        }
+
+        #[test]
+        fn test_rolls_back_address() {
+            let throttle = throttle();
+            let now = Utc::now();
+            let ip = ThrottleKey::Ip("192.0.2.1".parse().unwrap());
+            for i in 0..4 {
+                let email = ThrottleKey::Account(format!("{}@example.com", i));
+                fail(&throttle, &[email, ip.clone()], now);
+            }
+            let attempt = throttle.reserve(&[account(), ip.clone()], now).unwrap();
+            assert_eq!(attempt.lockouts[0].key, ip);
+
+            throttle.record_success(&attempt);
+
+            // 成功した試行は数えず、次の失敗で上限に達する
+            let lockouts = fail(&throttle, &[account(), ip.clone()], now);
+            assert_eq!(lockouts.len(), 1);
+            assert_eq!(lockouts[0].failures, 5);
+        }
+    }
+}
@@ -1 +1,12 @@
-pub mod jwt;
\ No newline at end of file
+pub mod auth;
+pub mod cursor;
+pub mod diff;
+pub mod etag;
+pub mod events;
+pub mod jwt;
+pub mod login_throttle;
+pub mod password;
+pub mod policy;
+pub mod refresh_token;
+pub mod search;
+pub mod webhook;
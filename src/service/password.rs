@@ -0,0 +1,216 @@
+use crate::config::PasswordConfig;
+use crate::value_object::{Email, Name, Password};
+use argon2::{Algorithm, Params, Version};
+use password_hash::PasswordHash;
+use thiserror::Error;
+
+/// 名前やメールアドレスを含むか判定する際に無視する短い部分の文字数
+const MIN_PERSONAL_LENGTH: usize = 3;
+
+/// パスワードの規則に違反した理由
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum PasswordError {
+    #[error("must be at least {min} characters")]
+    TooShort { min: usize },
+    #[error("must contain a lowercase letter")]
+    MissingLowercase,
+    #[error("must contain an uppercase letter")]
+    MissingUppercase,
+    #[error("must contain a digit")]
+    MissingDigit,
+    #[error("must contain a symbol")]
+    MissingSymbol,
+    #[error("is too common")]
+    Common,
+    #[error("must not contain the user's name or email address")]
+    PersonalInfo,
+}
+
+impl PasswordError {
+    /// 検証エラーとして返す際の種類
+    pub fn code(&self) -> &'static str {
+        match self {
+            PasswordError::TooShort { .. } => "length",
+            PasswordError::MissingLowercase
+            | PasswordError::MissingUppercase
+            | PasswordError::MissingDigit
+            | PasswordError::MissingSymbol => "characters",
+            PasswordError::Common => "common",
+            PasswordError::PersonalInfo => "personal",
+        }
+    }
+}
+
+/// 登録するパスワードを設定された規則で検証し、違反したすべての規則を返す
+pub fn check(
+    config: &PasswordConfig,
+    password: &Password,
+    name: &Name,
+    email: &Email,
+) -> Vec<PasswordError> {
+    let password = password.as_str();
+    let mut errors = Vec::new();
+    if password.chars().count() < config.min_length {
+        errors.push(PasswordError::TooShort {
+            min: config.min_length,
+        });
+    }
+    let classes = [
+        (
+            config.require_lowercase,
+            char::is_lowercase as fn(char) -> bool,
+        ),
+        (config.require_uppercase, char::is_uppercase),
+        (config.require_digit, |c: char| c.is_ascii_digit()),
+        (config.require_symbol, |c: char| !c.is_alphanumeric()),
+    ];
+    let missing = [
+        PasswordError::MissingLowercase,
+        PasswordError::MissingUppercase,
+        PasswordError::MissingDigit,
+        PasswordError::MissingSymbol,
+    ];
+    for ((required, is_class), error) in classes.into_iter().zip(missing) {
+        if required && !password.chars().any(is_class) {
+            errors.push(error);
+        }
+    }
+    let lowercase = password.to_lowercase();
+    if config.deny_list.contains(&lowercase) {
+        errors.push(PasswordError::Common);
+    }
+    let local_part = email.as_str().split('@').next().unwrap_or_default();
+    let personal = [name.as_str().to_lowercase(), local_part.to_string()];
+    if personal.iter().any(|part| {
+        part.chars().count() >= MIN_PERSONAL_LENGTH && lowercase.contains(part.as_str())
+    }) {
+        errors.push(PasswordError::PersonalInfo);
+    }
+    errors
+}
+
+/// 保存されたハッシュが現在の設定より弱いパラメータか、Argon2id以外で作られているか判定する
+pub fn needs_rehash(hash: &PasswordHash<'_>, params: &Params) -> bool {
+    if hash.algorithm != Algorithm::Argon2id.ident() || hash.version != Some(Version::V0x13 as u32)
+    {
+        return true;
+    }
+    match Params::try_from(hash) {
+        Ok(current) => {
+            current.m_cost() < params.m_cost()
+                || current.t_cost() < params.t_cost()
+                || current.p_cost() < params.p_cost()
+        }
+        Err(_) => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entity::hash_password;
+    use argon2::Argon2;
+
+    fn check_with(config: &PasswordConfig, password: &str) -> Vec<PasswordError> {
+        check(
+            config,
+            &Password::parse(password).unwrap(),
+            &Name::parse("Alice").unwrap(),
+            &Email::parse("alice.b@example.com").unwrap(),
+        )
+    }
+
+    mod check {
+        use super::*;
+
+        #[test]
+        fn test_default_policy() {
+            let config = PasswordConfig::default();
+
+            assert_eq!(check_with(&config, "correct horse"), vec![]);
+            assert_eq!(
+                check_with(&config, "short"),
+                vec![PasswordError::TooShort { min: 8 }]
+            );
+        }
+
+        #[test]
+        fn test_character_classes() {
+            let config = PasswordConfig {
+                require_lowercase: true,
+                require_uppercase: true,
+                require_digit: true,
+                require_symbol: true,
+                ..PasswordConfig::default()
+            };
+
+            assert_eq!(check_with(&config, "Correct-horse-9"), vec![]);
+            assert_eq!(
+                check_with(&config, "correcthorse"),
+                vec![
+                    PasswordError::MissingUppercase,
+                    PasswordError::MissingDigit,
+                    PasswordError::MissingSymbol
+                ]
+            );
+        }
+
+        #[test]
+        fn test_deny_list() {
+            let config = PasswordConfig {
+                deny_list: ["qwerty123".to_string()].into(),
+                ..PasswordConfig::default()
+            };
+
+            assert_eq!(
+                check_with(&config, "QWERTY123"),
+                vec![PasswordError::Common]
+            );
+        }
+
+        #[test]
+        fn test_personal_info() {
+            let config = PasswordConfig::default();
+
+            assert_eq!(
+                check_with(&config, "ALICE-secret"),
+                vec![PasswordError::PersonalInfo]
+            );
+            assert_eq!(
+                check_with(&config, "my alice.b pass"),
+                vec![PasswordError::PersonalInfo]
+            );
+        }
+    }
+
+    mod needs_rehash {
+        use super::*;
+
+        fn hash(params: Params) -> String {
+            let argon2 = Argon2::new(Algorithm::Argon2id, Version::V0x13, params);
+            hash_password(&argon2, &Password::parse("password").unwrap()).unwrap()
+        }
+
+        #[test]
+        fn test_weaker_params() {
+            let current = Params::new(2048, 2, 1, None).unwrap();
+            let weak = hash(Params::new(1024, 1, 1, None).unwrap());
+            let strong = hash(current.clone());
+
+            assert!(needs_rehash(&PasswordHash::new(&weak).unwrap(), &current));
+            assert!(!needs_rehash(
+                &PasswordHash::new(&strong).unwrap(),
+                &current
+            ));
+        }
+
+        #[test]
+        fn test_other_algorithm() {
+            let params = Params::new(1024, 1, 1, None).unwrap();
+            let argon2 = Argon2::new(Algorithm::Argon2i, Version::V0x13, params.clone());
+            let hash = hash_password(&argon2, &Password::parse("password").unwrap()).unwrap();
+
+            assert!(needs_rehash(&PasswordHash::new(&hash).unwrap(), &params));
+        }
+    }
+}
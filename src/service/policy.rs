@@ -0,0 +1,244 @@
+use crate::entity::{Board, Post};
+use crate::service::auth::AuthenticatedUser;
+use crate::value_object::Role;
+
+/// 投稿に対する操作
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PostAction {
+    Edit,
+    Delete,
+    Reply,
+    Lock,
+    Pin,
+    Revert,
+    /// ゴミ箱から戻す
+    Restore,
+    /// ゴミ箱を経由せずに完全に削除する
+    Purge,
+    /// 下書きまたは公開予定の投稿を公開する、または公開する日時を変更する
+    Publish,
+}
+
+/// 利用者が投稿に対して操作を行えるか判定する
+///
+/// モデレーターと管理者はすべての投稿を操作できる。
+/// 一般ユーザーはロックされていない投稿への返信と、自分の投稿の編集、削除、版の復元、公開、
+/// 自分でゴミ箱に移した投稿を戻すことのみ行える。ただし公開前の投稿には誰も返信できない
+pub fn can(user: &AuthenticatedUser, action: PostAction, post: &Post) -> bool {
+    if action == PostAction::Reply && !post.is_published() {
+        return false;
+    }
+    if is_moderator(user.role) {
+        return true;
+    }
+    match action {
+        PostAction::Edit | PostAction::Delete | PostAction::Revert | PostAction::Publish => {
+            post.user_id == user.id && !post.locked
+        }
+        PostAction::Reply => !post.locked,
+        // モデレーターが削除した投稿は作成者でも戻せない
+        PostAction::Restore => post.deleted_by == Some(user.id),
+        PostAction::Lock | PostAction::Pin | PostAction::Purge => false,
+    }
+}
+
+/// 利用者が投稿を参照できるか判定する。公開前の投稿はモデレーターと管理者であっても作成者以外は参照できない
+pub fn can_view(user: &AuthenticatedUser, post: &Post) -> bool {
+    post.is_published() || post.user_id == user.id
+}
+
+/// 利用者がボードにスレッドを作成できるか判定する。ボードに設定されたロール以上のユーザーのみ作成できる
+pub fn can_post(user: &AuthenticatedUser, board: &Board) -> bool {
+    user.role >= board.settings.posting_role
+}
+
+/// ロールの付与と取り消しは管理者のみ行える
+pub fn can_manage_roles(user: &AuthenticatedUser) -> bool {
+    user.role == Role::Admin
+}
+
+/// Webhookの登録と配信の管理は管理者のみ行える
+pub fn can_manage_webhooks(user: &AuthenticatedUser) -> bool {
+    user.role == Role::Admin
+}
+
+/// ボードの作成、更新と削除は管理者のみ行える
+pub fn can_manage_boards(user: &AuthenticatedUser) -> bool {
+    user.role == Role::Admin
+}
+
+fn is_moderator(role: Role) -> bool {
+    matches!(role, Role::Moderator | Role::Admin)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entity::BoardSettings;
+    use crate::value_object::{BoardName, BoardSlug, Content, PostStatus, Title};
+    use chrono::Utc;
+    use uuid::Uuid;
+
+    fn user(id: i64, role: Role) -> AuthenticatedUser {
+        AuthenticatedUser {
+            id,
+            role,
+            jti: Uuid::new_v4().to_string(),
+            expires_at: Utc::now(),
+        }
+    }
+
+    fn post(user_id: i64, locked: bool) -> Post {
+        Post {
+            id: Uuid::new_v4(),
+            user_id,
+            board_id: Uuid::nil(),
+            title: Title::parse("title").unwrap(),
+            content: Content::parse("content").unwrap(),
+            locked,
+            pinned: false,
+            parent_id: None,
+            reply_count: 0,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            edited_count: 0,
+            last_activity_at: Utc::now(),
+            version: 1,
+            deleted_at: None,
+            deleted_by: None,
+            status: PostStatus::Published,
+            publish_at: None,
+        }
+    }
+
+    mod can {
+        use super::*;
+
+        #[test]
+        fn test_member_edits_own_unlocked_post() {
+            let member = user(1, Role::Member);
+
+            assert!(can(&member, PostAction::Edit, &post(1, false)));
+            assert!(can(&member, PostAction::Delete, &post(1, false)));
+            assert!(!can(&member, PostAction::Edit, &post(2, false)));
+            assert!(!can(&member, PostAction::Delete, &post(1, true)));
+            assert!(can(&member, PostAction::Revert, &post(1, false)));
+            assert!(!can(&member, PostAction::Revert, &post(2, false)));
+            assert!(can(&member, PostAction::Reply, &post(2, false)));
+            assert!(!can(&member, PostAction::Reply, &post(2, true)));
+            assert!(!can(&member, PostAction::Pin, &post(1, false)));
+            assert!(!can(&member, PostAction::Lock, &post(1, false)));
+            assert!(!can(&member, PostAction::Purge, &post(1, false)));
+            assert!(can(&member, PostAction::Publish, &post(1, false)));
+            assert!(!can(&member, PostAction::Publish, &post(2, false)));
+        }
+
+        #[test]
+        fn test_member_restores_only_own_deletion() {
+            let member = user(1, Role::Member);
+            let mut deleted = post(1, false);
+
+            deleted.trash(1);
+            assert!(can(&member, PostAction::Restore, &deleted));
+
+            deleted.trash(2);
+            assert!(!can(&member, PostAction::Restore, &deleted));
+        }
+
+        #[test]
+        fn test_moderator_and_admin_act_on_any_post() {
+            for role in [Role::Moderator, Role::Admin] {
+                let moderator = user(1, role);
+                let locked = post(2, true);
+
+                for action in [
+                    PostAction::Edit,
+                    PostAction::Delete,
+                    PostAction::Reply,
+                    PostAction::Lock,
+                    PostAction::Pin,
+                    PostAction::Revert,
+                    PostAction::Restore,
+                    PostAction::Purge,
+                    PostAction::Publish,
+                ] {
+                    assert!(can(&moderator, action, &locked), "{:?} {:?}", role, action);
+                }
+            }
+        }
+    }
+
+    mod can_view {
+        use super::*;
+
+        #[test]
+        fn test_unpublished_post_is_visible_only_to_author() {
+            let draft = Post::unpublished(
+                1,
+                Uuid::nil(),
+                Title::parse("title").unwrap(),
+                Content::parse("content").unwrap(),
+                None,
+            );
+
+            assert!(can_view(&user(1, Role::Member), &draft));
+            assert!(!can_view(&user(2, Role::Admin), &draft));
+            assert!(can_view(&user(2, Role::Member), &post(1, false)));
+            assert!(!can(&user(1, Role::Admin), PostAction::Reply, &draft));
+        }
+    }
+
+    mod can_post {
+        use super::*;
+
+        #[test]
+        fn test_role_at_least_posting_role() {
+            let board = Board::new(
+                BoardSlug::parse("news").unwrap(),
+                BoardName::parse("News").unwrap(),
+                None,
+                BoardSettings {
+                    posting_role: Role::Moderator,
+                    ..BoardSettings::default()
+                },
+            );
+
+            assert!(can_post(&user(1, Role::Admin), &board));
+            assert!(can_post(&user(1, Role::Moderator), &board));
+            assert!(!can_post(&user(1, Role::Member), &board));
+        }
+    }
+
+    mod can_manage_roles {
+        use super::*;
+
+        #[test]
+        fn test_only_admin() {
+            assert!(can_manage_roles(&user(1, Role::Admin)));
+            assert!(!can_manage_roles(&user(1, Role::Moderator)));
+            assert!(!can_manage_roles(&user(1, Role::Member)));
+        }
+    }
+
+    mod can_manage_webhooks {
+        use super::*;
+
+        #[test]
+        fn test_only_admin() {
+            assert!(can_manage_webhooks(&user(1, Role::Admin)));
+            assert!(!can_manage_webhooks(&user(1, Role::Moderator)));
+            assert!(!can_manage_webhooks(&user(1, Role::Member)));
+        }
+    }
+
+    mod can_manage_boards {
+        use super::*;
+
+        #[test]
+        fn test_only_admin() {
+            assert!(can_manage_boards(&user(1, Role::Admin)));
+            assert!(!can_manage_boards(&user(1, Role::Moderator)));
+            assert!(!can_manage_boards(&user(1, Role::Member)));
+        }
+    }
+}
@@ -0,0 +1,140 @@
+use crate::entity::RefreshToken;
+use crate::repository::{RefreshTokenRepository, RepositoryError};
+use crate::value_object::UserId;
+use argon2::password_hash::rand_core::{OsRng, RngCore};
+use chrono::Utc;
+use sha2::{Digest, Sha256};
+use std::time::Duration;
+use thiserror::Error;
+use uuid::Uuid;
+
+#[derive(Debug, Error)]
+pub enum RefreshError {
+    #[error("Refresh token is invalid")]
+    Invalid,
+    #[error("Refresh token has expired")]
+    Expired,
+    #[error("Refresh token was already used")]
+    Reused,
+    #[error(transparent)]
+    Repository(#[from] RepositoryError),
+}
+
+/// 推測できない32バイトのトークンを生成する
+pub fn generate_token() -> String {
+    let mut bytes = [0u8; 32];
+    OsRng.fill_bytes(&mut bytes);
+    hex::encode(bytes)
+}
+
+/// 保存・照合に使うトークンのハッシュ
+pub fn hash_token(token: &str) -> String {
+    hex::encode(Sha256::digest(token.as_bytes()))
+}
+
+/// 系列に新しいリフレッシュトークンを発行し、平文のトークンを返す
+pub fn issue(
+    repo: &dyn RefreshTokenRepository,
+    user_id: UserId,
+    family_id: Uuid,
+    lifetime: Duration,
+) -> Result<String, RepositoryError> {
+    let token = generate_token();
+    repo.insert(RefreshToken {
+        token_hash: hash_token(&token),
+        family_id,
+        user_id,
+        expires_at: Utc::now() + lifetime,
+        used: false,
+        revoked: false,
+    })?;
+    Ok(token)
+}
+
+/// 提示されたトークンを使用済みにし、同じ系列の新しいトークンを発行する。
+/// 使用済みのトークンが再び提示された場合は漏洩とみなして系列全体を失効させる
+pub fn rotate(
+    repo: &dyn RefreshTokenRepository,
+    presented: &str,
+    lifetime: Duration,
+) -> Result<(UserId, String), RefreshError> {
+    let Some(token) = repo.consume(&hash_token(presented))? else {
+        return Err(RefreshError::Invalid);
+    };
+    if token.revoked {
+        return Err(RefreshError::Invalid);
+    }
+    if token.used {
+        tracing::warn!(
+            user_id = token.user_id,
+            family_id = %token.family_id,
+            "refresh token reuse detected, revoking token family"
+        );
+        repo.revoke_family(token.family_id)?;
+        return Err(RefreshError::Reused);
+    }
+    if token.expires_at < Utc::now() {
+        return Err(RefreshError::Expired);
+    }
+    let next = issue(repo, token.user_id, token.family_id, lifetime)?;
+    Ok((token.user_id, next))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repository::memory::InMemoryRefreshTokenRepository;
+
+    const LIFETIME: Duration = Duration::from_secs(60);
+
+    mod rotate {
+        use super::*;
+
+        #[test]
+        fn test_rotate_issues_new_token() {
+            let repo = InMemoryRefreshTokenRepository::new();
+            let token = issue(&repo, 1, Uuid::new_v4(), LIFETIME).unwrap();
+
+            let (user_id, next) = rotate(&repo, &token, LIFETIME).unwrap();
+
+            assert_eq!(user_id, 1);
+            assert_ne!(next, token);
+            assert!(rotate(&repo, &next, LIFETIME).is_ok());
+        }
+
+        #[test]
+        fn test_reuse_revokes_family() {
+            let repo = InMemoryRefreshTokenRepository::new();
+            let token = issue(&repo, 1, Uuid::new_v4(), LIFETIME).unwrap();
+            let (_, next) = rotate(&repo, &token, LIFETIME).unwrap();
+
+            let reused = rotate(&repo, &token, LIFETIME);
+
+            assert!(matches!(reused, Err(RefreshError::Reused)));
+            assert!(matches!(
+                rotate(&repo, &next, LIFETIME),
+                Err(RefreshError::Invalid)
+            ));
+        }
+
+        #[test]
+        fn test_expired() {
+            let repo = InMemoryRefreshTokenRepository::new();
+            let token = issue(&repo, 1, Uuid::new_v4(), Duration::ZERO).unwrap();
+            std::thread::sleep(Duration::from_millis(5));
+
+            let result = rotate(&repo, &token, LIFETIME);
+
+            assert!(matches!(result, Err(RefreshError::Expired)));
+        }
+
+        #[test]
+        fn test_unknown_token() {
+            let repo = InMemoryRefreshTokenRepository::new();
+
+            let result = rotate(&repo, &generate_token(), LIFETIME);
+
+            assert!(matches!(result, Err(RefreshError::Invalid)));
+        }
+    }
+}
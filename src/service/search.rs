@@ -0,0 +1,496 @@
+use crate::entity::Post;
+use crate::repository::SearchCandidate;
+use chrono::{DateTime, Utc};
+use std::collections::BTreeMap;
+use std::ops::Range;
+
+/// 索引の作り方の版。変更した場合は保存済みの索引を作り直す
+pub const INDEX_VERSION: u32 = 1;
+/// 一度の検索で順位を付ける投稿の最大数
+pub const MAX_CANDIDATES: usize = 1000;
+/// タイトルに含まれる語の重み。本文の語は1
+const TITLE_WEIGHT: u32 = 3;
+/// 新しさによる加点が半分になるまでの日数
+const RECENCY_HALF_LIFE_DAYS: f64 = 30.0;
+/// 抜粋の文字数
+const SNIPPET_LENGTH: usize = 120;
+/// 抜粋に含める、最初に一致した部分より前の文字数
+const SNIPPET_CONTEXT: usize = 30;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CharClass {
+    /// 空白で分かち書きする文字
+    Word,
+    /// 分かち書きしない日本語などの文字
+    Cjk,
+    /// 空白や記号。索引しない
+    Other,
+}
+
+fn classify(c: char) -> CharClass {
+    match c {
+        '\u{3005}'
+        | '\u{3040}'..='\u{30FF}'
+        | '\u{31F0}'..='\u{31FF}'
+        | '\u{3400}'..='\u{4DBF}'
+        | '\u{4E00}'..='\u{9FFF}'
+        | '\u{AC00}'..='\u{D7AF}'
+        | '\u{F900}'..='\u{FAFF}'
+        | '\u{FF66}'..='\u{FF9F}'
+        | '\u{20000}'..='\u{2FFFF}' => CharClass::Cjk,
+        c if c.is_alphanumeric() => CharClass::Word,
+        _ => CharClass::Other,
+    }
+}
+
+/// 全角英数字と大文字を揃える。1文字を1文字に変換するため元の位置を保てる
+fn normalize_char(c: char) -> char {
+    let c = match c {
+        '\u{FF01}'..='\u{FF5E}' => char::from_u32(c as u32 - 0xFEE0).unwrap_or(c),
+        '\u{3000}' => ' ',
+        c => c,
+    };
+    let mut lower = c.to_lowercase();
+    match (lower.next(), lower.next()) {
+        (Some(lower), None) => lower,
+        _ => c,
+    }
+}
+
+/// 正規化した文字の種類ごとの並び。記号や空白は含めない
+fn runs(text: &str) -> Vec<(CharClass, Vec<char>)> {
+    let mut runs: Vec<(CharClass, Vec<char>)> = Vec::new();
+    for c in text.chars().map(normalize_char) {
+        let class = classify(c);
+        match runs.last_mut() {
+            Some((last, chars)) if *last == class => chars.push(c),
+            _ => runs.push((class, vec![c])),
+        }
+    }
+    runs.retain(|(class, _)| *class != CharClass::Other);
+    runs
+}
+
+/// 文字列を索引の語に分割する
+///
+/// 英数字は単語を、分かち書きしない文字は隣接する2文字を語とする。1文字でも検索できるよう、
+/// 索引には1文字ずつの語も含める。検索語では1文字だけの並びに限り1文字の語を使う
+fn terms(text: &str, for_query: bool) -> Vec<String> {
+    let mut terms = Vec::new();
+    for (class, chars) in runs(text) {
+        if class == CharClass::Word {
+            terms.push(chars.iter().collect());
+            continue;
+        }
+        if !for_query || chars.len() == 1 {
+            terms.extend(chars.iter().map(char::to_string));
+        }
+        terms.extend(chars.windows(2).map(|pair| pair.iter().collect()));
+    }
+    terms
+}
+
+/// 投稿を索引する語と、出現した回数に基づく重み
+pub fn index_terms(post: &Post) -> BTreeMap<String, u32> {
+    let mut weights = BTreeMap::new();
+    for term in terms(post.title.as_str(), false) {
+        *weights.entry(term).or_insert(0) += TITLE_WEIGHT;
+    }
+    for term in terms(post.content.as_str(), false) {
+        *weights.entry(term).or_insert(0) += 1;
+    }
+    weights
+}
+
+/// 正規化した文字列と、各文字の元の文字列での位置。連続する空白は1文字にまとめる
+struct Normalized {
+    chars: Vec<char>,
+    offsets: Vec<usize>,
+}
+
+impl Normalized {
+    fn new(text: &str) -> Self {
+        let mut chars = Vec::new();
+        let mut offsets = Vec::new();
+        for (offset, c) in text.chars().map(normalize_char).enumerate() {
+            let c = if c.is_whitespace() { ' ' } else { c };
+            if c == ' ' && chars.last() == Some(&' ') {
+                continue;
+            }
+            chars.push(c);
+            offsets.push(offset);
+        }
+        Self { chars, offsets }
+    }
+
+    /// `pattern`が現れる範囲を元の文字の位置で返す。単語の途中から始まる、または途中で終わる位置は除く
+    fn find(&self, pattern: &[char]) -> Vec<Range<usize>> {
+        let is_word = |c: Option<&char>| c.is_some_and(|&c| classify(c) == CharClass::Word);
+        let (Some(first), Some(last)) = (pattern.first(), pattern.last()) else {
+            return Vec::new();
+        };
+        let mut found = Vec::new();
+        for (start, window) in self.chars.windows(pattern.len()).enumerate() {
+            let end = start + pattern.len();
+            if window != pattern
+                || (is_word(Some(first)) && start > 0 && is_word(self.chars.get(start - 1)))
+                || (is_word(Some(last)) && is_word(self.chars.get(end)))
+            {
+                continue;
+            }
+            found.push(self.offsets[start]..self.offsets[end - 1] + 1);
+        }
+        found
+    }
+}
+
+/// 文字列の断片。`highlighted`の断片は検索した語に一致する
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fragment {
+    pub text: String,
+    pub highlighted: bool,
+}
+
+/// 解釈した検索語
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchQuery {
+    /// 投稿がすべて含む必要がある正規化した文字列
+    patterns: Vec<Vec<char>>,
+    /// 索引を引く語。重複は除く
+    terms: Vec<String>,
+}
+
+impl SearchQuery {
+    /// 検索語を解釈する。`"`で囲んだ部分は語句として扱う。索引を引ける語がない場合は`None`
+    pub fn parse(q: &str) -> Option<Self> {
+        let mut patterns: Vec<Vec<char>> = Vec::new();
+        for (i, part) in q.split('"').enumerate() {
+            if i % 2 == 1 {
+                if !terms(part, true).is_empty() {
+                    patterns.push(Normalized::new(part.trim()).chars);
+                }
+            } else {
+                patterns.extend(runs(part).into_iter().map(|(_, chars)| chars));
+            }
+        }
+        let mut unique: Vec<String> = Vec::new();
+        for pattern in &patterns {
+            for term in terms(&pattern.iter().collect::<String>(), true) {
+                if !unique.contains(&term) {
+                    unique.push(term);
+                }
+            }
+        }
+        if unique.is_empty() {
+            return None;
+        }
+        patterns.dedup();
+        Some(Self {
+            patterns,
+            terms: unique,
+        })
+    }
+
+    pub fn terms(&self) -> &[String] {
+        &self.terms
+    }
+
+    /// タイトルか本文にすべての語句を含むか
+    ///
+    /// 索引は語の位置を持たないため、分かち書きしない文字列や語句が連続して現れるかはここで確かめる
+    pub fn matches(&self, post: &Post) -> bool {
+        let title = Normalized::new(post.title.as_str());
+        let content = Normalized::new(post.content.as_str());
+        self.patterns
+            .iter()
+            .all(|pattern| !title.find(pattern).is_empty() || !content.find(pattern).is_empty())
+    }
+
+    /// 一致した部分の範囲を元の文字の位置で返す。重なる範囲はまとめる
+    fn ranges(&self, text: &str) -> Vec<Range<usize>> {
+        let normalized = Normalized::new(text);
+        let mut found: Vec<Range<usize>> = self
+            .patterns
+            .iter()
+            .flat_map(|pattern| normalized.find(pattern))
+            .collect();
+        found.sort_by_key(|range| range.start);
+        let mut merged: Vec<Range<usize>> = Vec::new();
+        for range in found {
+            match merged.last_mut() {
+                Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+                _ => merged.push(range),
+            }
+        }
+        merged
+    }
+
+    /// 文字列全体を一致した部分で区切る
+    pub fn highlight(&self, text: &str) -> Vec<Fragment> {
+        let chars: Vec<char> = text.chars().collect();
+        fragments(&chars, &self.ranges(text), 0..chars.len())
+    }
+
+    /// 最初に一致した部分の前後を抜き出し、一致した部分で区切る。一致しない場合は先頭を抜き出す
+    pub fn snippet(&self, text: &str) -> Vec<Fragment> {
+        let chars: Vec<char> = text.chars().collect();
+        let ranges = self.ranges(text);
+        let start = ranges
+            .first()
+            .map_or(0, |range| range.start.saturating_sub(SNIPPET_CONTEXT));
+        let end = chars.len().min(start + SNIPPET_LENGTH);
+        // 末尾に近い場合は抜粋の長さを保つよう前に広げる
+        let start = start.min(end.saturating_sub(SNIPPET_LENGTH));
+        let mut fragments = fragments(&chars, &ranges, start..end);
+        if start > 0 {
+            fragments.insert(0, plain("…"));
+        }
+        if end < chars.len() {
+            fragments.push(plain("…"));
+        }
+        merge_plain(fragments)
+    }
+}
+
+fn plain(text: &str) -> Fragment {
+    Fragment {
+        text: text.to_string(),
+        highlighted: false,
+    }
+}
+
+/// `window`の範囲の文字を、`ranges`の部分を強調して区切る
+fn fragments(chars: &[char], ranges: &[Range<usize>], window: Range<usize>) -> Vec<Fragment> {
+    let mut fragments = Vec::new();
+    let mut push = |range: Range<usize>, highlighted: bool| {
+        if !range.is_empty() {
+            fragments.push(Fragment {
+                text: chars[range].iter().collect(),
+                highlighted,
+            });
+        }
+    };
+    let mut at = window.start;
+    for range in ranges {
+        let start = range.start.clamp(at, window.end);
+        let end = range.end.clamp(start, window.end);
+        push(at..start, false);
+        push(start..end, true);
+        at = end;
+    }
+    push(at..window.end, false);
+    fragments
+}
+
+/// 隣り合う強調しない断片をつなげる
+fn merge_plain(fragments: Vec<Fragment>) -> Vec<Fragment> {
+    let mut merged: Vec<Fragment> = Vec::new();
+    for fragment in fragments {
+        match merged.last_mut() {
+            Some(last) if !last.highlighted && !fragment.highlighted => {
+                last.text.push_str(&fragment.text)
+            }
+            _ => merged.push(fragment),
+        }
+    }
+    merged
+}
+
+/// 一致した投稿とスコア
+#[derive(Debug, Clone)]
+pub struct SearchHit {
+    pub post: Post,
+    pub score: f64,
+}
+
+/// 語の重みから求めた関連度に、新しさによる加点を掛ける。作成した直後の投稿は関連度の2倍になる
+fn score(weights: &[u32], created_at: DateTime<Utc>, now: DateTime<Utc>) -> f64 {
+    let relevance: f64 = weights
+        .iter()
+        .map(|&weight| f64::from(weight).ln_1p())
+        .sum();
+    let age_days = (now - created_at).num_seconds().max(0) as f64 / 86400.0;
+    relevance * (1.0 + 0.5_f64.powf(age_days / RECENCY_HALF_LIFE_DAYS))
+}
+
+/// 語句を連続して含まない候補を除き、スコアの高い順に並べる。同じスコアでは新しい順にする
+pub fn rank(
+    query: &SearchQuery,
+    candidates: Vec<SearchCandidate>,
+    now: DateTime<Utc>,
+) -> Vec<SearchHit> {
+    let mut hits: Vec<SearchHit> = candidates
+        .into_iter()
+        .filter(|candidate| query.matches(&candidate.post))
+        .map(|candidate| SearchHit {
+            score: score(&candidate.weights, candidate.post.created_at, now),
+            post: candidate.post,
+        })
+        .collect();
+    hits.sort_by(|a, b| {
+        b.score
+            .total_cmp(&a.score)
+            .then((b.post.created_at, b.post.id).cmp(&(a.post.created_at, a.post.id)))
+    });
+    hits
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::value_object::{Content, Title};
+    use chrono::Duration;
+    use uuid::Uuid;
+
+    fn post(title: &str, content: &str) -> Post {
+        Post::new(
+            1,
+            Uuid::nil(),
+            Title::parse(title).unwrap(),
+            Content::parse(content).unwrap(),
+            None,
+        )
+    }
+
+    fn highlighted(fragments: &[Fragment]) -> Vec<&str> {
+        fragments
+            .iter()
+            .filter(|fragment| fragment.highlighted)
+            .map(|fragment| fragment.text.as_str())
+            .collect()
+    }
+
+    mod index_terms {
+        use super::*;
+
+        #[test]
+        fn test_cjk_bigrams() {
+            let weights = index_terms(&post("東京都", "Rust入門"));
+
+            assert_eq!(
+                weights.keys().collect::<Vec<_>>(),
+                ["rust", "京", "京都", "入", "入門", "東", "東京", "都", "門"]
+            );
+            assert_eq!(weights["東京"], 3);
+            assert_eq!(weights["入門"], 1);
+        }
+
+        #[test]
+        fn test_normalizes_width_and_case() {
+            let weights = index_terms(&post("ＲＵＳＴ", "Rust rust"));
+
+            assert_eq!(weights.len(), 1);
+            assert_eq!(weights["rust"], 5);
+        }
+    }
+
+    mod parse {
+        use super::*;
+
+        #[test]
+        fn test_terms() {
+            let query = SearchQuery::parse("猫 東京都 Rust \"hello  world\"").unwrap();
+
+            assert_eq!(
+                query.terms(),
+                ["猫", "東京", "京都", "rust", "hello", "world"]
+            );
+        }
+
+        #[test]
+        fn test_without_terms() {
+            assert_eq!(SearchQuery::parse(" 、。!? \"\" "), None);
+        }
+    }
+
+    mod matches {
+        use super::*;
+
+        #[test]
+        fn test_cjk_must_be_contiguous() {
+            let query = SearchQuery::parse("東京都").unwrap();
+
+            assert!(query.matches(&post("title", "東京都に住む")));
+            assert!(!query.matches(&post("title", "東京の京都")));
+        }
+
+        #[test]
+        fn test_phrase() {
+            let query = SearchQuery::parse("\"Hello World\"").unwrap();
+
+            assert!(query.matches(&post("title", "say hello\n world")));
+            assert!(!query.matches(&post("title", "world hello")));
+        }
+
+        #[test]
+        fn test_word_boundary() {
+            let query = SearchQuery::parse("cat").unwrap();
+
+            assert!(query.matches(&post("Cat", "content")));
+            assert!(!query.matches(&post("category", "content")));
+        }
+    }
+
+    mod snippet {
+        use super::*;
+
+        #[test]
+        fn test_highlights_around_first_match() {
+            let query = SearchQuery::parse("検索").unwrap();
+            let text = format!("{}全文検索の例{}", "あ".repeat(50), "い".repeat(200));
+
+            let snippet = query.snippet(&text);
+
+            assert_eq!(highlighted(&snippet), ["検索"]);
+            assert_eq!(snippet[0].text, format!("…{}全文", "あ".repeat(28)));
+            assert!(snippet.last().unwrap().text.ends_with("い…"));
+            let length: usize = snippet.iter().map(|f| f.text.chars().count()).sum();
+            assert_eq!(length, SNIPPET_LENGTH + 2);
+        }
+
+        #[test]
+        fn test_short_text() {
+            let query = SearchQuery::parse("ＲＵＳＴ").unwrap();
+
+            let snippet = query.snippet("I like Rust and rust.");
+
+            assert_eq!(highlighted(&snippet), ["Rust", "rust"]);
+            assert_eq!(
+                snippet.iter().map(|f| f.text.as_str()).collect::<String>(),
+                "I like Rust and rust."
+            );
+        }
+    }
+
+    mod rank {
+        use super::*;
+
+        #[test]
+        fn test_relevance_and_recency() {
+            let query = SearchQuery::parse("rust").unwrap();
+            let now = Utc::now();
+            let candidate = |title: &str, weight: u32, age_days: i64| {
+                let mut post = post(title, "rust");
+                post.created_at = now - Duration::days(age_days);
+                SearchCandidate {
+                    post,
+                    weights: vec![weight],
+                }
+            };
+
+            let hits = rank(
+                &query,
+                vec![
+                    candidate("old", 1, 365),
+                    candidate("rust", 4, 365),
+                    candidate("new", 1, 0),
+                    candidate("yesterday", 1, 1),
+                ],
+                now,
+            );
+
+            let titles: Vec<&str> = hits.iter().map(|hit| hit.post.title.as_str()).collect();
+            assert_eq!(titles, ["rust", "new", "yesterday", "old"]);
+        }
+    }
+}
@@ -0,0 +1,474 @@
+use crate::config::WebhooksConfig;
+use crate::entity::{Webhook, WebhookDelivery};
+use crate::repository::{RepositoryError, WebhookRepository};
+use crate::value_object::{DeliveryStatus, WebhookEvent};
+use argon2::password_hash::rand_core::{OsRng, RngCore};
+use chrono::{DateTime, Duration, Utc};
+use futures_util::future;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::Notify;
+use uuid::Uuid;
+
+/// `sha256=`に続けて`{timestamp}.{body}`のHMAC-SHA256を16進数で表したもの
+pub const SIGNATURE_HEADER: &str = "X-Board-Signature";
+/// 署名した時刻(UNIX時間の秒)。受信側はこれが古い配信を拒否してリプレイを防ぐ
+pub const TIMESTAMP_HEADER: &str = "X-Board-Timestamp";
+pub const EVENT_HEADER: &str = "X-Board-Event";
+/// 配信のID。再試行でも変わらないため、受信側で重複を除くのに使える
+pub const DELIVERY_HEADER: &str = "X-Board-Delivery";
+
+/// 一度に試行する配信の数
+const BATCH_SIZE: usize = 50;
+
+/// 署名の鍵を生成する
+pub fn generate_secret() -> String {
+    let mut bytes = [0u8; 32];
+    OsRng.fill_bytes(&mut bytes);
+    hex::encode(bytes)
+}
+
+/// `X-Board-Signature`の値
+pub fn sign(secret: &str, timestamp: i64, body: &str) -> String {
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC accepts keys of any length");
+    mac.update(format!("{}.", timestamp).as_bytes());
+    mac.update(body.as_bytes());
+    format!("sha256={}", hex::encode(mac.finalize().into_bytes()))
+}
+
+/// `attempts`回目の試行に失敗してから再試行するまでの時間。失敗するたびに倍になる
+///
+/// 時間として表せない長さの場合は`None`を返す
+fn backoff(config: &WebhooksConfig, attempts: u32) -> Option<Duration> {
+    let doublings = attempts.saturating_sub(1).min(31);
+    let seconds = config
+        .backoff
+        .saturating_mul(1 << doublings)
+        .min(config.max_backoff.max(config.backoff));
+    i64::try_from(seconds).ok().and_then(Duration::try_seconds)
+}
+
+/// 1回の試行の結果
+#[derive(Debug)]
+struct Attempt {
+    status_code: Option<u16>,
+    error: Option<String>,
+}
+
+/// イベントの配信を記録し、バックグラウンドで送信先に送る
+pub struct WebhookDispatcher {
+    webhooks: Arc<dyn WebhookRepository>,
+    config: WebhooksConfig,
+    client: reqwest::Client,
+    wake: Notify,
+}
+
+impl WebhookDispatcher {
+    pub fn new(webhooks: Arc<dyn WebhookRepository>, config: WebhooksConfig) -> Self {
+        let client = reqwest::Client::builder()
+            .timeout(std::time::Duration::from_secs(config.timeout))
+            // 転送先は登録したURLと異なるため、署名した配信を送らない
+            .redirect(reqwest::redirect::Policy::none())
+            .build()
+            .expect("HTTP client configuration is valid");
+        Self {
+            webhooks,
+            config,
+            client,
+            wake: Notify::new(),
+        }
+    }
+
+    /// イベントを通知するすべての送信先への配信を記録し、配信するタスクを起こす。記録した配信の数を返す
+    pub fn enqueue(&self, event: WebhookEvent, payload: &str) -> Result<usize, RepositoryError> {
+        let mut count = 0;
+        for webhook in self.webhooks.list()? {
+            if webhook.accepts(event) {
+                let delivery = WebhookDelivery::new(webhook.id, event, payload.to_string());
+                self.webhooks.insert_delivery(delivery)?;
+                count += 1;
+            }
+        }
+        if count > 0 {
+            self.wake();
+        }
+        Ok(count)
+    }
+
+    /// 試行する予定の配信があることを配信するタスクに知らせる
+    pub fn wake(&self) {
+        self.wake.notify_one();
+    }
+
+    /// 予定を過ぎた配信を試行し、試行した数を返す
+    pub async fn deliver_due(&self, now: DateTime<Utc>) -> Result<usize, RepositoryError> {
+        let mut total = 0;
+        loop {
+            let due = self.webhooks.due_deliveries(now, BATCH_SIZE)?;
+            let mut webhooks: HashMap<Uuid, Option<Webhook>> = HashMap::new();
+            for delivery in &due {
+                if let Entry::Vacant(entry) = webhooks.entry(delivery.webhook_id) {
+                    entry.insert(self.webhooks.find(delivery.webhook_id)?);
+                }
+            }
+            let attempts = due.iter().filter_map(|delivery| {
+                // 削除された送信先の配信は記録ごと削除されている
+                let webhook = webhooks.get(&delivery.webhook_id)?.as_ref()?;
+                Some(async move { (delivery, self.attempt(webhook, delivery, now).await) })
+            });
+            for (delivery, attempt) in future::join_all(attempts).await {
+                self.record(delivery.clone(), attempt, now)?;
+                total += 1;
+            }
+            if due.len() < BATCH_SIZE {
+                return Ok(total);
+            }
+        }
+    }
+
+    async fn attempt(
+        &self,
+        webhook: &Webhook,
+        delivery: &WebhookDelivery,
+        now: DateTime<Utc>,
+    ) -> Attempt {
+        let timestamp = now.timestamp();
+        let response = self
+            .client
+            .post(webhook.url.as_str())
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .header(
+                SIGNATURE_HEADER,
+                sign(&webhook.secret, timestamp, &delivery.payload),
+            )
+            .header(TIMESTAMP_HEADER, timestamp)
+            .header(EVENT_HEADER, delivery.event.as_str())
+            .header(DELIVERY_HEADER, delivery.id.to_string())
+            .body(delivery.payload.clone())
+            .send()
+            .await;
+        match response {
+            Ok(response) if response.status().is_success() => Attempt {
+                status_code: Some(response.status().as_u16()),
+                error: None,
+            },
+            Ok(response) => Attempt {
+                status_code: Some(response.status().as_u16()),
+                error: Some(format!("unexpected status {}", response.status())),
+            },
+            Err(e) => Attempt {
+                status_code: None,
+                error: Some(e.without_url().to_string()),
+            },
+        }
+    }
+
+    /// 試行の結果を記録し、失敗した場合は再試行を予定する
+    fn record(
+        &self,
+        mut delivery: WebhookDelivery,
+        attempt: Attempt,
+        now: DateTime<Utc>,
+    ) -> Result<(), RepositoryError> {
+        delivery.attempts += 1;
+        delivery.last_attempt_at = Some(now);
+        delivery.last_status_code = attempt.status_code;
+        delivery.status = match &attempt.error {
+            None => DeliveryStatus::Succeeded,
+            Some(_) if delivery.attempts >= self.config.max_attempts => DeliveryStatus::Dead,
+            Some(_) => match backoff(&self.config, delivery.attempts)
+                .and_then(|backoff| now.checked_add_signed(backoff))
+            {
+                Some(next_attempt_at) => {
+                    delivery.next_attempt_at = next_attempt_at;
+                    DeliveryStatus::Pending
+                }
+                // 再試行の予定が日時の範囲を超える場合は再試行しない
+                None => DeliveryStatus::Dead,
+            },
+        };
+        match &attempt.error {
+            None => tracing::debug!(delivery_id = %delivery.id, "delivered webhook"),
+            Some(error) => tracing::warn!(
+                delivery_id = %delivery.id,
+                attempts = delivery.attempts,
+                status = %delivery.status,
+                error,
+                "webhook delivery failed"
+            ),
+        }
+        delivery.last_error = attempt.error;
+        self.webhooks.update_delivery(delivery)?;
+        Ok(())
+    }
+
+    /// 配信を続ける。新しい配信が記録されるか、`poll_interval`ごとに予定を確認する
+    pub async fn run(&self) {
+        let interval = std::time::Duration::from_secs(self.config.poll_interval);
+        loop {
+            if let Err(e) = self.deliver_due(Utc::now()).await {
+                tracing::warn!(error = %e, "failed to deliver webhooks");
+            }
+            tokio::select! {
+                _ = self.wake.notified() => {}
+                _ = tokio::time::sleep(interval) => {}
+            }
+        }
+    }
+
+    /// 保持する期間を過ぎた成功した配信の記録を削除し、削除した件数を返す
+    pub fn purge(&self, now: DateTime<Utc>) -> Result<usize, RepositoryError> {
+        // 期間が日時の範囲を超える場合は削除する記録がない
+        match i64::try_from(self.config.retention)
+            .ok()
+            .and_then(Duration::try_seconds)
+            .and_then(|retention| now.checked_sub_signed(retention))
+        {
+            Some(before) => self.webhooks.purge_deliveries(before),
+            None => Ok(0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repository::memory::InMemoryWebhookRepository;
+    use crate::repository::DeliveryQuery;
+    use crate::value_object::WebhookUrl;
+    use axum::extract::State;
+    use axum::http::{HeaderMap, StatusCode};
+    use axum::routing::post;
+    use axum::Router;
+    use std::sync::atomic::{AtomicU16, Ordering};
+    use std::sync::Mutex;
+
+    /// 受信した配信を記録し、指定したステータスコードを返す送信先
+    #[derive(Clone)]
+    struct Receiver {
+        status: Arc<AtomicU16>,
+        received: Arc<Mutex<Vec<(HeaderMap, String)>>>,
+    }
+
+    async fn receive(
+        State(receiver): State<Receiver>,
+        headers: HeaderMap,
+        body: String,
+    ) -> StatusCode {
+        receiver.received.lock().unwrap().push((headers, body));
+        StatusCode::from_u16(receiver.status.load(Ordering::SeqCst)).unwrap()
+    }
+
+    async fn receiver(status: u16) -> (Receiver, String) {
+        let receiver = Receiver {
+            status: Arc::new(AtomicU16::new(status)),
+            received: Arc::new(Mutex::new(Vec::new())),
+        };
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/hook", listener.local_addr().unwrap());
+        let router = Router::new()
+            .route("/hook", post(receive))
+            .with_state(receiver.clone());
+        tokio::spawn(async move { axum::serve(listener, router).await.unwrap() });
+        (receiver, url)
+    }
+
+    fn dispatcher(url: &str, events: Vec<WebhookEvent>) -> (WebhookDispatcher, Webhook) {
+        let repo = Arc::new(InMemoryWebhookRepository::new());
+        let webhook = Webhook::new(
+            WebhookUrl::parse(url).unwrap(),
+            events,
+            generate_secret(),
+            1,
+        );
+        repo.insert(webhook.clone()).unwrap();
+        let config = WebhooksConfig {
+            max_attempts: 3,
+            backoff: 10,
+            max_backoff: 15,
+            ..Default::default()
+        };
+        (WebhookDispatcher::new(repo, config), webhook)
+    }
+
+    fn deliveries(dispatcher: &WebhookDispatcher) -> Vec<WebhookDelivery> {
+        dispatcher
+            .webhooks
+            .list_deliveries(&DeliveryQuery {
+                webhook_id: None,
+                status: None,
+                limit: 10,
+            })
+            .unwrap()
+    }
+
+    mod sign {
+        use super::*;
+
+        #[test]
+        fn test_signs_timestamp_and_body() {
+            let signature = sign("secret", 1700000000, r#"{"a":1}"#);
+
+            let mut mac = Hmac::<Sha256>::new_from_slice(b"secret").unwrap();
+            mac.update(br#"1700000000.{"a":1}"#);
+            assert_eq!(
+                signature,
+                format!("sha256={}", hex::encode(mac.finalize().into_bytes()))
+            );
+            assert_ne!(signature, sign("secret", 1700000001, r#"{"a":1}"#));
+            assert_ne!(signature, sign("other", 1700000000, r#"{"a":1}"#));
+        }
+    }
+
+    mod backoff {
+        use super::*;
+
+        #[test]
+        fn test_doubles_up_to_max() {
+            let config = WebhooksConfig {
+                backoff: 30,
+                max_backoff: 200,
+                ..Default::default()
+            };
+
+            let backoffs: Vec<i64> = (1..=5)
+                .map(|attempts| backoff(&config, attempts).unwrap().num_seconds())
+                .collect();
+
+            assert_eq!(backoffs, vec![30, 60, 120, 200, 200]);
+            assert_eq!(backoff(&config, u32::MAX).unwrap().num_seconds(), 200);
+        }
+
+        #[test]
+        fn test_unrepresentable_backoff() {
+            let config = WebhooksConfig {
+                backoff: u64::MAX,
+                max_backoff: u64::MAX,
+                ..Default::default()
+            };
+
+            assert_eq!(backoff(&config, 1), None);
+        }
+    }
+
+    mod purge {
+        use super::*;
+
+        #[test]
+        fn test_unrepresentable_retention() {
+            let dispatcher = WebhookDispatcher::new(
+                Arc::new(InMemoryWebhookRepository::new()),
+                WebhooksConfig {
+                    retention: u64::MAX,
+                    ..Default::default()
+                },
+            );
+
+            assert_eq!(dispatcher.purge(Utc::now()).unwrap(), 0);
+        }
+    }
+
+    mod deliver_due {
+        use super::*;
+
+        #[tokio::test]
+        async fn test_delivers_signed_payload() {
+            let (receiver, url) = receiver(204).await;
+            let (dispatcher, webhook) = dispatcher(&url, vec![WebhookEvent::PostCreated]);
+
+            assert_eq!(
+                dispatcher.enqueue(WebhookEvent::UserCreated, "{}").unwrap(),
+                0
+            );
+            assert_eq!(
+                dispatcher
+                    .enqueue(WebhookEvent::PostCreated, r#"{"a":1}"#)
+                    .unwrap(),
+                1
+            );
+            let now = Utc::now();
+            assert_eq!(dispatcher.deliver_due(now).await.unwrap(), 1);
+
+            let (headers, body) = receiver.received.lock().unwrap().pop().unwrap();
+            let delivery = deliveries(&dispatcher).pop().unwrap();
+            assert_eq!(body, r#"{"a":1}"#);
+            assert_eq!(headers["content-type"], "application/json");
+            assert_eq!(headers["x-board-event"], "post_created");
+            assert_eq!(
+                headers["x-board-delivery"],
+                delivery.id.to_string().as_str()
+            );
+            assert_eq!(
+                headers["x-board-timestamp"],
+                now.timestamp().to_string().as_str()
+            );
+            assert_eq!(
+                headers["x-board-signature"],
+                sign(&webhook.secret, now.timestamp(), &body).as_str()
+            );
+            assert_eq!(delivery.status, DeliveryStatus::Succeeded);
+            assert_eq!(delivery.attempts, 1);
+            assert_eq!(delivery.last_status_code, Some(204));
+            assert_eq!(dispatcher.deliver_due(now).await.unwrap(), 0);
+        }
+
+        #[tokio::test]
+        async fn test_retries_with_backoff_until_dead() {
+            let (receiver, url) = receiver(500).await;
+            let (dispatcher, _) = dispatcher(&url, Vec::new());
+            dispatcher.enqueue(WebhookEvent::UserCreated, "{}").unwrap();
+            let mut now = Utc::now();
+
+            let mut delays = Vec::new();
+            for _ in 0..3 {
+                assert_eq!(dispatcher.deliver_due(now).await.unwrap(), 1);
+                // 再試行の予定より前には試行しない
+                assert_eq!(dispatcher.deliver_due(now).await.unwrap(), 0);
+                let delivery = deliveries(&dispatcher).pop().unwrap();
+                if delivery.status == DeliveryStatus::Pending {
+                    delays.push((delivery.next_attempt_at - now).num_seconds());
+                    now = delivery.next_attempt_at;
+                }
+            }
+
+            let delivery = deliveries(&dispatcher).pop().unwrap();
+            assert_eq!(delays, vec![10, 15]);
+            assert_eq!(delivery.status, DeliveryStatus::Dead);
+            assert_eq!(delivery.attempts, 3);
+            assert_eq!(delivery.last_status_code, Some(500));
+            assert_eq!(
+                delivery.last_error.as_deref(),
+                Some("unexpected status 500 Internal Server Error")
+            );
+            assert_eq!(receiver.received.lock().unwrap().len(), 3);
+            assert_eq!(
+                dispatcher
+                    .deliver_due(now + Duration::days(1))
+                    .await
+                    .unwrap(),
+                0
+            );
+        }
+
+        #[tokio::test]
+        async fn test_unreachable_receiver() {
+            // 待ち受けを閉じたアドレスには接続できない
+            let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+            let url = format!("http://{}/hook", listener.local_addr().unwrap());
+            drop(listener);
+            let (dispatcher, _) = dispatcher(&url, Vec::new());
+            dispatcher.enqueue(WebhookEvent::PostDeleted, "{}").unwrap();
+
+            dispatcher.deliver_due(Utc::now()).await.unwrap();
+
+            let delivery = deliveries(&dispatcher).pop().unwrap();
+            assert_eq!(delivery.status, DeliveryStatus::Pending);
+            assert_eq!(delivery.last_status_code, None);
+            assert!(delivery.last_error.is_some());
+        }
+    }
+}
@@ -0,0 +1,217 @@
+//! `GET /events`のServer-Sent Events。生成したルーターはストリームを返せないため、ここで定義して合成する
+
+use crate::service::events::{BoardEvent, EventFilter};
+use crate::value_object::{PostId, UserId};
+use crate::{error_response, event_model, problem_response, unauthorized, ApiImpl};
+use axum::extract::{Query, State};
+use axum::http::{HeaderMap, HeaderName};
+use axum::response::sse::{Event, KeepAlive, Sse};
+use axum::response::{IntoResponse, Response};
+use axum::routing::get;
+use axum::Router;
+use chrono::Utc;
+use futures_util::stream;
+use openapi::problem;
+use serde::Deserialize;
+
+const LAST_EVENT_ID: HeaderName = HeaderName::from_static("last-event-id");
+
+pub fn router(api: ApiImpl) -> Router {
+    Router::new().route("/events", get(events)).with_state(api)
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct EventsQuery {
+    thread_id: Option<PostId>,
+    user_id: Option<UserId>,
+    #[serde(rename = "access_token")]
+    access_token: Option<String>,
+}
+
+/// 条件に一致するイベントを配信する。ストリームはアクセストークンの有効期限で終える
+async fn events(
+    State(api): State<ApiImpl>,
+    Query(query): Query<EventsQuery>,
+    headers: HeaderMap,
+) -> Response {
+    let user = match api.authenticate_request(&headers, query.access_token.as_deref()) {
+        Ok(Ok(user)) => user,
+        Ok(Err(www_authenticate)) => return unauthorized(www_authenticate),
+        Err(e) => return error_response(e),
+    };
+    let Ok(last_event_id) = last_event_id(&headers) else {
+        return problem_response(problem::header_problem(
+            "Last-Event-ID",
+            "must be the id of a received event",
+        ));
+    };
+
+    let filter = EventFilter {
+        thread_id: query.thread_id,
+        user_id: query.user_id,
+    };
+    let subscription = api.events.subscribe(last_event_id, filter);
+    let remaining = (user.expires_at - Utc::now()).to_std().unwrap_or_default();
+    let deadline = tokio::time::Instant::now() + remaining;
+    let stream = stream::unfold(subscription, move |mut subscription| async move {
+        tokio::select! {
+            event = subscription.next() => {
+                let event = event?;
+                Some((sse_event(&event), subscription))
+            }
+            _ = tokio::time::sleep_until(deadline) => None,
+        }
+    });
+    Sse::new(stream)
+        .keep_alive(KeepAlive::default())
+        .into_response()
+}
+
+/// 再接続したクライアントが送る`Last-Event-ID`
+fn last_event_id(headers: &HeaderMap) -> Result<Option<u64>, ()> {
+    headers
+        .get(LAST_EVENT_ID)
+        .map(|value| {
+            value
+                .to_str()
+                .ok()
+                .and_then(|value| value.trim().parse().ok())
+                .ok_or(())
+        })
+        .transpose()
+}
+
+fn sse_event(event: &BoardEvent) -> Result<Event, axum::Error> {
+    Event::default()
+        .id(event.id.to_string())
+        .event(event.kind.to_string())
+        .json_data(event_model(event))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entity;
+    use crate::service::events::EventKind;
+    use crate::tests::api;
+    use crate::value_object::{Content, Role, Title};
+    use axum::http::header::{AUTHORIZATION, CONTENT_TYPE, WWW_AUTHENTICATE};
+    use axum::http::{HeaderValue, StatusCode};
+    use futures_util::StreamExt;
+
+    fn token(api: &ApiImpl) -> String {
+        api.keys
+            .create_token("1", Role::Member, api.config.access_token_lifetime())
+            .unwrap()
+    }
+
+    fn headers(api: &ApiImpl, last_event_id: Option<&str>) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            AUTHORIZATION,
+            HeaderValue::from_str(&format!("Bearer {}", token(api))).unwrap(),
+        );
+        if let Some(id) = last_event_id {
+            headers.insert(LAST_EVENT_ID, HeaderValue::from_str(id).unwrap());
+        }
+        headers
+    }
+
+    fn query() -> Query<EventsQuery> {
+        Query(EventsQuery {
+            thread_id: None,
+            user_id: None,
+            access_token: None,
+        })
+    }
+
+    fn publish(api: &ApiImpl) -> u64 {
+        let post = entity::Post::new(
+            1,
+            uuid::Uuid::nil(),
+            Title::parse("title").unwrap(),
+            Content::parse("content").unwrap(),
+            None,
+        );
+        api.events.publish(
+            EventKind::PostCreated,
+            post.id,
+            post,
+            Some("name".to_string()),
+        )
+    }
+
+    mod events {
+        use super::*;
+
+        #[tokio::test]
+        async fn test_missing_authorization_is_challenged() {
+            let api = api();
+
+            let response = events(State(api), query(), HeaderMap::new()).await;
+
+            assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+            assert!(response.headers().contains_key(WWW_AUTHENTICATE));
+        }
+
+        #[tokio::test]
+        async fn test_access_token_query() {
+            let api = api();
+            let access_token = Some(token(&api));
+
+            let response = events(
+                State(api.clone()),
+                Query(EventsQuery {
+                    access_token: access_token.clone(),
+                    ..query().0
+                }),
+                HeaderMap::new(),
+            )
+            .await;
+            let duplicate = events(
+                State(api.clone()),
+                Query(EventsQuery {
+                    access_token,
+                    ..query().0
+                }),
+                headers(&api, None),
+            )
+            .await;
+
+            assert_eq!(response.status(), StatusCode::OK);
+            assert_eq!(duplicate.status(), StatusCode::BAD_REQUEST);
+        }
+
+        #[tokio::test]
+        async fn test_invalid_last_event_id() {
+            let api = api();
+            let headers = headers(&api, Some("latest"));
+
+            let response = events(State(api), query(), headers).await;
+
+            assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+            assert_eq!(response.headers()[CONTENT_TYPE], "application/problem+json");
+        }
+
+        #[tokio::test]
+        async fn test_resumes_after_last_event_id() {
+            let api = api();
+            let first = publish(&api);
+            let second = publish(&api);
+            let headers = headers(&api, Some(&first.to_string()));
+
+            let response = events(State(api), query(), headers).await;
+
+            assert_eq!(response.status(), StatusCode::OK);
+            assert_eq!(response.headers()[CONTENT_TYPE], "text/event-stream");
+            let mut body = response.into_body().into_data_stream();
+            let chunk = body.next().await.unwrap().unwrap();
+            let chunk = std::str::from_utf8(&chunk).unwrap();
+            assert!(chunk.contains(&format!("id: {}\n", second)));
+            assert!(chunk.contains("event: post_created\n"));
+            assert!(chunk.contains("\"type\":\"post_created\""));
+            assert!(chunk.contains("\"authorName\":\"name\""));
+        }
+    }
+}
@@ -1,10 +1,609 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+use thiserror::Error;
+use unicode_normalization::UnicodeNormalization;
 use uuid::Uuid;
 
 pub type UserId = i64;
 pub type PostId = Uuid;
-pub type Name = String;
-pub type Email = String;
-pub type Password = String;
-pub type Title = String;
-pub type Content = String;
+pub type BoardId = Uuid;
 pub type HashedPassword = String;
+
+/// 値オブジェクトの規則に違反した理由
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ValueError {
+    #[error("must not be empty")]
+    Empty,
+    #[error("must be at most {max} characters")]
+    TooLong { max: usize },
+    #[error("must not contain control characters")]
+    ControlCharacter,
+    #[error("must be a valid email address")]
+    InvalidEmail,
+    #[error("must be an absolute http or https URL")]
+    InvalidUrl,
+    #[error("must contain only lowercase letters, digits and hyphens")]
+    InvalidSlug,
+}
+
+impl ValueError {
+    /// 検証エラーとして返す際の種類
+    pub fn code(&self) -> &'static str {
+        match self {
+            ValueError::Empty => "required",
+            ValueError::TooLong { .. } => "length",
+            ValueError::ControlCharacter => "characters",
+            ValueError::InvalidEmail => "email",
+            ValueError::InvalidUrl => "url",
+            ValueError::InvalidSlug => "slug",
+        }
+    }
+}
+
+/// 文字列の値オブジェクトに共通する実装
+macro_rules! string_value {
+    ($(#[$meta:meta])* $name:ident) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        pub struct $name(String);
+
+        impl $name {
+            /// 保存済みの値を復元する。規則の追加前に保存された値も読めるよう検証しない
+            pub fn from_stored(value: String) -> Self {
+                Self(value)
+            }
+
+            pub fn as_str(&self) -> &str {
+                &self.0
+            }
+
+            pub fn into_string(self) -> String {
+                self.0
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(&self.0)
+            }
+        }
+
+        impl PartialEq<str> for $name {
+            fn eq(&self, other: &str) -> bool {
+                self.0 == other
+            }
+        }
+
+        impl PartialEq<&str> for $name {
+            fn eq(&self, other: &&str) -> bool {
+                self.0 == *other
+            }
+        }
+    };
+}
+
+string_value!(
+    /// ユーザーの名前
+    Name
+);
+string_value!(
+    /// 正規化済みのメールアドレス
+    Email
+);
+string_value!(
+    /// 投稿のタイトル
+    Title
+);
+string_value!(
+    /// 投稿の内容
+    Content
+);
+string_value!(
+    /// Webhookの送信先のURL
+    WebhookUrl
+);
+string_value!(
+    /// URLでボードを指定する名前
+    BoardSlug
+);
+string_value!(
+    /// ボードの表示名
+    BoardName
+);
+string_value!(
+    /// ボードの説明
+    BoardDescription
+);
+
+impl Name {
+    pub const MAX_LENGTH: usize = 50;
+
+    pub fn parse(value: &str) -> Result<Self, ValueError> {
+        single_line(value, Self::MAX_LENGTH).map(Self)
+    }
+}
+
+impl Email {
+    /// RFC 5321の経路の上限
+    pub const MAX_LENGTH: usize = 254;
+
+    pub fn parse(value: &str) -> Result<Self, ValueError> {
+        let email = normalize_email(value);
+        if email.is_empty() {
+            return Err(ValueError::Empty);
+        }
+        if email.chars().count() > Self::MAX_LENGTH {
+            return Err(ValueError::TooLong {
+                max: Self::MAX_LENGTH,
+            });
+        }
+        if !is_email(&email) {
+            return Err(ValueError::InvalidEmail);
+        }
+        Ok(Self(email))
+    }
+}
+
+/// ハッシュ化前のパスワード。ログなどに出力されないよう`Display`は実装せず、`Debug`も伏せる
+#[derive(Clone, PartialEq, Eq)]
+pub struct Password(String);
+
+impl fmt::Debug for Password {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Password(***)")
+    }
+}
+
+impl Password {
+    /// Argon2に渡す入力の上限
+    pub const MAX_LENGTH: usize = 128;
+
+    /// 前後の空白もパスワードの一部として扱い、Unicodeの正規化のみ行う
+    pub fn parse(value: &str) -> Result<Self, ValueError> {
+        let password: String = value.nfc().collect();
+        if password.is_empty() {
+            return Err(ValueError::Empty);
+        }
+        if password.chars().count() > Self::MAX_LENGTH {
+            return Err(ValueError::TooLong {
+                max: Self::MAX_LENGTH,
+            });
+        }
+        Ok(Self(password))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Title {
+    pub const MAX_LENGTH: usize = 100;
+
+    pub fn parse(value: &str) -> Result<Self, ValueError> {
+        single_line(value, Self::MAX_LENGTH).map(Self)
+    }
+}
+
+impl Content {
+    pub const MAX_LENGTH: usize = 10_000;
+
+    /// 改行とタブは本文に含められる
+    pub fn parse(value: &str) -> Result<Self, ValueError> {
+        multi_line(value, Self::MAX_LENGTH).map(Self)
+    }
+}
+
+impl WebhookUrl {
+    pub const MAX_LENGTH: usize = 2048;
+
+    /// ホストを含むhttpまたはhttpsのURL。保存する値は正規化した形式にする
+    pub fn parse(value: &str) -> Result<Self, ValueError> {
+        let value = value.trim();
+        if value.is_empty() {
+            return Err(ValueError::Empty);
+        }
+        if value.chars().count() > Self::MAX_LENGTH {
+            return Err(ValueError::TooLong {
+                max: Self::MAX_LENGTH,
+            });
+        }
+        match url::Url::parse(value) {
+            Ok(url) if matches!(url.scheme(), "http" | "https") && url.has_host() => {
+                Ok(Self(url.into()))
+            }
+            _ => Err(ValueError::InvalidUrl),
+        }
+    }
+}
+
+impl BoardSlug {
+    pub const MAX_LENGTH: usize = 32;
+
+    /// 英小文字、数字とハイフンのみからなる文字列。先頭と末尾にはハイフンを置けない
+    pub fn parse(value: &str) -> Result<Self, ValueError> {
+        let value = value.trim();
+        if value.is_empty() {
+            return Err(ValueError::Empty);
+        }
+        if value.chars().count() > Self::MAX_LENGTH {
+            return Err(ValueError::TooLong {
+                max: Self::MAX_LENGTH,
+            });
+        }
+        if !value
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+            || value.starts_with('-')
+            || value.ends_with('-')
+        {
+            return Err(ValueError::InvalidSlug);
+        }
+        Ok(Self(value.to_string()))
+    }
+}
+
+impl BoardName {
+    pub const MAX_LENGTH: usize = 50;
+
+    pub fn parse(value: &str) -> Result<Self, ValueError> {
+        single_line(value, Self::MAX_LENGTH).map(Self)
+    }
+}
+
+impl BoardDescription {
+    pub const MAX_LENGTH: usize = 500;
+
+    /// 本文と同じく改行とタブを含められる
+    pub fn parse(value: &str) -> Result<Self, ValueError> {
+        multi_line(value, Self::MAX_LENGTH).map(Self)
+    }
+}
+
+/// 前後の空白を除いてNFCに正規化する。空になる場合はエラー
+fn normalize(value: &str) -> Result<String, ValueError> {
+    let value: String = value.trim().nfc().collect();
+    if value.is_empty() {
+        return Err(ValueError::Empty);
+    }
+    Ok(value)
+}
+
+/// 制御文字を含まない1行の文字列
+fn single_line(value: &str, max: usize) -> Result<String, ValueError> {
+    let value = normalize(value)?;
+    if value.chars().any(char::is_control) {
+        return Err(ValueError::ControlCharacter);
+    }
+    if value.chars().count() > max {
+        return Err(ValueError::TooLong { max });
+    }
+    Ok(value)
+}
+
+/// 改行とタブ以外の制御文字を含まない文字列
+fn multi_line(value: &str, max: usize) -> Result<String, ValueError> {
+    let value = normalize(value)?;
+    if value
+        .chars()
+        .any(|c| c.is_control() && !matches!(c, '\n' | '\r' | '\t'))
+    {
+        return Err(ValueError::ControlCharacter);
+    }
+    if value.chars().count() > max {
+        return Err(ValueError::TooLong { max });
+    }
+    Ok(value)
+}
+
+/// `local@domain`の形式か判定する。ローカル部はdot-atom、ドメインは2つ以上のラベルに限る
+fn is_email(email: &str) -> bool {
+    let Some((local, domain)) = email.split_once('@') else {
+        return false;
+    };
+    let is_atext = |c: char| c.is_ascii_alphanumeric() || "!#$%&'*+/=?^_`{|}~-".contains(c);
+    let local_ok = local.len() <= 64
+        && local
+            .split('.')
+            .all(|atom| !atom.is_empty() && atom.chars().all(is_atext));
+    let labels: Vec<&str> = domain.split('.').collect();
+    let domain_ok = labels.len() >= 2
+        && labels.iter().all(|label| {
+            (1..=63).contains(&label.len())
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+                && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+        });
+    local_ok && domain_ok
+}
+
+/// メールアドレスを比較・保存用に正規化する(前後の空白除去、NFC正規化と小文字化)
+pub fn normalize_email(email: &str) -> String {
+    email.trim().nfc().collect::<String>().to_lowercase()
+}
+
+/// ユーザーのロール。権限の小さい順に並ぶ
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Role {
+    #[default]
+    Member,
+    Moderator,
+    Admin,
+}
+
+impl Role {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Role::Member => "member",
+            Role::Moderator => "moderator",
+            Role::Admin => "admin",
+        }
+    }
+}
+
+impl fmt::Display for Role {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Role {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "member" => Ok(Role::Member),
+            "moderator" => Ok(Role::Moderator),
+            "admin" => Ok(Role::Admin),
+            _ => Err(format!("Unknown role: {}", s)),
+        }
+    }
+}
+
+/// 投稿の公開状態
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PostStatus {
+    /// 作成者のみ参照できる下書き
+    Draft,
+    /// `publish_at`に公開される予定の投稿。公開されるまでは作成者のみ参照できる
+    Scheduled,
+    Published,
+}
+
+impl PostStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PostStatus::Draft => "draft",
+            PostStatus::Scheduled => "scheduled",
+            PostStatus::Published => "published",
+        }
+    }
+}
+
+impl fmt::Display for PostStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for PostStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "draft" => Ok(PostStatus::Draft),
+            "scheduled" => Ok(PostStatus::Scheduled),
+            "published" => Ok(PostStatus::Published),
+            _ => Err(format!("Unknown post status: {}", s)),
+        }
+    }
+}
+
+/// Webhookで通知するイベントの種類
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WebhookEvent {
+    PostCreated,
+    PostUpdated,
+    PostDeleted,
+    ReplyCreated,
+    UserCreated,
+    UserRoleChanged,
+}
+
+impl WebhookEvent {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WebhookEvent::PostCreated => "post_created",
+            WebhookEvent::PostUpdated => "post_updated",
+            WebhookEvent::PostDeleted => "post_deleted",
+            WebhookEvent::ReplyCreated => "reply_created",
+            WebhookEvent::UserCreated => "user_created",
+            WebhookEvent::UserRoleChanged => "user_role_changed",
+        }
+    }
+}
+
+impl fmt::Display for WebhookEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for WebhookEvent {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "post_created" => Ok(WebhookEvent::PostCreated),
+            "post_updated" => Ok(WebhookEvent::PostUpdated),
+            "post_deleted" => Ok(WebhookEvent::PostDeleted),
+            "reply_created" => Ok(WebhookEvent::ReplyCreated),
+            "user_created" => Ok(WebhookEvent::UserCreated),
+            "user_role_changed" => Ok(WebhookEvent::UserRoleChanged),
+            _ => Err(format!("Unknown webhook event: {}", s)),
+        }
+    }
+}
+
+/// Webhookの配信の状態
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeliveryStatus {
+    /// 配信または再試行を待っている
+    Pending,
+    Succeeded,
+    /// 再試行の上限に達した。手動で再試行するまで配信しない
+    Dead,
+}
+
+impl DeliveryStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DeliveryStatus::Pending => "pending",
+            DeliveryStatus::Succeeded => "succeeded",
+            DeliveryStatus::Dead => "dead",
+        }
+    }
+}
+
+impl fmt::Display for DeliveryStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for DeliveryStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "pending" => Ok(DeliveryStatus::Pending),
+            "succeeded" => Ok(DeliveryStatus::Succeeded),
+            "dead" => Ok(DeliveryStatus::Dead),
+            _ => Err(format!("Unknown delivery status: {}", s)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod parse {
+        use super::*;
+
+        #[test]
+        fn test_trims_and_normalizes() {
+            assert_eq!(Name::parse("  Cafe\u{301} ").unwrap(), "Caf\u{e9}");
+            assert_eq!(Title::parse("\ttitle\n").unwrap(), "title");
+            assert_eq!(
+                Content::parse(" line 1\r\n\tline 2 ").unwrap(),
+                "line 1\r\n\tline 2"
+            );
+        }
+
+        #[test]
+        fn test_empty() {
+            assert_eq!(Name::parse(" "), Err(ValueError::Empty));
+            assert_eq!(Title::parse(""), Err(ValueError::Empty));
+            assert_eq!(Content::parse("\n\n"), Err(ValueError::Empty));
+            assert_eq!(Email::parse("  ").map(|_| ()), Err(ValueError::Empty));
+            assert!(Password::parse("").is_err());
+        }
+
+        #[test]
+        fn test_too_long() {
+            assert!(Title::parse(&"a".repeat(Title::MAX_LENGTH)).is_ok());
+            assert_eq!(
+                Title::parse(&"a".repeat(Title::MAX_LENGTH + 1)),
+                Err(ValueError::TooLong {
+                    max: Title::MAX_LENGTH
+                })
+            );
+            // バイト数ではなく文字数で数える
+            assert!(Name::parse(&"あ".repeat(Name::MAX_LENGTH)).is_ok());
+            assert!(Password::parse(&"a".repeat(Password::MAX_LENGTH + 1)).is_err());
+        }
+
+        #[test]
+        fn test_control_characters() {
+            assert_eq!(Title::parse("a\nb"), Err(ValueError::ControlCharacter));
+            assert_eq!(Name::parse("a\u{0}b"), Err(ValueError::ControlCharacter));
+            assert_eq!(Content::parse("a\u{7}b"), Err(ValueError::ControlCharacter));
+        }
+
+        #[test]
+        fn test_password_is_not_trimmed() {
+            assert_eq!(Password::parse(" pass ").unwrap().as_str(), " pass ");
+            assert_eq!(
+                Password::parse("Cafe\u{301}").unwrap(),
+                Password::parse("Caf\u{e9}").unwrap()
+            );
+        }
+
+        #[test]
+        fn test_email() {
+            assert_eq!(
+                Email::parse(" Alice.B+tag@Example.COM ").unwrap(),
+                "alice.b+tag@example.com"
+            );
+            for value in [
+                "alice",
+                "@example.com",
+                "alice@",
+                "alice@localhost",
+                "alice@@example.com",
+                "al ice@example.com",
+                ".alice@example.com",
+                "alice..b@example.com",
+                "alice@-example.com",
+                "alice@example..com",
+                "アリス@example.com",
+            ] {
+                assert_eq!(
+                    Email::parse(value),
+                    Err(ValueError::InvalidEmail),
+                    "{}",
+                    value
+                );
+            }
+        }
+
+        #[test]
+        fn test_board_slug() {
+            assert_eq!(BoardSlug::parse(" rust-2024 ").unwrap(), "rust-2024");
+            assert_eq!(BoardSlug::parse(""), Err(ValueError::Empty));
+            assert!(BoardSlug::parse(&"a".repeat(BoardSlug::MAX_LENGTH + 1)).is_err());
+            for value in ["Rust", "rust lang", "-rust", "rust-", "ラスト", "rust_lang"] {
+                assert_eq!(
+                    BoardSlug::parse(value),
+                    Err(ValueError::InvalidSlug),
+                    "{}",
+                    value
+                );
+            }
+        }
+
+        #[test]
+        fn test_webhook_url() {
+            assert_eq!(
+                WebhookUrl::parse(" https://Example.com ").unwrap(),
+                "https://example.com/"
+            );
+            assert!(WebhookUrl::parse("http://127.0.0.1:8080/hook?a=1").is_ok());
+            for value in [
+                "example.com/hook",
+                "ftp://example.com",
+                "mailto:a@example.com",
+            ] {
+                assert_eq!(
+                    WebhookUrl::parse(value),
+                    Err(ValueError::InvalidUrl),
+                    "{}",
+                    value
+                );
+            }
+        }
+    }
+}
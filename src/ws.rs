@@ -0,0 +1,609 @@
+//! `GET /ws`。購読したスレッドの返信と編集を配信し、入力中の通知を中継する
+
+use crate::error::AppError;
+use crate::service::auth::AuthenticatedUser;
+use crate::service::events::{BoardEvent, EventFilter, EventKind, Typing};
+use crate::value_object::{PostId, UserId};
+use crate::{error_response, event_model, unauthorized, ApiImpl};
+use axum::extract::ws::{close_code, CloseFrame, Message, WebSocket, WebSocketUpgrade};
+use axum::extract::{Query, State};
+use axum::http::HeaderMap;
+use axum::response::Response;
+use axum::routing::get;
+use axum::Router;
+use chrono::Utc;
+use openapi::models;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::time::Duration;
+use tokio::sync::broadcast::error::RecvError;
+use tokio::time::Instant;
+
+/// クライアントから受け付けるメッセージの最大のバイト数
+const MAX_MESSAGE_SIZE: usize = 4 * 1024;
+
+/// 同じスレッドへの入力中の通知を中継する最短の間隔
+const TYPING_INTERVAL: Duration = Duration::from_secs(1);
+
+/// 応答がなくても接続を保つPingの数
+const MAX_MISSED_PONGS: u32 = 2;
+
+pub fn router(api: ApiImpl) -> Router {
+    Router::new().route("/ws", get(upgrade)).with_state(api)
+}
+
+/// クライアントが送るメッセージ
+#[derive(Debug, PartialEq, Deserialize)]
+#[serde(
+    tag = "type",
+    rename_all = "snake_case",
+    rename_all_fields = "camelCase"
+)]
+enum ClientMessage {
+    /// スレッドを購読する。返信を指定した場合はその投稿が属するスレッドを購読する
+    Subscribe {
+        thread_id: PostId,
+    },
+    Unsubscribe {
+        thread_id: PostId,
+    },
+    /// 購読しているスレッドで入力中であることを通知する
+    Typing {
+        thread_id: PostId,
+    },
+    Ping,
+}
+
+/// サーバーが送るメッセージ
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(
+    tag = "type",
+    rename_all = "snake_case",
+    rename_all_fields = "camelCase"
+)]
+enum ServerMessage {
+    Subscribed {
+        thread_id: PostId,
+    },
+    Unsubscribed {
+        thread_id: PostId,
+    },
+    Event {
+        event: Box<models::BoardEvent>,
+    },
+    Typing {
+        thread_id: PostId,
+        user_id: UserId,
+        user_name: Option<String>,
+    },
+    Pong,
+    Error {
+        message: String,
+    },
+}
+
+impl ServerMessage {
+    fn error(message: impl ToString) -> Self {
+        ServerMessage::Error {
+            message: message.to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct UpgradeQuery {
+    access_token: Option<String>,
+}
+
+/// 接続を認証してからWebSocketに切り替える。接続はアクセストークンの有効期限で閉じる
+async fn upgrade(
+    State(api): State<ApiImpl>,
+    Query(query): Query<UpgradeQuery>,
+    headers: HeaderMap,
+    ws: WebSocketUpgrade,
+) -> Response {
+    let user = match api.authenticate_request(&headers, query.access_token.as_deref()) {
+        Ok(Ok(user)) => user,
+        Ok(Err(www_authenticate)) => return unauthorized(www_authenticate),
+        Err(e) => return error_response(e),
+    };
+    let user_name = match api.users.find(user.id) {
+        Ok(found) => found.map(|found| found.name.into_string()),
+        Err(e) => return error_response(e.into()),
+    };
+    let remaining = (user.expires_at - Utc::now()).to_std().unwrap_or_default();
+    let deadline = Instant::now() + remaining;
+    let connection = Connection::new(api, user, user_name);
+    ws.max_message_size(MAX_MESSAGE_SIZE)
+        .on_upgrade(move |socket| serve(socket, connection, deadline))
+}
+
+/// 1つの接続の購読の状態
+struct Connection {
+    api: ApiImpl,
+    user: AuthenticatedUser,
+    user_name: Option<String>,
+    threads: HashSet<PostId>,
+    /// スレッドごとに最後に入力中の通知を中継した時刻
+    typing_sent: HashMap<PostId, Instant>,
+}
+
+impl Connection {
+    fn new(api: ApiImpl, user: AuthenticatedUser, user_name: Option<String>) -> Self {
+        Self {
+            api,
+            user,
+            user_name,
+            threads: HashSet::new(),
+            typing_sent: HashMap::new(),
+        }
+    }
+
+    /// クライアントのメッセージを処理し、応答があれば返す
+    fn receive(&mut self, text: &str, now: Instant) -> Option<ServerMessage> {
+        let message = match serde_json::from_str(text) {
+            Ok(message) => message,
+            Err(e) => return Some(ServerMessage::error(e)),
+        };
+        let result = match message {
+            ClientMessage::Subscribe { thread_id } => self.subscribe(thread_id).map(Some),
+            ClientMessage::Unsubscribe { thread_id } => {
+                self.threads.remove(&thread_id);
+                self.typing_sent.remove(&thread_id);
+                Ok(Some(ServerMessage::Unsubscribed { thread_id }))
+            }
+            ClientMessage::Typing { thread_id } => Ok(self.typing(thread_id, now)),
+            ClientMessage::Ping => Ok(Some(ServerMessage::Pong)),
+        };
+        result.unwrap_or_else(|e| {
+            tracing::error!(error = %e);
+            Some(ServerMessage::error("internal error"))
+        })
+    }
+
+    fn subscribe(&mut self, post_id: PostId) -> Result<ServerMessage, AppError> {
+        // 公開前の投稿は作成者以外には存在しないものとして扱う
+        let Some(post) = self.api.find_visible(&self.user, post_id)? else {
+            return Ok(ServerMessage::error("post not found"));
+        };
+        let thread_id = self.api.thread_id(&post)?;
+        let max_threads = self.api.config.websocket.max_threads;
+        if !self.threads.contains(&thread_id) && self.threads.len() >= max_threads {
+            return Ok(ServerMessage::error(format!(
+                "cannot subscribe to more than {} threads",
+                max_threads
+            )));
+        }
+        self.threads.insert(thread_id);
+        Ok(ServerMessage::Subscribed { thread_id })
+    }
+
+    fn typing(&mut self, thread_id: PostId, now: Instant) -> Option<ServerMessage> {
+        if !self.threads.contains(&thread_id) {
+            return Some(ServerMessage::error("not subscribed to the thread"));
+        }
+        // キー入力ごとに送られても一定の間隔でのみ中継する
+        if let Some(sent) = self.typing_sent.get(&thread_id) {
+            if now.duration_since(*sent) < TYPING_INTERVAL {
+                return None;
+            }
+        }
+        self.typing_sent.insert(thread_id, now);
+        self.api.events.typing(Typing {
+            thread_id,
+            user_id: self.user.id,
+            user_name: self.user_name.clone(),
+        });
+        None
+    }
+
+    /// 購読しているスレッドのイベントを送るメッセージにする
+    fn event(&mut self, event: &BoardEvent) -> Option<ServerMessage> {
+        if !self.threads.contains(&event.thread_id) {
+            return None;
+        }
+        // スレッドごと削除された場合は購読を終える
+        if event.kind == EventKind::PostDeleted && event.post.id == event.thread_id {
+            self.threads.remove(&event.thread_id);
+            self.typing_sent.remove(&event.thread_id);
+        }
+        Some(ServerMessage::Event {
+            event: Box::new(event_model(event)),
+        })
+    }
+
+    /// 購読しているスレッドでの他のユーザーの入力中の通知を送るメッセージにする
+    fn typing_signal(&self, typing: &Typing) -> Option<ServerMessage> {
+        if !self.threads.contains(&typing.thread_id) || typing.user_id == self.user.id {
+            return None;
+        }
+        Some(ServerMessage::Typing {
+            thread_id: typing.thread_id,
+            user_id: typing.user_id,
+            user_name: typing.user_name.clone(),
+        })
+    }
+}
+
+/// 接続が閉じるまでメッセージを送受信する
+///
+/// 送信が詰まっている間に配信されたイベントは保持している範囲で後から送り、
+/// 入力中の通知は捨てる。1つの送信が`send_timeout`を超えた接続は切断する
+async fn serve(mut socket: WebSocket, mut connection: Connection, deadline: Instant) {
+    let config = connection.api.config.websocket.clone();
+    let send_timeout = Duration::from_secs(config.send_timeout);
+    let ping_interval = Duration::from_secs(config.ping_interval);
+    let mut events = connection
+        .api
+        .events
+        .subscribe(None, EventFilter::default());
+    let mut typing = connection.api.events.subscribe_typing();
+    let mut ping = tokio::time::interval_at(Instant::now() + ping_interval, ping_interval);
+    let mut missed_pongs = 0;
+    let expired = tokio::time::sleep_until(deadline);
+    tokio::pin!(expired);
+
+    loop {
+        let message = tokio::select! {
+            received = socket.recv() => {
+                let Some(Ok(received)) = received else {
+                    break;
+                };
+                // どのメッセージも接続が生きている印とする
+                missed_pongs = 0;
+                match received {
+                    Message::Text(text) => connection.receive(&text, Instant::now()),
+                    Message::Binary(_) => {
+                        Some(ServerMessage::error("binary messages are not supported"))
+                    }
+                    // Pingへの応答は次の送信と一緒に送られる
+                    Message::Ping(_) | Message::Pong(_) => None,
+                    Message::Close(_) => break,
+                }
+            }
+            event = events.next() => match event {
+                Some(event) => connection.event(&event),
+                None => break,
+            },
+            signal = typing.recv() => match signal {
+                Ok(signal) => connection.typing_signal(&signal),
+                // 入力中の通知は取りこぼしても次の通知で補われる
+                Err(RecvError::Lagged(_)) => None,
+                Err(RecvError::Closed) => break,
+            },
+            _ = ping.tick() => {
+                if missed_pongs >= MAX_MISSED_PONGS {
+                    tracing::debug!(user_id = connection.user.id, "closing unresponsive websocket");
+                    break;
+                }
+                missed_pongs += 1;
+                if send(&mut socket, Message::Ping(Vec::new()), send_timeout).await.is_err() {
+                    break;
+                }
+                continue;
+            }
+            _ = &mut expired => {
+                let close = CloseFrame {
+                    code: close_code::POLICY,
+                    reason: "access token expired".into(),
+                };
+                let _ = send(&mut socket, Message::Close(Some(close)), send_timeout).await;
+                break;
+            }
+        };
+        let Some(message) = message else {
+            continue;
+        };
+        let text = match serde_json::to_string(&message) {
+            Ok(text) => text,
+            Err(e) => {
+                tracing::error!(error = %e, "failed to serialize websocket message");
+                continue;
+            }
+        };
+        if send(&mut socket, Message::Text(text), send_timeout)
+            .await
+            .is_err()
+        {
+            break;
+        }
+    }
+}
+
+/// メッセージを送る。`timeout`以内に送れない場合は受信が追いつかない接続とみなす
+async fn send(socket: &mut WebSocket, message: Message, timeout: Duration) -> Result<(), ()> {
+    match tokio::time::timeout(timeout, socket.send(message)).await {
+        Ok(Ok(())) => Ok(()),
+        Ok(Err(e)) => {
+            tracing::debug!(error = %e, "failed to send websocket message");
+            Err(())
+        }
+        Err(_) => {
+            tracing::info!("disconnecting slow websocket consumer");
+            Err(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entity;
+    use crate::tests::{api, general_board};
+    use crate::value_object::{Content, Role, Title};
+    use axum::http::header::AUTHORIZATION;
+    use axum::http::{HeaderValue, StatusCode};
+    use futures_util::{SinkExt, StreamExt};
+    use std::net::SocketAddr;
+    use std::sync::Arc;
+    use tokio_tungstenite::tungstenite::client::IntoClientRequest;
+    use tokio_tungstenite::tungstenite::{self, Error as WsError};
+
+    fn insert_post(api: &ApiImpl, parent_id: Option<PostId>) -> entity::Post {
+        let post = entity::Post::new(
+            1,
+            general_board(api),
+            Title::parse("title").unwrap(),
+            Content::parse("content").unwrap(),
+            parent_id,
+        );
+        api.posts.insert(post.clone()).unwrap();
+        post
+    }
+
+    fn member(id: UserId) -> AuthenticatedUser {
+        AuthenticatedUser {
+            id,
+            role: Role::Member,
+            jti: PostId::new_v4().to_string(),
+            expires_at: Utc::now() + chrono::Duration::minutes(5),
+        }
+    }
+
+    fn message(thread_id: PostId, kind: &str) -> String {
+        format!(r#"{{"type":"{}","threadId":"{}"}}"#, kind, thread_id)
+    }
+
+    mod connection {
+        use super::*;
+
+        #[tokio::test]
+        async fn test_subscribe_resolves_thread() {
+            let api = api();
+            let root = insert_post(&api, None);
+            let reply = insert_post(&api, Some(root.id));
+            let mut connection = Connection::new(api, member(1), None);
+            let now = Instant::now();
+
+            assert_eq!(
+                connection.receive(&message(reply.id, "subscribe"), now),
+                Some(ServerMessage::Subscribed { thread_id: root.id })
+            );
+            assert_eq!(
+                connection.receive(&message(PostId::new_v4(), "subscribe"), now),
+                Some(ServerMessage::error("post not found"))
+            );
+            assert_eq!(
+                connection.receive(r#"{"type":"ping"}"#, now),
+                Some(ServerMessage::Pong)
+            );
+            assert!(matches!(
+                connection.receive(r#"{"type":"subscribe"}"#, now),
+                Some(ServerMessage::Error { .. })
+            ));
+        }
+
+        #[tokio::test]
+        async fn test_unpublished_post_is_visible_only_to_author() {
+            let api = api();
+            let draft = entity::Post::unpublished(
+                1,
+                general_board(&api),
+                Title::parse("title").unwrap(),
+                Content::parse("content").unwrap(),
+                None,
+            );
+            api.posts.insert(draft.clone()).unwrap();
+            let mut author = Connection::new(api.clone(), member(1), None);
+            let mut other = Connection::new(api, member(2), None);
+            let now = Instant::now();
+
+            assert_eq!(
+                other.receive(&message(draft.id, "subscribe"), now),
+                Some(ServerMessage::error("post not found"))
+            );
+            assert_eq!(
+                author.receive(&message(draft.id, "subscribe"), now),
+                Some(ServerMessage::Subscribed {
+                    thread_id: draft.id
+                })
+            );
+        }
+
+        #[tokio::test]
+        async fn test_subscription_limit() {
+            let mut api = api();
+            Arc::make_mut(&mut api.config).websocket.max_threads = 1;
+            let first = insert_post(&api, None);
+            let second = insert_post(&api, None);
+            let mut connection = Connection::new(api, member(1), None);
+            let now = Instant::now();
+            connection.receive(&message(first.id, "subscribe"), now);
+
+            assert_eq!(
+                connection.receive(&message(second.id, "subscribe"), now),
+                Some(ServerMessage::error(
+                    "cannot subscribe to more than 1 threads"
+                ))
+            );
+            connection.receive(&message(first.id, "unsubscribe"), now);
+            assert_eq!(
+                connection.receive(&message(second.id, "subscribe"), now),
+                Some(ServerMessage::Subscribed {
+                    thread_id: second.id
+                })
+            );
+        }
+
+        #[tokio::test]
+        async fn test_typing_is_throttled() {
+            let api = api();
+            let thread = insert_post(&api, None);
+            let mut signals = api.events.subscribe_typing();
+            let mut connection = Connection::new(api, member(1), Some("name".to_string()));
+            let now = Instant::now();
+
+            assert_eq!(
+                connection.receive(&message(thread.id, "typing"), now),
+                Some(ServerMessage::error("not subscribed to the thread"))
+            );
+            connection.receive(&message(thread.id, "subscribe"), now);
+            for offset in [0, 500, 1000] {
+                let at = now + Duration::from_millis(offset);
+                assert_eq!(connection.receive(&message(thread.id, "typing"), at), None);
+            }
+
+            let signal = signals.try_recv().unwrap();
+            assert_eq!(signal.user_name.as_deref(), Some("name"));
+            assert!(connection.typing_signal(&signal).is_none());
+            assert!(signals.try_recv().is_ok());
+            assert!(signals.try_recv().is_err());
+        }
+
+        #[tokio::test]
+        async fn test_deleted_thread_ends_subscription() {
+            let api = api();
+            let thread = insert_post(&api, None);
+            let events = api.events.clone();
+            let mut connection = Connection::new(api, member(1), None);
+            connection.receive(&message(thread.id, "subscribe"), Instant::now());
+            let mut subscription = events.subscribe(None, EventFilter::default());
+
+            events.publish(EventKind::PostDeleted, thread.id, thread.clone(), None);
+            let event = subscription.next().await.unwrap();
+
+            assert!(connection.event(&event).is_some());
+            assert!(connection.threads.is_empty());
+            assert!(connection.event(&event).is_none());
+        }
+    }
+
+    mod upgrade {
+        use super::*;
+
+        async fn spawn(api: ApiImpl) -> SocketAddr {
+            let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+            let addr = listener.local_addr().unwrap();
+            tokio::spawn(async move { axum::serve(listener, router(api)).await });
+            addr
+        }
+
+        async fn connect(
+            addr: SocketAddr,
+            authorization: Option<String>,
+        ) -> Result<
+            tokio_tungstenite::WebSocketStream<
+                tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>,
+            >,
+            WsError,
+        > {
+            let mut request = format!("ws://{}/ws", addr).into_client_request().unwrap();
+            if let Some(authorization) = authorization {
+                request.headers_mut().insert(
+                    AUTHORIZATION,
+                    HeaderValue::from_str(&authorization).unwrap(),
+                );
+            }
+            tokio_tungstenite::connect_async(request)
+                .await
+                .map(|(socket, _)| socket)
+        }
+
+        fn authorization(api: &ApiImpl, user_id: UserId) -> Option<String> {
+            let token = api
+                .keys
+                .create_token(
+                    &user_id.to_string(),
+                    Role::Member,
+                    api.config.access_token_lifetime(),
+                )
+                .unwrap();
+            Some(format!("Bearer {}", token))
+        }
+
+        async fn receive<S>(socket: &mut S) -> serde_json::Value
+        where
+            S: StreamExt<Item = Result<tungstenite::Message, WsError>> + Unpin,
+        {
+            let next = tokio::time::timeout(Duration::from_secs(5), socket.next());
+            match next.await.unwrap().unwrap().unwrap() {
+                tungstenite::Message::Text(text) => serde_json::from_str(&text).unwrap(),
+                other => panic!("unexpected message: {:?}", other),
+            }
+        }
+
+        #[tokio::test]
+        async fn test_missing_authorization_is_rejected() {
+            let addr = spawn(api()).await;
+
+            let result = connect(addr, None).await;
+
+            let Err(WsError::Http(response)) = result else {
+                panic!("handshake was not rejected");
+            };
+            assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+        }
+
+        #[tokio::test]
+        async fn test_access_token_query() {
+            let api = api();
+            let addr = spawn(api.clone()).await;
+            let token = authorization(&api, 1).unwrap().replace("Bearer ", "");
+
+            let (mut socket, _) = tokio_tungstenite::connect_async(format!(
+                "ws://{}/ws?access_token={}",
+                addr, token
+            ))
+            .await
+            .unwrap();
+            socket
+                .send(r#"{"type":"ping"}"#.to_string().into())
+                .await
+                .unwrap();
+
+            assert_eq!(receive(&mut socket).await["type"], "pong");
+        }
+
+        #[tokio::test]
+        async fn test_relays_replies_and_typing() {
+            let api = api();
+            let thread = insert_post(&api, None);
+            let addr = spawn(api.clone()).await;
+            let mut alice = connect(addr, authorization(&api, 1)).await.unwrap();
+            let mut bob = connect(addr, authorization(&api, 2)).await.unwrap();
+            for socket in [&mut alice, &mut bob] {
+                let subscribe = message(thread.id, "subscribe");
+                socket.send(subscribe.into()).await.unwrap();
+                assert_eq!(receive(socket).await["type"], "subscribed");
+            }
+
+            alice
+                .send(message(thread.id, "typing").into())
+                .await
+                .unwrap();
+            let typing = receive(&mut bob).await;
+            let reply = insert_post(&api, Some(thread.id));
+            api.publish(EventKind::ReplyCreated, reply.clone());
+
+            assert_eq!(typing["type"], "typing");
+            assert_eq!(typing["userId"], 1);
+            for socket in [&mut alice, &mut bob] {
+                let event = receive(socket).await;
+                assert_eq!(event["type"], "event");
+                assert_eq!(event["event"]["type"], "reply_created");
+                assert_eq!(event["event"]["threadId"], thread.id.to_string());
+                assert_eq!(event["event"]["post"]["id"], reply.id.to_string());
+            }
+        }
+    }
+}